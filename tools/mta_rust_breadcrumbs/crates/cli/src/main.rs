@@ -7,8 +7,12 @@ use anyhow::{Context, Result};
 use clap::{Parser, Subcommand, ValueEnum};
 use indicatif::{ProgressBar, ProgressStyle};
 use mta_breadcrumbs_core::{
-    format_output, format_output_grouped, get_breadcrumb, scan_file, BreadcrumbScanner, Language,
-    NodeFilter, OutputFormat, ScanConfig,
+    format_dot, format_file_markdown, format_json_with_indent, format_language_comparison,
+    format_language_comparison_markdown, format_markdown_list, format_output_grouped_with_indent,
+    format_output_with_indent, format_toml, format_toml_list, get_breadcrumb,
+    get_breadcrumbs_for_range, scan_file, write_run_summary, AsyncStats, BreadcrumbScanner,
+    ComplexFunction, FileOutline, IndentStyle, Language, LanguageComparison, LongParameterList,
+    NodeFilter, OutputFormat, Redefinition, ScanConfig, Symbol,
 };
 use std::fs;
 use std::path::PathBuf;
@@ -43,6 +47,8 @@ Examples:
   mta-breadcrumbs --grouped                   # Group by language
   mta-breadcrumbs file src/main.py            # Single file outline
   mta-breadcrumbs breadcrumb src/main.py 10 5 # Breadcrumb at line 10, col 5
+  mta-breadcrumbs symbols src/                # Flat symbol table (FQNs)
+  mta-breadcrumbs toc src/service.py          # Markdown table of contents
 "#)]
 pub struct Args {
     /// Subcommand to run
@@ -81,6 +87,26 @@ pub struct Args {
     #[arg(long)]
     pub no_control_flow: bool,
 
+    /// Omit outline nodes (and their children) spanning fewer than N lines
+    #[arg(long)]
+    pub min_lines: Option<usize>,
+
+    /// Include top-level constant/assignment declarations in the outline
+    #[arg(long)]
+    pub module_constants: bool,
+
+    /// Outline JS/TS `describe`/`it` test blocks, named by their titles
+    #[arg(long)]
+    pub test_blocks: bool,
+
+    /// Record each file's module docstring / leading block comment as `module_doc`
+    #[arg(long)]
+    pub module_doc: bool,
+
+    /// Record each node's position among its siblings ("method 3 of 7")
+    #[arg(long)]
+    pub relative_depth: bool,
+
     /// Include preview text
     #[arg(long, default_value_t = true)]
     pub preview: bool,
@@ -93,10 +119,76 @@ pub struct Args {
     #[arg(long, action = clap::ArgAction::Append)]
     pub ignore: Vec<String>,
 
+    /// Only scan files matching these globs, relative to the root (can be specified multiple times)
+    #[arg(long, action = clap::ArgAction::Append)]
+    pub include: Vec<String>,
+
+    /// List functions declaring more than N parameters (excluding self/this)
+    #[arg(long)]
+    pub max_params: Option<usize>,
+
+    /// List functions whose cyclomatic complexity exceeds N
+    #[arg(long, conflicts_with = "max_params")]
+    pub max_complexity: Option<usize>,
+
+    /// List names defined more than once in the same scope
+    #[arg(long, conflicts_with_all = ["max_params", "max_complexity"])]
+    pub find_redefinitions: bool,
+
+    /// Only list files that had parse errors, with their error count and first error location
+    #[arg(long, conflicts_with_all = ["max_params", "max_complexity", "find_redefinitions"])]
+    pub summary_only_errors: bool,
+
+    /// Report async function and await counts per file (modernization tracking)
+    #[arg(
+        long,
+        conflicts_with_all = ["max_params", "max_complexity", "find_redefinitions", "summary_only_errors"]
+    )]
+    pub async_stats: bool,
+
+    /// Compare files, functions, classes and error rates across languages in one table
+    #[arg(
+        long,
+        conflicts_with_all = ["max_params", "max_complexity", "find_redefinitions", "summary_only_errors", "async_stats"]
+    )]
+    pub compare: bool,
+
+    /// Only scan files directly in the root directory (no subdirectories)
+    #[arg(long)]
+    pub no_recursion: bool,
+
+    /// Maximum directory depth to walk (1 = root directory only)
+    #[arg(long, conflicts_with = "no_recursion")]
+    pub max_directory_depth: Option<usize>,
+
+    /// Exclude files whose parse quality (1 - error nodes / total nodes) is below this value
+    #[arg(long)]
+    pub min_quality: Option<f64>,
+
+    /// Remove this leading prefix from every file path in the output
+    #[arg(long)]
+    pub strip_prefix: Option<String>,
+
+    /// Pin the metadata timestamp (RFC 3339); defaults to SOURCE_DATE_EPOCH, then the current time
+    #[arg(long, value_name = "RFC3339")]
+    pub timestamp: Option<String>,
+
+    /// Record a zero scan duration so repeated runs produce identical output
+    #[arg(long)]
+    pub reproducible: bool,
+
+    /// After writing output, print a one-line JSON run summary to stderr
+    #[arg(long)]
+    pub emit_run_summary: bool,
+
     /// Number of threads for parallel processing (default: auto)
     #[arg(long)]
     pub threads: Option<usize>,
 
+    /// Indentation for JSON output: 2, 4 or tab
+    #[arg(long, default_value = "2")]
+    pub json_indent: IndentStyle,
+
     /// Verbose output
     #[arg(short, long)]
     pub verbose: bool,
@@ -128,19 +220,60 @@ pub enum Commands {
         #[arg(short, long)]
         line: Option<usize>,
 
-        /// Column number (0-indexed) - only for single file
+        /// Column as a UTF-8 byte offset (0-indexed) - only for single file
         #[arg(short, long, default_value_t = 0)]
         column: usize,
+
+        /// First line (1-indexed) of a range to get breadcrumbs for - only for single file
+        #[arg(long, conflicts_with = "line", requires = "end_line")]
+        start_line: Option<usize>,
+
+        /// Last line (1-indexed, inclusive) of the range - only for single file
+        #[arg(long, requires = "start_line")]
+        end_line: Option<usize>,
+
+        /// Separator between path components in plain-text output (e.g. "/", "::", " › ")
+        #[arg(long, default_value = " > ")]
+        separator: String,
+
+        /// Show component names only, or prefix each with its node type (`class Foo > method bar`)
+        #[arg(long, value_enum, default_value_t = PathStyleArg::Name)]
+        path_style: PathStyleArg,
+    },
+
+    /// List defined symbols with fully-qualified names - accepts file or directory
+    Symbols {
+        /// Path to file or directory (recursive)
+        #[arg(default_value = ".")]
+        path: PathBuf,
+    },
+
+    /// Markdown table of contents of a file's named scopes
+    Toc {
+        /// Path to file
+        path: PathBuf,
     },
 }
 
+/// Breadcrumb path style argument
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PathStyleArg {
+    /// Component names only: `Foo > bar`
+    Name,
+    /// Node type and name: `class Foo > method bar`
+    Typed,
+}
+
 /// Output format argument
 #[derive(ValueEnum, Clone, Debug)]
 pub enum OutputFormatArg {
     Json,
     Yaml,
+    Toml,
     Ansi,
     Summary,
+    Markdown,
+    Dot,
 }
 
 impl From<OutputFormatArg> for OutputFormat {
@@ -148,8 +281,11 @@ impl From<OutputFormatArg> for OutputFormat {
         match arg {
             OutputFormatArg::Json => OutputFormat::Json,
             OutputFormatArg::Yaml => OutputFormat::Yaml,
+            OutputFormatArg::Toml => OutputFormat::Toml,
             OutputFormatArg::Ansi => OutputFormat::Ansi,
             OutputFormatArg::Summary => OutputFormat::Summary,
+            OutputFormatArg::Markdown => OutputFormat::Markdown,
+            OutputFormatArg::Dot => OutputFormat::Dot,
         }
     }
 }
@@ -161,6 +297,7 @@ pub enum LanguageFilter {
     Node,
     Javascript,
     Typescript,
+    Csharp,
 }
 
 fn main() -> Result<()> {
@@ -169,9 +306,21 @@ fn main() -> Result<()> {
     match &args.command {
         Some(Commands::Scan { path }) => run_scan(path, &args),
         Some(Commands::File { path }) => run_file(path, &args),
-        Some(Commands::Breadcrumb { path, line, column }) => {
-            run_breadcrumb(path, *line, *column, &args)
+        Some(Commands::Breadcrumb {
+            path,
+            line,
+            column,
+            start_line,
+            end_line,
+            separator,
+            path_style,
+        }) => {
+            let range = start_line.zip(*end_line);
+            let include_type = *path_style == PathStyleArg::Typed;
+            run_breadcrumb(path, *line, *column, range, separator, include_type, &args)
         }
+        Some(Commands::Symbols { path }) => run_symbols(path, &args),
+        Some(Commands::Toc { path }) => run_toc(path, &args),
         None => run_scan(&args.path, &args),
     }
 }
@@ -184,6 +333,7 @@ fn build_config(path: &PathBuf, args: &Args) -> ScanConfig {
         LanguageFilter::Node => vec![Language::JavaScript, Language::TypeScript],
         LanguageFilter::Javascript => vec![Language::JavaScript],
         LanguageFilter::Typescript => vec![Language::TypeScript],
+        LanguageFilter::Csharp => vec![Language::CSharp],
     });
 
     // Build node filter
@@ -197,12 +347,22 @@ fn build_config(path: &PathBuf, args: &Args) -> ScanConfig {
     if args.no_control_flow {
         node_filter.exclude_control_flow = true;
     }
+    node_filter.min_lines = args.min_lines;
 
     // Build config
     let mut config = ScanConfig::new(path.clone())
         .with_ignore_patterns(args.ignore.clone())
+        .with_include_patterns(args.include.clone())
         .with_node_filter(node_filter)
-        .with_preview(args.preview, args.preview_length);
+        .with_preview(args.preview, args.preview_length)
+        .with_module_constants(args.module_constants)
+        .with_test_blocks(args.test_blocks)
+        .with_module_doc(args.module_doc)
+        .with_sibling_info(args.relative_depth)
+        .with_complexity(
+            args.max_complexity.is_some() || matches!(args.format, OutputFormatArg::Summary),
+        )
+        .with_json_indent(args.json_indent);
 
     if let Some(threads) = args.threads {
         config = config.with_threads(threads);
@@ -212,11 +372,30 @@ fn build_config(path: &PathBuf, args: &Args) -> ScanConfig {
         config = config.with_language_filter(languages);
     }
 
+    if args.no_recursion {
+        config = config.with_max_directory_depth(1);
+    } else if let Some(depth) = args.max_directory_depth {
+        config = config.with_max_directory_depth(depth);
+    }
+
+    if let Some(min_quality) = args.min_quality {
+        config = config.with_min_quality(min_quality);
+    }
+
+    if let Some(ref prefix) = args.strip_prefix {
+        config = config.with_path_transform(prefix.clone());
+    }
+
+    config = config
+        .with_fixed_timestamp(args.timestamp.clone())
+        .with_reproducible(args.reproducible);
+
     config
 }
 
 fn run_scan(path: &PathBuf, args: &Args) -> Result<()> {
     let config = build_config(path, args);
+    let json_indent = config.json_indent;
 
     // Show progress spinner
     let spinner = if args.verbose && atty::is(atty::Stream::Stderr) {
@@ -245,32 +424,246 @@ fn run_scan(path: &PathBuf, args: &Args) -> Result<()> {
         ));
     }
 
-    // Format output
-    let format: OutputFormat = args.format.clone().into();
-    let output = if args.grouped {
-        format_output_grouped(&result, format)?
+    if let Some(max_params) = args.max_params {
+        // Report long parameter lists instead of the outline
+        let functions = result.long_parameter_lists(max_params);
+        write_long_parameter_lists(&functions, json_indent, args)?;
+    } else if let Some(max_complexity) = args.max_complexity {
+        // Report over-complex functions instead of the outline
+        let functions = result.complex_functions(max_complexity);
+        write_complex_functions(&functions, json_indent, args)?;
+    } else if args.find_redefinitions {
+        // Report same-scope redefinitions instead of the outline
+        let redefinitions = result.find_redefinitions();
+        write_redefinitions(&redefinitions, json_indent, args)?;
+    } else if args.summary_only_errors {
+        // Report only files with parse errors
+        let files = result.error_files();
+        write_error_files(&files, json_indent, args)?;
+    } else if args.async_stats {
+        // Report async usage instead of the outline
+        write_async_stats(&result.async_stats(), json_indent, args)?;
+    } else if args.compare {
+        // Report per-language aggregates instead of the outline
+        write_language_comparison(&result.to_grouped().compare_languages(), json_indent, args)?;
     } else {
-        format_output(&result, format)?
-    };
+        // Format output
+        let format: OutputFormat = args.format.clone().into();
+        let output = if args.grouped {
+            format_output_grouped_with_indent(&result, format, json_indent)?
+        } else {
+            format_output_with_indent(&result, format, json_indent)?
+        };
 
-    // Write output
-    write_output(&output, args.output.as_ref())?;
+        // Write output
+        write_output(&output, args.output.as_ref())?;
+    }
+
+    // Run telemetry goes to stderr so stdout stays machine-readable
+    if args.emit_run_summary {
+        write_run_summary(&mut std::io::stderr(), &result.run_summary(0))?;
+    }
 
     Ok(())
 }
 
+fn write_long_parameter_lists(
+    functions: &[LongParameterList],
+    json_indent: IndentStyle,
+    args: &Args,
+) -> Result<()> {
+    let format: OutputFormat = args.format.clone().into();
+    let output = match format {
+        OutputFormat::Json => format_json_with_indent(functions, json_indent)?,
+        OutputFormat::Yaml => serde_yaml::to_string(functions)?,
+        OutputFormat::Toml => format_toml_list("functions", functions)?,
+        OutputFormat::Dot => anyhow::bail!(DOT_OUTLINES_ONLY),
+        OutputFormat::Ansi | OutputFormat::Summary | OutputFormat::Markdown => format_lines(
+            functions
+                .iter()
+                .map(|f| {
+                    format!(
+                        "{}:{} {} {} ({} params)",
+                        f.path.display(),
+                        f.start_line,
+                        f.node_type.label(),
+                        f.name.as_deref().unwrap_or("<anon>"),
+                        f.param_count
+                    )
+                })
+                .collect(),
+            format,
+        ),
+    };
+
+    write_output(&output, args.output.as_ref())
+}
+
+fn write_complex_functions(
+    functions: &[ComplexFunction],
+    json_indent: IndentStyle,
+    args: &Args,
+) -> Result<()> {
+    let format: OutputFormat = args.format.clone().into();
+    let output = match format {
+        OutputFormat::Json => format_json_with_indent(functions, json_indent)?,
+        OutputFormat::Yaml => serde_yaml::to_string(functions)?,
+        OutputFormat::Toml => format_toml_list("functions", functions)?,
+        OutputFormat::Dot => anyhow::bail!(DOT_OUTLINES_ONLY),
+        OutputFormat::Ansi | OutputFormat::Summary | OutputFormat::Markdown => format_lines(
+            functions
+                .iter()
+                .map(|f| {
+                    format!(
+                        "{}:{} {} {} (complexity {})",
+                        f.path.display(),
+                        f.start_line,
+                        f.node_type.label(),
+                        f.name.as_deref().unwrap_or("<anon>"),
+                        f.complexity
+                    )
+                })
+                .collect(),
+            format,
+        ),
+    };
+
+    write_output(&output, args.output.as_ref())
+}
+
+fn write_redefinitions(
+    redefinitions: &[Redefinition],
+    json_indent: IndentStyle,
+    args: &Args,
+) -> Result<()> {
+    let format: OutputFormat = args.format.clone().into();
+    let output = match format {
+        OutputFormat::Json => format_json_with_indent(redefinitions, json_indent)?,
+        OutputFormat::Yaml => serde_yaml::to_string(redefinitions)?,
+        OutputFormat::Toml => format_toml_list("redefinitions", redefinitions)?,
+        OutputFormat::Dot => anyhow::bail!(DOT_OUTLINES_ONLY),
+        OutputFormat::Ansi | OutputFormat::Summary | OutputFormat::Markdown => format_lines(
+            redefinitions
+                .iter()
+                .map(|r| {
+                    let lines: Vec<String> = r.lines.iter().map(|l| l.to_string()).collect();
+                    format!(
+                        "{}: '{}' defined at lines {}",
+                        r.path.display(),
+                        r.name,
+                        lines.join(", ")
+                    )
+                })
+                .collect(),
+            format,
+        ),
+    };
+
+    write_output(&output, args.output.as_ref())
+}
+
+fn write_error_files(files: &[&FileOutline], json_indent: IndentStyle, args: &Args) -> Result<()> {
+    let format: OutputFormat = args.format.clone().into();
+    let output = match format {
+        OutputFormat::Json => format_json_with_indent(files, json_indent)?,
+        OutputFormat::Yaml => serde_yaml::to_string(files)?,
+        OutputFormat::Toml => format_toml_list("files", files)?,
+        OutputFormat::Dot => anyhow::bail!(DOT_OUTLINES_ONLY),
+        OutputFormat::Ansi | OutputFormat::Summary | OutputFormat::Markdown => format_lines(
+            files
+                .iter()
+                .map(|f| {
+                    let location = f
+                        .first_error()
+                        .map(|e| format!(", first at {}:{}", e.line, e.column))
+                        .unwrap_or_default();
+                    format!("{}: {} errors{}", f.path.display(), f.errors.len(), location)
+                })
+                .collect(),
+            format,
+        ),
+    };
+
+    write_output(&output, args.output.as_ref())
+}
+
+fn write_async_stats(stats: &[AsyncStats], json_indent: IndentStyle, args: &Args) -> Result<()> {
+    let format: OutputFormat = args.format.clone().into();
+    let output = match format {
+        OutputFormat::Json => format_json_with_indent(stats, json_indent)?,
+        OutputFormat::Yaml => serde_yaml::to_string(stats)?,
+        OutputFormat::Toml => format_toml_list("files", stats)?,
+        OutputFormat::Dot => anyhow::bail!(DOT_OUTLINES_ONLY),
+        OutputFormat::Ansi | OutputFormat::Summary | OutputFormat::Markdown => {
+            let async_functions: usize = stats.iter().map(|s| s.async_functions).sum();
+            let await_points: usize = stats.iter().map(|s| s.await_points).sum();
+            let lines: Vec<String> = stats
+                .iter()
+                .map(|s| {
+                    format!(
+                        "{}: {} async functions, {} await points",
+                        s.path.display(),
+                        s.async_functions,
+                        s.await_points
+                    )
+                })
+                .chain(std::iter::once(format!(
+                    "total: {} async functions, {} await points",
+                    async_functions, await_points
+                )))
+                .collect();
+            format_lines(lines, format)
+        }
+    };
+
+    write_output(&output, args.output.as_ref())
+}
+
+fn write_language_comparison(
+    rows: &[LanguageComparison],
+    json_indent: IndentStyle,
+    args: &Args,
+) -> Result<()> {
+    let format: OutputFormat = args.format.clone().into();
+    let output = match format {
+        OutputFormat::Json => format_json_with_indent(rows, json_indent)?,
+        OutputFormat::Yaml => serde_yaml::to_string(rows)?,
+        OutputFormat::Toml => format_toml_list("languages", rows)?,
+        OutputFormat::Markdown => format_language_comparison_markdown(rows),
+        OutputFormat::Ansi | OutputFormat::Summary => format_language_comparison(rows),
+        OutputFormat::Dot => anyhow::bail!(DOT_OUTLINES_ONLY),
+    };
+
+    write_output(&output, args.output.as_ref())
+}
+
+/// `--format dot` graphs outline trees; reports and breadcrumbs have no tree to draw
+const DOT_OUTLINES_ONLY: &str = "--format dot is only supported for outlines";
+
+/// Join plain-text listing lines; `--format markdown` renders them as a bullet list
+fn format_lines(lines: Vec<String>, format: OutputFormat) -> String {
+    match format {
+        OutputFormat::Markdown => format_markdown_list(&lines),
+        _ => lines.join("\n"),
+    }
+}
+
 fn run_file(path: &PathBuf, args: &Args) -> Result<()> {
     let config = build_config(path, args);
+    let json_indent = config.json_indent;
 
     let outline = scan_file(path, &config).context("Failed to parse file")?;
 
     // Format output
     let format: OutputFormat = args.format.clone().into();
     let output = match format {
-        OutputFormat::Json => serde_json::to_string_pretty(&outline)?,
+        OutputFormat::Json => format_json_with_indent(&outline, json_indent)?,
         OutputFormat::Yaml => serde_yaml::to_string(&outline)?,
+        OutputFormat::Toml => format_toml(&outline)?,
         OutputFormat::Ansi => format_file_ansi(&outline),
         OutputFormat::Summary => format_file_summary(&outline),
+        OutputFormat::Markdown => format_file_markdown(&outline),
+        OutputFormat::Dot => format_dot(&outline),
     };
 
     write_output(&output, args.output.as_ref())?;
@@ -278,23 +671,69 @@ fn run_file(path: &PathBuf, args: &Args) -> Result<()> {
     Ok(())
 }
 
-fn run_breadcrumb(path: &PathBuf, line: Option<usize>, column: usize, args: &Args) -> Result<()> {
+fn run_breadcrumb(
+    path: &PathBuf,
+    line: Option<usize>,
+    column: usize,
+    range: Option<(usize, usize)>,
+    separator: &str,
+    include_type: bool,
+    args: &Args,
+) -> Result<()> {
     let config = build_config(path, args);
+    let json_indent = config.json_indent;
 
     // Check if path is a file or directory
     if path.is_file() {
         // Single file mode
-        if let Some(line) = line {
+        if let Some((start_line, end_line)) = range {
+            // Get breadcrumbs for every line in the range, parsing the file once
+            let breadcrumbs = get_breadcrumbs_for_range(path, start_line, end_line, &config)
+                .context("Failed to get breadcrumbs")?;
+
+            let format: OutputFormat = args.format.clone().into();
+            let output = match format {
+                OutputFormat::Json => {
+                    let crumbs: Vec<_> = breadcrumbs.iter().map(|(_, b)| b).collect();
+                    format_json_with_indent(&crumbs, json_indent)?
+                }
+                OutputFormat::Yaml => {
+                    let crumbs: Vec<_> = breadcrumbs.iter().map(|(_, b)| b).collect();
+                    serde_yaml::to_string(&crumbs)?
+                }
+                OutputFormat::Toml => {
+                    let crumbs: Vec<_> = breadcrumbs.iter().map(|(_, b)| b).collect();
+                    format_toml_list("breadcrumbs", &crumbs)?
+                }
+                OutputFormat::Ansi => breadcrumbs
+                    .iter()
+                    .map(|(line, b)| format!("{:>5}  {}", line, format_breadcrumb_ansi(b)))
+                    .collect::<Vec<_>>()
+                    .join("\n"),
+                OutputFormat::Dot => anyhow::bail!(DOT_OUTLINES_ONLY),
+                OutputFormat::Summary | OutputFormat::Markdown => breadcrumbs
+                    .iter()
+                    .map(|(line, b)| format!("{}: {}", line, b.path_with(separator, include_type)))
+                    .collect::<Vec<_>>()
+                    .join("\n"),
+            };
+
+            write_output(&output, args.output.as_ref())?;
+        } else if let Some(line) = line {
             // Get breadcrumb at specific position
             let breadcrumb =
                 get_breadcrumb(path, line, column, &config).context("Failed to get breadcrumb")?;
 
             let format: OutputFormat = args.format.clone().into();
             let output = match format {
-                OutputFormat::Json => serde_json::to_string_pretty(&breadcrumb)?,
+                OutputFormat::Json => format_json_with_indent(&breadcrumb, json_indent)?,
                 OutputFormat::Yaml => serde_yaml::to_string(&breadcrumb)?,
+                OutputFormat::Toml => format_toml(&breadcrumb)?,
                 OutputFormat::Ansi => format_breadcrumb_ansi(&breadcrumb),
-                OutputFormat::Summary => breadcrumb.path(),
+                OutputFormat::Dot => anyhow::bail!(DOT_OUTLINES_ONLY),
+                OutputFormat::Summary | OutputFormat::Markdown => {
+                    breadcrumb.path_with(separator, include_type)
+                }
             };
 
             write_output(&output, args.output.as_ref())?;
@@ -304,10 +743,13 @@ fn run_breadcrumb(path: &PathBuf, line: Option<usize>, column: usize, args: &Arg
 
             let format: OutputFormat = args.format.clone().into();
             let output = match format {
-                OutputFormat::Json => serde_json::to_string_pretty(&outline)?,
+                OutputFormat::Json => format_json_with_indent(&outline, json_indent)?,
                 OutputFormat::Yaml => serde_yaml::to_string(&outline)?,
+                OutputFormat::Toml => format_toml(&outline)?,
                 OutputFormat::Ansi => format_file_ansi(&outline),
                 OutputFormat::Summary => format_file_summary(&outline),
+                OutputFormat::Markdown => format_file_markdown(&outline),
+                OutputFormat::Dot => format_dot(&outline),
             };
 
             write_output(&output, args.output.as_ref())?;
@@ -340,9 +782,9 @@ fn run_breadcrumb(path: &PathBuf, line: Option<usize>, column: usize, args: &Arg
 
         let format: OutputFormat = args.format.clone().into();
         let output = if args.grouped {
-            format_output_grouped(&result, format)?
+            format_output_grouped_with_indent(&result, format, json_indent)?
         } else {
-            format_output(&result, format)?
+            format_output_with_indent(&result, format, json_indent)?
         };
 
         write_output(&output, args.output.as_ref())?;
@@ -353,6 +795,56 @@ fn run_breadcrumb(path: &PathBuf, line: Option<usize>, column: usize, args: &Arg
     Ok(())
 }
 
+fn run_toc(path: &PathBuf, args: &Args) -> Result<()> {
+    let config = build_config(path, args);
+    let outline = scan_file(path, &config).context("Failed to parse file")?;
+
+    write_output(&outline.to_markdown_toc(), args.output.as_ref())
+}
+
+fn run_symbols(path: &PathBuf, args: &Args) -> Result<()> {
+    let config = build_config(path, args);
+    let json_indent = config.json_indent;
+
+    let outlines = if path.is_file() {
+        vec![scan_file(path, &config).context("Failed to parse file")?]
+    } else if path.is_dir() {
+        let scanner = BreadcrumbScanner::new(config).context("Failed to create scanner")?;
+        scanner.scan().context("Failed to scan directory")?.files
+    } else {
+        anyhow::bail!("Path does not exist: {}", path.display());
+    };
+
+    let symbols: Vec<Symbol> = outlines.iter().flat_map(|o| o.symbol_table()).collect();
+
+    let format: OutputFormat = args.format.clone().into();
+    let output = match format {
+        OutputFormat::Json => format_json_with_indent(&symbols, json_indent)?,
+        OutputFormat::Yaml => serde_yaml::to_string(&symbols)?,
+        OutputFormat::Toml => format_toml_list("symbols", &symbols)?,
+        OutputFormat::Dot => anyhow::bail!(DOT_OUTLINES_ONLY),
+        OutputFormat::Ansi | OutputFormat::Summary | OutputFormat::Markdown => format_lines(
+            symbols
+                .iter()
+                .map(|s| {
+                    format!(
+                        "{} ({}) {}-{}",
+                        s.fqn,
+                        s.node_type.label(),
+                        s.start_line,
+                        s.end_line
+                    )
+                })
+                .collect(),
+            format,
+        ),
+    };
+
+    write_output(&output, args.output.as_ref())?;
+
+    Ok(())
+}
+
 fn write_output(output: &str, path: Option<&PathBuf>) -> Result<()> {
     if let Some(path) = path {
         fs::write(path, output).context("Failed to write output file")?;
@@ -389,7 +881,14 @@ fn format_file_ansi(outline: &mta_breadcrumbs_core::FileOutline) -> String {
             } else {
                 0
             },
+            csharp_files: if outline.language == mta_breadcrumbs_core::Language::CSharp {
+                1
+            } else {
+                0
+            },
             files_with_errors: if outline.has_errors() { 1 } else { 0 },
+            async_functions: outline.async_functions(),
+            await_points: outline.await_points,
         },
         metadata: ScanMetadata {
             scan_duration_ms: 0,
@@ -427,6 +926,9 @@ fn format_node_summary(node: &mta_breadcrumbs_core::OutlineNode, indent: usize)
     let indent_str = "  ".repeat(indent);
 
     let name = node.name.as_deref().unwrap_or("");
+    if !node.decorators.is_empty() {
+        output.push_str(&format!("{}{}\n", indent_str, node.decorators.join(" ")));
+    }
     output.push_str(&format!(
         "{}{} {} ({}:{})\n",
         indent_str,
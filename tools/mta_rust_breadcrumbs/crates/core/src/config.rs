@@ -4,8 +4,10 @@
 //! for controlling how the scanner processes source files.
 
 use crate::models::Language;
+use crate::output::IndentStyle;
 use globset::{Glob, GlobSet, GlobSetBuilder};
 use ignore::gitignore::{Gitignore, GitignoreBuilder};
+use ignore::Match;
 use std::path::{Path, PathBuf};
 use thiserror::Error;
 
@@ -36,6 +38,9 @@ pub struct NodeFilter {
 
     /// Exclude control flow nodes (if, for, while, etc.)
     pub exclude_control_flow: bool,
+
+    /// Omit nodes spanning fewer lines than this, together with their children
+    pub min_lines: Option<usize>,
 }
 
 impl NodeFilter {
@@ -68,6 +73,9 @@ pub struct ScanConfig {
     /// Custom ignore patterns
     pub ignore_patterns: Vec<String>,
 
+    /// Include patterns relative to root (empty = every file)
+    pub include_patterns: Vec<String>,
+
     /// Path to custom ignore file
     pub ignore_file: Option<PathBuf>,
 
@@ -91,6 +99,42 @@ pub struct ScanConfig {
 
     /// Whether to include hidden files
     pub include_hidden: bool,
+
+    /// Maximum directory depth to walk (None = unlimited, 1 = root only)
+    pub max_directory_depth: Option<usize>,
+
+    /// Indentation for pretty-printed JSON output
+    pub json_indent: IndentStyle,
+
+    /// Exclude files whose parse quality falls below this threshold (0.0-1.0)
+    pub min_quality: Option<f64>,
+
+    /// Leading path prefix removed from every file's path/absolute_path
+    pub strip_prefix: Option<PathBuf>,
+
+    /// Write serialized paths with `/` separators regardless of platform
+    pub forward_slashes: bool,
+
+    /// Pinned metadata timestamp (overrides `SOURCE_DATE_EPOCH` and the clock)
+    pub fixed_timestamp: Option<String>,
+
+    /// Record a zero scan duration in metadata
+    pub reproducible: bool,
+
+    /// Include module-level constant/assignment declarations in outlines
+    pub module_constants: bool,
+
+    /// Compute cyclomatic complexity for callables
+    pub complexity: bool,
+
+    /// Outline JS/TS `describe`/`it` test blocks as nodes named by their title
+    pub test_blocks: bool,
+
+    /// Record each node's position among its siblings
+    pub sibling_info: bool,
+
+    /// Capture the module docstring / leading block comment as a file description
+    pub module_doc: bool,
 }
 
 impl Default for ScanConfig {
@@ -99,6 +143,7 @@ impl Default for ScanConfig {
             root: PathBuf::from("."),
             language_filter: None,
             ignore_patterns: Vec::new(),
+            include_patterns: Vec::new(),
             ignore_file: None,
             threads: num_cpus(),
             max_file_size: 10 * 1024 * 1024, // 10 MB
@@ -107,6 +152,18 @@ impl Default for ScanConfig {
             node_filter: NodeFilter::default(),
             follow_symlinks: false,
             include_hidden: false,
+            max_directory_depth: None,
+            json_indent: IndentStyle::default(),
+            min_quality: None,
+            strip_prefix: None,
+            forward_slashes: true,
+            fixed_timestamp: None,
+            reproducible: false,
+            module_constants: false,
+            complexity: false,
+            test_blocks: false,
+            sibling_info: false,
+            module_doc: false,
         }
     }
 }
@@ -132,6 +189,13 @@ impl ScanConfig {
         self
     }
 
+    /// Only scan files matching at least one of these globs, relative to root
+    /// (builder pattern); applied on top of the ignore and language filters
+    pub fn with_include_patterns(mut self, patterns: Vec<String>) -> Self {
+        self.include_patterns = patterns;
+        self
+    }
+
     /// Set ignore file path (builder pattern)
     pub fn with_ignore_file(mut self, path: PathBuf) -> Self {
         self.ignore_file = Some(path);
@@ -174,6 +238,110 @@ impl ScanConfig {
         self.include_hidden = include;
         self
     }
+
+    /// Set maximum directory depth to walk (builder pattern)
+    pub fn with_max_directory_depth(mut self, depth: usize) -> Self {
+        self.max_directory_depth = Some(depth);
+        self
+    }
+
+    /// Set JSON output indentation (builder pattern)
+    pub fn with_json_indent(mut self, indent: IndentStyle) -> Self {
+        self.json_indent = indent;
+        self
+    }
+
+    /// Exclude files with a parse quality below `threshold`
+    pub fn with_min_quality(mut self, threshold: f64) -> Self {
+        self.min_quality = Some(threshold);
+        self
+    }
+
+    /// Strip `prefix_to_strip` from the start of every file path in scan results
+    pub fn with_path_transform(mut self, prefix_to_strip: String) -> Self {
+        self.strip_prefix = Some(PathBuf::from(prefix_to_strip));
+        self
+    }
+
+    /// Include top-level constants (`MAX_RETRIES = 3`, `const API_URL = ...`) in outlines
+    pub fn with_module_constants(mut self, include: bool) -> Self {
+        self.module_constants = include;
+        self
+    }
+
+    /// Compute `OutlineNode::complexity` for functions and methods
+    pub fn with_complexity(mut self, enabled: bool) -> Self {
+        self.complexity = enabled;
+        self
+    }
+
+    /// Treat `describe('...', () => {...})` / `it('...', ...)` calls as outline nodes
+    pub fn with_test_blocks(mut self, enabled: bool) -> Self {
+        self.test_blocks = enabled;
+        self
+    }
+
+    /// Fill `OutlineNode::sibling_index`/`sibling_count` ("method 3 of 7")
+    pub fn with_sibling_info(mut self, enabled: bool) -> Self {
+        self.sibling_info = enabled;
+        self
+    }
+
+    /// Fill `FileOutline::module_doc` with the first paragraph of the module docstring
+    /// (Python) or leading block comment (JS/TS/C#)
+    pub fn with_module_doc(mut self, enabled: bool) -> Self {
+        self.module_doc = enabled;
+        self
+    }
+
+    /// Normalize serialized paths to forward slashes (default: true)
+    pub fn with_forward_slashes(mut self, enabled: bool) -> Self {
+        self.forward_slashes = enabled;
+        self
+    }
+
+    /// Pin the metadata timestamp (RFC 3339) for reproducible output
+    pub fn with_fixed_timestamp(mut self, timestamp: Option<String>) -> Self {
+        self.fixed_timestamp = timestamp;
+        self
+    }
+
+    /// Zero the scan duration in metadata so repeated runs are byte-identical
+    pub fn with_reproducible(mut self, enabled: bool) -> Self {
+        self.reproducible = enabled;
+        self
+    }
+
+    /// Timestamp for scan metadata: the pinned value, then `SOURCE_DATE_EPOCH`, then now
+    pub fn metadata_timestamp(&self) -> String {
+        if let Some(ref timestamp) = self.fixed_timestamp {
+            return timestamp.clone();
+        }
+        std::env::var("SOURCE_DATE_EPOCH")
+            .ok()
+            .and_then(|epoch| epoch.trim().parse::<i64>().ok())
+            .and_then(|secs| chrono::DateTime::from_timestamp(secs, 0))
+            .map(|dt| dt.to_rfc3339())
+            .unwrap_or_else(|| chrono::Utc::now().to_rfc3339())
+    }
+
+    /// Apply the configured path transform; paths outside the prefix are unchanged
+    pub fn transform_path(&self, path: &Path) -> PathBuf {
+        let path = match self.strip_prefix {
+            Some(ref prefix) => path.strip_prefix(prefix).unwrap_or(path),
+            None => path,
+        };
+        self.normalize_separators(path)
+    }
+
+    /// Rewrite `\` separators to `/` when `forward_slashes` is enabled
+    pub fn normalize_separators(&self, path: &Path) -> PathBuf {
+        if self.forward_slashes {
+            PathBuf::from(path.to_string_lossy().replace('\\', "/"))
+        } else {
+            path.to_path_buf()
+        }
+    }
 }
 
 /// Get number of available CPUs
@@ -183,6 +351,9 @@ fn num_cpus() -> usize {
         .unwrap_or(4)
 }
 
+/// Tool-specific ignore file read from the project root on top of `.gitignore`
+pub const TOOL_IGNORE_FILE: &str = ".breadcrumbsignore";
+
 /// Filter for ignoring files and directories
 pub struct IgnoreFilter {
     /// Gitignore rules
@@ -194,6 +365,12 @@ pub struct IgnoreFilter {
     /// Default ignore patterns
     default_ignores: GlobSet,
 
+    /// Include patterns (None = include everything)
+    include_globs: Option<GlobSet>,
+
+    /// Root that include patterns are relative to
+    root: PathBuf,
+
     /// Whether to include hidden files
     include_hidden: bool,
 }
@@ -202,11 +379,18 @@ impl IgnoreFilter {
     /// Create a new ignore filter from config
     pub fn new(config: &ScanConfig) -> Result<Self, ConfigError> {
         // Build gitignore
-        let gitignore = Self::build_gitignore(&config.root)?;
+        let gitignore = Self::build_gitignore(config)?;
 
         // Build custom globs
         let custom_globs = Self::build_globset(&config.ignore_patterns)?;
 
+        // Build include globs
+        let include_globs = if config.include_patterns.is_empty() {
+            None
+        } else {
+            Some(Self::build_globset(&config.include_patterns)?)
+        };
+
         // Build default ignores
         let default_patterns = vec![
             "**/node_modules/**",
@@ -240,19 +424,33 @@ impl IgnoreFilter {
             gitignore,
             custom_globs,
             default_ignores,
+            include_globs,
+            root: config.root.clone(),
             include_hidden: config.include_hidden,
         })
     }
 
-    /// Build gitignore from root directory
-    fn build_gitignore(root: &Path) -> Result<Option<Gitignore>, ConfigError> {
-        let gitignore_path = root.join(".gitignore");
-        if !gitignore_path.exists() {
+    /// Build gitignore from `.gitignore` (or the configured ignore file) and the tool ignore file
+    ///
+    /// Both files go into one matcher in that order, so the tool file's rules win
+    /// and a `!pattern` there re-includes a gitignored path.
+    fn build_gitignore(config: &ScanConfig) -> Result<Option<Gitignore>, ConfigError> {
+        let base_ignore = config
+            .ignore_file
+            .clone()
+            .unwrap_or_else(|| config.root.join(".gitignore"));
+        let layers: Vec<PathBuf> = [base_ignore, config.root.join(TOOL_IGNORE_FILE)]
+            .into_iter()
+            .filter(|path| path.exists())
+            .collect();
+        if layers.is_empty() {
             return Ok(None);
         }
 
-        let mut builder = GitignoreBuilder::new(root);
-        builder.add(&gitignore_path);
+        let mut builder = GitignoreBuilder::new(&config.root);
+        for layer in &layers {
+            builder.add(layer);
+        }
 
         match builder.build() {
             Ok(gi) => Ok(Some(gi)),
@@ -273,6 +471,11 @@ impl IgnoreFilter {
     }
 
     /// Check if a path should be ignored
+    ///
+    /// Layers apply in increasing precedence: built-in excludes, `.gitignore`,
+    /// `.breadcrumbsignore`, then `--ignore` patterns. A `!pattern` in either
+    /// ignore file re-includes a path excluded by an earlier layer. Hidden files
+    /// are skipped before any layer unless `include_hidden` is set.
     pub fn should_ignore(&self, path: &Path, is_dir: bool) -> bool {
         let path_str = path.to_string_lossy();
 
@@ -286,23 +489,27 @@ impl IgnoreFilter {
         }
 
         // Check default ignores
-        if self.default_ignores.is_match(&*path_str) {
-            return true;
-        }
+        let mut ignored = self.default_ignores.is_match(&*path_str);
 
-        // Check custom patterns
-        if self.custom_globs.is_match(&*path_str) {
-            return true;
-        }
-
-        // Check gitignore
+        // Check .gitignore and the tool ignore file
         if let Some(ref gi) = self.gitignore {
-            if gi.matched(path, is_dir).is_ignore() {
-                return true;
+            match gi.matched(path, is_dir) {
+                Match::Ignore(_) => ignored = true,
+                Match::Whitelist(_) => ignored = false,
+                Match::None => {}
             }
         }
 
-        false
+        // Check custom patterns
+        ignored || self.custom_globs.is_match(&*path_str)
+    }
+
+    /// Check if a file matches the include patterns (always true when there are none)
+    pub fn matches_include_patterns(&self, path: &Path) -> bool {
+        match self.include_globs {
+            Some(ref globs) => globs.is_match(path.strip_prefix(&self.root).unwrap_or(path)),
+            None => true,
+        }
     }
 
     /// Check if path matches language filter
@@ -358,4 +565,49 @@ mod tests {
         ));
         assert!(filter.matches_language_filter(Path::new("test.ts"), &None));
     }
+
+    #[test]
+    fn test_include_patterns() {
+        let config = ScanConfig::new(PathBuf::from("/repo"));
+        let filter = IgnoreFilter::new(&config).unwrap();
+        assert!(filter.matches_include_patterns(Path::new("/repo/anything.py")));
+
+        let config = ScanConfig::new(PathBuf::from("/repo"))
+            .with_include_patterns(vec!["src/**".to_string(), "*.pyi".to_string()]);
+        let filter = IgnoreFilter::new(&config).unwrap();
+        assert!(filter.matches_include_patterns(Path::new("/repo/src/api/app.py")));
+        assert!(filter.matches_include_patterns(Path::new("/repo/stubs/app.pyi")));
+        assert!(!filter.matches_include_patterns(Path::new("/repo/tests/test_app.py")));
+    }
+
+    #[test]
+    fn test_forward_slashes() {
+        let windows_path = PathBuf::from("src\\pkg\\module.py");
+
+        let config = ScanConfig::default();
+        assert!(config.forward_slashes);
+        assert_eq!(config.transform_path(&windows_path), PathBuf::from("src/pkg/module.py"));
+
+        let config = ScanConfig::default().with_forward_slashes(false);
+        assert_eq!(config.transform_path(&windows_path), windows_path);
+    }
+
+    #[test]
+    fn test_tool_ignore_file_overrides_gitignore() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join(".gitignore"), "*.gen.py\n").unwrap();
+        std::fs::write(dir.path().join(TOOL_IGNORE_FILE), "!keep.gen.py\n").unwrap();
+        let keep = dir.path().join("keep.gen.py");
+        let other = dir.path().join("other.gen.py");
+
+        let filter = IgnoreFilter::new(&ScanConfig::new(dir.path().to_path_buf())).unwrap();
+        assert!(!filter.should_ignore(&keep, false));
+        assert!(filter.should_ignore(&other, false));
+
+        // `--ignore` patterns still take precedence over the ignore files
+        let config = ScanConfig::new(dir.path().to_path_buf())
+            .with_ignore_patterns(vec!["**/keep.gen.py".to_string()]);
+        let filter = IgnoreFilter::new(&config).unwrap();
+        assert!(filter.should_ignore(&keep, false));
+    }
 }
@@ -7,11 +7,13 @@ use crate::config::{IgnoreFilter, ScanConfig};
 use crate::models::{
     FileOutline, Language, OutlineMap, ScanMetadata, ScanStats,
 };
-use crate::parsers::{create_parser, parse_file, ParserError};
+use crate::parsers::{
+    create_parser_for_path, extract_module_doc, line_column_to_byte, parse_file, ParserError,
+};
 use rayon::prelude::*;
 use std::fs;
 use std::path::{Path, PathBuf};
-use std::time::Instant;
+use std::time::{Duration, Instant};
 use thiserror::Error;
 use walkdir::WalkDir;
 
@@ -56,6 +58,9 @@ impl BreadcrumbScanner {
 
         // Parse files (in parallel if configured)
         let files: Vec<FileOutline> = if self.config.threads == 1 {
+            // Strictly serial, in sorted path order, for reproducible debugging
+            let mut source_files = source_files;
+            source_files.sort_by(|a, b| a.0.cmp(&b.0));
             source_files
                 .into_iter()
                 .filter_map(|(path, lang)| self.parse_file(&path, &lang))
@@ -74,11 +79,21 @@ impl BreadcrumbScanner {
             })
         };
 
+        // Rewrite paths before serialization (strip a CI checkout prefix, normalize separators)
+        let mut files = files;
+        for file in &mut files {
+            self.rewrite_paths(file);
+        }
+
         // Calculate stats
         let stats = self.calculate_stats(&files);
 
         // Build metadata
-        let duration = start.elapsed();
+        let duration = if self.config.reproducible {
+            Duration::ZERO
+        } else {
+            start.elapsed()
+        };
         let file_count = files.len();
         let metadata = ScanMetadata {
             scan_duration_ms: duration.as_millis() as u64,
@@ -87,28 +102,40 @@ impl BreadcrumbScanner {
             } else {
                 file_count as f64
             },
-            timestamp: chrono::Utc::now().to_rfc3339(),
+            timestamp: self.config.metadata_timestamp(),
             tool_version: env!("CARGO_PKG_VERSION").to_string(),
         };
 
         Ok(OutlineMap {
-            root: self.config.root.clone(),
+            root: self.config.normalize_separators(&self.config.root),
             files,
             stats,
             metadata,
         })
     }
 
+    /// Rewrite paths before serialization (e.g. strip a CI checkout prefix)
+    fn rewrite_paths(&self, file: &mut FileOutline) {
+        if self.config.strip_prefix.is_some() || self.config.forward_slashes {
+            file.path = self.config.transform_path(&file.path);
+            file.absolute_path = self.config.transform_path(&file.absolute_path);
+        }
+    }
+
     /// Find all source files matching the configuration
     fn find_source_files(&self) -> Result<Vec<(PathBuf, Language)>, ScanError> {
         let mut files = Vec::new();
 
-        let walker = WalkDir::new(&self.config.root)
-            .follow_links(self.config.follow_symlinks)
+        let mut walk_dir = WalkDir::new(&self.config.root).follow_links(self.config.follow_symlinks);
+        if let Some(depth) = self.config.max_directory_depth {
+            walk_dir = walk_dir.max_depth(depth);
+        }
+
+        let walker = walk_dir
             .into_iter()
             .filter_entry(|e| {
-                // Skip ignored directories
-                if e.file_type().is_dir() {
+                // Skip ignored directories (never the root itself, which may be hidden)
+                if e.depth() > 0 && e.file_type().is_dir() {
                     return !self.ignore_filter.should_ignore(e.path(), true);
                 }
                 true
@@ -134,6 +161,11 @@ impl BreadcrumbScanner {
                 continue;
             }
 
+            // Check include patterns
+            if !self.ignore_filter.matches_include_patterns(path) {
+                continue;
+            }
+
             // Check file size
             if let Ok(metadata) = entry.metadata() {
                 if metadata.len() as usize > self.config.max_file_size {
@@ -163,10 +195,14 @@ impl BreadcrumbScanner {
         let total_lines = source.lines().count();
 
         // Parse the file
-        let (nodes, errors) = match parse_file(&source, language, &self.config) {
-            Ok(result) => result,
-            Err(_) => (Vec::new(), Vec::new()),
-        };
+        let parsed = parse_file(path, &source, language, &self.config).unwrap_or_default();
+
+        // Drop low-confidence outlines when a quality threshold is configured
+        if let Some(min_quality) = self.config.min_quality {
+            if parsed.parse_quality < min_quality {
+                return None;
+            }
+        }
 
         // Calculate absolute path
         let absolute_path = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
@@ -182,8 +218,11 @@ impl BreadcrumbScanner {
             absolute_path,
             language: language.clone(),
             total_lines,
-            nodes,
-            errors,
+            nodes: parsed.nodes,
+            errors: parsed.errors,
+            parse_quality: parsed.parse_quality,
+            await_points: parsed.await_points,
+            module_doc: module_doc(&source, language, &self.config),
         })
     }
 
@@ -205,8 +244,14 @@ impl BreadcrumbScanner {
             .iter()
             .filter(|f| f.language == Language::TypeScript)
             .count();
+        let csharp_files = files
+            .iter()
+            .filter(|f| f.language == Language::CSharp)
+            .count();
 
         let files_with_errors = files.iter().filter(|f| f.has_errors()).count();
+        let async_functions = files.iter().map(|f| f.async_functions()).sum();
+        let await_points = files.iter().map(|f| f.await_points).sum();
 
         ScanStats {
             total_files,
@@ -215,7 +260,10 @@ impl BreadcrumbScanner {
             python_files,
             javascript_files,
             typescript_files,
+            csharp_files,
             files_with_errors,
+            async_functions,
+            await_points,
         }
     }
 }
@@ -233,20 +281,32 @@ pub fn scan_file(path: &Path, config: &ScanConfig) -> Result<FileOutline, ScanEr
     let source = fs::read_to_string(path)?;
     let total_lines = source.lines().count();
 
-    let (nodes, errors) = parse_file(&source, &language, config)?;
+    let parsed = parse_file(path, &source, &language, config)?;
 
     let absolute_path = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
 
     Ok(FileOutline {
-        path: path.to_path_buf(),
-        absolute_path,
-        language,
+        path: config.transform_path(path),
+        absolute_path: config.transform_path(&absolute_path),
+        language: language.clone(),
         total_lines,
-        nodes,
-        errors,
+        nodes: parsed.nodes,
+        errors: parsed.errors,
+        parse_quality: parsed.parse_quality,
+        await_points: parsed.await_points,
+        module_doc: module_doc(&source, &language, config),
     })
 }
 
+/// Module description for a file, when enabled in the config
+fn module_doc(source: &str, language: &Language, config: &ScanConfig) -> Option<String> {
+    if config.module_doc {
+        extract_module_doc(source, language)
+    } else {
+        None
+    }
+}
+
 /// Get breadcrumb at a specific position in a file
 pub fn get_breadcrumb(
     path: &Path,
@@ -254,17 +314,11 @@ pub fn get_breadcrumb(
     column: usize,
     config: &ScanConfig,
 ) -> Result<crate::models::Breadcrumb, ScanError> {
-    let ext = path
-        .extension()
-        .and_then(|e| e.to_str())
-        .ok_or_else(|| ScanError::ParserError(ParserError::ParseError("No extension".to_string())))?;
-
-    let language = Language::from_extension(ext)
-        .ok_or_else(|| ScanError::ParserError(ParserError::UnsupportedLanguage(Language::Python)))?;
+    let language = breadcrumb_language(path)?;
 
     let source = fs::read_to_string(path)?;
 
-    let mut parser = create_parser(&language)?;
+    let mut parser = create_parser_for_path(path, &language)?;
 
     // Convert line/column to byte offset
     let byte_offset = line_column_to_byte(&source, line, column);
@@ -274,31 +328,55 @@ pub fn get_breadcrumb(
         .map_err(ScanError::from)
 }
 
-/// Convert line/column (1-indexed) to byte offset
-fn line_column_to_byte(source: &str, line: usize, column: usize) -> usize {
-    let mut current_line = 1;
-
-    for (idx, ch) in source.char_indices() {
-        if current_line == line {
-            let line_start = idx;
-            let mut col = 0;
-            for (col_idx, col_ch) in source[idx..].char_indices() {
-                if col == column {
-                    return line_start + col_idx;
-                }
-                if col_ch == '\n' {
-                    break;
-                }
-                col += 1;
-            }
-            return line_start + column.min(source[idx..].find('\n').unwrap_or(source[idx..].len()));
+/// Get breadcrumbs for every line in `start_line..=end_line` (1-indexed)
+///
+/// The file is parsed once and the tree is reused for every line. Each line
+/// is resolved at its first non-blank column; blank lines resolve at column 0
+/// and therefore report the enclosing scope. Lines past the end of the file
+/// are skipped.
+pub fn get_breadcrumbs_for_range(
+    path: &Path,
+    start_line: usize,
+    end_line: usize,
+    config: &ScanConfig,
+) -> Result<Vec<(usize, crate::models::Breadcrumb)>, ScanError> {
+    let language = breadcrumb_language(path)?;
+
+    let source = fs::read_to_string(path)?;
+
+    let mut parser = create_parser_for_path(path, &language)?;
+
+    let start_line = start_line.max(1);
+    let mut lines = Vec::new();
+    let mut offsets = Vec::new();
+    let mut line_start = 0;
+    for (idx, text) in source.split_inclusive('\n').enumerate() {
+        let line = idx + 1;
+        if line > end_line {
+            break;
         }
-        if ch == '\n' {
-            current_line += 1;
+        if line >= start_line {
+            let indent = text.len() - text.trim_start().len();
+            let column = if text.trim().is_empty() { 0 } else { indent };
+            lines.push(line);
+            offsets.push(line_start + column);
         }
+        line_start += text.len();
     }
 
-    source.len()
+    let breadcrumbs = parser.get_breadcrumbs_at(&source, &offsets, config)?;
+    Ok(lines.into_iter().zip(breadcrumbs).collect())
+}
+
+/// Detect the language of a file for breadcrumb lookups
+fn breadcrumb_language(path: &Path) -> Result<Language, ScanError> {
+    let ext = path
+        .extension()
+        .and_then(|e| e.to_str())
+        .ok_or_else(|| ScanError::ParserError(ParserError::ParseError("No extension".to_string())))?;
+
+    Language::from_extension(ext)
+        .ok_or_else(|| ScanError::ParserError(ParserError::UnsupportedLanguage(Language::Python)))
 }
 
 #[cfg(test)]
@@ -375,6 +453,221 @@ class UserService {{
         assert!(!result.nodes.is_empty());
     }
 
+    #[test]
+    fn test_module_doc() {
+        let (_dir, root) = create_test_project();
+        let py_path = root.join("service.py");
+        fs::write(
+            &py_path,
+            "#!/usr/bin/env python\n\"\"\"Billing service client.\n\nWraps the REST API.\n\"\"\"\n\n\
+             import os\n",
+        )
+        .unwrap();
+        let js_path = root.join("util.js");
+        fs::write(
+            &js_path,
+            "/**\n * String helpers\n * for templates.\n * @module util\n */\nexport {};\n",
+        )
+        .unwrap();
+
+        let config = ScanConfig::default().with_module_doc(true);
+        let py = scan_file(&py_path, &config).unwrap();
+        assert_eq!(py.module_doc.as_deref(), Some("Billing service client."));
+        let js = scan_file(&js_path, &config).unwrap();
+        assert_eq!(js.module_doc.as_deref(), Some("String helpers for templates."));
+
+        // Off by default, and absent when the file starts with code
+        assert_eq!(scan_file(&py_path, &ScanConfig::default()).unwrap().module_doc, None);
+        assert_eq!(scan_file(&root.join("test.py"), &config).unwrap().module_doc, None);
+    }
+
+    #[test]
+    fn test_symbol_table() {
+        let (_dir, root) = create_test_project();
+        let result = scan_file(&root.join("test.py"), &ScanConfig::default()).unwrap();
+
+        let symbols = result.symbol_table();
+        let method = symbols
+            .iter()
+            .find(|s| s.fqn == "test.MyClass.my_method")
+            .expect("method symbol");
+        assert_eq!(method.node_type, crate::models::NodeType::Function);
+        assert_eq!(method.start_line, 3);
+        assert!(symbols.iter().any(|s| s.fqn == "test.hello"));
+    }
+
+    #[test]
+    fn test_markdown_toc() {
+        let (_dir, root) = create_test_project();
+        let path = root.join("users.py");
+        fs::write(
+            &path,
+            "class UserService:\n    def get_user(self, id):\n        if id:\n            return id\n\n\
+             \x20   def delete_user(self, id):\n        pass\n\n\
+             def main():\n    pass\n",
+        )
+        .unwrap();
+
+        let outline = scan_file(&path, &ScanConfig::default()).unwrap();
+
+        assert_eq!(
+            outline.to_markdown_toc(),
+            "- [class UserService](#userservice)\n\
+             \x20 - [function get_user](#get_user)\n\
+             \x20 - [function delete_user](#delete_user)\n\
+             - [function main](#main)\n"
+        );
+    }
+
+    #[test]
+    fn test_long_parameter_lists() {
+        let (_dir, root) = create_test_project();
+        fs::write(
+            root.join("client.py"),
+            "class Client:\n    def request(self, a, b, c, d, e, f):\n        pass\n",
+        )
+        .unwrap();
+        fs::write(root.join("net.js"), "function connect(a, b, c) {\n    return a;\n}\n").unwrap();
+
+        let result = BreadcrumbScanner::new(ScanConfig::new(root)).unwrap().scan().unwrap();
+        let long = result.long_parameter_lists(5);
+
+        assert_eq!(long.len(), 1);
+        assert_eq!(long[0].name.as_deref(), Some("request"));
+        assert_eq!(long[0].param_count, 6);
+    }
+
+    #[test]
+    fn test_find_redefinitions() {
+        let (_dir, root) = create_test_project();
+        let path = root.join("dupes.py");
+        fs::write(
+            &path,
+            "def foo():\n    pass\n\n\
+             class A:\n    def run(self):\n        pass\n\n\
+             class B:\n    def run(self):\n        pass\n\n\
+             def foo():\n    return 1\n",
+        )
+        .unwrap();
+
+        let outline = scan_file(&path, &ScanConfig::default()).unwrap();
+        let redefinitions = outline.find_redefinitions();
+
+        assert_eq!(redefinitions.len(), 1);
+        assert_eq!(redefinitions[0].name, "foo");
+        assert_eq!(redefinitions[0].lines, vec![1, 12]);
+    }
+
+    #[test]
+    fn test_redefinitions_skip_properties_and_overloads() {
+        let (_dir, root) = create_test_project();
+        let path = root.join("accessors.py");
+        fs::write(
+            &path,
+            r#"import typing
+from typing import overload
+
+class Temperature:
+    @property
+    def celsius(self):
+        return self._c
+
+    @celsius.setter
+    def celsius(self, value):
+        self._c = value
+
+    @celsius.deleter
+    def celsius(self):
+        del self._c
+
+@overload
+def parse(x: int) -> int: ...
+
+@typing.overload
+def parse(x: str) -> str: ...
+
+def parse(x):
+    return x
+
+def parse(x):
+    return str(x)
+
+@app.route("/")
+def index():
+    pass
+
+@app.route("/")
+def index():
+    pass
+"#,
+        )
+        .unwrap();
+
+        let outline = scan_file(&path, &ScanConfig::default()).unwrap();
+        let redefinitions = outline.find_redefinitions();
+
+        // Overload stubs and property accessors are skipped; decorated
+        // definitions still count
+        let found: Vec<(&str, &[usize])> = redefinitions
+            .iter()
+            .map(|r| (r.name.as_str(), r.lines.as_slice()))
+            .collect();
+        assert_eq!(found, vec![("parse", &[23, 26][..]), ("index", &[29, 33][..])]);
+    }
+
+    #[test]
+    fn test_error_files() {
+        let (_dir, root) = create_test_project();
+        fs::write(root.join("broken.py"), "def broken(:\n    return ]\n").unwrap();
+
+        let result = BreadcrumbScanner::new(ScanConfig::new(root)).unwrap().scan().unwrap();
+        assert_eq!(result.files.len(), 3);
+
+        let error_files = result.error_files();
+        assert_eq!(error_files.len(), 1);
+        assert_eq!(error_files[0].path, PathBuf::from("broken.py"));
+        let first = error_files[0].first_error().expect("first error");
+        assert_eq!(first.line, 1);
+    }
+
+    #[test]
+    fn test_path_transform() {
+        let (_dir, root) = create_test_project();
+        let canonical_root = root.canonicalize().unwrap();
+        let config = ScanConfig::new(root).with_path_transform(canonical_root.to_string_lossy().into_owned());
+        let result = BreadcrumbScanner::new(config.clone()).unwrap().scan().unwrap();
+
+        assert_eq!(result.files.len(), 2);
+        for file in &result.files {
+            assert!(file.absolute_path.is_relative());
+            assert_eq!(file.absolute_path, file.path);
+        }
+
+        // Single-file scans apply the same transform
+        let outline = scan_file(&canonical_root.join("test.py"), &config).unwrap();
+        assert_eq!(outline.absolute_path, PathBuf::from("test.py"));
+    }
+
+    #[test]
+    fn test_parse_quality() {
+        let (_dir, root) = create_test_project();
+        let broken = root.join("broken.py");
+        fs::write(&broken, "def broken(:\n    x = (1,\n    return ]\nclass\n").unwrap();
+
+        let clean = scan_file(&root.join("test.py"), &ScanConfig::default()).unwrap();
+        let damaged = scan_file(&broken, &ScanConfig::default()).unwrap();
+
+        assert!((clean.parse_quality - 1.0).abs() < f64::EPSILON);
+        assert!(damaged.parse_quality < clean.parse_quality);
+        assert!(damaged.parse_quality >= 0.0);
+
+        // Low-quality files are dropped from directory scans
+        let config = ScanConfig::new(root).with_min_quality(damaged.parse_quality + 0.01);
+        let result = BreadcrumbScanner::new(config).unwrap().scan().unwrap();
+        assert!(result.files.iter().all(|f| f.path != Path::new("broken.py")));
+        assert_eq!(result.stats.total_files, 2);
+    }
+
     #[test]
     fn test_language_filter() {
         let (dir, root) = create_test_project();
@@ -385,4 +678,163 @@ class UserService {{
         assert_eq!(result.stats.javascript_files, 0);
         assert!(result.stats.python_files > 0);
     }
+
+    #[test]
+    fn test_include_patterns() {
+        let (_dir, root) = create_test_project();
+        fs::create_dir(root.join("src")).unwrap();
+        fs::write(root.join("src/api.py"), "def handler():\n    pass\n").unwrap();
+        fs::write(root.join("src/api.js"), "function handler() {}\n").unwrap();
+
+        // Include patterns and the language filter must both match
+        let config = ScanConfig::new(root.clone())
+            .with_include_patterns(vec!["src/**".to_string()])
+            .with_language_filter(vec![Language::Python]);
+        let result = BreadcrumbScanner::new(config).unwrap().scan().unwrap();
+
+        assert_eq!(result.stats.total_files, 1);
+        assert!(result.files[0].path.ends_with("src/api.py"));
+    }
+
+    #[test]
+    fn test_tsx_components() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("App.tsx");
+        fs::write(
+            &path,
+            "export function App(): JSX.Element {\n    return <main>Hello</main>;\n}\n",
+        )
+        .unwrap();
+
+        let outline = scan_file(&path, &ScanConfig::new(dir.path().to_path_buf())).unwrap();
+        assert!(outline.errors.is_empty());
+        let app = outline
+            .nodes
+            .iter()
+            .flat_map(|n| n.flatten())
+            .find(|n| n.name.as_deref() == Some("App"))
+            .unwrap();
+        assert_eq!(app.node_type, crate::models::NodeType::Component);
+    }
+
+    #[test]
+    fn test_max_directory_depth() {
+        let (_dir, root) = create_test_project();
+        fs::create_dir(root.join("nested")).unwrap();
+        fs::write(root.join("nested").join("inner.py"), "def inner():\n    pass\n").unwrap();
+
+        let config = ScanConfig::new(root.clone());
+        let result = BreadcrumbScanner::new(config).unwrap().scan().unwrap();
+        assert_eq!(result.stats.total_files, 3);
+
+        let config = ScanConfig::new(root).with_max_directory_depth(1);
+        let result = BreadcrumbScanner::new(config).unwrap().scan().unwrap();
+        assert_eq!(result.stats.total_files, 2);
+        assert!(result
+            .files
+            .iter()
+            .all(|f| !f.path.starts_with("nested")));
+    }
+
+    #[test]
+    fn test_hidden_scan_root() {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path().join(".project");
+        fs::create_dir_all(root.join(".cache")).unwrap();
+        fs::write(root.join("app.py"), "def main():\n    pass\n").unwrap();
+        fs::write(root.join(".cache").join("stale.py"), "def stale():\n    pass\n").unwrap();
+
+        // The root is scanned even though it is hidden; hidden directories below it are not
+        let result = BreadcrumbScanner::new(ScanConfig::new(root)).unwrap().scan().unwrap();
+        assert_eq!(result.stats.total_files, 1);
+        assert_eq!(result.files[0].path, PathBuf::from("app.py"));
+    }
+
+    #[test]
+    fn test_serial_scan_is_deterministic() {
+        let (_dir, root) = create_test_project();
+        fs::create_dir(root.join("nested")).unwrap();
+        fs::write(root.join("nested").join("inner.py"), "def inner():\n    pass\n").unwrap();
+
+        let config = ScanConfig::new(root)
+            .with_threads(1)
+            .with_reproducible(true)
+            .with_fixed_timestamp(Some("2024-01-01T00:00:00+00:00".to_string()));
+        let scanner = BreadcrumbScanner::new(config).unwrap();
+        let first = scanner.scan().unwrap();
+
+        let paths: Vec<PathBuf> = first.files.iter().map(|f| f.path.clone()).collect();
+        assert_eq!(
+            paths,
+            vec![
+                PathBuf::from("nested").join("inner.py"),
+                PathBuf::from("test.js"),
+                PathBuf::from("test.py"),
+            ]
+        );
+        assert_eq!(
+            serde_json::to_string(&first).unwrap(),
+            serde_json::to_string(&scanner.scan().unwrap()).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_fixed_timestamp() {
+        let (_dir, root) = create_test_project();
+
+        let run = || {
+            let config = ScanConfig::new(root.clone())
+                .with_fixed_timestamp(Some("2024-01-01T00:00:00+00:00".to_string()))
+                .with_reproducible(true);
+            BreadcrumbScanner::new(config).unwrap().scan().unwrap().metadata
+        };
+
+        let first = run();
+        assert_eq!(first.timestamp, "2024-01-01T00:00:00+00:00");
+        assert_eq!(first.scan_duration_ms, 0);
+        assert_eq!(first, run());
+    }
+
+    #[test]
+    fn test_async_stats() {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path().to_path_buf();
+        fs::write(
+            root.join("client.py"),
+            "async def fetch(session):\n    resp = await session.get()\n    return await resp.json()\n\n\
+             async def close(session):\n    await session.close()\n\n\
+             def sync_helper():\n    pass\n",
+        )
+        .unwrap();
+
+        let result = BreadcrumbScanner::new(ScanConfig::new(root)).unwrap().scan().unwrap();
+        assert_eq!(result.files.len(), 1);
+        assert_eq!(result.files[0].async_functions(), 2);
+        assert_eq!(result.files[0].await_points, 3);
+        assert_eq!(result.stats.async_functions, 2);
+        assert_eq!(result.stats.await_points, 3);
+    }
+
+    #[test]
+    fn test_breadcrumbs_for_range() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("service.py");
+        fs::write(
+            &path,
+            "class Service:\n    def run(self):\n        x = 1\n\n        return x\n",
+        )
+        .unwrap();
+
+        let config = ScanConfig::default();
+        let crumbs = get_breadcrumbs_for_range(&path, 2, 10, &config).unwrap();
+
+        let lines: Vec<usize> = crumbs.iter().map(|(line, _)| *line).collect();
+        assert_eq!(lines, vec![2, 3, 4, 5]);
+        // The blank line still reports the enclosing method
+        assert_eq!(crumbs[2].1.path(), "module > Service > run");
+        for (line, crumb) in &crumbs[1..] {
+            let single = get_breadcrumb(&path, *line, crumb.column, &config).unwrap();
+            assert_eq!(single.path(), crumb.path());
+        }
+    }
 }
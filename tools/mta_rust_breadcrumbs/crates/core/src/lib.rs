@@ -1,14 +1,14 @@
 //! mta_breadcrumbs_core - Core library for structural code navigation
 //!
 //! This crate provides the core functionality for extracting hierarchical
-//! structure (breadcrumbs and outlines) from Python and JavaScript/TypeScript
+//! structure (breadcrumbs and outlines) from Python, JavaScript/TypeScript and C#
 //! source code using Tree-sitter for resilient parsing.
 //!
 //! # Features
 //!
 //! - **Resilient Parsing**: Uses Tree-sitter for error-tolerant parsing that
 //!   works even with incomplete or malformed code.
-//! - **Multi-language Support**: Python, JavaScript, and TypeScript.
+//! - **Multi-language Support**: Python, JavaScript, TypeScript, and C#.
 //! - **Hierarchical Extraction**: Extract classes, functions, methods, and
 //!   control flow structures.
 //! - **Breadcrumb Navigation**: Get the structural context at any position.
@@ -40,10 +40,18 @@ pub mod parsers;
 
 // Re-exports for convenience
 pub use config::{NodeFilter, ScanConfig};
-pub use engine::{get_breadcrumb, scan_file, BreadcrumbScanner, ScanError};
+pub use engine::{
+    get_breadcrumb, get_breadcrumbs_for_range, scan_file, BreadcrumbScanner, ScanError,
+};
 pub use models::{
-    Breadcrumb, BreadcrumbComponent, FileOutline, GroupedOutlineMap, Language, LanguageSection,
-    NodeType, OutlineMap, OutlineNode, ParseError, ScanMetadata, ScanStats,
+    AsyncStats, Breadcrumb, BreadcrumbComponent, FileOutline, GroupedOutlineMap, Language, LanguageSection,
+    ComplexFunction, LanguageComparison, LongParameterList, NodeType, OutlineMap, OutlineNode, ParseError, Redefinition, RunSummary,
+    ScanMetadata, ScanStats, Symbol,
+};
+pub use output::{
+    format_dot, format_file_markdown, format_json_with_indent, format_language_comparison,
+    format_language_comparison_markdown, format_markdown_list, format_output,
+    format_output_grouped, format_output_grouped_with_config, format_output_grouped_with_indent,
+    format_output_with_config, format_output_with_indent, format_toml, format_toml_list, write_run_summary, FormatError, IndentStyle, OutputFormat,
 };
-pub use output::{format_output, format_output_grouped, FormatError, OutputFormat};
 pub use parsers::{create_parser, BreadcrumbParser, ParserError};
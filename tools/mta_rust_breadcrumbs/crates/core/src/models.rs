@@ -4,7 +4,8 @@
 //! including AST node types, breadcrumb trails, and hierarchical outlines.
 
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 
 /// Supported programming languages
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -13,6 +14,7 @@ pub enum Language {
     Python,
     JavaScript,
     TypeScript,
+    CSharp,
 }
 
 impl Language {
@@ -22,6 +24,7 @@ impl Language {
             "py" | "pyi" => Some(Language::Python),
             "js" | "mjs" | "cjs" | "jsx" => Some(Language::JavaScript),
             "ts" | "mts" | "cts" | "tsx" => Some(Language::TypeScript),
+            "cs" => Some(Language::CSharp),
             _ => None,
         }
     }
@@ -32,6 +35,7 @@ impl Language {
             Language::Python => "Python",
             Language::JavaScript => "JavaScript",
             Language::TypeScript => "TypeScript",
+            Language::CSharp => "C#",
         }
     }
 
@@ -56,6 +60,8 @@ pub enum NodeType {
     Constructor,
     Getter,
     Setter,
+    Constant,
+    Component,
 
     // Python-specific
     Decorator,
@@ -74,6 +80,8 @@ pub enum NodeType {
     Namespace,
     ObjectLiteral,
     ArrayLiteral,
+    TestSuite,
+    TestCase,
 
     // Control flow
     IfStatement,
@@ -103,6 +111,8 @@ impl NodeType {
             NodeType::Constructor => "constructor",
             NodeType::Getter => "getter",
             NodeType::Setter => "setter",
+            NodeType::Constant => "constant",
+            NodeType::Component => "component",
             NodeType::Decorator => "decorator",
             NodeType::Lambda => "lambda",
             NodeType::Comprehension => "comprehension",
@@ -117,6 +127,8 @@ impl NodeType {
             NodeType::Namespace => "namespace",
             NodeType::ObjectLiteral => "object",
             NodeType::ArrayLiteral => "array",
+            NodeType::TestSuite => "describe",
+            NodeType::TestCase => "test",
             NodeType::IfStatement => "if",
             NodeType::ElseClause => "else",
             NodeType::ElifClause => "elif",
@@ -141,9 +153,47 @@ impl NodeType {
                 | NodeType::Constructor
                 | NodeType::Getter
                 | NodeType::Setter
+                | NodeType::Component
                 | NodeType::Interface
                 | NodeType::Enum
                 | NodeType::Namespace
+                | NodeType::TestSuite
+                | NodeType::TestCase
+        )
+    }
+
+    /// Check if this node type is callable (functions, methods, lambdas)
+    pub fn is_callable(&self) -> bool {
+        matches!(
+            self,
+            NodeType::Function
+                | NodeType::Method
+                | NodeType::AsyncFunction
+                | NodeType::AsyncMethod
+                | NodeType::Constructor
+                | NodeType::Getter
+                | NodeType::Setter
+                | NodeType::Component
+                | NodeType::Lambda
+                | NodeType::ArrowFunction
+        )
+    }
+
+    /// Check if this node type is a control-flow block (branches, loops, handlers)
+    pub fn is_control_flow(&self) -> bool {
+        matches!(
+            self,
+            NodeType::IfStatement
+                | NodeType::ElseClause
+                | NodeType::ElifClause
+                | NodeType::ForLoop
+                | NodeType::WhileLoop
+                | NodeType::SwitchStatement
+                | NodeType::CaseClause
+                | NodeType::TryBlock
+                | NodeType::ExceptHandler
+                | NodeType::FinallyBlock
+                | NodeType::WithStatement
         )
     }
 
@@ -210,7 +260,8 @@ pub struct Breadcrumb {
     /// Line number where the breadcrumb applies
     pub line: usize,
 
-    /// Column number (0-indexed)
+    /// Column as a UTF-8 byte offset within the line (0-indexed), matching
+    /// tree-sitter's `Point::column`
     pub column: usize,
 
     /// Byte offset in the source
@@ -228,13 +279,21 @@ impl Breadcrumb {
         }
     }
 
-    /// Get the formatted path string
+    /// Get the formatted path string, e.g. `MyClass > my_method > if`
     pub fn path(&self) -> String {
+        self.path_with(" > ", false)
+    }
+
+    /// Get the path joined by `sep`, e.g. `MyClass::my_method`
+    ///
+    /// With `include_type`, each component carries its node type label
+    /// (`class MyClass > method my_method`).
+    pub fn path_with(&self, sep: &str, include_type: bool) -> String {
         self.components
             .iter()
-            .map(|c| c.short_display())
+            .map(|c| if include_type { c.display() } else { c.short_display() })
             .collect::<Vec<_>>()
-            .join(" > ")
+            .join(sep)
     }
 
     /// Get the depth of the current location
@@ -279,6 +338,29 @@ pub struct OutlineNode {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub preview: Option<String>,
 
+    /// Decorators applied to a decorated definition, in source order
+    /// (e.g. `@staticmethod`, `@app.route("/")`)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub decorators: Vec<String>,
+
+    /// Number of declared parameters, excluding `self`/`cls`/`this` (callables only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub param_count: Option<usize>,
+
+    /// Cyclomatic complexity: 1 + decision points in the body (callables only,
+    /// when enabled with `ScanConfig::with_complexity`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub complexity: Option<usize>,
+
+    /// Position among the parent's children, 0-indexed (when enabled with
+    /// `ScanConfig::with_sibling_info`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sibling_index: Option<usize>,
+
+    /// Number of children the parent has, including this node
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sibling_count: Option<usize>,
+
     /// Child nodes
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub children: Vec<OutlineNode>,
@@ -299,6 +381,11 @@ impl OutlineNode {
             line_count: end_line.saturating_sub(start_line) + 1,
             depth: 0,
             preview: None,
+            decorators: Vec::new(),
+            param_count: None,
+            complexity: None,
+            sibling_index: None,
+            sibling_count: None,
             children: Vec::new(),
             has_error: false,
         }
@@ -348,6 +435,23 @@ pub struct FileOutline {
     /// Parse errors encountered (if any)
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub errors: Vec<ParseError>,
+
+    /// Parse confidence from 0.0 to 1.0: `1 - error_nodes / total_nodes`
+    #[serde(default = "default_parse_quality")]
+    pub parse_quality: f64,
+
+    /// Number of `await` expressions in the file
+    #[serde(default)]
+    pub await_points: usize,
+
+    /// First paragraph of the module docstring or leading block comment
+    /// (when enabled with `ScanConfig::with_module_doc`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub module_doc: Option<String>,
+}
+
+fn default_parse_quality() -> f64 {
+    1.0
 }
 
 impl FileOutline {
@@ -365,6 +469,222 @@ impl FileOutline {
     pub fn has_errors(&self) -> bool {
         !self.errors.is_empty()
     }
+
+    /// Number of async functions and methods in the outline
+    pub fn async_functions(&self) -> usize {
+        self.flatten()
+            .iter()
+            .filter(|n| matches!(n.node_type, NodeType::AsyncFunction | NodeType::AsyncMethod))
+            .count()
+    }
+
+    /// Earliest parse error in the file, by line then column
+    pub fn first_error(&self) -> Option<&ParseError> {
+        self.errors.iter().min_by_key(|e| (e.line, e.column))
+    }
+
+    /// Build a flat list of every named symbol with its fully-qualified name.
+    ///
+    /// Names are rooted at the module (file stem) and joined with `.` through
+    /// enclosing scopes; control flow nodes are transparent and anonymous
+    /// scopes appear as `<anon>` in the chain.
+    pub fn symbol_table(&self) -> Vec<Symbol> {
+        let module = self
+            .path
+            .file_stem()
+            .map(|s| s.to_string_lossy().into_owned())
+            .unwrap_or_default();
+
+        let mut symbols = Vec::new();
+        for node in &self.nodes {
+            node.collect_symbols(&module, &mut symbols);
+        }
+        symbols
+    }
+
+    /// Render the named scopes as a nested Markdown bullet list with heading anchors.
+    ///
+    /// Each entry looks like `- [class UserService](#userservice)`, indented two
+    /// spaces per level of named nesting. Anonymous and control-flow nodes are
+    /// omitted; named scopes inside them are listed at the enclosing scope's level.
+    pub fn to_markdown_toc(&self) -> String {
+        let mut output = String::new();
+        let mut seen_anchors = HashMap::new();
+        for node in &self.nodes {
+            node.write_toc_entry(0, &mut seen_anchors, &mut output);
+        }
+        output
+    }
+}
+
+impl OutlineNode {
+    fn write_toc_entry(
+        &self,
+        level: usize,
+        seen_anchors: &mut HashMap<String, usize>,
+        output: &mut String,
+    ) {
+        let child_level = match self.name.as_deref() {
+            Some(name) if self.node_type.is_named_scope() => {
+                output.push_str(&format!(
+                    "{}- [{} {}](#{})\n",
+                    "  ".repeat(level),
+                    self.node_type.label(),
+                    name,
+                    unique_anchor(name, seen_anchors)
+                ));
+                level + 1
+            }
+            _ => level,
+        };
+
+        for child in &self.children {
+            child.write_toc_entry(child_level, seen_anchors, output);
+        }
+    }
+}
+
+/// GitHub-style heading anchor: lowercased, spaces to `-`, other punctuation dropped,
+/// with `-1`, `-2`, ... appended to repeats
+fn unique_anchor(name: &str, seen_anchors: &mut HashMap<String, usize>) -> String {
+    let slug: String = name
+        .trim()
+        .to_lowercase()
+        .chars()
+        .filter_map(|c| match c {
+            ' ' => Some('-'),
+            c if c.is_alphanumeric() || c == '-' || c == '_' => Some(c),
+            _ => None,
+        })
+        .collect();
+
+    let count = seen_anchors.entry(slug.clone()).or_insert(0);
+    let anchor = if *count == 0 { slug } else { format!("{}-{}", slug, count) };
+    *count += 1;
+    anchor
+}
+
+impl FileOutline {
+    /// Find named definitions that are redefined by a sibling in the same scope.
+    ///
+    /// Only direct siblings are compared, so alternatives in different branches
+    /// (`if`/`else`) or different classes are not flagged. A getter and setter
+    /// sharing a name are distinct accessors, not a redefinition, and neither are
+    /// Python `@name.setter`/`@name.deleter` methods or `@overload` stubs.
+    pub fn find_redefinitions(&self) -> Vec<Redefinition> {
+        let mut redefinitions = Vec::new();
+        collect_redefinitions(&self.path, &self.nodes, &mut redefinitions);
+        redefinitions
+    }
+}
+
+fn collect_redefinitions(path: &Path, siblings: &[OutlineNode], out: &mut Vec<Redefinition>) {
+    let mut seen: Vec<(&str, &str, Vec<usize>)> = Vec::new();
+
+    for node in siblings {
+        // A decorated definition stands in for the function or class it wraps
+        if !node.node_type.is_named_scope() && node.node_type != NodeType::Decorator {
+            continue;
+        }
+        let Some(name) = node.name.as_deref() else {
+            continue;
+        };
+        if is_overload_or_property_accessor(node, name) {
+            continue;
+        }
+        let accessor = match node.node_type {
+            NodeType::Getter => "get",
+            NodeType::Setter => "set",
+            _ => "",
+        };
+
+        match seen.iter_mut().find(|(n, a, _)| *n == name && *a == accessor) {
+            Some((_, _, lines)) => lines.push(node.start_line),
+            None => seen.push((name, accessor, vec![node.start_line])),
+        }
+    }
+
+    for (name, _, lines) in seen {
+        if lines.len() > 1 {
+            out.push(Redefinition {
+                path: path.to_path_buf(),
+                name: name.to_string(),
+                lines,
+            });
+        }
+    }
+
+    for node in siblings {
+        collect_redefinitions(path, &node.children, out);
+    }
+}
+
+/// Python definitions that legitimately reuse a name: `@typing.overload` stubs
+/// and the `@name.setter`/`@name.deleter`/`@name.getter` halves of a property
+fn is_overload_or_property_accessor(node: &OutlineNode, name: &str) -> bool {
+    node.decorators.iter().any(|decorator| {
+        let decorator = decorator.trim_start_matches('@');
+        decorator.rsplit('.').next() == Some("overload")
+            || decorator
+                .strip_prefix(name)
+                .and_then(|rest| rest.strip_prefix('.'))
+                .is_some_and(|accessor| matches!(accessor, "setter" | "deleter" | "getter"))
+    })
+}
+
+/// A name defined more than once in the same scope
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Redefinition {
+    /// Path to the source file
+    pub path: PathBuf,
+
+    /// Redefined name
+    pub name: String,
+
+    /// Starting line of each definition (1-indexed)
+    pub lines: Vec<usize>,
+}
+
+impl OutlineNode {
+    fn collect_symbols(&self, prefix: &str, symbols: &mut Vec<Symbol>) {
+        let defines_scope = self.node_type.is_named_scope()
+            || matches!(self.node_type, NodeType::ArrowFunction | NodeType::Lambda);
+
+        let child_prefix = if defines_scope {
+            let fqn = format!("{}.{}", prefix, self.name.as_deref().unwrap_or("<anon>"));
+            if self.name.is_some() {
+                symbols.push(Symbol {
+                    fqn: fqn.clone(),
+                    node_type: self.node_type.clone(),
+                    start_line: self.start_line,
+                    end_line: self.end_line,
+                });
+            }
+            fqn
+        } else {
+            prefix.to_string()
+        };
+
+        for child in &self.children {
+            child.collect_symbols(&child_prefix, symbols);
+        }
+    }
+}
+
+/// A defined symbol with its fully-qualified name
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Symbol {
+    /// Fully-qualified name (e.g. `module.MyClass.my_method`)
+    pub fqn: String,
+
+    /// Type of the defining node
+    pub node_type: NodeType,
+
+    /// Starting line number (1-indexed)
+    pub start_line: usize,
+
+    /// Ending line number (1-indexed)
+    pub end_line: usize,
 }
 
 /// Parse error information
@@ -422,6 +742,30 @@ impl LanguageSection {
             files_with_errors,
         }
     }
+
+    /// Aggregate this section's structure for a cross-language comparison
+    pub fn comparison(&self) -> LanguageComparison {
+        let nodes: Vec<&OutlineNode> = self.files.iter().flat_map(|f| f.flatten()).collect();
+        let functions = nodes.iter().filter(|n| n.node_type.is_callable()).count();
+        let classes = nodes.iter().filter(|n| n.node_type == NodeType::Class).count();
+        let ratio = |count: usize| {
+            if self.file_count == 0 {
+                0.0
+            } else {
+                count as f64 / self.file_count as f64
+            }
+        };
+
+        LanguageComparison {
+            language: self.language.clone(),
+            files: self.file_count,
+            functions,
+            classes,
+            avg_functions_per_file: ratio(functions),
+            files_with_errors: self.files_with_errors,
+            error_rate: ratio(self.files_with_errors),
+        }
+    }
 }
 
 /// Grouped output structure by language
@@ -436,10 +780,49 @@ pub struct GroupedOutlineMap {
     /// Node.js files section (JavaScript + TypeScript)
     pub nodejs: LanguageSection,
 
+    /// C# files section
+    pub csharp: LanguageSection,
+
     /// Scan metadata
     pub metadata: ScanMetadata,
 }
 
+impl GroupedOutlineMap {
+    /// Side-by-side structure aggregates for each language that has files
+    pub fn compare_languages(&self) -> Vec<LanguageComparison> {
+        [&self.python, &self.nodejs, &self.csharp]
+            .into_iter()
+            .filter(|section| section.file_count > 0)
+            .map(LanguageSection::comparison)
+            .collect()
+    }
+}
+
+/// Structure aggregates for one language, reported by `--compare`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LanguageComparison {
+    /// Language section name (python, nodejs, csharp)
+    pub language: String,
+
+    /// Files in the section
+    pub files: usize,
+
+    /// Callables (functions, methods, lambdas, arrow functions) at any depth
+    pub functions: usize,
+
+    /// Classes at any depth
+    pub classes: usize,
+
+    /// `functions / files`
+    pub avg_functions_per_file: f64,
+
+    /// Files with parse errors
+    pub files_with_errors: usize,
+
+    /// `files_with_errors / files`, from 0.0 to 1.0
+    pub error_rate: f64,
+}
+
 /// Flat output structure
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OutlineMap {
@@ -473,15 +856,182 @@ impl OutlineMap {
             .cloned()
             .collect();
 
+        let csharp_files: Vec<FileOutline> = self
+            .files
+            .iter()
+            .filter(|f| f.language == Language::CSharp)
+            .cloned()
+            .collect();
+
         GroupedOutlineMap {
             root: self.root.clone(),
             python: LanguageSection::new("python", python_files),
             nodejs: LanguageSection::new("nodejs", nodejs_files),
+            csharp: LanguageSection::new("csharp", csharp_files),
             metadata: self.metadata.clone(),
         }
     }
 }
 
+impl OutlineMap {
+    /// Summarize this scan for `--emit-run-summary`
+    pub fn run_summary(&self, exit: i32) -> RunSummary {
+        RunSummary {
+            files: self.stats.total_files,
+            errors: self.stats.files_with_errors,
+            duration_ms: self.metadata.scan_duration_ms,
+            exit,
+        }
+    }
+
+    /// Files that produced at least one parse error
+    pub fn error_files(&self) -> Vec<&FileOutline> {
+        self.files.iter().filter(|f| f.has_errors()).collect()
+    }
+
+    /// Per-file async function and `await` counts, for files using either
+    pub fn async_stats(&self) -> Vec<AsyncStats> {
+        self.files
+            .iter()
+            .map(|f| AsyncStats {
+                path: f.path.clone(),
+                async_functions: f.async_functions(),
+                await_points: f.await_points,
+            })
+            .filter(|s| s.async_functions > 0 || s.await_points > 0)
+            .collect()
+    }
+
+    /// Find same-scope redefinitions across all files
+    pub fn find_redefinitions(&self) -> Vec<Redefinition> {
+        self.files.iter().flat_map(|f| f.find_redefinitions()).collect()
+    }
+
+    /// Find callables declaring more than `max_params` parameters
+    pub fn long_parameter_lists(&self, max_params: usize) -> Vec<LongParameterList> {
+        let mut results = Vec::new();
+
+        for file in &self.files {
+            for node in file.flatten() {
+                if let Some(param_count) = node.param_count {
+                    if param_count > max_params {
+                        results.push(LongParameterList {
+                            path: file.path.clone(),
+                            name: node.name.clone(),
+                            node_type: node.node_type.clone(),
+                            start_line: node.start_line,
+                            param_count,
+                        });
+                    }
+                }
+            }
+        }
+
+        results
+    }
+
+    /// Find callables whose cyclomatic complexity exceeds `max_complexity`
+    ///
+    /// Only populated when the scan ran with `ScanConfig::with_complexity(true)`.
+    pub fn complex_functions(&self, max_complexity: usize) -> Vec<ComplexFunction> {
+        let mut results = Vec::new();
+
+        for file in &self.files {
+            for node in file.flatten() {
+                if let Some(complexity) = node.complexity {
+                    if complexity > max_complexity {
+                        results.push(ComplexFunction {
+                            path: file.path.clone(),
+                            name: node.name.clone(),
+                            node_type: node.node_type.clone(),
+                            start_line: node.start_line,
+                            complexity,
+                        });
+                    }
+                }
+            }
+        }
+
+        results
+    }
+
+    /// The `limit` callables with the highest cyclomatic complexity, most complex first
+    ///
+    /// Ties keep scan order. Empty unless the scan ran with `ScanConfig::with_complexity(true)`.
+    pub fn most_complex_functions(&self, limit: usize) -> Vec<ComplexFunction> {
+        let mut results = self.complex_functions(0);
+        results.sort_by_key(|f| std::cmp::Reverse(f.complexity));
+        results.truncate(limit);
+        results
+    }
+}
+
+/// One-line run telemetry written to stderr by `--emit-run-summary`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RunSummary {
+    /// Files scanned
+    pub files: usize,
+    /// Files with parse errors
+    pub errors: usize,
+    /// Scan duration in milliseconds
+    pub duration_ms: u64,
+    /// Process exit code the CLI returns
+    pub exit: i32,
+}
+
+/// Async usage counts for one file, reported by `--async-stats`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AsyncStats {
+    /// Path to the source file
+    pub path: PathBuf,
+
+    /// Async functions and methods declared in the file
+    pub async_functions: usize,
+
+    /// `await` expressions in the file
+    pub await_points: usize,
+}
+
+/// A callable whose parameter count exceeds the configured maximum
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LongParameterList {
+    /// Path to the source file
+    pub path: PathBuf,
+
+    /// Name of the callable
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Type of the callable
+    pub node_type: NodeType,
+
+    /// Starting line number (1-indexed)
+    pub start_line: usize,
+
+    /// Number of declared parameters
+    pub param_count: usize,
+}
+
+/// A callable whose cyclomatic complexity exceeds the configured maximum
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ComplexFunction {
+    /// Path to the source file
+    pub path: PathBuf,
+
+    /// Name of the callable
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Type of the callable
+    pub node_type: NodeType,
+
+    /// Starting line number (1-indexed)
+    pub start_line: usize,
+
+    /// 1 + number of decision points in the body
+    pub complexity: usize,
+}
+
 /// Summary statistics for a scan
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ScanStats {
@@ -503,12 +1053,24 @@ pub struct ScanStats {
     /// TypeScript files count
     pub typescript_files: usize,
 
+    /// C# files count
+    #[serde(default)]
+    pub csharp_files: usize,
+
     /// Files with parse errors
     pub files_with_errors: usize,
+
+    /// Async functions and methods across all files
+    #[serde(default)]
+    pub async_functions: usize,
+
+    /// `await` expressions across all files
+    #[serde(default)]
+    pub await_points: usize,
 }
 
 /// Metadata about the scan operation
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ScanMetadata {
     /// Duration of scan in milliseconds
     pub scan_duration_ms: u64,
@@ -522,3 +1084,34 @@ pub struct ScanMetadata {
     /// Tool version
     pub tool_version: String,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn component(node_type: NodeType, name: &str, depth: usize) -> BreadcrumbComponent {
+        BreadcrumbComponent {
+            node_type,
+            name: Some(name.to_string()),
+            start_line: 1,
+            end_line: 10,
+            start_byte: 0,
+            end_byte: 100,
+            depth,
+            has_error: false,
+        }
+    }
+
+    #[test]
+    fn test_breadcrumb_path_with() {
+        let mut breadcrumb = Breadcrumb::empty(5, 4, 60);
+        breadcrumb.components = vec![
+            component(NodeType::Class, "Foo", 0),
+            component(NodeType::Method, "bar", 1),
+        ];
+
+        assert_eq!(breadcrumb.path(), "Foo > bar");
+        assert_eq!(breadcrumb.path_with(" > ", true), "class Foo > method bar");
+        assert_eq!(breadcrumb.path_with("::", false), "Foo::bar");
+    }
+}
@@ -42,10 +42,11 @@ fn node_type_color(node_type: &NodeType) -> &'static str {
         NodeType::Module => BRIGHT_WHITE,
         NodeType::Class => BRIGHT_YELLOW,
         NodeType::Function | NodeType::AsyncFunction => BRIGHT_CYAN,
+        NodeType::Component => BRIGHT_GREEN,
         NodeType::Method | NodeType::AsyncMethod => CYAN,
         NodeType::Constructor => BRIGHT_MAGENTA,
         NodeType::Getter | NodeType::Setter => MAGENTA,
-        NodeType::Property => BLUE,
+        NodeType::Property | NodeType::Constant => BLUE,
         NodeType::Interface => BRIGHT_GREEN,
         NodeType::TypeAlias => GREEN,
         NodeType::Enum => BRIGHT_YELLOW,
@@ -53,6 +54,8 @@ fn node_type_color(node_type: &NodeType) -> &'static str {
         NodeType::ArrowFunction => CYAN,
         NodeType::Lambda => CYAN,
         NodeType::Decorator => MAGENTA,
+        NodeType::TestSuite => BRIGHT_BLUE,
+        NodeType::TestCase => GREEN,
         NodeType::IfStatement | NodeType::ElifClause | NodeType::ElseClause => DIM,
         NodeType::ForLoop | NodeType::WhileLoop => DIM,
         NodeType::TryBlock | NodeType::ExceptHandler | NodeType::FinallyBlock => YELLOW,
@@ -139,6 +142,11 @@ pub fn format_grouped_ansi(data: &GroupedOutlineMap) -> String {
         output.push_str(&format_language_section_ansi(&data.nodejs, BRIGHT_GREEN, "Node.js"));
     }
 
+    // C# section
+    if data.csharp.file_count > 0 {
+        output.push_str(&format_language_section_ansi(&data.csharp, BRIGHT_MAGENTA, "C#"));
+    }
+
     // Footer
     output.push_str(&format!(
         "\n{}Scan completed in {}ms ({:.2} files/sec){}\n",
@@ -191,6 +199,7 @@ fn format_file_ansi(file: &FileOutline) -> String {
         crate::models::Language::Python => BRIGHT_YELLOW,
         crate::models::Language::JavaScript => BRIGHT_GREEN,
         crate::models::Language::TypeScript => BRIGHT_BLUE,
+        crate::models::Language::CSharp => BRIGHT_MAGENTA,
     };
 
     output.push_str(&format!(
@@ -204,6 +213,10 @@ fn format_file_ansi(file: &FileOutline) -> String {
         RESET
     ));
 
+    if let Some(ref doc) = file.module_doc {
+        output.push_str(&format!("   {}{}{}\n", DIM, doc, RESET));
+    }
+
     // Errors indicator
     if !file.errors.is_empty() {
         output.push_str(&format!(
@@ -235,6 +248,16 @@ fn format_node_ansi(node: &OutlineNode, indent: usize) -> String {
     let name = node.name.as_deref().unwrap_or("");
     let line_info = format!(":{}-{}", node.start_line, node.end_line);
 
+    if !node.decorators.is_empty() {
+        output.push_str(&format!(
+            "{}{}{}{}\n",
+            indent_str,
+            DIM,
+            node.decorators.join(" "),
+            RESET
+        ));
+    }
+
     output.push_str(&format!(
         "{}{}{} {}{} {}{}{}{}",
         indent_str,
@@ -248,7 +271,11 @@ fn format_node_ansi(node: &OutlineNode, indent: usize) -> String {
         DIM,
     ));
 
-    output.push_str(&format!(" {}{}", line_info, RESET));
+    output.push_str(&format!(" {}", line_info));
+    if let (Some(index), Some(count)) = (node.sibling_index, node.sibling_count) {
+        output.push_str(&format!(" ({} of {})", index + 1, count));
+    }
+    output.push_str(RESET);
 
     if node.has_error {
         output.push_str(&format!(" {}⚠{}", BRIGHT_RED, RESET));
@@ -283,11 +310,13 @@ fn get_node_icon(node_type: &NodeType) -> &'static str {
         NodeType::Module => "📦",
         NodeType::Class => "🔷",
         NodeType::Function | NodeType::AsyncFunction => "⚡",
+        NodeType::Component => "🧩",
         NodeType::Method | NodeType::AsyncMethod => "🔹",
         NodeType::Constructor => "🔨",
         NodeType::Getter => "📖",
         NodeType::Setter => "📝",
         NodeType::Property => "📌",
+        NodeType::Constant => "🔒",
         NodeType::Interface => "📐",
         NodeType::TypeAlias => "🏷",
         NodeType::Enum => "📋",
@@ -295,6 +324,8 @@ fn get_node_icon(node_type: &NodeType) -> &'static str {
         NodeType::ArrowFunction => "➡",
         NodeType::Lambda => "λ",
         NodeType::Decorator => "🎨",
+        NodeType::TestSuite => "🧪",
+        NodeType::TestCase => "✅",
         NodeType::IfStatement => "❓",
         NodeType::ElseClause | NodeType::ElifClause => "↪",
         NodeType::ForLoop => "🔄",
@@ -348,6 +379,9 @@ mod tests {
                     5,
                 )],
                 errors: vec![],
+                parse_quality: 1.0,
+                await_points: 0,
+                module_doc: None,
             }],
             stats: ScanStats {
                 total_files: 1,
@@ -356,7 +390,10 @@ mod tests {
                 python_files: 1,
                 javascript_files: 0,
                 typescript_files: 0,
+                csharp_files: 0,
                 files_with_errors: 0,
+                async_functions: 0,
+                await_points: 0,
             },
             metadata: ScanMetadata {
                 scan_duration_ms: 100,
@@ -0,0 +1,143 @@
+//! Cross-language comparison table
+//!
+//! Renders `GroupedOutlineMap::compare_languages` as a fixed-width text table so
+//! polyglot repos can see at a glance where structure and parse errors concentrate.
+//! `--format markdown` gets the same columns as a Markdown table.
+
+use crate::models::LanguageComparison;
+
+/// Format per-language aggregates as an aligned plain-text table
+pub fn format_language_comparison(rows: &[LanguageComparison]) -> String {
+    let mut output = format!(
+        "{:<10} {:>7} {:>10} {:>8} {:>12} {:>11}\n",
+        "language", "files", "functions", "classes", "avg fn/file", "error rate"
+    );
+
+    for row in rows {
+        output.push_str(&format!(
+            "{:<10} {:>7} {:>10} {:>8} {:>12.2} {:>10.1}%\n",
+            row.language,
+            row.files,
+            row.functions,
+            row.classes,
+            row.avg_functions_per_file,
+            row.error_rate * 100.0
+        ));
+    }
+
+    output
+}
+
+/// Format per-language aggregates as a Markdown table
+pub fn format_language_comparison_markdown(rows: &[LanguageComparison]) -> String {
+    let mut output = String::from(
+        "| language | files | functions | classes | avg fn/file | error rate |\n\
+         |---|---:|---:|---:|---:|---:|\n",
+    );
+
+    for row in rows {
+        output.push_str(&format!(
+            "| {} | {} | {} | {} | {:.2} | {:.1}% |\n",
+            row.language,
+            row.files,
+            row.functions,
+            row.classes,
+            row.avg_functions_per_file,
+            row.error_rate * 100.0
+        ));
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{
+        FileOutline, Language, NodeType, OutlineMap, OutlineNode, ParseError, ScanMetadata, ScanStats,
+    };
+    use std::path::PathBuf;
+
+    fn file(path: &str, language: Language, nodes: Vec<OutlineNode>) -> FileOutline {
+        FileOutline {
+            path: PathBuf::from(path),
+            absolute_path: PathBuf::from("/test").join(path),
+            language,
+            total_lines: 20,
+            nodes,
+            errors: vec![],
+            parse_quality: 1.0,
+            await_points: 0,
+            module_doc: None,
+        }
+    }
+
+    #[test]
+    fn test_compare_languages() {
+        let mut service = OutlineNode::new(NodeType::Class, Some("Service".to_string()), 1, 10);
+        service.children = vec![
+            OutlineNode::new(NodeType::Method, Some("start".to_string()), 2, 5),
+            OutlineNode::new(NodeType::Method, Some("stop".to_string()), 6, 10),
+        ];
+        let mut handler = OutlineNode::new(NodeType::Function, Some("handler".to_string()), 1, 8);
+        handler.children = vec![OutlineNode::new(NodeType::ArrowFunction, None, 3, 5)];
+
+        let mut broken = file("broken.js", Language::JavaScript, vec![]);
+        broken.errors = vec![ParseError {
+            line: 1,
+            column: 0,
+            message: "unexpected token".to_string(),
+            error_type: "unexpected".to_string(),
+        }];
+
+        let map = OutlineMap {
+            root: PathBuf::from("/test"),
+            files: vec![
+                file("service.py", Language::Python, vec![service]),
+                file(
+                    "util.py",
+                    Language::Python,
+                    vec![OutlineNode::new(NodeType::Function, Some("helper".to_string()), 1, 3)],
+                ),
+                file("handler.js", Language::JavaScript, vec![handler]),
+                broken,
+            ],
+            stats: ScanStats {
+                total_files: 4,
+                total_lines: 80,
+                total_nodes: 7,
+                python_files: 2,
+                javascript_files: 2,
+                typescript_files: 0,
+                csharp_files: 0,
+                files_with_errors: 1,
+                async_functions: 0,
+                await_points: 0,
+            },
+            metadata: ScanMetadata {
+                scan_duration_ms: 100,
+                files_per_second: 40.0,
+                timestamp: "2024-01-01T00:00:00Z".to_string(),
+                tool_version: "0.1.0".to_string(),
+            },
+        };
+
+        let rows = map.to_grouped().compare_languages();
+        let summary: Vec<(&str, usize, usize, usize)> = rows
+            .iter()
+            .map(|r| (r.language.as_str(), r.files, r.functions, r.classes))
+            .collect();
+        assert_eq!(summary, vec![("python", 2, 3, 1), ("nodejs", 2, 2, 0)]);
+        assert_eq!(rows[0].avg_functions_per_file, 1.5);
+        assert_eq!(rows[1].error_rate, 0.5);
+
+        let table = format_language_comparison(&rows);
+        assert_eq!(table.lines().count(), 3);
+        assert!(table.lines().nth(2).unwrap().starts_with("nodejs"));
+        assert!(table.ends_with("50.0%\n"));
+
+        let markdown = format_language_comparison_markdown(&rows);
+        assert_eq!(markdown.lines().count(), 4);
+        assert_eq!(markdown.lines().nth(3), Some("| nodejs | 2 | 2 | 0 | 1.00 | 50.0% |"));
+    }
+}
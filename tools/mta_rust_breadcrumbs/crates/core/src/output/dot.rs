@@ -0,0 +1,128 @@
+//! Graphviz DOT output formatter
+//!
+//! Renders the outline node hierarchy as a directed graph for code-review
+//! visualizations. Named scopes are drawn as boxes, everything else as ellipses.
+
+use crate::models::{FileOutline, OutlineMap, OutlineNode};
+
+/// Format a single file's outline as a DOT digraph
+pub fn format_dot(outline: &FileOutline) -> String {
+    let mut output = String::from("digraph outline {\n");
+    output.push_str("  node [fontname=\"Helvetica\"];\n");
+    output.push_str(&format!("  label=\"{}\";\n", escape_dot(&outline.path.display().to_string())));
+    write_nodes(&outline.nodes, None, "f0", "  ", &mut 0, &mut output);
+    output.push_str("}\n");
+    output
+}
+
+/// Format a whole scan as a DOT digraph with one `subgraph cluster_*` per file
+pub fn format_dot_map(data: &OutlineMap) -> String {
+    let mut output = String::from("digraph outline {\n");
+    output.push_str("  node [fontname=\"Helvetica\"];\n");
+
+    for (index, file) in data.files.iter().enumerate() {
+        let prefix = format!("f{}", index);
+        output.push_str(&format!("  subgraph cluster_{} {{\n", prefix));
+        output.push_str(&format!("    label=\"{}\";\n", escape_dot(&file.path.display().to_string())));
+        write_nodes(&file.nodes, None, &prefix, "    ", &mut 0, &mut output);
+        output.push_str("  }\n");
+    }
+
+    output.push_str("}\n");
+    output
+}
+
+/// Emit a node statement for each node, plus an edge from `parent` when present
+///
+/// IDs are generated as `{prefix}_n{counter}` rather than derived from node names,
+/// so they are unique within the graph and never need quoting.
+fn write_nodes(
+    nodes: &[OutlineNode],
+    parent: Option<&str>,
+    prefix: &str,
+    indent: &str,
+    counter: &mut usize,
+    output: &mut String,
+) {
+    for node in nodes {
+        let id = format!("{}_n{}", prefix, counter);
+        *counter += 1;
+
+        let shape = if node.node_type.is_named_scope() { "box" } else { "ellipse" };
+        output.push_str(&format!(
+            "{}{} [label=\"{}\", shape={}];\n",
+            indent,
+            id,
+            node_label(node),
+            shape
+        ));
+        if let Some(parent) = parent {
+            output.push_str(&format!("{}{} -> {};\n", indent, parent, id));
+        }
+
+        write_nodes(&node.children, Some(&id), prefix, indent, counter, output);
+    }
+}
+
+/// `{label}\n{name}` for named nodes, the bare label otherwise
+fn node_label(node: &OutlineNode) -> String {
+    match &node.name {
+        Some(name) => format!("{}\\n{}", escape_dot(node.node_type.label()), escape_dot(name)),
+        None => escape_dot(node.node_type.label()),
+    }
+}
+
+/// Escape text for use inside a double-quoted DOT string
+fn escape_dot(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => {}
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Language, NodeType};
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_format_dot() {
+        let mut method = OutlineNode::new(NodeType::Method, Some("say \"hi\"".to_string()), 2, 6);
+        method.children = vec![OutlineNode::new(NodeType::IfStatement, None, 3, 4)];
+        let mut class = OutlineNode::new(NodeType::Class, Some("User Service".to_string()), 1, 6);
+        class.children = vec![method];
+
+        let file = FileOutline {
+            path: PathBuf::from("service.py"),
+            absolute_path: PathBuf::from("/test/service.py"),
+            language: Language::Python,
+            total_lines: 6,
+            nodes: vec![class],
+            errors: vec![],
+            parse_quality: 1.0,
+            await_points: 0,
+            module_doc: None,
+        };
+
+        assert_eq!(
+            format_dot(&file),
+            "digraph outline {\n\
+             \x20 node [fontname=\"Helvetica\"];\n\
+             \x20 label=\"service.py\";\n\
+             \x20 f0_n0 [label=\"class\\nUser Service\", shape=box];\n\
+             \x20 f0_n1 [label=\"method\\nsay \\\"hi\\\"\", shape=box];\n\
+             \x20 f0_n0 -> f0_n1;\n\
+             \x20 f0_n2 [label=\"if\", shape=ellipse];\n\
+             \x20 f0_n1 -> f0_n2;\n\
+             }\n"
+        );
+    }
+}
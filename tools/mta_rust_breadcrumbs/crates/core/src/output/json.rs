@@ -1,7 +1,10 @@
 //! JSON output formatter
 
-use crate::models::OutlineMap;
-use crate::output::FormatError;
+use crate::models::{OutlineMap, RunSummary};
+use crate::output::{FormatError, IndentStyle};
+use serde::Serialize;
+use serde_json::ser::{PrettyFormatter, Serializer};
+use std::io::Write;
 
 /// Format outline data as JSON
 pub fn format_json(data: &OutlineMap) -> Result<String, FormatError> {
@@ -14,10 +17,34 @@ pub fn format_json_compact(data: &OutlineMap) -> Result<String, FormatError> {
     serde_json::to_string(data).map_err(FormatError::from)
 }
 
+/// Format any serializable value as JSON with the given indentation
+pub fn format_json_with_indent<T: Serialize + ?Sized>(
+    data: &T,
+    indent: IndentStyle,
+) -> Result<String, FormatError> {
+    let mut buf = Vec::new();
+    let formatter = PrettyFormatter::with_indent(indent.as_bytes());
+    let mut serializer = Serializer::with_formatter(&mut buf, formatter);
+    data.serialize(&mut serializer)?;
+    Ok(String::from_utf8_lossy(&buf).into_owned())
+}
+
+/// Write a run summary as a single JSON line, e.g. to stderr for `--emit-run-summary`
+pub fn write_run_summary<W: Write>(
+    writer: &mut W,
+    summary: &RunSummary,
+) -> Result<(), FormatError> {
+    serde_json::to_writer(&mut *writer, summary)?;
+    writeln!(writer).map_err(serde_json::Error::io)?;
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config::ScanConfig;
     use crate::models::{FileOutline, Language, OutlineNode, NodeType, ScanMetadata, ScanStats};
+    use crate::output::{format_output_with_config, OutputFormat};
     use std::path::PathBuf;
 
     fn create_test_data() -> OutlineMap {
@@ -35,6 +62,9 @@ mod tests {
                     5,
                 )],
                 errors: vec![],
+                parse_quality: 1.0,
+                await_points: 0,
+                module_doc: None,
             }],
             stats: ScanStats {
                 total_files: 1,
@@ -43,7 +73,10 @@ mod tests {
                 python_files: 1,
                 javascript_files: 0,
                 typescript_files: 0,
+                csharp_files: 0,
                 files_with_errors: 0,
+                async_functions: 0,
+                await_points: 0,
             },
             metadata: ScanMetadata {
                 scan_duration_ms: 100,
@@ -74,4 +107,44 @@ mod tests {
         // Compact JSON should not have newlines
         assert!(!json.contains("\n  "));
     }
+
+    #[test]
+    fn test_format_json_with_indent() {
+        let data = create_test_data();
+        let json = format_json_with_indent(&data, IndentStyle::FourSpaces).unwrap();
+        // Top-level keys are indented by four spaces
+        assert!(json.contains("\n    \"root\""));
+        assert!(!json.contains("\n  \"root\""));
+    }
+
+    #[test]
+    fn test_format_output_with_config() {
+        let data = create_test_data();
+        let config = ScanConfig::default().with_json_indent(IndentStyle::FourSpaces);
+        let json = format_output_with_config(&data, OutputFormat::Json, &config).unwrap();
+        assert!(json.contains("\n    \"root\""));
+
+        let json = format_output_with_config(&data, OutputFormat::Json, &ScanConfig::default())
+            .unwrap();
+        assert!(json.contains("\n  \"root\""));
+    }
+
+    #[test]
+    fn test_write_run_summary() {
+        let summary = RunSummary {
+            files: 3,
+            errors: 1,
+            duration_ms: 42,
+            exit: 0,
+        };
+
+        let mut stderr = Vec::new();
+        write_run_summary(&mut stderr, &summary).unwrap();
+
+        let text = String::from_utf8(stderr).unwrap();
+        assert_eq!(text.lines().count(), 1);
+        let parsed: RunSummary = serde_json::from_str(text.trim_end()).unwrap();
+        assert_eq!(parsed, summary);
+        assert!(text.starts_with("{\"files\":3,\"errors\":1,\"duration_ms\":42,\"exit\":0}"));
+    }
 }
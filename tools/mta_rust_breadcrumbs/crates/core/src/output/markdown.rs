@@ -0,0 +1,209 @@
+//! Markdown output formatter
+//!
+//! Renders outlines as nested bullet lists for embedding in generated docs.
+
+use crate::models::{FileOutline, GroupedOutlineMap, LanguageSection, OutlineMap, OutlineNode};
+
+/// Format outline data as Markdown
+pub fn format_markdown(data: &OutlineMap) -> String {
+    let mut output = String::from("# Code Outline\n\n");
+    output.push_str(&format!(
+        "**Root:** `{}` · {} files · {} lines · {} nodes\n",
+        data.root.display(),
+        data.stats.total_files,
+        data.stats.total_lines,
+        data.stats.total_nodes
+    ));
+
+    for file in &data.files {
+        output.push('\n');
+        output.push_str(&format_file_section(file, "##"));
+    }
+
+    output
+}
+
+/// Format grouped outline data as Markdown, one section per language
+pub fn format_grouped_markdown(data: &GroupedOutlineMap) -> String {
+    let mut output = String::from("# Code Outline\n\n");
+    output.push_str(&format!("**Root:** `{}`\n", data.root.display()));
+
+    for (section, name) in [
+        (&data.python, "Python"),
+        (&data.nodejs, "Node.js"),
+        (&data.csharp, "C#"),
+    ] {
+        if section.file_count > 0 {
+            output.push_str(&format_language_section(section, name));
+        }
+    }
+
+    output
+}
+
+/// Format a single file's outline as Markdown
+pub fn format_file_markdown(file: &FileOutline) -> String {
+    format_file_section(file, "#")
+}
+
+fn format_language_section(section: &LanguageSection, name: &str) -> String {
+    let mut output = format!(
+        "\n## {}\n\n{} files · {} lines · {} nodes\n",
+        name, section.file_count, section.total_lines, section.total_nodes
+    );
+    for file in &section.files {
+        output.push('\n');
+        output.push_str(&format_file_section(file, "###"));
+    }
+    output
+}
+
+/// File heading followed by its outline as a nested bullet list
+fn format_file_section(file: &FileOutline, heading: &str) -> String {
+    let mut output = format!(
+        "{} `{}` ({})\n\n",
+        heading,
+        file.path.display(),
+        file.language.display_name()
+    );
+
+    if let Some(ref doc) = file.module_doc {
+        output.push_str(&format!("_{}_\n\n", escape_markdown(doc)));
+    }
+
+    if !file.errors.is_empty() {
+        output.push_str(&format!("_⚠ {} parse error(s)_\n\n", file.errors.len()));
+    }
+
+    // List nesting must start at zero even when shallow nodes were filtered out
+    let base_depth = file.nodes.iter().map(|n| n.depth).min().unwrap_or(0);
+    for node in &file.nodes {
+        format_node(node, base_depth, &mut output);
+    }
+
+    output
+}
+
+/// Append one list entry per node, indented two spaces per `depth` level
+fn format_node(node: &OutlineNode, base_depth: usize, output: &mut String) {
+    let indent = "  ".repeat(node.depth.saturating_sub(base_depth));
+    let lines = format!("<sub>L{}-{}</sub>", node.start_line, node.end_line);
+
+    // Control-flow nodes render as plain italic entries without names or previews
+    if node.node_type.is_control_flow() {
+        output.push_str(&format!("{}- *{}* {}", indent, node.node_type.label(), lines));
+    } else {
+        output.push_str(&format!("{}- **{}**", indent, node.node_type.label()));
+        if let Some(ref name) = node.name {
+            output.push_str(&format!(" {}", escape_markdown(name)));
+        }
+        output.push_str(&format!(" {}", lines));
+        if let Some(preview) = node.preview.as_deref().filter(|p| !p.is_empty()) {
+            output.push_str(&format!(" {}", code_span(preview)));
+        }
+    }
+
+    if node.has_error {
+        output.push_str(" ⚠");
+    }
+    output.push('\n');
+
+    for child in &node.children {
+        format_node(child, base_depth, output);
+    }
+}
+
+/// Format plain-text listing entries as a Markdown bullet list, one item per entry
+pub fn format_markdown_list<S: AsRef<str>>(items: &[S]) -> String {
+    items
+        .iter()
+        .map(|item| format!("- {}\n", escape_markdown(item.as_ref())))
+        .collect()
+}
+
+/// Escape characters that would otherwise be read as emphasis or markup
+fn escape_markdown(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(c, '\\' | '`' | '*' | '_' | '[' | ']' | '<' | '>' | '#' | '|') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// Wrap text in a code span, using a longer fence when it contains backticks
+fn code_span(text: &str) -> String {
+    let longest_run = text
+        .split(|c| c != '`')
+        .map(str::len)
+        .max()
+        .unwrap_or(0);
+    let fence = "`".repeat(longest_run + 1);
+    let pad = if text.starts_with('`') || text.ends_with('`') { " " } else { "" };
+    format!("{fence}{pad}{text}{pad}{fence}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Language, NodeType};
+    use std::path::PathBuf;
+
+    fn node(
+        node_type: NodeType,
+        name: Option<&str>,
+        lines: (usize, usize),
+        depth: usize,
+    ) -> OutlineNode {
+        let mut node = OutlineNode::new(node_type, name.map(str::to_string), lines.0, lines.1);
+        node.depth = depth;
+        node
+    }
+
+    #[test]
+    fn test_format_file_markdown() {
+        let mut method = node(NodeType::Method, Some("__init__"), (2, 6), 1);
+        method.preview = Some("def __init__(self):".to_string());
+        method.children = vec![node(NodeType::IfStatement, None, (3, 4), 2)];
+        let mut class = node(NodeType::Class, Some("Service"), (1, 6), 0);
+        class.preview = Some("class Service:".to_string());
+        class.children = vec![method];
+
+        let file = FileOutline {
+            path: PathBuf::from("service.py"),
+            absolute_path: PathBuf::from("/test/service.py"),
+            language: Language::Python,
+            total_lines: 6,
+            nodes: vec![class],
+            errors: vec![],
+            parse_quality: 1.0,
+            await_points: 0,
+            module_doc: None,
+        };
+
+        assert_eq!(
+            format_file_markdown(&file),
+            "# `service.py` (Python)\n\n\
+             - **class** Service <sub>L1-6</sub> `class Service:`\n\
+             \x20 - **method** \\_\\_init\\_\\_ <sub>L2-6</sub> `def __init__(self):`\n\
+             \x20   - *if* <sub>L3-4</sub>\n"
+        );
+    }
+
+    #[test]
+    fn test_format_markdown_list() {
+        assert_eq!(
+            format_markdown_list(&["src/app_main.py:3 function <anon> (6 params)"]),
+            "- src/app\\_main.py:3 function \\<anon\\> (6 params)\n"
+        );
+        assert_eq!(format_markdown_list::<&str>(&[]), "");
+    }
+
+    #[test]
+    fn test_code_span_with_backticks() {
+        assert_eq!(code_span("const s = `x`"), "`` const s = `x` ``");
+        assert_eq!(code_span("plain"), "`plain`");
+    }
+}
@@ -1,16 +1,25 @@
 //! Output formatting module
 //!
-//! This module provides formatters for JSON, YAML, and ANSI output of
+//! This module provides formatters for JSON, YAML, TOML, ANSI, Markdown, and DOT output of
 //! outline and breadcrumb data structures.
 
 pub mod ansi;
+pub mod compare;
+pub mod dot;
 mod json;
+pub mod markdown;
+mod toml;
 mod yaml;
 
 pub use ansi::{format_ansi, format_breadcrumb_ansi};
-pub use json::format_json;
+pub use compare::{format_language_comparison, format_language_comparison_markdown};
+pub use dot::{format_dot, format_dot_map};
+pub use json::{format_json, format_json_with_indent, write_run_summary};
+pub use markdown::{format_file_markdown, format_markdown, format_markdown_list};
+pub use self::toml::{format_toml, format_toml_list};
 pub use yaml::format_yaml;
 
+use crate::config::ScanConfig;
 use crate::models::{GroupedOutlineMap, OutlineMap};
 use thiserror::Error;
 
@@ -23,10 +32,50 @@ pub enum FormatError {
     #[error("YAML serialization error: {0}")]
     YamlError(#[from] serde_yaml::Error),
 
+    #[error("TOML serialization error: {0}")]
+    TomlError(#[from] ::toml::ser::Error),
+
     #[error("Formatting error: {0}")]
     FormattingError(String),
 }
 
+/// Indentation style for pretty-printed JSON output
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum IndentStyle {
+    /// Two spaces (default)
+    #[default]
+    TwoSpaces,
+    /// Four spaces
+    FourSpaces,
+    /// One tab character
+    Tab,
+}
+
+impl IndentStyle {
+    /// Indent bytes for one nesting level
+    pub fn as_bytes(&self) -> &'static [u8] {
+        match self {
+            IndentStyle::TwoSpaces => b"  ",
+            IndentStyle::FourSpaces => b"    ",
+            IndentStyle::Tab => b"\t",
+        }
+    }
+}
+
+impl std::str::FromStr for IndentStyle {
+    type Err = String;
+
+    /// Parse the `--json-indent` values `2`, `4` and `tab`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "2" => Ok(IndentStyle::TwoSpaces),
+            "4" => Ok(IndentStyle::FourSpaces),
+            "tab" => Ok(IndentStyle::Tab),
+            _ => Err(format!("invalid indent '{}' (expected 2, 4 or tab)", s)),
+        }
+    }
+}
+
 /// Available output formats
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum OutputFormat {
@@ -34,10 +83,16 @@ pub enum OutputFormat {
     Json,
     /// YAML format
     Yaml,
+    /// TOML format
+    Toml,
     /// ANSI colored text
     Ansi,
     /// Plain text summary
     Summary,
+    /// Markdown nested bullet list
+    Markdown,
+    /// Graphviz DOT graph of the outline hierarchy
+    Dot,
 }
 
 impl Default for OutputFormat {
@@ -51,8 +106,11 @@ pub fn format_output(data: &OutlineMap, format: OutputFormat) -> Result<String,
     match format {
         OutputFormat::Json => format_json(data),
         OutputFormat::Yaml => format_yaml(data),
+        OutputFormat::Toml => format_toml(data),
         OutputFormat::Ansi => Ok(format_ansi(data)),
         OutputFormat::Summary => Ok(format_summary(data)),
+        OutputFormat::Markdown => Ok(format_markdown(data)),
+        OutputFormat::Dot => Ok(format_dot_map(data)),
     }
 }
 
@@ -65,11 +123,56 @@ pub fn format_output_grouped(
     match format {
         OutputFormat::Json => format_json_grouped(&grouped),
         OutputFormat::Yaml => format_yaml_grouped(&grouped),
+        OutputFormat::Toml => format_toml(&grouped),
         OutputFormat::Ansi => Ok(format_ansi_grouped(&grouped)),
         OutputFormat::Summary => Ok(format_summary_grouped(&grouped)),
+        OutputFormat::Markdown => Ok(markdown::format_grouped_markdown(&grouped)),
+        OutputFormat::Dot => Ok(format_dot_map(data)),
     }
 }
 
+/// Format outline data using a custom JSON indentation
+pub fn format_output_with_indent(
+    data: &OutlineMap,
+    format: OutputFormat,
+    indent: IndentStyle,
+) -> Result<String, FormatError> {
+    match format {
+        OutputFormat::Json => format_json_with_indent(data, indent),
+        _ => format_output(data, format),
+    }
+}
+
+/// Format grouped outline data (by language) using a custom JSON indentation
+pub fn format_output_grouped_with_indent(
+    data: &OutlineMap,
+    format: OutputFormat,
+    indent: IndentStyle,
+) -> Result<String, FormatError> {
+    match format {
+        OutputFormat::Json => format_json_with_indent(&data.to_grouped(), indent),
+        _ => format_output_grouped(data, format),
+    }
+}
+
+/// Format outline data with the JSON indentation from `ScanConfig::with_json_indent`
+pub fn format_output_with_config(
+    data: &OutlineMap,
+    format: OutputFormat,
+    config: &ScanConfig,
+) -> Result<String, FormatError> {
+    format_output_with_indent(data, format, config.json_indent)
+}
+
+/// Format grouped outline data (by language) with the JSON indentation from `ScanConfig::with_json_indent`
+pub fn format_output_grouped_with_config(
+    data: &OutlineMap,
+    format: OutputFormat,
+    config: &ScanConfig,
+) -> Result<String, FormatError> {
+    format_output_grouped_with_indent(data, format, config.json_indent)
+}
+
 /// Format grouped data as JSON
 fn format_json_grouped(data: &GroupedOutlineMap) -> Result<String, FormatError> {
     serde_json::to_string_pretty(data).map_err(FormatError::from)
@@ -105,6 +208,7 @@ fn format_summary(data: &OutlineMap) -> String {
         "  TypeScript: {} files\n",
         data.stats.typescript_files
     ));
+    output.push_str(&format!("  C#: {} files\n", data.stats.csharp_files));
 
     if data.stats.files_with_errors > 0 {
         output.push_str(&format!(
@@ -113,6 +217,20 @@ fn format_summary(data: &OutlineMap) -> String {
         ));
     }
 
+    let complex = data.most_complex_functions(10);
+    if !complex.is_empty() {
+        output.push_str("\nMost Complex Functions:\n");
+        for f in &complex {
+            output.push_str(&format!(
+                "  {:>3}  {}:{} {}\n",
+                f.complexity,
+                f.path.display(),
+                f.start_line,
+                f.name.as_deref().unwrap_or("<anon>")
+            ));
+        }
+    }
+
     output.push_str(&format!("\nScan Duration: {}ms\n", data.metadata.scan_duration_ms));
     output.push_str(&format!(
         "Processing Speed: {:.2} files/sec\n",
@@ -155,6 +273,18 @@ fn format_summary_grouped(data: &GroupedOutlineMap) -> String {
         ));
     }
 
+    output.push_str(&format!("\nC#\n"));
+    output.push_str(&format!("--\n"));
+    output.push_str(&format!("  Files: {}\n", data.csharp.file_count));
+    output.push_str(&format!("  Nodes: {}\n", data.csharp.total_nodes));
+    output.push_str(&format!("  Lines: {}\n", data.csharp.total_lines));
+    if data.csharp.files_with_errors > 0 {
+        output.push_str(&format!(
+            "  Errors: {} files\n",
+            data.csharp.files_with_errors
+        ));
+    }
+
     output.push_str(&format!("\nScan Duration: {}ms\n", data.metadata.scan_duration_ms));
     output.push_str(&format!(
         "Processing Speed: {:.2} files/sec\n",
@@ -0,0 +1,87 @@
+//! TOML output formatter
+
+use crate::output::FormatError;
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+/// Format any serializable struct or map as TOML
+///
+/// Lists of structs such as `files` become `[[files]]` arrays of tables and nested
+/// structs such as `stats` become `[stats]` tables. Values TOML cannot represent
+/// (a bare list, maps with non-string keys) produce `FormatError::TomlError`.
+pub fn format_toml<T: Serialize + ?Sized>(data: &T) -> Result<String, FormatError> {
+    ::toml::to_string_pretty(data).map_err(FormatError::from)
+}
+
+/// Format a list as TOML, nested under `[[key]]` since a TOML document must be a table
+pub fn format_toml_list<T: Serialize>(key: &str, items: &[T]) -> Result<String, FormatError> {
+    format_toml(&BTreeMap::from([(key, items)]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{
+        FileOutline, Language, NodeType, OutlineMap, OutlineNode, ScanMetadata, ScanStats,
+    };
+    use std::collections::HashMap;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_format_toml() {
+        let data = OutlineMap {
+            root: PathBuf::from("/test"),
+            files: vec![FileOutline {
+                path: PathBuf::from("test.py"),
+                absolute_path: PathBuf::from("/test/test.py"),
+                language: Language::Python,
+                total_lines: 10,
+                nodes: vec![OutlineNode::new(
+                    NodeType::Function,
+                    Some("hello".to_string()),
+                    1,
+                    5,
+                )],
+                errors: vec![],
+                parse_quality: 1.0,
+                await_points: 0,
+                module_doc: None,
+            }],
+            stats: ScanStats {
+                total_files: 1,
+                total_lines: 10,
+                total_nodes: 1,
+                python_files: 1,
+                javascript_files: 0,
+                typescript_files: 0,
+                csharp_files: 0,
+                files_with_errors: 0,
+                async_functions: 0,
+                await_points: 0,
+            },
+            metadata: ScanMetadata {
+                scan_duration_ms: 100,
+                files_per_second: 10.0,
+                timestamp: "2024-01-01T00:00:00Z".to_string(),
+                tool_version: "0.1.0".to_string(),
+            },
+        };
+
+        let toml = format_toml(&data).unwrap();
+        assert!(toml.contains("root = \"/test\""));
+        assert!(toml.contains("[[files]]"));
+        assert!(toml.contains("[[files.nodes]]"));
+        assert!(toml.contains("[stats]"));
+        assert!(toml.contains("hello"));
+    }
+
+    #[test]
+    fn test_format_toml_errors() {
+        let keyed: HashMap<(u8, u8), usize> = HashMap::from([((1, 2), 3)]);
+        assert!(matches!(format_toml(&keyed), Err(FormatError::TomlError(_))));
+        assert!(matches!(format_toml(&[1, 2]), Err(FormatError::TomlError(_))));
+
+        let listed = format_toml_list("lines", &[1, 2]).unwrap();
+        assert!(listed.starts_with("lines = ["));
+    }
+}
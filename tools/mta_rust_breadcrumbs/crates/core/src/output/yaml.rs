@@ -29,6 +29,9 @@ mod tests {
                     5,
                 )],
                 errors: vec![],
+                parse_quality: 1.0,
+                await_points: 0,
+                module_doc: None,
             }],
             stats: ScanStats {
                 total_files: 1,
@@ -37,7 +40,10 @@ mod tests {
                 python_files: 1,
                 javascript_files: 0,
                 typescript_files: 0,
+                csharp_files: 0,
                 files_with_errors: 0,
+                async_functions: 0,
+                await_points: 0,
             },
             metadata: ScanMetadata {
                 scan_duration_ms: 100,
@@ -0,0 +1,380 @@
+//! C# parser for structural code analysis
+//!
+//! This module implements resilient parsing of C# source code using Tree-sitter,
+//! extracting namespaces, type declarations and their members.
+
+use crate::config::ScanConfig;
+use crate::models::{Breadcrumb, BreadcrumbComponent, Language, NodeType, OutlineNode, ParseError};
+use crate::parsers::{
+    assign_sibling_info, byte_to_line_column, count_parameters, cyclomatic_complexity,
+    drop_short_nodes, extract_node_name, extract_preview, map_csharp_node_kind, BreadcrumbParser,
+    ParserError,
+};
+use tree_sitter::{Node, Parser, Tree};
+
+/// C# parser implementation
+pub struct CSharpParser {
+    parser: Parser,
+}
+
+impl CSharpParser {
+    /// Create a new C# parser
+    pub fn new() -> Result<Self, ParserError> {
+        let mut parser = Parser::new();
+        parser
+            .set_language(&tree_sitter_c_sharp::LANGUAGE.into())
+            .map_err(|e| ParserError::InitError(e.to_string()))?;
+        Ok(Self { parser })
+    }
+
+    /// Parse source code into a tree
+    fn parse_tree(&mut self, source: &str) -> Result<Tree, ParserError> {
+        self.parser
+            .parse(source, None)
+            .ok_or_else(|| ParserError::ParseError("Failed to parse source".to_string()))
+    }
+
+    /// Traverse the tree and extract outline nodes
+    fn traverse_node(
+        &self,
+        node: &Node,
+        source: &[u8],
+        source_str: &str,
+        depth: usize,
+        config: &ScanConfig,
+    ) -> Vec<OutlineNode> {
+        let mut results = Vec::new();
+
+        if let Some(node_type) = map_csharp_node_kind(node.kind()) {
+            // Apply node filter
+            if let Some(max_depth) = config.node_filter.max_depth {
+                if depth > max_depth {
+                    return results;
+                }
+            }
+
+            let transparent = (config.node_filter.named_scopes_only && !node_type.is_named_scope())
+                || (config.node_filter.exclude_control_flow
+                    && matches!(
+                        node_type,
+                        NodeType::IfStatement
+                            | NodeType::ElseClause
+                            | NodeType::ForLoop
+                            | NodeType::WhileLoop
+                            | NodeType::SwitchStatement
+                            | NodeType::CaseClause
+                            | NodeType::TryBlock
+                            | NodeType::ExceptHandler
+                            | NodeType::FinallyBlock
+                    ));
+
+            if transparent {
+                // Skip this node but still traverse children
+                let mut cursor = node.walk();
+                for child in node.children(&mut cursor) {
+                    results.extend(self.traverse_node(&child, source, source_str, depth, config));
+                }
+                return results;
+            }
+
+            let name = self.extract_csharp_name(node, source);
+            let start_line = node.start_position().row + 1;
+            let end_line = node.end_position().row + 1;
+
+            let mut outline_node = OutlineNode::new(node_type, name, start_line, end_line);
+            outline_node.depth = depth;
+            outline_node.has_error = node.has_error();
+
+            if config.include_preview {
+                outline_node.preview = extract_preview(node, source_str, config.max_preview_length);
+            }
+
+            if outline_node.node_type.is_callable() {
+                outline_node.param_count = count_parameters(node, source);
+                if config.complexity {
+                    outline_node.complexity = Some(cyclomatic_complexity(node));
+                }
+            }
+
+            // Traverse children
+            let mut cursor = node.walk();
+            for child in node.children(&mut cursor) {
+                outline_node
+                    .children
+                    .extend(self.traverse_node(&child, source, source_str, depth + 1, config));
+            }
+
+            results.push(outline_node);
+        } else {
+            // Not a tracked node type, but traverse children
+            let mut cursor = node.walk();
+            for child in node.children(&mut cursor) {
+                results.extend(self.traverse_node(&child, source, source_str, depth, config));
+            }
+        }
+
+        results
+    }
+
+    /// Extract name for C#-specific nodes
+    fn extract_csharp_name(&self, node: &Node, source: &[u8]) -> Option<String> {
+        match node.kind() {
+            "compilation_unit" | "lambda_expression" => None,
+            _ => node
+                .child_by_field_name("name")
+                .and_then(|n| n.utf8_text(source).ok())
+                .map(|s| s.to_string())
+                .or_else(|| extract_node_name(node, source)),
+        }
+    }
+
+    /// Build breadcrumb trail from node to root
+    fn build_breadcrumb_from_node(
+        &self,
+        node: &Node,
+        source: &[u8],
+        line: usize,
+        column: usize,
+        byte_offset: usize,
+    ) -> Breadcrumb {
+        let mut components = Vec::new();
+        let mut current = Some(*node);
+
+        // Build stack of nodes from leaf to root
+        let mut stack = Vec::new();
+        while let Some(n) = current {
+            if let Some(node_type) = map_csharp_node_kind(n.kind()) {
+                // Skip error nodes unless they're the innermost
+                if node_type != NodeType::ErrorNode || stack.is_empty() {
+                    stack.push((n, node_type));
+                }
+            }
+            current = n.parent();
+        }
+
+        // Reverse to get root-to-leaf order
+        stack.reverse();
+
+        for (idx, (n, node_type)) in stack.into_iter().enumerate() {
+            let name = self.extract_csharp_name(&n, source);
+            components.push(BreadcrumbComponent {
+                node_type,
+                name,
+                start_line: n.start_position().row + 1,
+                end_line: n.end_position().row + 1,
+                start_byte: n.start_byte(),
+                end_byte: n.end_byte(),
+                depth: idx,
+                has_error: n.has_error(),
+            });
+        }
+
+        Breadcrumb {
+            components,
+            line,
+            column,
+            byte_offset,
+        }
+    }
+
+    /// Recursively find the deepest node containing the offset
+    fn find_deepest_node_at<'a>(&self, node: &Node<'a>, offset: usize) -> Option<Node<'a>> {
+        if offset < node.start_byte() || offset > node.end_byte() {
+            return None;
+        }
+
+        // Check children first for a more specific match
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            if let Some(deeper) = self.find_deepest_node_at(&child, offset) {
+                return Some(deeper);
+            }
+        }
+
+        Some(*node)
+    }
+
+    /// Find nearest named scope when inside an error node
+    fn bubble_up_to_named_scope<'a>(&self, node: &Node<'a>) -> Option<Node<'a>> {
+        let mut current = Some(*node);
+
+        while let Some(n) = current {
+            if let Some(node_type) = map_csharp_node_kind(n.kind()) {
+                if node_type.is_named_scope() {
+                    return Some(n);
+                }
+            }
+            current = n.parent();
+        }
+
+        None
+    }
+
+    /// Collect all error nodes from the tree
+    fn collect_errors(&self, node: &Node, errors: &mut Vec<ParseError>) {
+        if node.is_error() || node.is_missing() {
+            let pos = node.start_position();
+            errors.push(ParseError {
+                line: pos.row + 1,
+                column: pos.column,
+                message: if node.is_missing() {
+                    format!("Missing: {}", node.kind())
+                } else {
+                    format!("Syntax error at: {}", node.kind())
+                },
+                error_type: if node.is_missing() {
+                    "missing".to_string()
+                } else {
+                    "error".to_string()
+                },
+            });
+        }
+
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            self.collect_errors(&child, errors);
+        }
+    }
+}
+
+impl BreadcrumbParser for CSharpParser {
+    fn language(&self) -> Language {
+        Language::CSharp
+    }
+
+    fn parse_outline(
+        &mut self,
+        source: &str,
+        config: &ScanConfig,
+    ) -> Result<Vec<OutlineNode>, ParserError> {
+        let tree = self.parse_tree(source)?;
+        let root = tree.root_node();
+
+        let mut nodes = self.traverse_node(&root, source.as_bytes(), source, 0, config);
+        if let Some(min_lines) = config.node_filter.min_lines {
+            drop_short_nodes(&mut nodes, min_lines);
+        }
+        if config.sibling_info {
+            assign_sibling_info(&mut nodes);
+        }
+        Ok(nodes)
+    }
+
+    fn get_breadcrumb_at(
+        &mut self,
+        source: &str,
+        byte_offset: usize,
+        _config: &ScanConfig,
+    ) -> Result<Breadcrumb, ParserError> {
+        let tree = self.parse_tree(source)?;
+        self.breadcrumb_in_tree(&tree, source, byte_offset)
+    }
+
+    fn get_breadcrumbs_at(
+        &mut self,
+        source: &str,
+        byte_offsets: &[usize],
+        _config: &ScanConfig,
+    ) -> Result<Vec<Breadcrumb>, ParserError> {
+        let tree = self.parse_tree(source)?;
+        byte_offsets
+            .iter()
+            .map(|&offset| self.breadcrumb_in_tree(&tree, source, offset))
+            .collect()
+    }
+
+    fn extract_errors(&self, _source: &str, tree: &Tree) -> Vec<ParseError> {
+        let mut errors = Vec::new();
+        self.collect_errors(&tree.root_node(), &mut errors);
+        errors
+    }
+}
+
+impl CSharpParser {
+    /// Breadcrumb at a byte offset of an already parsed tree
+    fn breadcrumb_in_tree(
+        &self,
+        tree: &Tree,
+        source: &str,
+        byte_offset: usize,
+    ) -> Result<Breadcrumb, ParserError> {
+        let node = self
+            .find_deepest_node_at(&tree.root_node(), byte_offset)
+            .ok_or_else(|| ParserError::ParseError("No node found at offset".to_string()))?;
+
+        // If we're in an error node, bubble up to nearest named scope
+        let effective_node = if node.has_error() || node.kind() == "ERROR" {
+            self.bubble_up_to_named_scope(&node).unwrap_or(node)
+        } else {
+            node
+        };
+
+        let (line, column) = byte_to_line_column(source, byte_offset);
+
+        Ok(self.build_breadcrumb_from_node(
+            &effective_node,
+            source.as_bytes(),
+            line,
+            column,
+            byte_offset,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_class_with_two_methods() {
+        let source = r#"
+using System;
+
+namespace App
+{
+    public class Greeter
+    {
+        public Greeter(string prefix) { }
+
+        public string Hello(string name, int times)
+        {
+            return name;
+        }
+
+        public void Reset()
+        {
+        }
+    }
+}
+"#;
+
+        let mut parser = CSharpParser::new().unwrap();
+        let config = ScanConfig::default();
+        let nodes = parser.parse_outline(source, &config).unwrap();
+
+        let namespace = nodes
+            .iter()
+            .flat_map(|n| n.flatten())
+            .find(|n| n.node_type == NodeType::Namespace)
+            .expect("namespace should be outlined");
+        assert_eq!(namespace.name.as_deref(), Some("App"));
+
+        let class = namespace
+            .flatten()
+            .into_iter()
+            .find(|n| n.node_type == NodeType::Class)
+            .expect("class should be outlined");
+        assert_eq!(class.name.as_deref(), Some("Greeter"));
+
+        let methods: Vec<_> = class
+            .children
+            .iter()
+            .filter(|n| n.node_type == NodeType::Method)
+            .collect();
+        assert_eq!(methods.len(), 2);
+        assert_eq!(methods[0].name.as_deref(), Some("Hello"));
+        assert_eq!(methods[0].param_count, Some(2));
+        assert_eq!(methods[1].name.as_deref(), Some("Reset"));
+        assert!(class.children.iter().any(|n| n.node_type == NodeType::Constructor));
+    }
+}
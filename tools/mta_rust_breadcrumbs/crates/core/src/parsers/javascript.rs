@@ -5,7 +5,11 @@
 
 use crate::config::ScanConfig;
 use crate::models::{Breadcrumb, BreadcrumbComponent, Language, NodeType, OutlineNode, ParseError};
-use crate::parsers::{extract_node_name, extract_preview, map_js_node_kind, BreadcrumbParser, ParserError};
+use crate::parsers::{
+    assign_sibling_info, byte_to_line_column, constant_node, count_parameters,
+    cyclomatic_complexity, drop_short_nodes, extract_node_name, extract_preview,
+    insert_module_constants, map_js_node_kind, BreadcrumbParser, ParserError,
+};
 use tree_sitter::{Node, Parser, Tree};
 
 /// JavaScript/TypeScript parser implementation
@@ -17,14 +21,21 @@ pub struct JavaScriptParser {
 impl JavaScriptParser {
     /// Create a new JavaScript/TypeScript parser
     pub fn new(typescript: bool) -> Result<Self, ParserError> {
-        let mut parser = Parser::new();
-
         let language = if typescript {
             tree_sitter_typescript::LANGUAGE_TYPESCRIPT.into()
         } else {
             tree_sitter_javascript::LANGUAGE.into()
         };
+        Self::with_grammar(language, typescript)
+    }
+
+    /// Create a TypeScript parser for `.tsx` files, which accepts JSX elements
+    pub fn new_tsx() -> Result<Self, ParserError> {
+        Self::with_grammar(tree_sitter_typescript::LANGUAGE_TSX.into(), true)
+    }
 
+    fn with_grammar(language: tree_sitter::Language, typescript: bool) -> Result<Self, ParserError> {
+        let mut parser = Parser::new();
         parser
             .set_language(&language)
             .map_err(|e| ParserError::InitError(e.to_string()))?;
@@ -105,6 +116,13 @@ impl JavaScriptParser {
                 outline_node.preview = extract_preview(node, source_str, config.max_preview_length);
             }
 
+            if outline_node.node_type.is_callable() {
+                outline_node.param_count = count_parameters(node, source);
+                if config.complexity {
+                    outline_node.complexity = Some(cyclomatic_complexity(node));
+                }
+            }
+
             // Traverse children
             let mut cursor = node.walk();
             for child in node.children(&mut cursor) {
@@ -115,6 +133,14 @@ impl JavaScriptParser {
 
             results.push(outline_node);
         } else {
+            // Test framework blocks (`describe`/`it`) become named outline nodes
+            if config.test_blocks && node.kind() == "call_expression" {
+                if let Some(outline) = self.extract_test_block(node, source, source_str, depth, config) {
+                    results.push(outline);
+                    return results;
+                }
+            }
+
             // Check for special cases that need name extraction
             if self.is_variable_with_function(node, source) {
                 if let Some(outline) = self.extract_variable_function(node, source, source_str, depth, config) {
@@ -163,6 +189,9 @@ impl JavaScriptParser {
                 NodeType::Method
             }
             "function_declaration" | "function" => {
+                if self.returns_jsx(node) {
+                    return NodeType::Component;
+                }
                 // Check if async
                 for i in 0..node.child_count() as usize {
                     if let Some(child) = node.child(i as u32) {
@@ -257,8 +286,10 @@ impl JavaScriptParser {
                         _ => return None,
                     };
 
-                    // Check for async
-                    let node_type = if self.is_async_function(&value) {
+                    // Functions returning JSX are components; otherwise check for async
+                    let node_type = if self.returns_jsx(&value) {
+                        NodeType::Component
+                    } else if self.is_async_function(&value) {
                         NodeType::AsyncFunction
                     } else {
                         node_type
@@ -275,6 +306,11 @@ impl JavaScriptParser {
                         outline.preview = extract_preview(node, source_str, config.max_preview_length);
                     }
 
+                    outline.param_count = count_parameters(&value, source);
+                    if config.complexity {
+                        outline.complexity = Some(cyclomatic_complexity(&value));
+                    }
+
                     // Traverse the function body for children
                     let mut inner_cursor = value.walk();
                     for inner_child in value.children(&mut inner_cursor) {
@@ -291,6 +327,67 @@ impl JavaScriptParser {
         None
     }
 
+    /// Extract a `describe('name', fn)` / `it('name', fn)` call as a test outline node
+    ///
+    /// Modifiers such as `describe.only` and `it.skip` are recognized by their base name.
+    /// The callback body is traversed so nested suites and tests become children.
+    fn extract_test_block(
+        &self,
+        node: &Node,
+        source: &[u8],
+        source_str: &str,
+        depth: usize,
+        config: &ScanConfig,
+    ) -> Option<OutlineNode> {
+        let mut callee = node.child_by_field_name("function")?;
+        while callee.kind() == "member_expression" {
+            callee = callee.child_by_field_name("object")?;
+        }
+        let node_type = match callee.utf8_text(source).ok()? {
+            "describe" | "context" | "suite" => NodeType::TestSuite,
+            "it" | "test" | "specify" => NodeType::TestCase,
+            _ => return None,
+        };
+
+        let arguments = node.child_by_field_name("arguments")?;
+        let title = arguments.named_child(0)?;
+        if title.kind() != "string" && title.kind() != "template_string" {
+            return None;
+        }
+        let name = title
+            .utf8_text(source)
+            .ok()
+            .map(|s| s.trim_matches(|c| c == '\'' || c == '"' || c == '`').to_string());
+
+        let start_line = node.start_position().row + 1;
+        let end_line = node.end_position().row + 1;
+
+        let mut outline = OutlineNode::new(node_type, name, start_line, end_line);
+        outline.depth = depth;
+        outline.has_error = node.has_error();
+
+        if config.include_preview {
+            outline.preview = extract_preview(node, source_str, config.max_preview_length);
+        }
+
+        // Traverse the callback body so nested blocks become children
+        let mut cursor = arguments.walk();
+        for arg in arguments.named_children(&mut cursor) {
+            if arg.kind() == "arrow_function" || arg.kind() == "function" {
+                if let Some(body) = arg.child_by_field_name("body") {
+                    let mut inner_cursor = body.walk();
+                    for inner_child in body.children(&mut inner_cursor) {
+                        outline
+                            .children
+                            .extend(self.traverse_node(&inner_child, source, source_str, depth + 1, config));
+                    }
+                }
+            }
+        }
+
+        Some(outline)
+    }
+
     /// Check if a function node is async
     fn is_async_function(&self, node: &Node) -> bool {
         for i in 0..node.child_count() as usize {
@@ -303,6 +400,37 @@ impl JavaScriptParser {
         false
     }
 
+    /// Check if a function returns JSX, making it a function component
+    ///
+    /// Looks at an arrow function's expression body or at `return` statements
+    /// in the function body, without descending into nested functions.
+    fn returns_jsx(&self, node: &Node) -> bool {
+        let Some(body) = node.child_by_field_name("body") else {
+            return false;
+        };
+        if body.kind() == "statement_block" {
+            self.block_returns_jsx(&body)
+        } else {
+            is_jsx(&body)
+        }
+    }
+
+    fn block_returns_jsx(&self, node: &Node) -> bool {
+        let mut cursor = node.walk();
+        for child in node.named_children(&mut cursor) {
+            let found = match child.kind() {
+                "return_statement" => child.named_child(0).is_some_and(|value| is_jsx(&value)),
+                "function_declaration" | "function" | "function_expression" | "arrow_function"
+                | "class_declaration" | "class" | "method_definition" => false,
+                _ => self.block_returns_jsx(&child),
+            };
+            if found {
+                return true;
+            }
+        }
+        false
+    }
+
     /// Build breadcrumb trail from node to root
     fn build_breadcrumb_from_node(
         &self,
@@ -394,26 +522,6 @@ impl JavaScriptParser {
         None
     }
 
-    /// Convert byte offset to line/column
-    fn byte_to_line_column(&self, source: &str, offset: usize) -> (usize, usize) {
-        let mut line = 1;
-        let mut column = 0;
-
-        for (idx, ch) in source.char_indices() {
-            if idx >= offset {
-                break;
-            }
-            if ch == '\n' {
-                line += 1;
-                column = 0;
-            } else {
-                column += 1;
-            }
-        }
-
-        (line, column)
-    }
-
     /// Collect all error nodes from the tree
     fn collect_errors(&self, node: &Node, source: &str, errors: &mut Vec<ParseError>) {
         if node.is_error() || node.is_missing() {
@@ -439,6 +547,67 @@ impl JavaScriptParser {
             self.collect_errors(&child, source, errors);
         }
     }
+
+    /// Collect top-level `const` declarations (including exported ones) whose
+    /// value is not a function or class, which are outlined separately
+    fn extract_module_constants(
+        &self,
+        root: &Node,
+        source: &[u8],
+        source_str: &str,
+        config: &ScanConfig,
+    ) -> Vec<OutlineNode> {
+        let mut constants = Vec::new();
+
+        let mut cursor = root.walk();
+        for stmt in root.children(&mut cursor) {
+            let decl = match stmt.kind() {
+                "export_statement" => match stmt.child_by_field_name("declaration") {
+                    Some(d) => d,
+                    None => continue,
+                },
+                _ => stmt,
+            };
+            if decl.kind() != "lexical_declaration"
+                || decl.child(0).map(|k| k.kind()) != Some("const")
+            {
+                continue;
+            }
+
+            let mut decl_cursor = decl.walk();
+            for declarator in decl.named_children(&mut decl_cursor) {
+                if declarator.kind() != "variable_declarator" {
+                    continue;
+                }
+                let Some(name) = declarator
+                    .child_by_field_name("name")
+                    .filter(|n| n.kind() == "identifier")
+                else {
+                    continue;
+                };
+                let value = declarator.child_by_field_name("value");
+                if value.is_some_and(|v| {
+                    matches!(v.kind(), "arrow_function" | "function" | "function_expression" | "class")
+                }) {
+                    continue;
+                }
+                if let Ok(name) = name.utf8_text(source) {
+                    constants.push(constant_node(&stmt, name, value, source_str, config));
+                }
+            }
+        }
+
+        constants
+    }
+}
+
+/// Check if an expression is a JSX element, unwrapping parentheses
+fn is_jsx(node: &Node) -> bool {
+    match node.kind() {
+        "jsx_element" | "jsx_self_closing_element" | "jsx_fragment" => true,
+        "parenthesized_expression" => node.named_child(0).is_some_and(|inner| is_jsx(&inner)),
+        _ => false,
+    }
 }
 
 impl BreadcrumbParser for JavaScriptParser {
@@ -459,7 +628,18 @@ impl BreadcrumbParser for JavaScriptParser {
         let root = tree.root_node();
         let source_bytes = source.as_bytes();
 
-        Ok(self.traverse_node(&root, source_bytes, source, 0, config))
+        let mut nodes = self.traverse_node(&root, source_bytes, source, 0, config);
+        if config.module_constants {
+            let constants = self.extract_module_constants(&root, source_bytes, source, config);
+            insert_module_constants(&mut nodes, constants, config);
+        }
+        if let Some(min_lines) = config.node_filter.min_lines {
+            drop_short_nodes(&mut nodes, min_lines);
+        }
+        if config.sibling_info {
+            assign_sibling_info(&mut nodes);
+        }
+        Ok(nodes)
     }
 
     fn get_breadcrumb_at(
@@ -469,10 +649,41 @@ impl BreadcrumbParser for JavaScriptParser {
         _config: &ScanConfig,
     ) -> Result<Breadcrumb, ParserError> {
         let tree = self.parse_tree(source)?;
+        self.breadcrumb_in_tree(&tree, source, byte_offset)
+    }
+
+    fn get_breadcrumbs_at(
+        &mut self,
+        source: &str,
+        byte_offsets: &[usize],
+        _config: &ScanConfig,
+    ) -> Result<Vec<Breadcrumb>, ParserError> {
+        let tree = self.parse_tree(source)?;
+        byte_offsets
+            .iter()
+            .map(|&offset| self.breadcrumb_in_tree(&tree, source, offset))
+            .collect()
+    }
+
+    fn extract_errors(&self, source: &str, tree: &Tree) -> Vec<ParseError> {
+        let mut errors = Vec::new();
+        self.collect_errors(&tree.root_node(), source, &mut errors);
+        errors
+    }
+}
+
+impl JavaScriptParser {
+    /// Breadcrumb at a byte offset of an already parsed tree
+    fn breadcrumb_in_tree(
+        &self,
+        tree: &Tree,
+        source: &str,
+        byte_offset: usize,
+    ) -> Result<Breadcrumb, ParserError> {
         let source_bytes = source.as_bytes();
 
         let node = self
-            .find_node_at_offset(&tree, byte_offset)
+            .find_node_at_offset(tree, byte_offset)
             .ok_or_else(|| ParserError::ParseError("No node found at offset".to_string()))?;
 
         // If we're in an error node, bubble up to nearest named scope
@@ -482,7 +693,7 @@ impl BreadcrumbParser for JavaScriptParser {
             node
         };
 
-        let (line, column) = self.byte_to_line_column(source, byte_offset);
+        let (line, column) = byte_to_line_column(source, byte_offset);
 
         Ok(self.build_breadcrumb_from_node(
             &effective_node,
@@ -492,12 +703,6 @@ impl BreadcrumbParser for JavaScriptParser {
             byte_offset,
         ))
     }
-
-    fn extract_errors(&self, source: &str, tree: &Tree) -> Vec<ParseError> {
-        let mut errors = Vec::new();
-        self.collect_errors(&tree.root_node(), source, &mut errors);
-        errors
-    }
 }
 
 #[cfg(test)]
@@ -583,6 +788,26 @@ const add = (a, b) => a + b;
             .any(|n| n.node_type == NodeType::ArrowFunction));
     }
 
+    #[test]
+    fn test_param_count() {
+        let source = r#"
+function connect(host, port, options = {}) {
+    return null;
+}
+"#;
+
+        let mut parser = JavaScriptParser::new(false).unwrap();
+        let config = ScanConfig::default();
+        let nodes = parser.parse_outline(source, &config).unwrap();
+
+        let func = nodes
+            .iter()
+            .flat_map(|n| n.flatten())
+            .find(|n| n.name.as_deref() == Some("connect"))
+            .expect("function node");
+        assert_eq!(func.param_count, Some(3));
+    }
+
     #[test]
     fn test_parse_with_errors() {
         let source = r#"
@@ -602,4 +827,191 @@ class ValidClass {
         let result = parser.parse_outline(source, &config);
         assert!(result.is_ok());
     }
+
+    #[test]
+    fn test_module_constants() {
+        let source = r#"
+const MAX_RETRIES = 3;
+export const API_URL = "https://example.com";
+let counter = 0;
+
+const handler = () => {
+    const local = 1;
+};
+"#;
+
+        let mut parser = JavaScriptParser::new(false).unwrap();
+        let config = ScanConfig::default().with_module_constants(true);
+        let nodes = parser.parse_outline(source, &config).unwrap();
+
+        let constants: Vec<_> = nodes
+            .iter()
+            .flat_map(|n| n.flatten())
+            .filter(|n| n.node_type == NodeType::Constant)
+            .collect();
+        assert_eq!(constants.len(), 2);
+        assert_eq!(constants[0].name.as_deref(), Some("MAX_RETRIES"));
+        assert_eq!(constants[0].preview.as_deref(), Some("3"));
+        assert_eq!(constants[1].name.as_deref(), Some("API_URL"));
+        assert_eq!(constants[1].preview.as_deref(), Some("\"https://example.com\""));
+    }
+
+    #[test]
+    fn test_function_components() {
+        let source = r#"
+function Greeting({ name }) {
+    if (!name) {
+        return null;
+    }
+    return (
+        <div className="greeting">Hello, {name}</div>
+    );
+}
+
+const Badge = ({ label }) => <span>{label}</span>;
+
+function formatName(user) {
+    return user.first + " " + user.last;
+}
+"#;
+
+        let mut parser = JavaScriptParser::new(false).unwrap();
+        let config = ScanConfig::default();
+        let nodes = parser.parse_outline(source, &config).unwrap();
+        let all: Vec<_> = nodes.iter().flat_map(|n| n.flatten()).collect();
+
+        let kind_of = |name: &str| {
+            all.iter()
+                .find(|n| n.name.as_deref() == Some(name))
+                .map(|n| n.node_type.clone())
+                .expect("node present")
+        };
+        assert_eq!(kind_of("Greeting"), NodeType::Component);
+        assert_eq!(kind_of("Badge"), NodeType::Component);
+        assert_eq!(kind_of("formatName"), NodeType::Function);
+    }
+
+    #[test]
+    fn test_tsx_function_components() {
+        let source = r#"
+function Greeting({ name }: { name: string }): JSX.Element {
+    return <div className="greeting">Hello, {name}</div>;
+}
+
+const Badge = ({ label }: BadgeProps) => <span>{label}</span>;
+"#;
+
+        let mut parser = JavaScriptParser::new_tsx().unwrap();
+        let config = ScanConfig::default();
+        let nodes = parser.parse_outline(source, &config).unwrap();
+        let all: Vec<_> = nodes.iter().flat_map(|n| n.flatten()).collect();
+
+        let kind_of = |name: &str| {
+            all.iter()
+                .find(|n| n.name.as_deref() == Some(name))
+                .map(|n| n.node_type.clone())
+                .expect("node present")
+        };
+        assert_eq!(kind_of("Greeting"), NodeType::Component);
+        assert_eq!(kind_of("Badge"), NodeType::Component);
+        assert!(all.iter().all(|n| !n.has_error));
+    }
+
+    #[test]
+    fn test_nested_test_blocks() {
+        let source = r#"
+describe('Cart', () => {
+    describe('add', () => {
+        it('adds an item', () => {
+            expect(cart.add(item)).toBe(1);
+        });
+
+        it.skip("rejects duplicates", async function () {
+            await cart.add(item);
+        });
+    });
+
+    test(`empties`, () => {});
+});
+"#;
+
+        let mut parser = JavaScriptParser::new(false).unwrap();
+        let config = ScanConfig::default().with_test_blocks(true);
+        let nodes = parser.parse_outline(source, &config).unwrap();
+
+        let suites: Vec<_> = nodes.iter().flat_map(|n| n.flatten()).filter(|n| n.depth == 1).collect();
+        assert_eq!(suites.len(), 1);
+        let cart = suites[0];
+        assert_eq!(cart.node_type, NodeType::TestSuite);
+        assert_eq!(cart.name.as_deref(), Some("Cart"));
+
+        let names = |n: &OutlineNode| -> Vec<(NodeType, String)> {
+            n.children
+                .iter()
+                .map(|c| (c.node_type.clone(), c.name.clone().unwrap_or_default()))
+                .collect()
+        };
+        assert_eq!(
+            names(cart),
+            vec![
+                (NodeType::TestSuite, "add".to_string()),
+                (NodeType::TestCase, "empties".to_string()),
+            ]
+        );
+        assert_eq!(
+            names(&cart.children[0]),
+            vec![
+                (NodeType::TestCase, "adds an item".to_string()),
+                (NodeType::TestCase, "rejects duplicates".to_string()),
+            ]
+        );
+
+        // Without the option, test calls stay out of the outline
+        let plain = parser.parse_outline(source, &ScanConfig::default()).unwrap();
+        assert!(plain
+            .iter()
+            .flat_map(|n| n.flatten())
+            .all(|n| n.node_type != NodeType::TestSuite));
+    }
+
+    #[test]
+    fn test_breadcrumb_position_with_multibyte_columns() {
+        let source = "const banner = \"café 🎉\";\nfunction café() { return \"🎉\"; } function target() { x; } function other() { return 3; }\n";
+        let config = ScanConfig::default();
+
+        // Columns are UTF-8 byte offsets within the line, as in tree-sitter's Point
+        let line_start = source.find('\n').unwrap() + 1;
+        let target_byte = source.find("x;").unwrap();
+        let column = target_byte - line_start;
+
+        let breadcrumb =
+            crate::parsers::get_breadcrumb_at_position(source, &Language::JavaScript, 2, column, &config)
+                .unwrap();
+
+        assert_eq!(breadcrumb.byte_offset, target_byte);
+        assert_eq!((breadcrumb.line, breadcrumb.column), (2, column));
+        let scope = breadcrumb
+            .components
+            .iter()
+            .rev()
+            .find_map(|c| c.name.as_deref());
+        assert_eq!(scope, Some("target"));
+    }
+
+    #[test]
+    fn test_line_column_byte_round_trip() {
+        let source = "café = 1\nparty = \"🎉\" + label\n";
+        let offset = source.find("label").unwrap();
+
+        let (line, column) = byte_to_line_column(source, offset);
+        assert_eq!((line, column), (2, "party = \"🎉\" + ".len()));
+        assert_eq!(crate::parsers::line_column_to_byte(source, line, column), offset);
+
+        // A column inside the emoji snaps back to its first byte
+        let emoji = source.find('🎉').unwrap();
+        let emoji_column = emoji - (source.find('\n').unwrap() + 1);
+        assert_eq!(crate::parsers::line_column_to_byte(source, 2, emoji_column + 2), emoji);
+        // Columns past the end of the line clamp to the newline
+        assert_eq!(crate::parsers::line_column_to_byte(source, 1, 100), "café = 1".len());
+    }
 }
@@ -3,14 +3,17 @@
 //! This module provides resilient parsing using Tree-sitter to extract
 //! hierarchical structure from source code, even when it contains syntax errors.
 
+mod csharp;
 mod python;
 mod javascript;
 
+pub use csharp::CSharpParser;
 pub use javascript::JavaScriptParser;
 pub use python::PythonParser;
 
 use crate::config::ScanConfig;
 use crate::models::{Breadcrumb, Language, NodeType, OutlineNode, ParseError};
+use std::path::Path;
 use thiserror::Error;
 
 /// Parser errors
@@ -49,6 +52,14 @@ pub trait BreadcrumbParser: Send + Sync {
         config: &ScanConfig,
     ) -> Result<Breadcrumb, ParserError>;
 
+    /// Get breadcrumbs at several byte offsets, parsing the source only once
+    fn get_breadcrumbs_at(
+        &mut self,
+        source: &str,
+        byte_offsets: &[usize],
+        config: &ScanConfig,
+    ) -> Result<Vec<Breadcrumb>, ParserError>;
+
     /// Extract parse errors from the tree
     fn extract_errors(&self, source: &str, tree: &tree_sitter::Tree) -> Vec<ParseError>;
 }
@@ -59,16 +70,48 @@ pub fn create_parser(language: &Language) -> Result<Box<dyn BreadcrumbParser>, P
         Language::Python => Ok(Box::new(PythonParser::new()?)),
         Language::JavaScript => Ok(Box::new(JavaScriptParser::new(false)?)),
         Language::TypeScript => Ok(Box::new(JavaScriptParser::new(true)?)),
+        Language::CSharp => Ok(Box::new(CSharpParser::new()?)),
     }
 }
 
-/// Parse a source file and return its outline
+/// Create a parser for a file; `.tsx` files get the TSX grammar so JSX parses
+pub fn create_parser_for_path(
+    path: &Path,
+    language: &Language,
+) -> Result<Box<dyn BreadcrumbParser>, ParserError> {
+    if is_tsx(path) {
+        Ok(Box::new(JavaScriptParser::new_tsx()?))
+    } else {
+        create_parser(language)
+    }
+}
+
+/// Whether `path` is a `.tsx` file
+fn is_tsx(path: &Path) -> bool {
+    path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("tsx"))
+}
+
+/// Everything `parse_file` extracts from one source file
+#[derive(Debug, Clone, Default)]
+pub struct ParsedFile {
+    /// Top-level outline nodes
+    pub nodes: Vec<OutlineNode>,
+    /// Syntax errors found in the tree
+    pub errors: Vec<ParseError>,
+    /// Share of the tree free of error nodes (0.0 to 1.0)
+    pub parse_quality: f64,
+    /// Number of `await` expressions in the file
+    pub await_points: usize,
+}
+
+/// Parse a source file and return its outline, parse errors, parse quality and await count
 pub fn parse_file(
+    path: &Path,
     source: &str,
     language: &Language,
     config: &ScanConfig,
-) -> Result<(Vec<OutlineNode>, Vec<ParseError>), ParserError> {
-    let mut parser = create_parser(language)?;
+) -> Result<ParsedFile, ParserError> {
+    let mut parser = create_parser_for_path(path, language)?;
     let nodes = parser.parse_outline(source, config)?;
 
     // Create a temporary tree to extract errors
@@ -76,16 +119,204 @@ pub fn parse_file(
     let ts_lang = match language {
         Language::Python => tree_sitter_python::LANGUAGE.into(),
         Language::JavaScript => tree_sitter_javascript::LANGUAGE.into(),
+        Language::TypeScript if is_tsx(path) => tree_sitter_typescript::LANGUAGE_TSX.into(),
         Language::TypeScript => tree_sitter_typescript::LANGUAGE_TYPESCRIPT.into(),
+        Language::CSharp => tree_sitter_c_sharp::LANGUAGE.into(),
     };
     ts_parser.set_language(&ts_lang).ok();
-    let errors = if let Some(tree) = ts_parser.parse(source, None) {
-        parser.extract_errors(source, &tree)
-    } else {
-        Vec::new()
+    let Some(tree) = ts_parser.parse(source, None) else {
+        return Ok(ParsedFile {
+            nodes,
+            ..ParsedFile::default()
+        });
     };
 
-    Ok((nodes, errors))
+    Ok(ParsedFile {
+        nodes,
+        errors: parser.extract_errors(source, &tree),
+        parse_quality: parse_quality(&tree),
+        await_points: count_await_points(&tree),
+    })
+}
+
+/// First paragraph of a file's module docstring (Python) or leading `/* */`
+/// block comment (JS/TS/C#), with its lines joined into one.
+///
+/// Only comments before the first statement count; a shebang and, in Python,
+/// `#` comments such as encoding lines are skipped.
+pub fn extract_module_doc(source: &str, language: &Language) -> Option<String> {
+    let mut rest = source.trim_start_matches('\u{feff}');
+    if rest.starts_with("#!") {
+        rest = rest.split_once('\n').map_or("", |(_, tail)| tail);
+    }
+
+    match language {
+        Language::Python => {
+            let rest = skip_python_comments(rest);
+            let rest = rest.trim_start_matches(['r', 'R', 'u', 'U']);
+            let quote = ["\"\"\"", "'''"].into_iter().find(|q| rest.starts_with(q))?;
+            let body = &rest[quote.len()..];
+            let doc = &body[..body.find(quote)?];
+            first_paragraph(doc.lines().map(str::trim))
+        }
+        Language::JavaScript | Language::TypeScript | Language::CSharp => {
+            let body = rest.trim_start().strip_prefix("/*")?;
+            let doc = &body[..body.find("*/")?];
+            // JSDoc tags (`@module`, `@license`) end the description
+            first_paragraph(
+                doc.lines()
+                    .map(|line| line.trim().trim_start_matches('*').trim())
+                    .take_while(|line| !line.starts_with('@')),
+            )
+        }
+    }
+}
+
+/// Skip blank lines and `#` comment lines at the start of Python source
+fn skip_python_comments(mut source: &str) -> &str {
+    loop {
+        let trimmed = source.trim_start();
+        if !trimmed.starts_with('#') {
+            return trimmed;
+        }
+        source = trimmed.split_once('\n').map_or("", |(_, tail)| tail);
+    }
+}
+
+/// Join the first run of non-empty (pre-trimmed) lines into one line
+fn first_paragraph<'a>(lines: impl Iterator<Item = &'a str>) -> Option<String> {
+    let paragraph: Vec<&str> = lines
+        .skip_while(|line| line.is_empty())
+        .take_while(|line| !line.is_empty())
+        .collect();
+    (!paragraph.is_empty()).then(|| paragraph.join(" "))
+}
+
+/// Count `await` expressions in the syntax tree.
+///
+/// Python names the expression `await`, the same as its keyword token, so
+/// only named nodes are counted.
+pub fn count_await_points(tree: &tree_sitter::Tree) -> usize {
+    let mut count = 0usize;
+
+    let mut cursor = tree.walk();
+    loop {
+        let node = cursor.node();
+        if node.is_named() && matches!(node.kind(), "await" | "await_expression") {
+            count += 1;
+        }
+
+        if cursor.goto_first_child() || cursor.goto_next_sibling() {
+            continue;
+        }
+        // Climb until a sibling is available or the root is reached
+        loop {
+            if !cursor.goto_parent() {
+                return count;
+            }
+            if cursor.goto_next_sibling() {
+                break;
+            }
+        }
+    }
+}
+
+/// Cyclomatic complexity of a callable: 1 + the decision points in its body.
+///
+/// Decision points are `if`/`elif`, loops, `case` arms, `catch`/`except`
+/// handlers, ternaries and short-circuit `&&`/`||`/`and`/`or` operators.
+/// Nested functions and classes are skipped; they get their own score.
+pub fn cyclomatic_complexity(node: &tree_sitter::Node) -> usize {
+    let mut complexity = 1usize;
+
+    let mut cursor = node.walk();
+    loop {
+        let current = cursor.node();
+        let nested = current.id() != node.id() && is_nested_scope(current.kind());
+        if !nested && is_decision_point(&current) {
+            complexity += 1;
+        }
+
+        if (!nested && cursor.goto_first_child()) || cursor.goto_next_sibling() {
+            continue;
+        }
+        // Climb until a sibling is available or the callable itself is reached
+        loop {
+            if !cursor.goto_parent() {
+                return complexity;
+            }
+            if cursor.goto_next_sibling() {
+                break;
+            }
+        }
+    }
+}
+
+fn is_decision_point(node: &tree_sitter::Node) -> bool {
+    match node.kind() {
+        "if_statement" | "elif_clause" | "for_statement" | "for_in_statement" | "foreach_statement"
+        | "while_statement" | "do_statement" | "case_clause" | "switch_case" | "switch_section"
+        | "except_clause" | "catch_clause" | "conditional_expression" | "ternary_expression"
+        | "boolean_operator" => true,
+        "binary_expression" => node
+            .child_by_field_name("operator")
+            .is_some_and(|op| matches!(op.kind(), "&&" | "||")),
+        _ => false,
+    }
+}
+
+fn is_nested_scope(kind: &str) -> bool {
+    matches!(
+        kind,
+        "function_definition"
+            | "lambda"
+            | "class_definition"
+            | "function_declaration"
+            | "function_expression"
+            | "function"
+            | "generator_function_declaration"
+            | "generator_function"
+            | "arrow_function"
+            | "method_definition"
+            | "class_declaration"
+            | "class"
+            | "method_declaration"
+            | "constructor_declaration"
+            | "local_function_statement"
+            | "lambda_expression"
+            | "anonymous_method_expression"
+    )
+}
+
+/// Compute `1 - error_nodes / total_nodes` over every node in the syntax tree.
+///
+/// Both `ERROR` nodes and nodes inserted by error recovery (missing tokens)
+/// count as errors.
+pub fn parse_quality(tree: &tree_sitter::Tree) -> f64 {
+    let mut total = 0usize;
+    let mut errors = 0usize;
+
+    let mut cursor = tree.walk();
+    loop {
+        let node = cursor.node();
+        total += 1;
+        if node.is_error() || node.is_missing() {
+            errors += 1;
+        }
+
+        if cursor.goto_first_child() || cursor.goto_next_sibling() {
+            continue;
+        }
+        // Climb until a sibling is available or the root is reached
+        loop {
+            if !cursor.goto_parent() {
+                return 1.0 - errors as f64 / total as f64;
+            }
+            if cursor.goto_next_sibling() {
+                break;
+            }
+        }
+    }
 }
 
 /// Get breadcrumb at a specific line and column
@@ -102,33 +333,42 @@ pub fn get_breadcrumb_at_position(
     parser.get_breadcrumb_at(source, byte_offset, config)
 }
 
-/// Convert line/column (1-indexed) to byte offset
-fn line_column_to_byte(source: &str, line: usize, column: usize) -> usize {
-    let mut current_line = 1;
-    let mut current_byte = 0;
-
-    for (idx, ch) in source.char_indices() {
-        if current_line == line {
-            // Found the line, now count columns
-            let mut col = 0;
-            for (col_idx, col_ch) in source[idx..].char_indices() {
-                if col == column {
-                    return idx + col_idx;
-                }
-                if col_ch == '\n' {
-                    break;
-                }
-                col += 1;
-            }
-            return idx + column.min(source[idx..].find('\n').unwrap_or(source[idx..].len()));
-        }
-        if ch == '\n' {
-            current_line += 1;
-        }
-        current_byte = idx;
+/// Convert a line (1-indexed) and column (0-indexed) to a byte offset.
+///
+/// Columns are UTF-8 byte offsets within the line, the same unit tree-sitter
+/// uses for `Point::column`; they are not characters or UTF-16 code units, so
+/// LSP clients must convert from their negotiated position encoding first.
+/// Columns past the end of the line clamp to the line end, and a column that
+/// falls inside a multi-byte character snaps back to the character's start.
+pub(crate) fn line_column_to_byte(source: &str, line: usize, column: usize) -> usize {
+    let line_start = match line {
+        0 => return source.len(),
+        1 => 0,
+        _ => match source.match_indices('\n').nth(line - 2) {
+            Some((idx, _)) => idx + 1,
+            None => return source.len(),
+        },
+    };
+    let line_end = source[line_start..]
+        .find('\n')
+        .map_or(source.len(), |idx| line_start + idx);
+
+    let mut offset = (line_start + column).min(line_end);
+    while !source.is_char_boundary(offset) {
+        offset -= 1;
     }
+    offset
+}
 
-    current_byte
+/// Convert a byte offset to a line (1-indexed) and UTF-8 byte column (0-indexed).
+///
+/// This is the inverse of [`line_column_to_byte`] and agrees with tree-sitter's
+/// `Point` for the same offset.
+pub(crate) fn byte_to_line_column(source: &str, offset: usize) -> (usize, usize) {
+    let before = &source.as_bytes()[..offset.min(source.len())];
+    let line = before.iter().filter(|&&b| b == b'\n').count() + 1;
+    let line_start = before.iter().rposition(|&b| b == b'\n').map_or(0, |idx| idx + 1);
+    (line, before.len() - line_start)
 }
 
 /// Helper to map tree-sitter node kind to NodeType
@@ -136,6 +376,7 @@ pub fn map_node_kind(kind: &str, language: &Language) -> Option<NodeType> {
     match language {
         Language::Python => map_python_node_kind(kind),
         Language::JavaScript | Language::TypeScript => map_js_node_kind(kind),
+        Language::CSharp => map_csharp_node_kind(kind),
     }
 }
 
@@ -192,6 +433,30 @@ fn map_js_node_kind(kind: &str) -> Option<NodeType> {
     }
 }
 
+fn map_csharp_node_kind(kind: &str) -> Option<NodeType> {
+    match kind {
+        "compilation_unit" => Some(NodeType::Module),
+        "namespace_declaration" | "file_scoped_namespace_declaration" => Some(NodeType::Namespace),
+        "class_declaration" | "struct_declaration" | "record_declaration" => Some(NodeType::Class),
+        "interface_declaration" => Some(NodeType::Interface),
+        "enum_declaration" => Some(NodeType::Enum),
+        "method_declaration" => Some(NodeType::Method),
+        "constructor_declaration" => Some(NodeType::Constructor),
+        "property_declaration" => Some(NodeType::Property),
+        "lambda_expression" => Some(NodeType::Lambda),
+        "if_statement" => Some(NodeType::IfStatement),
+        "for_statement" | "foreach_statement" => Some(NodeType::ForLoop),
+        "while_statement" | "do_statement" => Some(NodeType::WhileLoop),
+        "switch_statement" => Some(NodeType::SwitchStatement),
+        "switch_section" => Some(NodeType::CaseClause),
+        "try_statement" => Some(NodeType::TryBlock),
+        "catch_clause" => Some(NodeType::ExceptHandler),
+        "finally_clause" => Some(NodeType::FinallyBlock),
+        "ERROR" => Some(NodeType::ErrorNode),
+        _ => None,
+    }
+}
+
 /// Extract name from a tree-sitter node
 pub fn extract_node_name(node: &tree_sitter::Node, source: &[u8]) -> Option<String> {
     // Look for name child node
@@ -216,6 +481,107 @@ pub fn extract_node_name(node: &tree_sitter::Node, source: &[u8]) -> Option<Stri
     None
 }
 
+/// Count the declared parameters of a callable node.
+///
+/// Implicit receivers (`self`/`cls` in Python, a TypeScript `this` parameter)
+/// are excluded. Returns `None` if the node has no parameter list.
+pub fn count_parameters(node: &tree_sitter::Node, source: &[u8]) -> Option<usize> {
+    // Arrow functions with a single unparenthesized parameter: `x => x * 2`
+    if node.child_by_field_name("parameter").is_some() {
+        return Some(1);
+    }
+
+    let params = node.child_by_field_name("parameters")?;
+    let mut cursor = params.walk();
+    let count = params
+        .named_children(&mut cursor)
+        .filter(|param| match param.kind() {
+            "comment" | "keyword_separator" | "positional_separator" | "this" => false,
+            "identifier" => !matches!(param.utf8_text(source).unwrap_or(""), "self" | "cls"),
+            "required_parameter" | "optional_parameter" => param
+                .child_by_field_name("pattern")
+                .is_none_or(|pattern| pattern.kind() != "this"),
+            _ => true,
+        })
+        .count();
+
+    Some(count)
+}
+
+/// Build a `Constant` outline node for a module-level declaration.
+///
+/// `decl` spans the whole statement; the preview is the assigned value.
+pub fn constant_node(
+    decl: &tree_sitter::Node,
+    name: &str,
+    value: Option<tree_sitter::Node>,
+    source_str: &str,
+    config: &ScanConfig,
+) -> OutlineNode {
+    let mut node = OutlineNode::new(
+        NodeType::Constant,
+        Some(name.to_string()),
+        decl.start_position().row + 1,
+        decl.end_position().row + 1,
+    );
+    node.has_error = decl.has_error();
+
+    if config.include_preview {
+        node.preview = value.and_then(|v| extract_preview(&v, source_str, config.max_preview_length));
+    }
+
+    node
+}
+
+/// Record every node's position among its siblings, recursively
+pub fn assign_sibling_info(nodes: &mut [OutlineNode]) {
+    let count = nodes.len();
+    for (index, node) in nodes.iter_mut().enumerate() {
+        node.sibling_index = Some(index);
+        node.sibling_count = Some(count);
+        assign_sibling_info(&mut node.children);
+    }
+}
+
+/// Remove nodes spanning fewer than `min_lines` lines, recursively
+///
+/// Named scopes and control flow are treated alike. A dropped node's children lie
+/// within its lines, so they are shorter still and go with it; a node that is kept
+/// is filtered in turn, keeping only its children that pass on their own.
+pub fn drop_short_nodes(nodes: &mut Vec<OutlineNode>, min_lines: usize) {
+    nodes.retain(|node| node.line_count >= min_lines);
+    for node in nodes.iter_mut() {
+        drop_short_nodes(&mut node.children, min_lines);
+    }
+}
+
+/// Merge module-level constants into an outline, keeping source order.
+///
+/// Constants go under the module node when the outline is rooted at one,
+/// otherwise they sit alongside the other top-level nodes.
+pub fn insert_module_constants(
+    nodes: &mut Vec<OutlineNode>,
+    constants: Vec<OutlineNode>,
+    config: &ScanConfig,
+) {
+    let rooted = matches!(nodes.as_slice(), [module] if module.node_type == NodeType::Module);
+    let (target, depth) = if rooted {
+        (&mut nodes[0].children, 1)
+    } else {
+        (nodes, 0)
+    };
+
+    if config.node_filter.max_depth.is_some_and(|max| depth > max) {
+        return;
+    }
+
+    for mut constant in constants {
+        constant.depth = depth;
+        target.push(constant);
+    }
+    target.sort_by_key(|n| n.start_line);
+}
+
 /// Extract preview line from source
 pub fn extract_preview(node: &tree_sitter::Node, source: &str, max_length: usize) -> Option<String> {
     let start = node.start_byte();
@@ -235,3 +601,35 @@ pub fn extract_preview(node: &tree_sitter::Node, source: &str, max_length: usize
         Some(trimmed.to_string())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_drop_short_nodes() {
+        let mut method = OutlineNode::new(NodeType::Method, Some("save".to_string()), 4, 9);
+        method.children = vec![
+            OutlineNode::new(NodeType::IfStatement, None, 5, 6),
+            OutlineNode::new(NodeType::ForLoop, None, 7, 9),
+        ];
+        let mut class = OutlineNode::new(NodeType::Class, Some("Store".to_string()), 1, 9);
+        class.children = vec![
+            OutlineNode::new(NodeType::Getter, Some("name".to_string()), 2, 2),
+            method,
+        ];
+        let mut helper = OutlineNode::new(NodeType::Function, Some("helper".to_string()), 11, 12);
+        helper.children = vec![OutlineNode::new(NodeType::IfStatement, None, 12, 12)];
+        let mut nodes = vec![class, helper];
+
+        drop_short_nodes(&mut nodes, 3);
+
+        fn names(nodes: &[OutlineNode]) -> Vec<String> {
+            nodes
+                .iter()
+                .map(|n| format!("{}{:?}", n.display(), names(&n.children)))
+                .collect()
+        }
+        assert_eq!(names(&nodes), vec![r#"class Store["method save[\"for[]\"]"]"#]);
+    }
+}
@@ -5,7 +5,11 @@
 
 use crate::config::ScanConfig;
 use crate::models::{Breadcrumb, BreadcrumbComponent, Language, NodeType, OutlineNode, ParseError};
-use crate::parsers::{extract_node_name, extract_preview, map_python_node_kind, BreadcrumbParser, ParserError};
+use crate::parsers::{
+    assign_sibling_info, byte_to_line_column, constant_node, count_parameters,
+    cyclomatic_complexity, drop_short_nodes, extract_node_name, extract_preview,
+    insert_module_constants, map_python_node_kind, BreadcrumbParser, ParserError,
+};
 use tree_sitter::{Node, Parser, Tree};
 
 /// Python parser implementation
@@ -87,6 +91,13 @@ impl PythonParser {
                 *node
             };
 
+            // tree-sitter-python marks `async def` with an `async` token on function_definition
+            let node_type = if node_type == NodeType::Function && is_async_def(&actual_node) {
+                NodeType::AsyncFunction
+            } else {
+                node_type
+            };
+
             let name = self.extract_python_name(&actual_node, source);
             let start_line = node.start_position().row + 1;
             let end_line = node.end_position().row + 1;
@@ -94,11 +105,21 @@ impl PythonParser {
             let mut outline_node = OutlineNode::new(node_type, name, start_line, end_line);
             outline_node.depth = depth;
             outline_node.has_error = node.has_error();
+            if node.kind() == "decorated_definition" {
+                outline_node.decorators = extract_decorators(node, source);
+            }
 
             if config.include_preview {
                 outline_node.preview = extract_preview(node, source_str, config.max_preview_length);
             }
 
+            if outline_node.node_type.is_callable() {
+                outline_node.param_count = count_parameters(&actual_node, source);
+                if config.complexity {
+                    outline_node.complexity = Some(cyclomatic_complexity(&actual_node));
+                }
+            }
+
             // Traverse children
             let mut cursor = node.walk();
             for child in node.children(&mut cursor) {
@@ -164,6 +185,11 @@ impl PythonParser {
         stack.reverse();
 
         for (idx, (n, node_type)) in stack.into_iter().enumerate() {
+            let node_type = if node_type == NodeType::Function && is_async_def(&n) {
+                NodeType::AsyncFunction
+            } else {
+                node_type
+            };
             let name = self.extract_python_name(&n, source);
             components.push(BreadcrumbComponent {
                 node_type,
@@ -224,6 +250,42 @@ impl PythonParser {
 
         None
     }
+
+    /// Collect simple `NAME = value` assignments that sit directly in the module
+    fn extract_module_constants(
+        &self,
+        root: &Node,
+        source: &[u8],
+        source_str: &str,
+        config: &ScanConfig,
+    ) -> Vec<OutlineNode> {
+        let mut constants = Vec::new();
+
+        let mut cursor = root.walk();
+        for stmt in root.children(&mut cursor) {
+            if stmt.kind() != "expression_statement" {
+                continue;
+            }
+            let Some(assignment) = stmt.named_child(0).filter(|n| n.kind() == "assignment") else {
+                continue;
+            };
+            let Some(left) = assignment
+                .child_by_field_name("left")
+                .filter(|n| n.kind() == "identifier")
+            else {
+                continue;
+            };
+            let value = assignment.child_by_field_name("right");
+            if value.is_some_and(|v| v.kind() == "lambda") {
+                continue;
+            }
+            if let Ok(name) = left.utf8_text(source) {
+                constants.push(constant_node(&stmt, name, value, source_str, config));
+            }
+        }
+
+        constants
+    }
 }
 
 impl BreadcrumbParser for PythonParser {
@@ -240,7 +302,18 @@ impl BreadcrumbParser for PythonParser {
         let root = tree.root_node();
         let source_bytes = source.as_bytes();
 
-        Ok(self.traverse_node(&root, source_bytes, source, 0, config))
+        let mut nodes = self.traverse_node(&root, source_bytes, source, 0, config);
+        if config.module_constants {
+            let constants = self.extract_module_constants(&root, source_bytes, source, config);
+            insert_module_constants(&mut nodes, constants, config);
+        }
+        if let Some(min_lines) = config.node_filter.min_lines {
+            drop_short_nodes(&mut nodes, min_lines);
+        }
+        if config.sibling_info {
+            assign_sibling_info(&mut nodes);
+        }
+        Ok(nodes)
     }
 
     fn get_breadcrumb_at(
@@ -250,11 +323,42 @@ impl BreadcrumbParser for PythonParser {
         _config: &ScanConfig,
     ) -> Result<Breadcrumb, ParserError> {
         let tree = self.parse_tree(source)?;
+        self.breadcrumb_in_tree(&tree, source, byte_offset)
+    }
+
+    fn get_breadcrumbs_at(
+        &mut self,
+        source: &str,
+        byte_offsets: &[usize],
+        _config: &ScanConfig,
+    ) -> Result<Vec<Breadcrumb>, ParserError> {
+        let tree = self.parse_tree(source)?;
+        byte_offsets
+            .iter()
+            .map(|&offset| self.breadcrumb_in_tree(&tree, source, offset))
+            .collect()
+    }
+
+    fn extract_errors(&self, source: &str, tree: &Tree) -> Vec<ParseError> {
+        let mut errors = Vec::new();
+        self.collect_errors(&tree.root_node(), source, &mut errors);
+        errors
+    }
+}
+
+impl PythonParser {
+    /// Breadcrumb at a byte offset of an already parsed tree
+    fn breadcrumb_in_tree(
+        &self,
+        tree: &Tree,
+        source: &str,
+        byte_offset: usize,
+    ) -> Result<Breadcrumb, ParserError> {
         let source_bytes = source.as_bytes();
 
         // Find node at offset
         let node = self
-            .find_node_at_offset(&tree, byte_offset)
+            .find_node_at_offset(tree, byte_offset)
             .ok_or_else(|| ParserError::ParseError("No node found at offset".to_string()))?;
 
         // If we're in an error node, bubble up to nearest named scope
@@ -266,7 +370,7 @@ impl BreadcrumbParser for PythonParser {
         };
 
         // Calculate line/column from offset
-        let (line, column) = self.byte_to_line_column(source, byte_offset);
+        let (line, column) = byte_to_line_column(source, byte_offset);
 
         Ok(self.build_breadcrumb_from_node(
             &effective_node,
@@ -276,35 +380,9 @@ impl BreadcrumbParser for PythonParser {
             byte_offset,
         ))
     }
-
-    fn extract_errors(&self, source: &str, tree: &Tree) -> Vec<ParseError> {
-        let mut errors = Vec::new();
-        self.collect_errors(&tree.root_node(), source, &mut errors);
-        errors
-    }
 }
 
 impl PythonParser {
-    /// Convert byte offset to line/column
-    fn byte_to_line_column(&self, source: &str, offset: usize) -> (usize, usize) {
-        let mut line = 1;
-        let mut column = 0;
-
-        for (idx, ch) in source.char_indices() {
-            if idx >= offset {
-                break;
-            }
-            if ch == '\n' {
-                line += 1;
-                column = 0;
-            } else {
-                column += 1;
-            }
-        }
-
-        (line, column)
-    }
-
     /// Collect all error nodes from the tree
     fn collect_errors(&self, node: &Node, source: &str, errors: &mut Vec<ParseError>) {
         if node.is_error() || node.is_missing() {
@@ -332,6 +410,23 @@ impl PythonParser {
     }
 }
 
+/// Check whether a `function_definition` is declared with `async def`
+fn is_async_def(node: &Node) -> bool {
+    let mut cursor = node.walk();
+    let is_async = node.children(&mut cursor).any(|child| child.kind() == "async");
+    is_async
+}
+
+/// Text of each `@decorator` line on a decorated definition, whitespace collapsed
+fn extract_decorators(node: &Node, source: &[u8]) -> Vec<String> {
+    let mut cursor = node.walk();
+    node.children(&mut cursor)
+        .filter(|child| child.kind() == "decorator")
+        .filter_map(|child| child.utf8_text(source).ok())
+        .map(|text| text.split_whitespace().collect::<Vec<_>>().join(" "))
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -357,6 +452,120 @@ class MyClass:
         assert!(nodes.iter().any(|n| n.node_type == NodeType::Class));
     }
 
+    #[test]
+    fn test_decorators() {
+        let source = r#"
+@app.route("/")
+@login_required
+def index():
+    pass
+
+def plain():
+    pass
+"#;
+
+        let mut parser = PythonParser::new().unwrap();
+        let config = ScanConfig::default();
+        let nodes = parser.parse_outline(source, &config).unwrap();
+
+        let decorated = nodes
+            .iter()
+            .flat_map(|n| n.flatten())
+            .find(|n| n.node_type == NodeType::Decorator)
+            .expect("decorated definition");
+        assert_eq!(decorated.name.as_deref(), Some("index"));
+        assert_eq!(decorated.decorators, vec!["@app.route(\"/\")", "@login_required"]);
+
+        let plain = nodes
+            .iter()
+            .flat_map(|n| n.flatten())
+            .find(|n| n.name.as_deref() == Some("plain"))
+            .expect("plain function");
+        assert!(plain.decorators.is_empty());
+        assert!(!serde_json::to_string(plain).unwrap().contains("decorators"));
+    }
+
+    #[test]
+    fn test_param_count_excludes_self() {
+        let source = r#"
+class Client:
+    def request(self, method, url, headers, body, timeout, retries):
+        pass
+"#;
+
+        let mut parser = PythonParser::new().unwrap();
+        let config = ScanConfig::default();
+        let nodes = parser.parse_outline(source, &config).unwrap();
+
+        let method = nodes
+            .iter()
+            .flat_map(|n| n.flatten())
+            .find(|n| n.name.as_deref() == Some("request") && n.node_type == NodeType::Function)
+            .expect("method node");
+        assert_eq!(method.param_count, Some(6));
+    }
+
+    #[test]
+    fn test_complexity_counts_decision_points() {
+        let source = r#"
+def classify(items, limit):
+    if not items:
+        return None
+    for item in items:
+        if item > limit:
+            return item
+    return limit
+"#;
+
+        let mut parser = PythonParser::new().unwrap();
+        let config = ScanConfig::default().with_complexity(true);
+        let nodes = parser.parse_outline(source, &config).unwrap();
+
+        let func = nodes
+            .iter()
+            .flat_map(|n| n.flatten())
+            .find(|n| n.name.as_deref() == Some("classify"))
+            .expect("function node");
+        assert_eq!(func.complexity, Some(4));
+
+        let config = ScanConfig::default();
+        let nodes = parser.parse_outline(source, &config).unwrap();
+        assert!(nodes.iter().flat_map(|n| n.flatten()).all(|n| n.complexity.is_none()));
+    }
+
+    #[test]
+    fn test_complexity_getter_vs_nested_conditionals() {
+        let source = r#"
+class Account:
+    def name(self):
+        return self._name
+
+    def route(self, user, action):
+        if user and user.active:
+            if action == "read":
+                return 1
+            elif action == "write" or user.admin:
+                return 2
+        return 0
+"#;
+
+        let mut parser = PythonParser::new().unwrap();
+        let config = ScanConfig::default().with_complexity(true);
+        let nodes = parser.parse_outline(source, &config).unwrap();
+        let complexity = |name: &str| {
+            nodes
+                .iter()
+                .flat_map(|n| n.flatten())
+                .find(|n| n.name.as_deref() == Some(name))
+                .and_then(|n| n.complexity)
+        };
+
+        assert_eq!(complexity("name"), Some(1));
+        // 1 + if + and + if + elif + or
+        assert_eq!(complexity("route"), Some(6));
+        assert_eq!(complexity("Account"), None);
+    }
+
     #[test]
     fn test_parse_with_errors() {
         let source = r#"
@@ -395,4 +604,72 @@ class MyClass:
         let path = breadcrumb.path();
         assert!(path.contains("MyClass") || path.contains("my_method"));
     }
+
+    #[test]
+    fn test_module_constants() {
+        let source = r#"
+MAX_RETRIES = 3
+BASE_URL = "https://example.com"
+
+def fetch():
+    timeout = 10
+"#;
+
+        let mut parser = PythonParser::new().unwrap();
+        let config = ScanConfig::default().with_module_constants(true);
+        let nodes = parser.parse_outline(source, &config).unwrap();
+
+        let constants: Vec<_> = nodes
+            .iter()
+            .flat_map(|n| n.flatten())
+            .filter(|n| n.node_type == NodeType::Constant)
+            .collect();
+        assert_eq!(constants.len(), 2);
+        assert_eq!(constants[0].name.as_deref(), Some("MAX_RETRIES"));
+        assert_eq!(constants[0].preview.as_deref(), Some("3"));
+        assert_eq!(constants[1].name.as_deref(), Some("BASE_URL"));
+        assert_eq!(constants[1].start_line, 3);
+
+        let without = parser.parse_outline(source, &ScanConfig::default()).unwrap();
+        assert!(!without
+            .iter()
+            .flat_map(|n| n.flatten())
+            .any(|n| n.node_type == NodeType::Constant));
+    }
+
+    #[test]
+    fn test_sibling_info() {
+        let source = r#"
+class Account:
+    def open(self):
+        pass
+
+    def deposit(self, amount):
+        pass
+
+    def withdraw(self, amount):
+        pass
+
+    def close(self):
+        pass
+"#;
+
+        let mut parser = PythonParser::new().unwrap();
+        let config = ScanConfig::default().with_sibling_info(true);
+        let nodes = parser.parse_outline(source, &config).unwrap();
+
+        let all: Vec<_> = nodes.iter().flat_map(|n| n.flatten()).collect();
+        let withdraw = all
+            .iter()
+            .find(|n| n.name.as_deref() == Some("withdraw"))
+            .expect("withdraw method");
+        assert_eq!(withdraw.sibling_index, Some(2));
+        assert_eq!(withdraw.sibling_count, Some(4));
+
+        let without = parser.parse_outline(source, &ScanConfig::default()).unwrap();
+        assert!(without
+            .iter()
+            .flat_map(|n| n.flatten())
+            .all(|n| n.sibling_index.is_none() && n.sibling_count.is_none()));
+    }
 }
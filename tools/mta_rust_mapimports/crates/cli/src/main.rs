@@ -2,10 +2,15 @@ use clap::{Parser, ValueEnum};
 use indicatif::{ProgressBar, ProgressStyle};
 use colored::control;
 use mta_rust_mapimports_core::{
-    format_output, format_output_grouped, ImportScanner, Language, OutputFormat, ScanConfig,
+    format_output_grouped_with_indent, format_output_with_indent, scan_archive,
+    to_json_with_indent, to_toml, write_run_summary, ArchiveKind, DependencyAllowlist,
+    ImportGroupOrder, ImportScanner, ImportType, IndentStyle, Language, OutputFormat, PrefixRule,
+    RunSummary, ScanConfig, ScanProgress,
 };
 use std::fs;
+use std::io::{self, BufWriter, Write};
 use std::path::PathBuf;
+use std::sync::Arc;
 use std::time::Duration;
 
 #[derive(Parser)]
@@ -17,8 +22,13 @@ use std::time::Duration;
     imports, and standard library modules. Supports Python (.py), JavaScript (.js, .mjs, .cjs), \
     and TypeScript (.ts, .tsx) files.\n\n\
     Output is grouped by language (python/nodejs) by default. Use --flat for ungrouped output.")]
+#[command(group(
+    clap::ArgGroup::new("policy")
+        .args(["allowed_deps", "import_groups"])
+        .multiple(true)
+))]
 pub struct Args {
-    /// Project root directory to scan
+    /// Project root directory to scan, or a Python wheel (.whl) / sdist (.tar.gz)
     #[arg(default_value = ".")]
     pub path: PathBuf,
 
@@ -38,14 +48,30 @@ pub struct Args {
     #[arg(long, action = clap::ArgAction::Append)]
     pub ignore: Vec<String>,
 
-    /// Ignore file path (defaults to .gitignore)
+    /// Only scan files matching these globs, relative to the project root
+    #[arg(long, action = clap::ArgAction::Append)]
+    pub include: Vec<String>,
+
+    /// Ignore file path (defaults to .gitignore; .mapimportsignore is layered on top)
     #[arg(long)]
     pub ignore_file: Option<PathBuf>,
 
+    /// Treat imports starting with this prefix as internal (e.g. @mycompany/)
+    #[arg(long, action = clap::ArgAction::Append)]
+    pub internal_prefix: Vec<String>,
+
     /// Include node_modules / .venv in scan
     #[arg(long)]
     pub include_deps: bool,
 
+    /// Only scan files directly in the root directory (no subdirectories)
+    #[arg(long)]
+    pub no_recursion: bool,
+
+    /// Maximum directory depth to walk (1 = root directory only)
+    #[arg(long, conflicts_with = "no_recursion")]
+    pub max_directory_depth: Option<usize>,
+
     /// Show only external dependencies with versions
     #[arg(long)]
     pub deps_only: bool,
@@ -54,6 +80,46 @@ pub struct Args {
     #[arg(long)]
     pub unknown_only: bool,
 
+    /// Show only imports whose names are never used; exits non-zero if any are found
+    #[arg(long, conflicts_with_all = ["deps_only", "unknown_only", "find_barrels"])]
+    pub unused_only: bool,
+
+    /// Report unknown imports as JSON grouped by likely cause (typo, missing manifest, unknown)
+    #[arg(
+        long,
+        conflicts_with_all = ["format", "deps_only", "unknown_only", "find_barrels", "stats_only"]
+    )]
+    pub diagnose_unknown: bool,
+
+    /// Emit only stats and dependency versions as JSON, omitting per-file data
+    #[arg(long, conflicts_with_all = ["format", "unknown_only", "find_barrels"])]
+    pub stats_only: bool,
+
+    /// List barrel files (JS/TS modules that only re-export other modules)
+    #[arg(long, conflicts_with_all = ["deps_only", "unknown_only"])]
+    pub find_barrels: bool,
+
+    /// Allowlist of approved external packages (newline-separated); others are reported
+    #[arg(long)]
+    pub allowed_deps: Option<PathBuf>,
+
+    /// Canonical import group order (e.g. "stdlib,internal,external,local"); out-of-order
+    /// imports are reported. Groups not listed are not checked
+    #[arg(long, value_name = "GROUPS")]
+    pub import_groups: Option<String>,
+
+    /// Exit with a non-zero status if any policy or import order violations are found
+    #[arg(long, requires = "policy")]
+    pub fail_on_violation: bool,
+
+    /// Exit with a non-zero status if internal packages import each other in a cycle
+    #[arg(long)]
+    pub check_cycles: bool,
+
+    /// Report local/internal modules named like a stdlib or builtin module (e.g. `logging.py`)
+    #[arg(long)]
+    pub find_shadowing: bool,
+
     /// Use flat output structure (not grouped by language)
     #[arg(long)]
     pub flat: bool,
@@ -65,13 +131,43 @@ pub struct Args {
     /// Parallel threads (0 = auto)
     #[arg(long, default_value_t = 0)]
     pub threads: usize,
+
+    /// Indentation for JSON output: 2, 4 or tab
+    #[arg(long, default_value = "2")]
+    pub json_indent: IndentStyle,
+
+    /// Remove this leading prefix from every file path in the output
+    #[arg(long)]
+    pub strip_prefix: Option<String>,
+
+    /// Pin the metadata timestamp (RFC 3339); defaults to SOURCE_DATE_EPOCH, then the current time
+    #[arg(long, value_name = "RFC3339")]
+    pub timestamp: Option<String>,
+
+    /// Record a zero scan duration so repeated runs produce identical output
+    #[arg(long)]
+    pub reproducible: bool,
+
+    /// Only analyze files staged in git (for pre-commit hooks)
+    #[arg(long)]
+    pub staged: bool,
+
+    /// After writing output, print a one-line JSON run summary to stderr
+    #[arg(long)]
+    pub emit_run_summary: bool,
 }
 
 #[derive(ValueEnum, Clone, Debug)]
 pub enum OutputFormatArg {
     Json,
     Yaml,
+    Toml,
     Summary,
+    StatsJson,
+    /// One JSON object per file, streamed as parsed, then a summary object
+    Jsonl,
+    /// SARIF 2.1.0 with one result per unresolved import (for GitHub code scanning)
+    Sarif,
 }
 
 impl From<OutputFormatArg> for OutputFormat {
@@ -79,7 +175,11 @@ impl From<OutputFormatArg> for OutputFormat {
         match arg {
             OutputFormatArg::Json => OutputFormat::Json,
             OutputFormatArg::Yaml => OutputFormat::Yaml,
+            OutputFormatArg::Toml => OutputFormat::Toml,
             OutputFormatArg::Summary => OutputFormat::Summary,
+            OutputFormatArg::StatsJson => OutputFormat::StatsJson,
+            OutputFormatArg::Jsonl => OutputFormat::Jsonl,
+            OutputFormatArg::Sarif => OutputFormat::Sarif,
         }
     }
 }
@@ -96,6 +196,11 @@ pub enum LanguageFilter {
 fn main() -> anyhow::Result<()> {
     let args = Args::parse();
 
+    // Wheels and sdists are read in memory instead of walking a directory
+    if args.path.is_file() && ArchiveKind::from_path(&args.path).is_some() {
+        return run_archive(&args);
+    }
+
     // Convert language filter
     let language_filter = args.language.map(|l| match l {
         LanguageFilter::Python => vec![Language::Python],
@@ -107,17 +212,38 @@ fn main() -> anyhow::Result<()> {
     // Build config
     let mut config = ScanConfig::new(args.path.clone())
         .with_ignore_patterns(args.ignore.clone())
+        .with_include_patterns(args.include.clone())
         .with_include_deps(args.include_deps)
-        .with_threads(args.threads);
+        .with_staged_only(args.staged)
+        .with_threads(args.threads)
+        .with_json_indent(args.json_indent);
 
     if let Some(languages) = language_filter {
         config = config.with_language_filter(languages);
     }
 
+    for prefix in &args.internal_prefix {
+        config = config.with_category_rule(PrefixRule::new(prefix.clone(), ImportType::Internal));
+    }
+
+    if args.no_recursion {
+        config = config.with_max_directory_depth(1);
+    } else if let Some(depth) = args.max_directory_depth {
+        config = config.with_max_directory_depth(depth);
+    }
+
     if let Some(ignore_file) = args.ignore_file {
         config = config.with_ignore_file(ignore_file);
     }
 
+    if let Some(prefix) = args.strip_prefix {
+        config = config.with_path_transform(prefix);
+    }
+
+    config = config
+        .with_fixed_timestamp(args.timestamp.clone())
+        .with_reproducible(args.reproducible);
+
     // Show progress if verbose
     let spinner = if args.verbose {
         let pb = ProgressBar::new_spinner();
@@ -133,8 +259,54 @@ fn main() -> anyhow::Result<()> {
         None
     };
 
+    if let Some(ref pb) = spinner {
+        let pb = pb.clone();
+        config = config.with_progress(Arc::new(move |p: ScanProgress| {
+            pb.set_message(format!("Scanning project... {}/{} files", p.files_done, p.files_total));
+        }));
+    }
+
     // Create scanner and run
+    let json_indent = config.json_indent;
     let scanner = ImportScanner::new(config)?;
+
+    // JSON Lines streams straight from the scanner unless a filter or check needs every file
+    let needs_full_map = args.deps_only
+        || args.unknown_only
+        || args.unused_only
+        || args.find_barrels
+        || args.allowed_deps.is_some()
+        || args.import_groups.is_some()
+        || args.check_cycles
+        || args.find_shadowing;
+    if matches!(args.format, OutputFormatArg::Jsonl) && !needs_full_map {
+        let writer: Box<dyn Write> = match args.output {
+            Some(ref path) => Box::new(BufWriter::new(fs::File::create(path)?)),
+            None => Box::new(BufWriter::new(io::stdout().lock())),
+        };
+        let summary = scanner.scan_streaming(writer)?;
+
+        if let Some(ref pb) = spinner {
+            pb.finish_with_message(format!(
+                "Scanned {} files in {}ms",
+                summary.stats.total_files, summary.metadata.scan_duration_ms
+            ));
+        }
+        if let (Some(path), true) = (&args.output, args.verbose) {
+            eprintln!("Output written to: {}", path.display());
+        }
+        if args.emit_run_summary {
+            let run = RunSummary {
+                files: summary.stats.total_files,
+                errors: summary.stats.failed_files,
+                duration_ms: summary.metadata.scan_duration_ms,
+                exit: 0,
+            };
+            write_run_summary(&mut io::stderr(), &run)?;
+        }
+        return Ok(());
+    }
+
     let result = scanner.scan()?;
 
     if let Some(ref pb) = spinner {
@@ -144,11 +316,45 @@ fn main() -> anyhow::Result<()> {
         ));
     }
 
+    // Check external imports against the allowlist before filtering
+    let violations = match args.allowed_deps {
+        Some(ref path) => DependencyAllowlist::from_file(path)?.find_violations(&result),
+        None => vec![],
+    };
+
+    let order_violations = match args.import_groups {
+        Some(ref spec) => ImportGroupOrder::parse(spec)?.find_violations(&result),
+        None => vec![],
+    };
+
+    let diagnosis = args.diagnose_unknown.then(|| result.diagnose_unknown());
+
+    let cycles = if args.check_cycles {
+        result.dependency_cycles()
+    } else {
+        vec![]
+    };
+
+    let shadowed = if args.find_shadowing {
+        result.find_shadowing()
+    } else {
+        vec![]
+    };
+
+    // Capture run totals before filtering rewrites the stats
+    let total_files = result.stats.total_files;
+    let failed_files = result.stats.failed_files;
+    let duration_ms = result.metadata.scan_duration_ms;
+
     // Apply filters
     let filtered_result = if args.deps_only {
         result.filter_to_dependencies()
     } else if args.unknown_only {
         result.filter_to_unknown()
+    } else if args.unused_only {
+        result.filter_to_unused()
+    } else if args.find_barrels {
+        result.filter_to_barrels()
     } else {
         result
     };
@@ -158,10 +364,18 @@ fn main() -> anyhow::Result<()> {
         control::set_override(false);
     }
 
-    let output = if args.flat {
-        format_output(&filtered_result, args.format.into())?
+    let format = if args.stats_only {
+        OutputFormat::StatsJson
     } else {
-        format_output_grouped(&filtered_result, args.format.into())?
+        args.format.into()
+    };
+
+    let output = if let Some(ref diagnosis) = diagnosis {
+        to_json_with_indent(diagnosis, json_indent)?
+    } else if args.flat {
+        format_output_with_indent(&filtered_result, format, json_indent)?
+    } else {
+        format_output_grouped_with_indent(&filtered_result, format, json_indent)?
     };
 
     // Write output
@@ -175,5 +389,94 @@ fn main() -> anyhow::Result<()> {
         println!("{}", output);
     }
 
+    // Report violations on stderr so stdout stays machine-readable
+    for violation in &violations {
+        eprintln!(
+            "policy_violation: {}:{}: '{}' is not an allowed dependency",
+            violation.file.display(),
+            violation.line,
+            violation.package
+        );
+    }
+
+    for violation in &order_violations {
+        eprintln!(
+            "import_order: {}:{}: '{}' ({}) should come before {} imports (line {})",
+            violation.file.display(),
+            violation.line,
+            violation.module,
+            violation.group.as_str(),
+            violation.after_group.as_str(),
+            violation.after_line
+        );
+    }
+
+    for cycle in &cycles {
+        eprintln!("dependency_cycle: {}", cycle.join(" <-> "));
+    }
+
+    let unused_count = if args.unused_only {
+        for file in &filtered_result.files {
+            for import in &file.imports {
+                eprintln!(
+                    "unused_import: {}:{}: '{}' is imported but never used",
+                    file.path.display(),
+                    import.line,
+                    import.raw.trim()
+                );
+            }
+        }
+        filtered_result.stats.total_imports
+    } else {
+        0
+    };
+
+    for import in &shadowed {
+        eprintln!(
+            "stdlib_shadowing: {}:{}: local module '{}' shadows the standard library",
+            import.file.display(),
+            import.line,
+            import.shadowed_name
+        );
+    }
+
+    let failed = (args.fail_on_violation
+        && (!violations.is_empty() || !order_violations.is_empty()))
+        || !cycles.is_empty()
+        || unused_count > 0;
+    let exit = if failed { 1 } else { 0 };
+
+    // Run telemetry goes to stderr so stdout stays machine-readable
+    if args.emit_run_summary {
+        let summary = RunSummary {
+            files: total_files,
+            errors: failed_files,
+            duration_ms,
+            exit,
+        };
+        write_run_summary(&mut io::stderr(), &summary)?;
+    }
+
+    if exit != 0 {
+        std::process::exit(exit);
+    }
+
+    Ok(())
+}
+
+/// Scan a wheel or sdist and write its imports and declared dependencies
+fn run_archive(args: &Args) -> anyhow::Result<()> {
+    let scan = scan_archive(&args.path)?;
+    let output = match args.format {
+        OutputFormatArg::Json => to_json_with_indent(&scan, args.json_indent)?,
+        OutputFormatArg::Yaml => serde_yaml::to_string(&scan)?,
+        OutputFormatArg::Toml => to_toml(&scan)?,
+        _ => anyhow::bail!("archives support --format json, yaml or toml"),
+    };
+
+    match args.output {
+        Some(ref path) => fs::write(path, &output)?,
+        None => println!("{}", output),
+    }
     Ok(())
 }
@@ -0,0 +1,48 @@
+use std::fs;
+use std::process::Command;
+
+/// Stderr of a successful `mapimports --emit-run-summary` run with `args`
+fn stderr_of(args: &[&str]) -> String {
+    let output = Command::new(env!("CARGO_BIN_EXE_mapimports"))
+        .args(args)
+        .arg("--emit-run-summary")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    String::from_utf8(output.stderr).unwrap()
+}
+
+/// The JSON run summary line in `stderr`
+fn run_summary(stderr: &str) -> serde_json::Value {
+    let line = stderr
+        .lines()
+        .find(|line| line.starts_with('{'))
+        .expect("no run summary on stderr");
+    serde_json::from_str(line).unwrap()
+}
+
+#[test]
+fn test_run_summary_counts_only_unreadable_files() {
+    let dir = tempfile::TempDir::new().unwrap();
+    let root = dir.path().to_str().unwrap();
+    // Both stdlib imports after a local import break the `stdlib,local` order
+    fs::write(
+        dir.path().join("app.py"),
+        "from . import helpers\nimport os\nimport sys\n",
+    )
+    .unwrap();
+    fs::write(dir.path().join("helpers.py"), "import os\n").unwrap();
+    fs::write(dir.path().join("latin1.py"), b"# caf\xe9\nimport os\n").unwrap();
+
+    let stderr = stderr_of(&[root, "--import-groups", "stdlib,local"]);
+    assert!(stderr.contains("import_order: app.py:2"));
+    assert!(stderr.contains("import_order: app.py:3"));
+    let summary = run_summary(&stderr);
+    assert_eq!(summary["files"], 2);
+    assert_eq!(summary["errors"], 1);
+    assert_eq!(summary["exit"], 0);
+
+    let summary = run_summary(&stderr_of(&[root, "--format", "jsonl"]));
+    assert_eq!(summary["files"], 2);
+    assert_eq!(summary["errors"], 1);
+}
@@ -0,0 +1,280 @@
+//! Import analysis of packaged Python distributions without unpacking them
+//!
+//! Wheels (`.whl`, a zip) and sdists (`.tar.gz`) are read in memory: every `.py`
+//! member is parsed for imports, and the embedded `METADATA` (wheel) or
+//! `PKG-INFO` (sdist) supplies the declared `Requires-Dist` dependencies that
+//! feed the categorizer.
+
+use crate::categorizer::ImportCategorizer;
+use crate::models::{Language, PackageManifest, SourceFile};
+use crate::parsers::create_parser;
+use crate::requirements::parse_requirement;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::io::{Read, Seek};
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ArchiveError {
+    #[error("IO error: {0}")]
+    IoError(#[from] std::io::Error),
+    #[error("Zip error: {0}")]
+    ZipError(#[from] zip::result::ZipError),
+    #[error("Parser error: {0}")]
+    ParserError(#[from] crate::parsers::ParserError),
+    #[error("Unsupported archive (expected .whl, .tar.gz or .tgz): {0}")]
+    Unsupported(PathBuf),
+}
+
+/// Packaging format of a Python distribution
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ArchiveKind {
+    /// Built distribution (`.whl`)
+    Wheel,
+    /// Source distribution (`.tar.gz` / `.tgz`)
+    Sdist,
+}
+
+impl ArchiveKind {
+    /// Detect the format from the file name
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let name = path.file_name()?.to_string_lossy().to_lowercase();
+        if name.ends_with(".whl") {
+            Some(ArchiveKind::Wheel)
+        } else if name.ends_with(".tar.gz") || name.ends_with(".tgz") {
+            Some(ArchiveKind::Sdist)
+        } else {
+            None
+        }
+    }
+
+    /// Whether an archive member is the distribution's metadata file
+    ///
+    /// Wheels keep it at `<name>-<version>.dist-info/METADATA`, sdists at
+    /// `<name>-<version>/PKG-INFO` (nested `*.egg-info/PKG-INFO` copies are ignored).
+    fn is_metadata(&self, member: &str) -> bool {
+        let parts: Vec<&str> = member.split('/').collect();
+        match (self, parts.as_slice()) {
+            (ArchiveKind::Wheel, [dir, "METADATA"]) => dir.ends_with(".dist-info"),
+            (ArchiveKind::Sdist, [_, "PKG-INFO"]) => true,
+            _ => false,
+        }
+    }
+}
+
+/// Imports and declared dependencies of one wheel or sdist
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArchiveScan {
+    /// Path of the archive
+    pub archive: PathBuf,
+    /// Packaging format
+    pub kind: ArchiveKind,
+    /// Name, version and `Requires-Dist` dependencies from METADATA / PKG-INFO
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub manifest: Option<PackageManifest>,
+    /// Python modules, with paths relative to the archive root
+    pub files: Vec<SourceFile>,
+}
+
+/// Scan a `.whl`, `.tar.gz` or `.tgz` file, picking the format from its name
+pub fn scan_archive(path: &Path) -> Result<ArchiveScan, ArchiveError> {
+    let kind =
+        ArchiveKind::from_path(path).ok_or_else(|| ArchiveError::Unsupported(path.to_path_buf()))?;
+    let file = fs::File::open(path)?;
+    match kind {
+        ArchiveKind::Wheel => scan_wheel(file, path),
+        ArchiveKind::Sdist => scan_sdist(file, path),
+    }
+}
+
+/// Scan a wheel read from `reader`; `archive` is only used to label paths
+pub fn scan_wheel<R: Read + Seek>(reader: R, archive: &Path) -> Result<ArchiveScan, ArchiveError> {
+    let kind = ArchiveKind::Wheel;
+    let mut zip = zip::ZipArchive::new(reader)?;
+    let mut members = Vec::new();
+
+    for i in 0..zip.len() {
+        let mut entry = zip.by_index(i)?;
+        let name = entry.name().to_string();
+        if entry.is_file() && (name.ends_with(".py") || kind.is_metadata(&name)) {
+            let mut content = String::new();
+            // Members that are not valid UTF-8 cannot be parsed and are skipped
+            if entry.read_to_string(&mut content).is_ok() {
+                members.push((name, content));
+            }
+        }
+    }
+
+    build_scan(archive, kind, members)
+}
+
+/// Scan a gzipped sdist tarball read from `reader`; `archive` is only used to label paths
+pub fn scan_sdist<R: Read>(reader: R, archive: &Path) -> Result<ArchiveScan, ArchiveError> {
+    let kind = ArchiveKind::Sdist;
+    let mut tar = tar::Archive::new(flate2::read::GzDecoder::new(reader));
+    let mut members = Vec::new();
+
+    for entry in tar.entries()? {
+        let mut entry = entry?;
+        let name = entry.path()?.to_string_lossy().replace('\\', "/");
+        if entry.header().entry_type().is_file()
+            && (name.ends_with(".py") || kind.is_metadata(&name))
+        {
+            let mut content = String::new();
+            if entry.read_to_string(&mut content).is_ok() {
+                members.push((name, content));
+            }
+        }
+    }
+
+    build_scan(archive, kind, members)
+}
+
+/// Parse the headers of a METADATA / PKG-INFO file into a manifest
+///
+/// Only `Name`, `Version` and `Requires-Dist` are read; the headers end at the
+/// first blank line, after which the long description starts. Dependencies that
+/// are only pulled in by an extra keep their `extra == "..."` marker.
+pub fn parse_package_metadata(content: &str, source: &Path) -> Option<PackageManifest> {
+    let mut name = None;
+    let mut version = None;
+    let mut dependencies = HashMap::new();
+
+    for line in content.lines().take_while(|line| !line.trim().is_empty()) {
+        let Some((key, value)) = line.split_once(':') else {
+            continue;
+        };
+        let value = value.trim();
+        match key.trim() {
+            "Name" => name = Some(value.to_string()),
+            "Version" => version = Some(value.to_string()),
+            "Requires-Dist" => {
+                if let Some(dep) = parse_requirement(value, source) {
+                    dependencies.insert(dep.name.clone(), dep);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    Some(PackageManifest {
+        name: name?,
+        version,
+        path: source.to_path_buf(),
+        language: Language::Python,
+        dependencies,
+        dev_dependencies: HashMap::new(),
+    })
+}
+
+/// Parse the collected `(member path, content)` pairs into an archive scan
+fn build_scan(
+    archive: &Path,
+    kind: ArchiveKind,
+    members: Vec<(String, String)>,
+) -> Result<ArchiveScan, ArchiveError> {
+    let manifest = members
+        .iter()
+        .find(|(name, _)| kind.is_metadata(name))
+        .and_then(|(name, content)| parse_package_metadata(content, &archive.join(name)));
+    let manifests: Vec<PackageManifest> = manifest.iter().cloned().collect();
+    let categorizer = ImportCategorizer::new(&manifests);
+    let mut parser = create_parser(&Language::Python)?;
+
+    let mut files: Vec<SourceFile> = members
+        .into_iter()
+        .filter(|(name, _)| name.ends_with(".py"))
+        .map(|(name, content)| {
+            let mut imports = parser.parse(&content);
+            for import in &mut imports {
+                import.import_type = categorizer.categorize(&import.module, &Language::Python);
+            }
+            SourceFile {
+                absolute_path: archive.join(&name),
+                path: PathBuf::from(name),
+                language: Language::Python,
+                imports,
+                package: manifest.as_ref().map(|m| m.name.clone()),
+                is_barrel: false,
+            }
+        })
+        .collect();
+    files.sort_by(|a, b| a.path.cmp(&b.path));
+
+    Ok(ArchiveScan {
+        archive: archive.to_path_buf(),
+        kind,
+        manifest,
+        files,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::ImportType;
+    use std::io::{Cursor, Write};
+
+    fn wheel(members: &[(&str, &str)]) -> Cursor<Vec<u8>> {
+        let mut zip = zip::ZipWriter::new(Cursor::new(Vec::new()));
+        for (name, content) in members {
+            zip.start_file(*name, zip::write::SimpleFileOptions::default()).unwrap();
+            zip.write_all(content.as_bytes()).unwrap();
+        }
+        let mut bytes = zip.finish().unwrap();
+        bytes.set_position(0);
+        bytes
+    }
+
+    #[test]
+    fn test_scan_wheel() {
+        let archive = wheel(&[
+            (
+                "demo/__init__.py",
+                "import os\nimport requests\nfrom yaml import safe_load\nfrom .util import helper\n",
+            ),
+            (
+                "demo-1.0.dist-info/METADATA",
+                "Metadata-Version: 2.1\nName: demo\nVersion: 1.0\n\
+                 Requires-Dist: requests (>=2.28)\n\
+                 Requires-Dist: pytest; extra == \"test\"\n\n\
+                 Long description mentioning Name: other\n",
+            ),
+        ]);
+
+        let scan = scan_wheel(archive, Path::new("demo-1.0-py3-none-any.whl")).unwrap();
+        assert_eq!(scan.kind, ArchiveKind::Wheel);
+
+        let manifest = scan.manifest.as_ref().unwrap();
+        assert_eq!(manifest.name, "demo");
+        assert_eq!(manifest.version.as_deref(), Some("1.0"));
+        assert_eq!(manifest.dependencies["requests"].version, ">=2.28");
+        assert_eq!(manifest.dependencies["pytest"].marker.as_deref(), Some("extra == \"test\""));
+
+        assert_eq!(scan.files.len(), 1);
+        let file = &scan.files[0];
+        assert_eq!(file.path, PathBuf::from("demo/__init__.py"));
+        assert_eq!(file.package.as_deref(), Some("demo"));
+        let types: HashMap<&str, ImportType> = file
+            .imports
+            .iter()
+            .map(|i| (i.module.as_str(), i.import_type.clone()))
+            .collect();
+        assert_eq!(types["os"], ImportType::Stdlib);
+        assert_eq!(types["requests"], ImportType::External);
+        assert_eq!(types["yaml"], ImportType::Unknown);
+        assert_eq!(types[".util"], ImportType::Local);
+    }
+
+    #[test]
+    fn test_archive_kind() {
+        assert_eq!(ArchiveKind::from_path(Path::new("a-1.0.whl")), Some(ArchiveKind::Wheel));
+        assert_eq!(ArchiveKind::from_path(Path::new("a-1.0.tar.gz")), Some(ArchiveKind::Sdist));
+        assert_eq!(ArchiveKind::from_path(Path::new("a-1.0.zip")), None);
+        assert!(ArchiveKind::Sdist.is_metadata("a-1.0/PKG-INFO"));
+        assert!(!ArchiveKind::Sdist.is_metadata("a-1.0/a.egg-info/PKG-INFO"));
+    }
+}
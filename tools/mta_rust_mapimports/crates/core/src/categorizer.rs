@@ -1,5 +1,7 @@
 use crate::models::{ImportType, Language, PackageManifest};
 use std::collections::HashSet;
+use std::fmt;
+use std::sync::Arc;
 
 /// Directories that contain internal/workspace packages
 const INTERNAL_PACKAGE_DIRS: &[&str] = &[
@@ -27,6 +29,39 @@ const EXCLUDED_DIRS: &[&str] = &[
     "target",
 ];
 
+/// A custom classification rule consulted before the built-in heuristics
+///
+/// Rules let an organization encode its own conventions (internal prefixes,
+/// private registries) without patching the categorizer.
+pub trait CategoryRule: Send + Sync + fmt::Debug {
+    /// Return the category for `module`, or `None` to defer to later rules
+    fn classify(&self, module: &str, language: &Language) -> Option<ImportType>;
+}
+
+/// Classifies every module that starts with a prefix, e.g. `@mycompany/` → Internal
+#[derive(Debug, Clone)]
+pub struct PrefixRule {
+    prefix: String,
+    import_type: ImportType,
+}
+
+impl PrefixRule {
+    pub fn new(prefix: impl Into<String>, import_type: ImportType) -> Self {
+        Self {
+            prefix: prefix.into(),
+            import_type,
+        }
+    }
+}
+
+impl CategoryRule for PrefixRule {
+    fn classify(&self, module: &str, _language: &Language) -> Option<ImportType> {
+        module
+            .starts_with(&self.prefix)
+            .then(|| self.import_type.clone())
+    }
+}
+
 /// Categorizes imports as internal, external, local, stdlib, or unknown
 pub struct ImportCategorizer {
     /// Names of internal/workspace packages
@@ -37,6 +72,8 @@ pub struct ImportCategorizer {
     node_builtins: HashSet<String>,
     /// External dependencies from manifests
     external_deps: HashSet<String>,
+    /// Custom rules, consulted in registration order before the built-in heuristics
+    rules: Vec<Arc<dyn CategoryRule>>,
 }
 
 impl ImportCategorizer {
@@ -47,6 +84,7 @@ impl ImportCategorizer {
             python_stdlib: Self::python_stdlib_modules(),
             node_builtins: Self::node_builtin_modules(),
             external_deps: HashSet::new(),
+            rules: Vec::new(),
         };
 
         for manifest in manifests {
@@ -85,6 +123,18 @@ impl ImportCategorizer {
         categorizer
     }
 
+    /// Register a custom rule; rules run in registration order and the first match wins
+    pub fn with_rule(mut self, rule: Arc<dyn CategoryRule>) -> Self {
+        self.rules.push(rule);
+        self
+    }
+
+    /// Register several custom rules (see [`ImportCategorizer::with_rule`])
+    pub fn with_rules(mut self, rules: &[Arc<dyn CategoryRule>]) -> Self {
+        self.rules.extend(rules.iter().cloned());
+        self
+    }
+
     /// Check if a manifest path indicates an internal/workspace package
     fn is_internal_package_path(path: &str) -> bool {
         // Check if path is in any excluded directory
@@ -104,6 +154,13 @@ impl ImportCategorizer {
 
     /// Categorize an import based on its module name and language
     pub fn categorize(&self, module: &str, language: &Language) -> ImportType {
+        // 0. Custom rules take precedence over every built-in heuristic
+        for rule in &self.rules {
+            if let Some(import_type) = rule.classify(module, language) {
+                return import_type;
+            }
+        }
+
         // 1. Check for local/relative imports
         if module.starts_with('.')
             || module.starts_with("./")
@@ -162,9 +219,19 @@ impl ImportCategorizer {
         ImportType::Unknown
     }
 
+    /// Check whether `name` is a stdlib module (Python) or builtin module (Node.js)
+    pub fn is_builtin_name(&self, name: &str, language: &Language) -> bool {
+        match language {
+            Language::Python => self.python_stdlib.contains(name),
+            Language::JavaScript | Language::TypeScript => self.node_builtins.contains(name),
+        }
+    }
+
     /// Get the list of known internal packages
     pub fn internal_packages(&self) -> Vec<String> {
-        self.internal_packages.iter().cloned().collect()
+        let mut packages: Vec<String> = self.internal_packages.iter().cloned().collect();
+        packages.sort();
+        packages
     }
 
     /// Python standard library modules
@@ -269,6 +336,9 @@ mod tests {
                     internal: false,
                     relative: false,
                     local_path: None,
+                    extras: vec![],
+                    marker: None,
+                    group: None,
                 },
             );
         }
@@ -392,4 +462,37 @@ mod tests {
             ImportType::External
         );
     }
+
+    #[test]
+    fn test_optional_dependency_groups_are_external() {
+        let source = PathBuf::from("/project/pyproject.toml");
+        let content = "[project.optional-dependencies]\ntest = [\"pytest>=8\"]\n";
+        let manifest = crate::manifest::parse_pyproject(content, &source).unwrap();
+
+        let categorizer = ImportCategorizer::new(&[manifest]);
+        assert_eq!(
+            categorizer.categorize("pytest", &Language::Python),
+            ImportType::External
+        );
+        assert_eq!(
+            ImportCategorizer::new(&[]).categorize("pytest", &Language::Python),
+            ImportType::Unknown
+        );
+    }
+
+    #[test]
+    fn test_prefix_rule_overrides_scoped_package() {
+        let categorizer = ImportCategorizer::new(&[])
+            .with_rule(Arc::new(PrefixRule::new("@mycompany/", ImportType::Internal)));
+
+        assert_eq!(
+            categorizer.categorize("@mycompany/ui-kit", &Language::TypeScript),
+            ImportType::Internal
+        );
+        // Other scoped packages still fall through to the built-in heuristics
+        assert_eq!(
+            categorizer.categorize("@fastify/cors", &Language::JavaScript),
+            ImportType::External
+        );
+    }
 }
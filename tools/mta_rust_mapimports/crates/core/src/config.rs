@@ -1,7 +1,12 @@
-use crate::models::Language;
+use crate::categorizer::CategoryRule;
+use crate::models::{Language, ScanProgress};
+use crate::output::IndentStyle;
 use globset::{Glob, GlobSet, GlobSetBuilder};
 use ignore::gitignore::{Gitignore, GitignoreBuilder};
+use ignore::Match;
+use std::fmt;
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -14,6 +19,16 @@ pub enum ConfigError {
     IoError(#[from] std::io::Error),
 }
 
+/// Progress callback shared with the scan's worker threads
+#[derive(Clone)]
+pub struct ProgressHook(pub Arc<dyn Fn(ScanProgress) + Send + Sync>);
+
+impl fmt::Debug for ProgressHook {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ProgressHook(..)")
+    }
+}
+
 /// Configuration for scanning
 #[derive(Debug, Clone)]
 pub struct ScanConfig {
@@ -23,12 +38,32 @@ pub struct ScanConfig {
     pub language_filter: Option<Vec<Language>>,
     /// Additional ignore patterns (glob style)
     pub ignore_patterns: Vec<String>,
+    /// Include patterns relative to root (glob style, empty = every file)
+    pub include_patterns: Vec<String>,
     /// Custom ignore file path
     pub ignore_file: Option<PathBuf>,
     /// Include node_modules/.venv in scan
     pub include_deps: bool,
     /// Number of threads (0 = auto)
     pub threads: usize,
+    /// Maximum directory depth to walk (None = unlimited, 1 = root only)
+    pub max_directory_depth: Option<usize>,
+    /// Indentation for pretty-printed JSON output
+    pub json_indent: IndentStyle,
+    /// Leading path prefix removed from every file's path/absolute_path
+    pub strip_prefix: Option<PathBuf>,
+    /// Write serialized paths with `/` separators regardless of platform
+    pub forward_slashes: bool,
+    /// Pinned metadata timestamp (overrides `SOURCE_DATE_EPOCH` and the clock)
+    pub fixed_timestamp: Option<String>,
+    /// Record a zero scan duration in metadata
+    pub reproducible: bool,
+    /// Only scan files staged in the git index
+    pub staged_only: bool,
+    /// Custom categorization rules consulted before the built-in heuristics
+    pub category_rules: Vec<Arc<dyn CategoryRule>>,
+    /// Called periodically with scan progress
+    pub progress: Option<ProgressHook>,
 }
 
 impl Default for ScanConfig {
@@ -37,9 +72,19 @@ impl Default for ScanConfig {
             root: PathBuf::from("."),
             language_filter: None,
             ignore_patterns: vec![],
+            include_patterns: vec![],
             ignore_file: None,
             include_deps: false,
             threads: 0,
+            max_directory_depth: None,
+            json_indent: IndentStyle::default(),
+            strip_prefix: None,
+            forward_slashes: true,
+            fixed_timestamp: None,
+            reproducible: false,
+            staged_only: false,
+            category_rules: vec![],
+            progress: None,
         }
     }
 }
@@ -62,6 +107,13 @@ impl ScanConfig {
         self
     }
 
+    /// Only scan files matching at least one of these globs, relative to root;
+    /// the ignore and language filters still apply
+    pub fn with_include_patterns(mut self, patterns: Vec<String>) -> Self {
+        self.include_patterns = patterns;
+        self
+    }
+
     pub fn with_ignore_file(mut self, path: PathBuf) -> Self {
         self.ignore_file = Some(path);
         self
@@ -76,31 +128,125 @@ impl ScanConfig {
         self.threads = threads;
         self
     }
+
+    pub fn with_max_directory_depth(mut self, depth: usize) -> Self {
+        self.max_directory_depth = Some(depth);
+        self
+    }
+
+    pub fn with_json_indent(mut self, indent: IndentStyle) -> Self {
+        self.json_indent = indent;
+        self
+    }
+
+    pub fn with_path_transform(mut self, prefix_to_strip: String) -> Self {
+        self.strip_prefix = Some(PathBuf::from(prefix_to_strip));
+        self
+    }
+
+    /// Restrict the scan to files staged in git (`git diff --cached`)
+    pub fn with_staged_only(mut self, enabled: bool) -> Self {
+        self.staged_only = enabled;
+        self
+    }
+
+    /// Register a custom categorization rule (runs before the built-in heuristics)
+    pub fn with_category_rule(mut self, rule: impl CategoryRule + 'static) -> Self {
+        self.category_rules.push(Arc::new(rule));
+        self
+    }
+
+    /// Report progress while files are parsed, about 100 times per scan and always
+    /// after the last file. May be called from worker threads, one call at a time.
+    pub fn with_progress(mut self, callback: Arc<dyn Fn(ScanProgress) + Send + Sync>) -> Self {
+        self.progress = Some(ProgressHook(callback));
+        self
+    }
+
+    /// Normalize serialized paths to forward slashes (default: true)
+    pub fn with_forward_slashes(mut self, enabled: bool) -> Self {
+        self.forward_slashes = enabled;
+        self
+    }
+
+    /// Pin the metadata timestamp (RFC 3339) for reproducible output
+    pub fn with_fixed_timestamp(mut self, timestamp: Option<String>) -> Self {
+        self.fixed_timestamp = timestamp;
+        self
+    }
+
+    /// Zero the scan duration in metadata so repeated runs are byte-identical
+    pub fn with_reproducible(mut self, enabled: bool) -> Self {
+        self.reproducible = enabled;
+        self
+    }
+
+    /// Timestamp for scan metadata: the pinned value, then `SOURCE_DATE_EPOCH`, then now
+    pub fn metadata_timestamp(&self) -> String {
+        if let Some(ref timestamp) = self.fixed_timestamp {
+            return timestamp.clone();
+        }
+        std::env::var("SOURCE_DATE_EPOCH")
+            .ok()
+            .and_then(|epoch| epoch.trim().parse::<i64>().ok())
+            .and_then(|secs| chrono::DateTime::from_timestamp(secs, 0))
+            .map(|dt| dt.to_rfc3339())
+            .unwrap_or_else(|| chrono::Utc::now().to_rfc3339())
+    }
+
+    /// Apply the configured path transform; paths outside the prefix are unchanged
+    pub fn transform_path(&self, path: &Path) -> PathBuf {
+        let path = match self.strip_prefix {
+            Some(ref prefix) => path.strip_prefix(prefix).unwrap_or(path),
+            None => path,
+        };
+        self.normalize_separators(path)
+    }
+
+    /// Rewrite `\` separators to `/` when `forward_slashes` is enabled
+    pub fn normalize_separators(&self, path: &Path) -> PathBuf {
+        if self.forward_slashes {
+            PathBuf::from(path.to_string_lossy().replace('\\', "/"))
+        } else {
+            path.to_path_buf()
+        }
+    }
 }
 
+/// Tool-specific ignore file read from the project root on top of `.gitignore`
+pub const TOOL_IGNORE_FILE: &str = ".mapimportsignore";
+
 /// Filter for ignoring files and directories
 pub struct IgnoreFilter {
     gitignore: Option<Gitignore>,
     custom_globs: GlobSet,
     default_ignores: GlobSet,
+    /// Include globs (None = include everything)
+    include_globs: Option<GlobSet>,
+    /// Root that include globs are relative to
+    root: PathBuf,
 }
 
 impl IgnoreFilter {
     pub fn new(config: &ScanConfig) -> Result<Self, ConfigError> {
-        // Load .gitignore if present
-        let gitignore = if let Some(ref ignore_file) = config.ignore_file {
-            let mut builder = GitignoreBuilder::new(&config.root);
-            builder.add(ignore_file);
-            Some(builder.build()?)
+        // Layer the tool ignore file over .gitignore (or the configured ignore file)
+        let base_ignore = config
+            .ignore_file
+            .clone()
+            .unwrap_or_else(|| config.root.join(".gitignore"));
+        let layers: Vec<PathBuf> = [base_ignore, config.root.join(TOOL_IGNORE_FILE)]
+            .into_iter()
+            .filter(|path| path.exists())
+            .collect();
+        let gitignore = if layers.is_empty() {
+            None
         } else {
-            let gitignore_path = config.root.join(".gitignore");
-            if gitignore_path.exists() {
-                let mut builder = GitignoreBuilder::new(&config.root);
-                builder.add(&gitignore_path);
-                Some(builder.build()?)
-            } else {
-                None
+            // Later files win, so a `!pattern` in the tool file re-includes a gitignored path
+            let mut builder = GitignoreBuilder::new(&config.root);
+            for layer in &layers {
+                builder.add(layer);
             }
+            Some(builder.build()?)
         };
 
         // Build custom ignore globs
@@ -110,6 +256,17 @@ impl IgnoreFilter {
         }
         let custom_globs = custom_builder.build()?;
 
+        // Build include globs
+        let include_globs = if config.include_patterns.is_empty() {
+            None
+        } else {
+            let mut include_builder = GlobSetBuilder::new();
+            for pattern in &config.include_patterns {
+                include_builder.add(Glob::new(pattern)?);
+            }
+            Some(include_builder.build()?)
+        };
+
         // Default ignores (unless include_deps is true)
         let mut default_builder = GlobSetBuilder::new();
         if !config.include_deps {
@@ -134,31 +291,41 @@ impl IgnoreFilter {
             gitignore,
             custom_globs,
             default_ignores,
+            include_globs,
+            root: config.root.clone(),
         })
     }
 
     /// Check if a path should be ignored
+    ///
+    /// Layers apply in increasing precedence: built-in excludes, `.gitignore`,
+    /// `.mapimportsignore`, then `--ignore` patterns. A `!pattern` in either ignore
+    /// file re-includes a path excluded by an earlier layer.
     pub fn should_ignore(&self, path: &Path, is_dir: bool) -> bool {
         let path_str = path.to_string_lossy();
 
         // Check default ignores
-        if self.default_ignores.is_match(&*path_str) {
-            return true;
-        }
+        let mut ignored = self.default_ignores.is_match(&*path_str);
 
-        // Check custom patterns
-        if self.custom_globs.is_match(&*path_str) {
-            return true;
-        }
-
-        // Check gitignore
+        // Check .gitignore and the tool ignore file
         if let Some(ref gi) = self.gitignore {
-            if gi.matched(path, is_dir).is_ignore() {
-                return true;
+            match gi.matched(path, is_dir) {
+                Match::Ignore(_) => ignored = true,
+                Match::Whitelist(_) => ignored = false,
+                Match::None => {}
             }
         }
 
-        false
+        // Check custom patterns
+        ignored || self.custom_globs.is_match(&*path_str)
+    }
+
+    /// Check if a file matches the include patterns (always true when there are none)
+    pub fn matches_include_patterns(&self, path: &Path) -> bool {
+        match self.include_globs {
+            Some(ref globs) => globs.is_match(path.strip_prefix(&self.root).unwrap_or(path)),
+            None => true,
+        }
     }
 
     /// Check if a file extension matches the language filter
@@ -205,4 +372,47 @@ mod tests {
         assert!(config.include_deps);
         assert_eq!(config.threads, 4);
     }
+
+    #[test]
+    fn test_include_patterns() {
+        let filter = IgnoreFilter::new(&ScanConfig::new(PathBuf::from("/repo"))).unwrap();
+        assert!(filter.matches_include_patterns(Path::new("/repo/anything.py")));
+
+        let config = ScanConfig::new(PathBuf::from("/repo"))
+            .with_include_patterns(vec!["packages_py/**".to_string()]);
+        let filter = IgnoreFilter::new(&config).unwrap();
+        assert!(filter.matches_include_patterns(Path::new("/repo/packages_py/auth/app.py")));
+        assert!(!filter.matches_include_patterns(Path::new("/repo/fastify_apps/web/index.ts")));
+    }
+
+    #[test]
+    fn test_forward_slashes() {
+        let windows_path = PathBuf::from("src\\pkg\\module.py");
+
+        let config = ScanConfig::default();
+        assert!(config.forward_slashes);
+        assert_eq!(config.transform_path(&windows_path), PathBuf::from("src/pkg/module.py"));
+
+        let config = ScanConfig::default().with_forward_slashes(false);
+        assert_eq!(config.transform_path(&windows_path), windows_path);
+    }
+
+    #[test]
+    fn test_tool_ignore_file_overrides_gitignore() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join(".gitignore"), "*.gen.py\n").unwrap();
+        std::fs::write(dir.path().join(TOOL_IGNORE_FILE), "!keep.gen.py\n").unwrap();
+        let keep = dir.path().join("keep.gen.py");
+        let other = dir.path().join("other.gen.py");
+
+        let filter = IgnoreFilter::new(&ScanConfig::new(dir.path().to_path_buf())).unwrap();
+        assert!(!filter.should_ignore(&keep, false));
+        assert!(filter.should_ignore(&other, false));
+
+        // `--ignore` patterns still take precedence over the ignore files
+        let config = ScanConfig::new(dir.path().to_path_buf())
+            .with_ignore_patterns(vec!["**/keep.gen.py".to_string()]);
+        let filter = IgnoreFilter::new(&config).unwrap();
+        assert!(filter.should_ignore(&keep, false));
+    }
 }
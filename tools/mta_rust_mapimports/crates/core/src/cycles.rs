@@ -0,0 +1,168 @@
+use crate::models::{ImportMap, ImportType};
+use crate::policy::{base_package, normalize_package};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+impl ImportMap {
+    /// Find cycles in the internal package dependency graph
+    ///
+    /// Each file's `package` gets an edge to the package of every `Internal`
+    /// import it makes. Returns the strongly-connected components with more
+    /// than one package, each sorted by name, so `[a, b]` means `a` and `b`
+    /// (transitively) import each other. Files outside any package and
+    /// imports within the same package are ignored.
+    pub fn dependency_cycles(&self) -> Vec<Vec<String>> {
+        // Package names compare normalized; report the manifest spelling when known
+        let mut names: HashMap<String, String> = self
+            .internal_packages
+            .iter()
+            .map(|p| (normalize_package(p), p.clone()))
+            .collect();
+
+        let mut graph: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+        for file in &self.files {
+            let Some(package) = &file.package else {
+                continue;
+            };
+            let from = normalize_package(package);
+            names.entry(from.clone()).or_insert_with(|| package.clone());
+
+            for import in &file.imports {
+                if import.import_type != ImportType::Internal {
+                    continue;
+                }
+                let target = base_package(&import.module);
+                let to = normalize_package(&target);
+                if to != from {
+                    names.entry(to.clone()).or_insert(target);
+                    graph.entry(from.clone()).or_default().insert(to);
+                }
+            }
+        }
+
+        let mut cycles: Vec<Vec<String>> = strongly_connected(&graph)
+            .into_iter()
+            .filter(|component| component.len() > 1)
+            .map(|component| {
+                let mut packages: Vec<String> =
+                    component.iter().map(|p| names[*p].clone()).collect();
+                packages.sort();
+                packages
+            })
+            .collect();
+        cycles.sort();
+        cycles
+    }
+}
+
+/// Tarjan's algorithm over a package graph
+fn strongly_connected(graph: &BTreeMap<String, BTreeSet<String>>) -> Vec<Vec<&str>> {
+    struct Tarjan<'a> {
+        graph: &'a BTreeMap<String, BTreeSet<String>>,
+        index: HashMap<&'a str, usize>,
+        low: HashMap<&'a str, usize>,
+        stack: Vec<&'a str>,
+        on_stack: BTreeSet<&'a str>,
+        components: Vec<Vec<&'a str>>,
+    }
+
+    impl<'a> Tarjan<'a> {
+        fn visit(&mut self, node: &'a str) {
+            let index = self.index.len();
+            self.index.insert(node, index);
+            self.low.insert(node, index);
+            self.stack.push(node);
+            self.on_stack.insert(node);
+
+            let graph = self.graph;
+            for next in graph.get(node).into_iter().flatten() {
+                let next = next.as_str();
+                if !self.index.contains_key(next) {
+                    self.visit(next);
+                    let low = self.low[node].min(self.low[next]);
+                    self.low.insert(node, low);
+                } else if self.on_stack.contains(next) {
+                    let low = self.low[node].min(self.index[next]);
+                    self.low.insert(node, low);
+                }
+            }
+
+            if self.low[node] == self.index[node] {
+                let mut component = Vec::new();
+                while let Some(member) = self.stack.pop() {
+                    self.on_stack.remove(member);
+                    component.push(member);
+                    if member == node {
+                        break;
+                    }
+                }
+                self.components.push(component);
+            }
+        }
+    }
+
+    let mut tarjan = Tarjan {
+        graph,
+        index: HashMap::new(),
+        low: HashMap::new(),
+        stack: Vec::new(),
+        on_stack: BTreeSet::new(),
+        components: Vec::new(),
+    };
+    for node in graph.keys() {
+        if !tarjan.index.contains_key(node.as_str()) {
+            tarjan.visit(node);
+        }
+    }
+    tarjan.components
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::{ImportType, Language, SourceFile};
+    use crate::test_support::{import, import_map, source_file};
+
+    fn file(package: &str, imports: &[&str]) -> SourceFile {
+        SourceFile {
+            package: Some(package.to_string()),
+            ..source_file(
+                &format!("{}/index.ts", package),
+                Language::TypeScript,
+                imports
+                    .iter()
+                    .enumerate()
+                    .map(|(i, module)| import(module, i + 1, ImportType::Internal))
+                    .collect(),
+            )
+        }
+    }
+
+    #[test]
+    fn test_dependency_cycles() {
+        let map = import_map(vec![
+            file("@acme/api", &["@acme/db/client", "@acme/util"]),
+            file("@acme/db", &["@acme/auth"]),
+            file("@acme/auth", &["@acme/api", "@acme/auth/tokens"]),
+            file("@acme/util", &[]),
+            file("@acme/web", &["@acme/ui"]),
+            file("@acme/ui", &["@acme/web"]),
+        ]);
+
+        assert_eq!(
+            map.dependency_cycles(),
+            vec![
+                vec!["@acme/api", "@acme/auth", "@acme/db"],
+                vec!["@acme/ui", "@acme/web"],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_no_dependency_cycles() {
+        let map = import_map(vec![
+            file("@acme/api", &["@acme/db", "@acme/api/routes"]),
+            file("@acme/db", &["@acme/util"]),
+        ]);
+
+        assert!(map.dependency_cycles().is_empty());
+    }
+}
@@ -0,0 +1,191 @@
+use crate::models::{ImportMap, ImportType};
+use crate::policy::{base_package, normalize_package};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// An unknown import with the declared dependency it most likely misspells
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UnknownImport {
+    /// Relative path of the importing file
+    pub file: PathBuf,
+    /// Line number of the import statement
+    pub line: usize,
+    /// Full module path as written in the import
+    pub module: String,
+    /// Base package name the module resolves to
+    pub package: String,
+    /// Closest declared dependency (typo candidates only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub suggestion: Option<String>,
+}
+
+/// Unknown imports grouped by their likely cause
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UnknownImportReport {
+    /// Close edit distance to a declared external dependency
+    pub typo_candidate: Vec<UnknownImport>,
+    /// The importing file is not covered by any package manifest
+    pub missing_manifest: Vec<UnknownImport>,
+    /// No manifest declares the package and nothing similar is declared
+    pub truly_unknown: Vec<UnknownImport>,
+}
+
+impl UnknownImportReport {
+    /// Total number of unknown imports in the report
+    pub fn len(&self) -> usize {
+        self.typo_candidate.len() + self.missing_manifest.len() + self.truly_unknown.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl ImportMap {
+    /// Classify every `Unknown` import by its likely cause
+    ///
+    /// A package within a small Levenshtein distance of a declared external
+    /// dependency is a typo candidate; otherwise, imports from files outside
+    /// every manifest are `missing_manifest` and the rest `truly_unknown`.
+    pub fn diagnose_unknown(&self) -> UnknownImportReport {
+        let declared: Vec<(String, &String)> = self
+            .external_dependencies
+            .keys()
+            .map(|name| (normalize_package(name), name))
+            .collect();
+
+        let mut report = UnknownImportReport::default();
+        for file in &self.files {
+            for import in &file.imports {
+                if import.import_type != ImportType::Unknown {
+                    continue;
+                }
+
+                let package = base_package(&import.module);
+                let suggestion = closest_dependency(&normalize_package(&package), &declared);
+                let entry = UnknownImport {
+                    file: file.path.clone(),
+                    line: import.line,
+                    module: import.module.clone(),
+                    package,
+                    suggestion: suggestion.clone(),
+                };
+
+                if suggestion.is_some() {
+                    report.typo_candidate.push(entry);
+                } else if file.package.is_none() {
+                    report.missing_manifest.push(entry);
+                } else {
+                    report.truly_unknown.push(entry);
+                }
+            }
+        }
+
+        for group in [
+            &mut report.typo_candidate,
+            &mut report.missing_manifest,
+            &mut report.truly_unknown,
+        ] {
+            group.sort_by(|a, b| a.file.cmp(&b.file).then(a.line.cmp(&b.line)));
+        }
+        report
+    }
+}
+
+/// Declared dependency closest to `package`, if near enough to be a typo
+///
+/// Allows at most two edits, and no more than one edit per three characters
+/// so short names like `os` do not match everything.
+fn closest_dependency(package: &str, declared: &[(String, &String)]) -> Option<String> {
+    declared
+        .iter()
+        .map(|(normalized, name)| (levenshtein(package, normalized), *name))
+        .filter(|(distance, _)| *distance > 0 && *distance <= 2 && distance * 3 <= package.len())
+        .min()
+        .map(|(_, name)| name.clone())
+}
+
+/// Levenshtein edit distance between two strings (by character)
+pub fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+
+    for (i, ca) in a.chars().enumerate() {
+        current[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    previous[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{DependencyInfo, Language, SourceFile};
+    use crate::test_support::{import, import_map, source_file};
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_levenshtein() {
+        assert_eq!(levenshtein("expres", "express"), 1);
+        assert_eq!(levenshtein("kitten", "sitting"), 3);
+        assert_eq!(levenshtein("", "abc"), 3);
+        assert_eq!(levenshtein("same", "same"), 0);
+    }
+
+    #[test]
+    fn test_diagnose_unknown() {
+        let express = DependencyInfo {
+            name: "express".to_string(),
+            version: "^4.18.0".to_string(),
+            source: PathBuf::from("package.json"),
+            is_dev: false,
+            is_workspace: false,
+            internal: false,
+            relative: false,
+            local_path: None,
+            extras: vec![],
+            marker: None,
+            group: None,
+        };
+        let map = ImportMap {
+            external_dependencies: HashMap::from([("express".to_string(), express)]),
+            ..import_map(vec![
+                SourceFile {
+                    package: Some("api".to_string()),
+                    ..source_file(
+                        "server.js",
+                        Language::JavaScript,
+                        vec![
+                            import("expres", 1, ImportType::Unknown),
+                            import("left-pad", 2, ImportType::Unknown),
+                        ],
+                    )
+                },
+                source_file(
+                    "scripts/seed.js",
+                    Language::JavaScript,
+                    vec![import("faker", 1, ImportType::Unknown)],
+                ),
+            ])
+        };
+
+        let report = map.diagnose_unknown();
+        assert_eq!(report.len(), 3);
+
+        assert_eq!(report.typo_candidate.len(), 1);
+        assert_eq!(report.typo_candidate[0].module, "expres");
+        assert_eq!(report.typo_candidate[0].suggestion.as_deref(), Some("express"));
+
+        assert_eq!(report.missing_manifest.len(), 1);
+        assert_eq!(report.missing_manifest[0].package, "faker");
+
+        assert_eq!(report.truly_unknown.len(), 1);
+        assert_eq!(report.truly_unknown[0].package, "left-pad");
+    }
+}
@@ -0,0 +1,41 @@
+//! Git helpers for restricting a scan to the files staged in the index
+
+use git2::{Delta, DiffFindOptions, ErrorCode, Repository};
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Absolute paths of files staged in the git index of the repository containing `dir`
+///
+/// Diffs the index against `HEAD` (or against nothing on an unborn branch) with
+/// rename detection, and keeps added, modified, copied and renamed files, so
+/// deleted files and the old side of renames are excluded. Names are resolved
+/// against the repository's work tree.
+pub fn staged_files(dir: &Path) -> io::Result<Vec<PathBuf>> {
+    let repo = Repository::discover(dir).map_err(io::Error::other)?;
+    let workdir = repo
+        .workdir()
+        .ok_or_else(|| io::Error::other("bare repository has no work tree"))?;
+    let head = match repo.head() {
+        Ok(head) => Some(head.peel_to_tree().map_err(io::Error::other)?),
+        // Before the first commit everything in the index is new
+        Err(e) if e.code() == ErrorCode::UnbornBranch => None,
+        Err(e) => return Err(io::Error::other(e)),
+    };
+    let mut diff = repo
+        .diff_tree_to_index(head.as_ref(), None, None)
+        .map_err(io::Error::other)?;
+    diff.find_similar(Some(DiffFindOptions::new().renames(true)))
+        .map_err(io::Error::other)?;
+
+    Ok(diff
+        .deltas()
+        .filter(|delta| {
+            matches!(
+                delta.status(),
+                Delta::Added | Delta::Modified | Delta::Copied | Delta::Renamed
+            )
+        })
+        .filter_map(|delta| delta.new_file().path())
+        .map(|path| workdir.join(path))
+        .collect())
+}
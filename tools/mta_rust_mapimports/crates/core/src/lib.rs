@@ -8,6 +8,7 @@
 //! - Parse Python imports (import, from...import)
 //! - Parse JavaScript/TypeScript imports (ESM, CommonJS require, dynamic import)
 //! - Extract dependency versions from package.json, pyproject.toml, requirements.txt
+//! - Scan packaged Python wheels and sdists without unpacking them
 //! - Categorize imports as External, Internal, Local, Stdlib, or Unknown
 //! - Output results in JSON or YAML format
 //!
@@ -25,16 +26,39 @@
 //! println!("{}", json);
 //! ```
 
+pub mod archive;
 pub mod categorizer;
 pub mod config;
+pub mod cycles;
+pub mod diagnose;
+pub mod git;
 pub mod manifest;
 pub mod models;
 pub mod output;
 pub mod parsers;
+pub mod policy;
+pub mod requirements;
 pub mod scanner;
+pub mod shadowing;
+pub mod unused;
+
+#[cfg(test)]
+mod test_support;
 
 // Re-exports for convenience
-pub use config::ScanConfig;
+pub use archive::{scan_archive, ArchiveError, ArchiveKind, ArchiveScan};
+pub use categorizer::{CategoryRule, PrefixRule};
+pub use config::{ProgressHook, ScanConfig};
+pub use diagnose::{UnknownImport, UnknownImportReport};
 pub use models::*;
-pub use output::{format_output, format_output_grouped, format_summary, OutputFormat};
+pub use output::{
+    format_output, format_output_grouped, format_output_grouped_with_config,
+    format_output_grouped_with_indent, format_output_with_config, format_output_with_indent,
+    format_summary, to_json_with_indent, to_jsonl, to_sarif, to_stats_json, to_toml,
+    write_jsonl_record, write_run_summary, IndentStyle, OutputFormat,
+};
+pub use policy::{
+    DependencyAllowlist, ImportGroupError, ImportGroupOrder, OrderViolation, PolicyViolation,
+};
 pub use scanner::{ImportScanner, ScanError};
+pub use shadowing::ShadowedImport;
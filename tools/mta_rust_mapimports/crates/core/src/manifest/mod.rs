@@ -0,0 +1,176 @@
+//! Manifest discovery and parsing
+//!
+//! `find_manifests` walks the project for `package.json`, `pyproject.toml` and
+//! `requirements*.txt` files. The declared dependencies feed the categorizer
+//! (External vs Unknown) and `ImportMap::external_dependencies`; the manifest's
+//! directory assigns scanned files to their workspace package.
+
+mod npm;
+mod pyproject;
+
+pub use npm::parse_package_json;
+pub use pyproject::parse_pyproject;
+
+use crate::models::{Language, PackageManifest};
+use crate::requirements::parse_requirements;
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use walkdir::WalkDir;
+
+/// Directories never searched for manifests (installed dependencies and build output)
+const SKIPPED_DIRS: &[&str] = &[
+    "node_modules",
+    ".venv",
+    "venv",
+    "__pycache__",
+    "dist",
+    "build",
+    ".git",
+    "target",
+    "__SPECS__",
+    "__STAGE__",
+];
+
+/// Find and parse every manifest under `root`
+///
+/// Manifests are ordered deepest first, so the first manifest whose directory
+/// contains a file is the package that file belongs to. Unreadable or invalid
+/// manifests are skipped.
+pub fn find_manifests(root: &Path) -> Vec<PackageManifest> {
+    let mut manifests: Vec<PackageManifest> = WalkDir::new(root)
+        .into_iter()
+        .filter_entry(|entry| {
+            entry.depth() == 0
+                || !entry.file_type().is_dir()
+                || !SKIPPED_DIRS.contains(&entry.file_name().to_string_lossy().as_ref())
+        })
+        .filter_map(Result::ok)
+        .filter(|entry| entry.file_type().is_file())
+        .filter_map(|entry| parse_manifest(entry.path()))
+        .collect();
+
+    manifests.sort_by(|a, b| {
+        (Reverse(a.path.components().count()), &a.path)
+            .cmp(&(Reverse(b.path.components().count()), &b.path))
+    });
+    manifests
+}
+
+/// Parse a manifest file, or `None` if `path` is not a supported manifest
+pub fn parse_manifest(path: &Path) -> Option<PackageManifest> {
+    let parse: fn(&str, &Path) -> Option<PackageManifest> =
+        match path.file_name()?.to_str()? {
+            "package.json" => parse_package_json,
+            "pyproject.toml" => parse_pyproject,
+            name if requirements_group(name).is_some() => parse_requirements_txt,
+            _ => return None,
+        };
+    let content = fs::read_to_string(path).ok()?;
+    parse(&content, path)
+}
+
+/// Parse a `requirements*.txt` file into a manifest named after its directory
+///
+/// `requirements.txt` lists runtime dependencies. Suffixed files such as
+/// `requirements-dev.txt` or `requirements_test.txt` are dev dependencies in the
+/// group named by the suffix (`dev`, `test`).
+pub fn parse_requirements_txt(content: &str, path: &Path) -> Option<PackageManifest> {
+    let group = requirements_group(path.file_name()?.to_str()?)?;
+    let mut dependencies = HashMap::new();
+    let mut dev_dependencies = HashMap::new();
+    for mut dep in parse_requirements(content, path) {
+        if group.is_empty() {
+            dependencies.insert(dep.name.clone(), dep);
+        } else {
+            dep.is_dev = true;
+            dep.group = Some(group.to_string());
+            dev_dependencies.insert(dep.name.clone(), dep);
+        }
+    }
+
+    Some(PackageManifest {
+        name: directory_name(path),
+        version: None,
+        path: path.to_path_buf(),
+        language: Language::Python,
+        dependencies,
+        dev_dependencies,
+    })
+}
+
+/// Group of a requirements file name: `""` for `requirements.txt`, `dev` for
+/// `requirements-dev.txt`, `None` for any other file
+fn requirements_group(file_name: &str) -> Option<&str> {
+    let suffix = file_name.strip_prefix("requirements")?.strip_suffix(".txt")?;
+    match suffix.strip_prefix(['-', '_', '.']) {
+        Some(group) if !group.is_empty() => Some(group),
+        _ if suffix.is_empty() => Some(""),
+        _ => None,
+    }
+}
+
+/// Package name used when the manifest does not declare one: its directory name
+fn directory_name(path: &Path) -> String {
+    path.parent()
+        .and_then(Path::file_name)
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_find_manifests() {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path();
+        fs::write(
+            root.join("package.json"),
+            r#"{ "name": "web", "dependencies": { "express": "^4.18.0" } }"#,
+        )
+        .unwrap();
+        fs::create_dir_all(root.join("packages_py/vault_file")).unwrap();
+        fs::write(
+            root.join("packages_py/vault_file/pyproject.toml"),
+            "[project]\nname = \"vault_file\"\ndependencies = [\"httpx>=0.24\"]\n",
+        )
+        .unwrap();
+        fs::create_dir_all(root.join("node_modules/express")).unwrap();
+        fs::write(
+            root.join("node_modules/express/package.json"),
+            r#"{ "name": "express" }"#,
+        )
+        .unwrap();
+
+        let manifests = find_manifests(root);
+        let names: Vec<&str> = manifests.iter().map(|m| m.name.as_str()).collect();
+        // Deepest first; installed dependencies are not walked
+        assert_eq!(names, ["vault_file", "web"]);
+        assert!(manifests[0].dependencies.contains_key("httpx"));
+        assert!(manifests[1].dependencies.contains_key("express"));
+    }
+
+    #[test]
+    fn test_parse_requirements_txt() {
+        let content = "requests[security]>=2.28\n-r base.txt\nnumpy  # pinned elsewhere\n";
+        let manifest =
+            parse_requirements_txt(content, Path::new("/project/api/requirements.txt")).unwrap();
+        assert_eq!(manifest.name, "api");
+        assert_eq!(manifest.dependencies.len(), 2);
+        assert_eq!(manifest.dependencies["requests"].extras, ["security"]);
+
+        let manifest =
+            parse_requirements_txt("pytest>=8\n", Path::new("/project/api/requirements-test.txt"))
+                .unwrap();
+        assert!(manifest.dependencies.is_empty());
+        assert_eq!(manifest.dev_dependencies["pytest"].group.as_deref(), Some("test"));
+
+        assert_eq!(requirements_group("requirements.in"), None);
+        assert_eq!(requirements_group("requirementsfoo.txt"), None);
+    }
+}
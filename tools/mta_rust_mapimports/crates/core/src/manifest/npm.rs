@@ -0,0 +1,105 @@
+//! `package.json` manifests
+
+use super::directory_name;
+use crate::models::{DependencyInfo, Language, PackageManifest};
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Parse a `package.json`
+///
+/// `dependencies`, `peerDependencies` and `optionalDependencies` are all runtime
+/// dependencies; `devDependencies` become `dev_dependencies`. Invalid JSON yields `None`.
+pub fn parse_package_json(content: &str, path: &Path) -> Option<PackageManifest> {
+    let doc: Value = serde_json::from_str(content).ok()?;
+    let doc = doc.as_object()?;
+
+    let mut dependencies = HashMap::new();
+    for table in ["dependencies", "peerDependencies", "optionalDependencies"] {
+        collect(doc, table, path, false, &mut dependencies);
+    }
+    let mut dev_dependencies = HashMap::new();
+    collect(doc, "devDependencies", path, true, &mut dev_dependencies);
+
+    Some(PackageManifest {
+        name: doc
+            .get("name")
+            .and_then(Value::as_str)
+            .map(str::to_string)
+            .unwrap_or_else(|| directory_name(path)),
+        version: doc.get("version").and_then(Value::as_str).map(str::to_string),
+        path: path.to_path_buf(),
+        language: Language::JavaScript,
+        dependencies,
+        dev_dependencies,
+    })
+}
+
+/// Add the `name: version` entries of one dependency table
+fn collect(
+    doc: &Map<String, Value>,
+    table: &str,
+    path: &Path,
+    is_dev: bool,
+    deps: &mut HashMap<String, DependencyInfo>,
+) {
+    let Some(entries) = doc.get(table).and_then(Value::as_object) else {
+        return;
+    };
+    for (name, version) in entries {
+        let version = version.as_str().unwrap_or("*");
+        deps.entry(name.clone())
+            .or_insert_with(|| dependency(name, version, path, is_dev));
+    }
+}
+
+/// Dependency info for one entry; `workspace:`, `file:` and `link:` specs are local
+fn dependency(name: &str, version: &str, path: &Path, is_dev: bool) -> DependencyInfo {
+    let local = version
+        .strip_prefix("file:")
+        .or_else(|| version.strip_prefix("link:"));
+    DependencyInfo {
+        name: name.to_string(),
+        version: version.to_string(),
+        source: path.to_path_buf(),
+        is_dev,
+        is_workspace: version.starts_with("workspace:"),
+        internal: false,
+        relative: local.is_some(),
+        local_path: local.map(|target| path.parent().unwrap_or(Path::new("")).join(target)),
+        extras: vec![],
+        marker: None,
+        group: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_parse_package_json() {
+        let content = r#"{
+  "name": "@acme/web",
+  "version": "1.2.0",
+  "dependencies": { "react": "^18.2.0", "@acme/ui": "workspace:*", "shared": "file:../shared" },
+  "peerDependencies": { "react-dom": "^18.0.0" },
+  "devDependencies": { "vitest": "^1.0.0" }
+}"#;
+        let path = PathBuf::from("/project/apps/web/package.json");
+        let manifest = parse_package_json(content, &path).unwrap();
+
+        assert_eq!(manifest.name, "@acme/web");
+        assert_eq!(manifest.version.as_deref(), Some("1.2.0"));
+        assert_eq!(manifest.dependencies.len(), 4);
+        assert!(manifest.dependencies["@acme/ui"].is_workspace);
+        assert_eq!(
+            manifest.dependencies["shared"].local_path,
+            Some(PathBuf::from("/project/apps/web/../shared"))
+        );
+        assert!(manifest.dev_dependencies["vitest"].is_dev);
+
+        assert!(parse_package_json("{ not json", &path).is_none());
+    }
+}
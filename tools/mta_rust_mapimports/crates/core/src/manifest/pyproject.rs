@@ -0,0 +1,117 @@
+//! `pyproject.toml` manifests (PEP 621 and Poetry)
+
+use super::directory_name;
+use crate::models::{DependencyInfo, Language, PackageManifest};
+use crate::requirements::{parse_pyproject_groups, parse_requirement};
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Parse a `pyproject.toml`
+///
+/// Runtime dependencies come from `[project].dependencies` (PEP 508 strings) and
+/// `[tool.poetry.dependencies]` (`python` itself is skipped). Optional extras and
+/// Poetry groups become `dev_dependencies` via `parse_pyproject_groups`.
+/// Invalid TOML yields `None`.
+pub fn parse_pyproject(content: &str, path: &Path) -> Option<PackageManifest> {
+    let doc = content.parse::<toml::Table>().ok()?;
+    let project = doc.get("project").and_then(toml::Value::as_table);
+    let poetry = doc
+        .get("tool")
+        .and_then(|tool| tool.get("poetry"))
+        .and_then(toml::Value::as_table);
+
+    let mut dependencies = HashMap::new();
+    let requirements = project
+        .and_then(|p| p.get("dependencies"))
+        .and_then(toml::Value::as_array);
+    for line in requirements.into_iter().flatten().filter_map(toml::Value::as_str) {
+        if let Some(dep) = parse_requirement(line, path) {
+            dependencies.insert(dep.name.clone(), dep);
+        }
+    }
+    let poetry_deps = poetry
+        .and_then(|p| p.get("dependencies"))
+        .and_then(toml::Value::as_table);
+    for (name, spec) in poetry_deps.into_iter().flatten() {
+        if name != "python" {
+            dependencies.insert(name.clone(), poetry_dependency(name, spec, path));
+        }
+    }
+
+    let mut dev_dependencies = HashMap::new();
+    for dep in parse_pyproject_groups(content, path) {
+        dev_dependencies.entry(dep.name.clone()).or_insert(dep);
+    }
+
+    let field = |key: &str| {
+        project
+            .and_then(|p| p.get(key))
+            .or_else(|| poetry.and_then(|p| p.get(key)))
+            .and_then(toml::Value::as_str)
+            .map(str::to_string)
+    };
+
+    Some(PackageManifest {
+        name: field("name").unwrap_or_else(|| directory_name(path)),
+        version: field("version"),
+        path: path.to_path_buf(),
+        language: Language::Python,
+        dependencies,
+        dev_dependencies,
+    })
+}
+
+/// `name = "^1.0"`, `name = { version = "^1.0" }` or `name = { path = "../lib" }`
+fn poetry_dependency(name: &str, spec: &toml::Value, path: &Path) -> DependencyInfo {
+    let field = |key: &str| spec.get(key).and_then(toml::Value::as_str);
+    let local = field("path");
+    let version = spec.as_str().or(field("version")).or(local).unwrap_or("*");
+    DependencyInfo {
+        name: name.to_string(),
+        version: version.to_string(),
+        source: path.to_path_buf(),
+        is_dev: false,
+        is_workspace: false,
+        internal: false,
+        relative: local.is_some(),
+        local_path: local.map(|target| path.parent().unwrap_or(Path::new("")).join(target)),
+        extras: vec![],
+        marker: None,
+        group: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_parse_pyproject() {
+        let content = r#"
+[project]
+name = "service"
+version = "0.3.0"
+dependencies = ["fastapi>=0.110", "pydantic[email]~=2.6"]
+
+[project.optional-dependencies]
+test = ["pytest>=8"]
+
+[tool.poetry.dependencies]
+python = "^3.11"
+vault_file = { path = "../vault_file", develop = true }
+"#;
+        let path = PathBuf::from("/project/service/pyproject.toml");
+        let manifest = parse_pyproject(content, &path).unwrap();
+
+        assert_eq!(manifest.name, "service");
+        assert_eq!(manifest.version.as_deref(), Some("0.3.0"));
+        let mut names: Vec<&str> = manifest.dependencies.keys().map(String::as_str).collect();
+        names.sort();
+        assert_eq!(names, ["fastapi", "pydantic", "vault_file"]);
+        assert!(manifest.dependencies["vault_file"].relative);
+        assert_eq!(manifest.dev_dependencies["pytest"].group.as_deref(), Some("test"));
+
+        assert!(parse_pyproject("[project", &path).is_none());
+    }
+}
@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
-use std::path::PathBuf;
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
 
 /// Type of import source
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -24,6 +24,31 @@ impl Default for ImportType {
     }
 }
 
+impl ImportType {
+    /// Lowercase name as used in serialized output and CLI arguments
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ImportType::External => "external",
+            ImportType::Internal => "internal",
+            ImportType::Local => "local",
+            ImportType::Stdlib => "stdlib",
+            ImportType::Unknown => "unknown",
+        }
+    }
+
+    /// Parse a name produced by [`ImportType::as_str`] (case-insensitive)
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.trim().to_lowercase().as_str() {
+            "external" => Some(ImportType::External),
+            "internal" => Some(ImportType::Internal),
+            "local" => Some(ImportType::Local),
+            "stdlib" => Some(ImportType::Stdlib),
+            "unknown" => Some(ImportType::Unknown),
+            _ => None,
+        }
+    }
+}
+
 /// Language of the source file
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
@@ -55,9 +80,9 @@ pub struct ImportStatement {
     /// Whether it's a default import (JS) or wildcard
     #[serde(default)]
     pub is_default: bool,
-    /// Line number in source file
+    /// Line number in source file (1-indexed)
     pub line: usize,
-    /// Column position
+    /// Column position (0-indexed)
     pub column: usize,
     /// Full import statement text
     pub raw: String,
@@ -66,6 +91,12 @@ pub struct ImportStatement {
     /// Alias if any (e.g., `import numpy as np`)
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub alias: Option<String>,
+    /// Fallback import guarded by `try`/`except ImportError` (Python)
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub is_optional: bool,
+    /// Line of the `try` statement shared by alternative optional imports
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub optional_group: Option<usize>,
 }
 
 /// Represents a source file with its imports
@@ -82,6 +113,9 @@ pub struct SourceFile {
     /// Associated package (if in a workspace package)
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub package: Option<String>,
+    /// JS/TS file whose statements are exclusively re-exports (`export ... from`)
+    #[serde(default)]
+    pub is_barrel: bool,
 }
 
 /// Dependency information from manifest files
@@ -108,6 +142,15 @@ pub struct DependencyInfo {
     /// Resolved local path (for workspace deps)
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub local_path: Option<PathBuf>,
+    /// Optional feature extras requested (`requests[security]` -> `security`)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extras: Vec<String>,
+    /// PEP 508 environment marker (`python_version < "3.11"`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub marker: Option<String>,
+    /// Optional-dependency extra or Poetry group declaring it (`test`, `docs`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub group: Option<String>,
 }
 
 /// Package manifest (package.json, pyproject.toml, etc.)
@@ -123,13 +166,31 @@ pub struct PackageManifest {
     /// Language/ecosystem
     pub language: Language,
     /// Dependencies
-    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    #[serde(
+        default,
+        skip_serializing_if = "HashMap::is_empty",
+        serialize_with = "serialize_sorted"
+    )]
     pub dependencies: HashMap<String, DependencyInfo>,
     /// Dev dependencies
-    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    #[serde(
+        default,
+        skip_serializing_if = "HashMap::is_empty",
+        serialize_with = "serialize_sorted"
+    )]
     pub dev_dependencies: HashMap<String, DependencyInfo>,
 }
 
+/// Serialize a map with its keys in sorted order so output is stable across runs
+fn serialize_sorted<S, V>(map: &HashMap<String, V>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+    V: Serialize,
+{
+    let sorted: BTreeMap<&String, &V> = map.iter().collect();
+    sorted.serialize(serializer)
+}
+
 /// Aggregated import analysis results
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ImportMap {
@@ -140,6 +201,7 @@ pub struct ImportMap {
     /// All manifests found
     pub manifests: Vec<PackageManifest>,
     /// Aggregated external dependencies with versions
+    #[serde(serialize_with = "serialize_sorted")]
     pub external_dependencies: HashMap<String, DependencyInfo>,
     /// Internal package references
     pub internal_packages: Vec<String>,
@@ -149,7 +211,47 @@ pub struct ImportMap {
     pub metadata: ScanMetadata,
 }
 
+/// Compact summary of an ImportMap without per-file data, for CI dashboards
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportMapStats {
+    /// Import statistics
+    pub stats: ImportStats,
+    /// Aggregated external dependencies with versions
+    #[serde(serialize_with = "serialize_sorted")]
+    pub external_dependencies: HashMap<String, DependencyInfo>,
+    /// Internal package references
+    pub internal_packages: Vec<String>,
+    /// Scan metadata
+    pub metadata: ScanMetadata,
+}
+
+/// One line of JSON Lines output, tagged with `"type"`
+///
+/// A stream is zero or more `file` records followed by exactly one `summary` record.
+#[derive(Debug, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum StreamRecord<'a> {
+    /// A parsed source file, written as soon as it is available
+    File(&'a SourceFile),
+    /// Project-wide totals, written after the last file
+    Summary {
+        root: &'a Path,
+        #[serde(flatten)]
+        stats: &'a ImportMapStats,
+    },
+}
+
 impl ImportMap {
+    /// Keep only the numeric stats and dependency versions, dropping files and manifests
+    pub fn to_stats(&self) -> ImportMapStats {
+        ImportMapStats {
+            stats: self.stats.clone(),
+            external_dependencies: self.external_dependencies.clone(),
+            internal_packages: self.internal_packages.clone(),
+            metadata: self.metadata.clone(),
+        }
+    }
+
     /// Filter to only show external dependencies with versions
     pub fn filter_to_dependencies(&self) -> Self {
         ImportMap {
@@ -163,6 +265,25 @@ impl ImportMap {
         }
     }
 
+    /// Filter to only show barrel files (modules that only re-export others)
+    pub fn filter_to_barrels(&self) -> Self {
+        let files: Vec<SourceFile> = self.files.iter().filter(|f| f.is_barrel).cloned().collect();
+
+        ImportMap {
+            root: self.root.clone(),
+            stats: ImportStats {
+                total_files: files.len(),
+                total_imports: files.iter().map(|f| f.imports.len()).sum(),
+                ..Default::default()
+            },
+            files,
+            manifests: vec![],
+            external_dependencies: HashMap::new(),
+            internal_packages: vec![],
+            metadata: self.metadata.clone(),
+        }
+    }
+
     /// Filter to only show unknown/unresolved imports
     pub fn filter_to_unknown(&self) -> Self {
         let files: Vec<SourceFile> = self
@@ -185,6 +306,7 @@ impl ImportMap {
                         language: f.language.clone(),
                         imports: unknown_imports,
                         package: f.package.clone(),
+                        is_barrel: f.is_barrel,
                     })
                 }
             })
@@ -209,6 +331,7 @@ impl ImportMap {
                 python_files: 0,
                 javascript_files: 0,
                 typescript_files: 0,
+                failed_files: 0,
             },
             metadata: self.metadata.clone(),
         }
@@ -228,10 +351,37 @@ pub struct ImportStats {
     pub python_files: usize,
     pub javascript_files: usize,
     pub typescript_files: usize,
+    /// Source files found but not scanned because they could not be read or parsed
+    #[serde(default)]
+    pub failed_files: usize,
+}
+
+/// Snapshot passed to the `ScanConfig::with_progress` callback
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScanProgress {
+    /// Files parsed so far
+    pub files_done: usize,
+    /// Files found to parse (found so far in streaming scans, where the walk runs alongside parsing)
+    pub files_total: usize,
+    /// Relative path of the most recently finished file
+    pub current_path: PathBuf,
+}
+
+/// One-line run telemetry written to stderr by `--emit-run-summary`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RunSummary {
+    /// Files scanned
+    pub files: usize,
+    /// Files that could not be read or parsed
+    pub errors: usize,
+    /// Scan duration in milliseconds
+    pub duration_ms: u64,
+    /// Process exit code the CLI returns
+    pub exit: i32,
 }
 
 /// Scan metadata
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ScanMetadata {
     pub scan_duration_ms: u64,
     pub files_per_second: f64,
@@ -258,6 +408,7 @@ pub struct LanguageSection {
     /// Manifests for this language
     pub manifests: Vec<PackageManifest>,
     /// External dependencies with versions
+    #[serde(serialize_with = "serialize_sorted")]
     pub external_dependencies: HashMap<String, DependencyInfo>,
     /// Internal package references
     pub internal_packages: Vec<String>,
@@ -1,21 +1,81 @@
-use crate::models::ImportMap;
-use super::FormatError;
+use crate::models::{ImportMap, RunSummary, StreamRecord};
+use super::{FormatError, IndentStyle};
+use serde::Serialize;
+use serde_json::ser::{PrettyFormatter, Serializer};
+use std::io::Write;
 
 /// Serialize ImportMap to pretty-printed JSON
 pub fn to_json(import_map: &ImportMap) -> Result<String, FormatError> {
     serde_json::to_string_pretty(import_map).map_err(FormatError::from)
 }
 
+/// Serialize only the stats and dependency versions of an ImportMap, omitting files
+pub fn to_stats_json(import_map: &ImportMap) -> Result<String, FormatError> {
+    serde_json::to_string_pretty(&import_map.to_stats()).map_err(FormatError::from)
+}
+
+/// Serialize ImportMap as JSON Lines: one `file` record per source file, then a `summary` record
+pub fn to_jsonl(import_map: &ImportMap) -> Result<String, FormatError> {
+    let mut buf = Vec::new();
+    for file in &import_map.files {
+        write_jsonl_record(&mut buf, &StreamRecord::File(file))?;
+    }
+    let stats = import_map.to_stats();
+    write_jsonl_record(
+        &mut buf,
+        &StreamRecord::Summary {
+            root: &import_map.root,
+            stats: &stats,
+        },
+    )?;
+    Ok(String::from_utf8_lossy(&buf).into_owned())
+}
+
+/// Write one JSON Lines record followed by a newline
+pub fn write_jsonl_record<W: Write>(
+    writer: &mut W,
+    record: &StreamRecord,
+) -> Result<(), FormatError> {
+    serde_json::to_writer(&mut *writer, record)?;
+    writeln!(writer).map_err(serde_json::Error::io)?;
+    Ok(())
+}
+
 /// Serialize ImportMap to compact JSON
 #[allow(dead_code)]
 pub fn to_json_compact(import_map: &ImportMap) -> Result<String, FormatError> {
     serde_json::to_string(import_map).map_err(FormatError::from)
 }
 
+/// Serialize any value to pretty-printed JSON using the given indentation
+pub fn to_json_with_indent<T: Serialize + ?Sized>(
+    value: &T,
+    indent: IndentStyle,
+) -> Result<String, FormatError> {
+    let mut buf = Vec::new();
+    let formatter = PrettyFormatter::with_indent(indent.as_bytes());
+    let mut serializer = Serializer::with_formatter(&mut buf, formatter);
+    value.serialize(&mut serializer)?;
+    Ok(String::from_utf8_lossy(&buf).into_owned())
+}
+
+/// Write a run summary as a single JSON line, e.g. to stderr for `--emit-run-summary`
+pub fn write_run_summary<W: Write>(
+    writer: &mut W,
+    summary: &RunSummary,
+) -> Result<(), FormatError> {
+    serde_json::to_writer(&mut *writer, summary)?;
+    writeln!(writer).map_err(serde_json::Error::io)?;
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config::ScanConfig;
     use crate::models::{ImportStats, ScanMetadata};
+    use crate::output::{format_output_with_config, OutputFormat};
+    use crate::test_support::import_map;
     use std::collections::HashMap;
     use std::path::PathBuf;
 
@@ -35,4 +95,71 @@ mod tests {
         assert!(json.contains("\"root\""));
         assert!(json.contains("\"files\""));
     }
+
+    #[test]
+    fn test_to_stats_json() {
+        let mut import_map = ImportMap {
+            root: PathBuf::from("/test"),
+            files: vec![],
+            manifests: vec![],
+            external_dependencies: HashMap::new(),
+            internal_packages: vec!["@acme/core".to_string()],
+            stats: ImportStats::default(),
+            metadata: ScanMetadata::default(),
+        };
+        import_map.stats.total_files = 12;
+
+        let json = to_stats_json(&import_map).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        let mut keys: Vec<_> = value.as_object().unwrap().keys().cloned().collect();
+        keys.sort();
+        assert_eq!(
+            keys,
+            ["external_dependencies", "internal_packages", "metadata", "stats"]
+        );
+        assert_eq!(value["stats"]["total_files"], 12);
+    }
+
+    #[test]
+    fn test_to_json_with_indent() {
+        let value = serde_json::json!({ "root": { "files": [] } });
+
+        // `files` is nested one level below `root`, so it is indented twice
+        let json = to_json_with_indent(&value, IndentStyle::FourSpaces).unwrap();
+        assert!(json.contains("\n        \"files\""));
+
+        let json = to_json_with_indent(&value, IndentStyle::Tab).unwrap();
+        assert!(json.contains("\n\t\t\"files\""));
+
+        assert_eq!("tab".parse(), Ok(IndentStyle::Tab));
+        assert!("3".parse::<IndentStyle>().is_err());
+    }
+
+    #[test]
+    fn test_format_output_with_config() {
+        let import_map = import_map(vec![]);
+
+        let config = ScanConfig::default().with_json_indent(IndentStyle::FourSpaces);
+        let json = format_output_with_config(&import_map, OutputFormat::Json, &config).unwrap();
+        assert!(json.contains("\n    \"root\""));
+    }
+
+    #[test]
+    fn test_write_run_summary() {
+        let summary = RunSummary {
+            files: 3,
+            errors: 1,
+            duration_ms: 42,
+            exit: 0,
+        };
+
+        let mut stderr = Vec::new();
+        write_run_summary(&mut stderr, &summary).unwrap();
+
+        let text = String::from_utf8(stderr).unwrap();
+        assert_eq!(text.lines().count(), 1);
+        let parsed: RunSummary = serde_json::from_str(text.trim_end()).unwrap();
+        assert_eq!(parsed, summary);
+        assert!(text.starts_with("{\"files\":3,\"errors\":1,\"duration_ms\":42,\"exit\":0}"));
+    }
 }
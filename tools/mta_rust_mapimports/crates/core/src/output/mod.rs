@@ -1,19 +1,71 @@
 mod json;
+mod sarif;
+mod toml;
 mod yaml;
 
 use colored::*;
 
-pub use json::to_json;
+pub use json::{
+    to_json, to_json_with_indent, to_jsonl, to_stats_json, write_jsonl_record, write_run_summary,
+};
+pub use sarif::to_sarif;
+pub use self::toml::to_toml;
 pub use yaml::to_yaml;
 
+use crate::config::ScanConfig;
 use crate::models::{GroupedImportMap, ImportMap};
 
+/// Indentation style for pretty-printed JSON output
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum IndentStyle {
+    /// Two spaces (default)
+    #[default]
+    TwoSpaces,
+    /// Four spaces
+    FourSpaces,
+    /// One tab character
+    Tab,
+}
+
+impl IndentStyle {
+    /// Indent bytes for one nesting level
+    pub fn as_bytes(&self) -> &'static [u8] {
+        match self {
+            IndentStyle::TwoSpaces => b"  ",
+            IndentStyle::FourSpaces => b"    ",
+            IndentStyle::Tab => b"\t",
+        }
+    }
+}
+
+impl std::str::FromStr for IndentStyle {
+    type Err = String;
+
+    /// Parse the `--json-indent` values `2`, `4` and `tab`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "2" => Ok(IndentStyle::TwoSpaces),
+            "4" => Ok(IndentStyle::FourSpaces),
+            "tab" => Ok(IndentStyle::Tab),
+            _ => Err(format!("invalid indent '{}' (expected 2, 4 or tab)", s)),
+        }
+    }
+}
+
 /// Output format options
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum OutputFormat {
     Json,
     Yaml,
+    /// TOML: `files` as `[[files]]` tables, stats under `[stats]`
+    Toml,
     Summary,
+    /// JSON with only stats and dependency versions (no per-file data)
+    StatsJson,
+    /// Newline-delimited JSON: one record per file, then a summary record
+    Jsonl,
+    /// SARIF 2.1.0 log with one result per unresolved import, for code scanning
+    Sarif,
 }
 
 /// Format an ImportMap according to the specified format (flat structure)
@@ -21,7 +73,11 @@ pub fn format_output(import_map: &ImportMap, format: OutputFormat) -> Result<Str
     match format {
         OutputFormat::Json => to_json(import_map),
         OutputFormat::Yaml => to_yaml(import_map),
+        OutputFormat::Toml => to_toml(import_map),
         OutputFormat::Summary => Ok(format_summary(import_map)),
+        OutputFormat::StatsJson => to_stats_json(import_map),
+        OutputFormat::Jsonl => to_jsonl(import_map),
+        OutputFormat::Sarif => to_sarif(import_map),
     }
 }
 
@@ -31,10 +87,61 @@ pub fn format_output_grouped(import_map: &ImportMap, format: OutputFormat) -> Re
     match format {
         OutputFormat::Json => to_json_grouped(&grouped),
         OutputFormat::Yaml => to_yaml_grouped(&grouped),
+        OutputFormat::Toml => to_toml(&grouped),
         OutputFormat::Summary => Ok(format_summary_grouped(&grouped)),
+        // Stats are project-wide, so there is nothing to group
+        OutputFormat::StatsJson => to_stats_json(import_map),
+        // Each file record already carries its language
+        OutputFormat::Jsonl => to_jsonl(import_map),
+        // Results point at files directly, so SARIF is never grouped
+        OutputFormat::Sarif => to_sarif(import_map),
     }
 }
 
+/// Format an ImportMap (flat structure) using a custom JSON indentation
+pub fn format_output_with_indent(
+    import_map: &ImportMap,
+    format: OutputFormat,
+    indent: IndentStyle,
+) -> Result<String, FormatError> {
+    match format {
+        OutputFormat::Json => to_json_with_indent(import_map, indent),
+        OutputFormat::StatsJson => to_json_with_indent(&import_map.to_stats(), indent),
+        _ => format_output(import_map, format),
+    }
+}
+
+/// Format an ImportMap grouped by language using a custom JSON indentation
+pub fn format_output_grouped_with_indent(
+    import_map: &ImportMap,
+    format: OutputFormat,
+    indent: IndentStyle,
+) -> Result<String, FormatError> {
+    match format {
+        OutputFormat::Json => to_json_with_indent(&import_map.to_grouped(), indent),
+        OutputFormat::StatsJson => to_json_with_indent(&import_map.to_stats(), indent),
+        _ => format_output_grouped(import_map, format),
+    }
+}
+
+/// Format an ImportMap (flat structure) with the JSON indentation from `ScanConfig::with_json_indent`
+pub fn format_output_with_config(
+    import_map: &ImportMap,
+    format: OutputFormat,
+    config: &ScanConfig,
+) -> Result<String, FormatError> {
+    format_output_with_indent(import_map, format, config.json_indent)
+}
+
+/// Format an ImportMap grouped by language with the JSON indentation from `ScanConfig::with_json_indent`
+pub fn format_output_grouped_with_config(
+    import_map: &ImportMap,
+    format: OutputFormat,
+    config: &ScanConfig,
+) -> Result<String, FormatError> {
+    format_output_grouped_with_indent(import_map, format, config.json_indent)
+}
+
 fn to_json_grouped(grouped: &GroupedImportMap) -> Result<String, FormatError> {
     serde_json::to_string_pretty(grouped).map_err(FormatError::from)
 }
@@ -210,4 +317,6 @@ pub enum FormatError {
     JsonError(#[from] serde_json::Error),
     #[error("YAML serialization error: {0}")]
     YamlError(#[from] serde_yaml::Error),
+    #[error("TOML serialization error: {0}")]
+    TomlError(#[from] ::toml::ser::Error),
 }
@@ -0,0 +1,105 @@
+use crate::models::{ImportMap, ImportType};
+use super::FormatError;
+use serde_json::{json, Value};
+
+/// SARIF schema referenced by every log
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+/// Rule ID reported for imports categorized as `Unknown`
+const UNRESOLVED_IMPORT_RULE: &str = "unresolved-import";
+
+/// Serialize the `Unknown` imports of an ImportMap as a SARIF 2.1.0 log
+///
+/// Each unresolved import becomes one `result` located at its file (relative to
+/// the scan root) and position. SARIF regions are 1-indexed in both dimensions,
+/// so the 0-indexed `ImportStatement::column` is shifted by one.
+pub fn to_sarif(import_map: &ImportMap) -> Result<String, FormatError> {
+    let results: Vec<Value> = import_map
+        .files
+        .iter()
+        .flat_map(|file| {
+            let uri = file.path.to_string_lossy().replace('\\', "/");
+            file.imports
+                .iter()
+                .filter(|import| import.import_type == ImportType::Unknown)
+                .map(move |import| {
+                    json!({
+                        "ruleId": UNRESOLVED_IMPORT_RULE,
+                        "level": "warning",
+                        "message": {
+                            "text": format!("Import '{}' could not be resolved", import.module),
+                        },
+                        "locations": [{
+                            "physicalLocation": {
+                                "artifactLocation": { "uri": uri },
+                                "region": {
+                                    "startLine": import.line,
+                                    "startColumn": import.column + 1,
+                                },
+                            },
+                        }],
+                    })
+                })
+        })
+        .collect();
+
+    let log = json!({
+        "$schema": SARIF_SCHEMA,
+        "version": "2.1.0",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": env!("CARGO_PKG_NAME"),
+                    "version": env!("CARGO_PKG_VERSION"),
+                    "rules": [{
+                        "id": UNRESOLVED_IMPORT_RULE,
+                        "shortDescription": {
+                            "text": "Import is not stdlib, a declared dependency, or a project module",
+                        },
+                    }],
+                },
+            },
+            "results": results,
+        }],
+    });
+
+    serde_json::to_string_pretty(&log).map_err(FormatError::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{ImportStatement, Language};
+    use crate::test_support::{import, import_map, source_file};
+
+    #[test]
+    fn test_to_sarif() {
+        let import_map = import_map(vec![source_file(
+            "app/main.py",
+            Language::Python,
+            vec![
+                import("os", 1, ImportType::Stdlib),
+                ImportStatement {
+                    column: 4,
+                    ..import("mystery", 3, ImportType::Unknown)
+                },
+            ],
+        )]);
+
+        let sarif: Value = serde_json::from_str(&to_sarif(&import_map).unwrap()).unwrap();
+        assert_eq!(sarif["version"], "2.1.0");
+
+        let run = &sarif["runs"][0];
+        assert_eq!(run["tool"]["driver"]["version"], env!("CARGO_PKG_VERSION"));
+
+        let results = run["results"].as_array().unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0]["ruleId"], "unresolved-import");
+        assert!(results[0]["message"]["text"].as_str().unwrap().contains("mystery"));
+
+        let location = &results[0]["locations"][0]["physicalLocation"];
+        assert_eq!(location["artifactLocation"]["uri"], "app/main.py");
+        assert_eq!(location["region"]["startLine"], 3);
+        assert_eq!(location["region"]["startColumn"], 5);
+    }
+}
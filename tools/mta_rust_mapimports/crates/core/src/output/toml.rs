@@ -0,0 +1,36 @@
+use super::FormatError;
+use serde::Serialize;
+
+/// Serialize any struct or map to TOML
+///
+/// Lists of structs such as `files` become `[[files]]` arrays of tables and
+/// nested structs such as `stats` become `[stats]` tables. Values TOML cannot
+/// represent (a bare list, maps with non-string keys) produce `FormatError::TomlError`.
+pub fn to_toml<T: Serialize + ?Sized>(value: &T) -> Result<String, FormatError> {
+    ::toml::to_string_pretty(value).map_err(FormatError::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Language;
+    use crate::test_support::{import_map, source_file};
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_to_toml() {
+        let import_map = import_map(vec![source_file("app.py", Language::Python, vec![])]);
+
+        let toml = to_toml(&import_map).unwrap();
+        assert!(toml.contains("root = \"/project\""));
+        assert!(toml.contains("[[files]]"));
+        assert!(toml.contains("[stats]"));
+    }
+
+    #[test]
+    fn test_to_toml_unrepresentable() {
+        let keyed: HashMap<(u8, u8), usize> = HashMap::from([((1, 2), 3)]);
+        assert!(matches!(to_toml(&keyed), Err(FormatError::TomlError(_))));
+        assert!(matches!(to_toml(&[1, 2]), Err(FormatError::TomlError(_))));
+    }
+}
@@ -1,4 +1,5 @@
 use crate::models::{ImportStatement, ImportType, Language};
+use std::collections::HashSet;
 use tree_sitter::{Node, Parser};
 
 use super::{ImportParser, ParserError};
@@ -93,6 +94,8 @@ impl JavaScriptParser {
                 raw: self.get_node_text(node, source),
                 import_type: ImportType::Unknown,
                 alias,
+                is_optional: false,
+                optional_group: None,
             });
         }
     }
@@ -193,19 +196,70 @@ impl JavaScriptParser {
         }
 
         if (is_require || is_import) && !module.is_empty() {
+            let mut items = Vec::new();
+            let mut is_default = true;
+            if is_require {
+                self.parse_require_binding(node, source, &mut items, &mut is_default);
+            }
+
             imports.push(ImportStatement {
                 module,
-                items: vec![],
-                is_default: true,
+                items,
+                is_default,
                 line: node.start_position().row + 1,
                 column: node.start_position().column,
                 raw: self.get_node_text(node, source),
                 import_type: ImportType::Unknown,
                 alias: None,
+                is_optional: false,
+                optional_group: None,
             });
         }
     }
 
+    /// Record the names bound by `const x = require('m')` / `const { a, b: c } = require('m')`
+    ///
+    /// A plain identifier is a default-style binding; an object pattern lists the
+    /// destructured export names (the original `b`, not the local alias `c`).
+    fn parse_require_binding(
+        &self,
+        node: &Node,
+        source: &str,
+        items: &mut Vec<String>,
+        is_default: &mut bool,
+    ) {
+        let Some(declarator) = node.parent().filter(|p| p.kind() == "variable_declarator") else {
+            return;
+        };
+        if declarator.child_by_field_name("value").map(|v| v.id()) != Some(node.id()) {
+            return;
+        }
+        let Some(name) = declarator.child_by_field_name("name") else {
+            return;
+        };
+
+        match name.kind() {
+            "identifier" => items.push(self.get_node_text(&name, source)),
+            "object_pattern" => {
+                *is_default = false;
+                let mut cursor = name.walk();
+                for property in name.named_children(&mut cursor) {
+                    let key = match property.kind() {
+                        "shorthand_property_identifier_pattern" => Some(property),
+                        "pair_pattern" => property.child_by_field_name("key"),
+                        // `{ a = fallback }`
+                        "object_assignment_pattern" => property.child_by_field_name("left"),
+                        _ => None,
+                    };
+                    if let Some(key) = key {
+                        items.push(self.get_node_text(&key, source));
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
     /// Parse export ... from 'module' statements
     fn parse_export_statement(
         &self,
@@ -246,6 +300,8 @@ impl JavaScriptParser {
                 raw,
                 import_type: ImportType::Unknown,
                 alias: None,
+                is_optional: false,
+                optional_group: None,
             });
         }
     }
@@ -282,6 +338,65 @@ impl JavaScriptParser {
         String::new()
     }
 
+    /// Collect identifiers outside imports and re-exports, plus `(name, local)`
+    /// pairs of renamed import specifiers and destructured requires
+    fn collect_references(
+        &self,
+        node: &Node,
+        source: &str,
+        used: &mut HashSet<String>,
+        renames: &mut Vec<(String, String)>,
+    ) {
+        match node.kind() {
+            "import_statement" => self.collect_import_renames(node, source, renames),
+            "export_statement" if node.child_by_field_name("source").is_some() => {}
+            "variable_declarator" if self.is_require_declarator(node, source) => {
+                if let Some(name) = node.child_by_field_name("name") {
+                    self.collect_import_renames(&name, source, renames);
+                }
+            }
+            "identifier" | "type_identifier" | "shorthand_property_identifier" => {
+                used.insert(self.get_node_text(node, source));
+            }
+            _ => {
+                let mut cursor = node.walk();
+                for child in node.children(&mut cursor) {
+                    self.collect_references(&child, source, used, renames);
+                }
+            }
+        }
+    }
+
+    /// Record `import { a as b }` and `const { a: b } = require(...)` renames under `node`
+    fn collect_import_renames(&self, node: &Node, source: &str, renames: &mut Vec<(String, String)>) {
+        let fields = match node.kind() {
+            "import_specifier" => Some(("name", "alias")),
+            "pair_pattern" => Some(("key", "value")),
+            _ => None,
+        };
+        if let Some((original, local)) = fields {
+            if let (Some(original), Some(local)) =
+                (node.child_by_field_name(original), node.child_by_field_name(local))
+            {
+                renames.push((self.get_node_text(&original, source), self.get_node_text(&local, source)));
+            }
+            return;
+        }
+
+        let mut cursor = node.walk();
+        for child in node.named_children(&mut cursor) {
+            self.collect_import_renames(&child, source, renames);
+        }
+    }
+
+    /// Whether a declarator binds the result of `require('m')`
+    fn is_require_declarator(&self, node: &Node, source: &str) -> bool {
+        node.child_by_field_name("value")
+            .filter(|value| value.kind() == "call_expression")
+            .and_then(|call| call.child_by_field_name("function"))
+            .is_some_and(|function| self.get_node_text(&function, source) == "require")
+    }
+
     fn get_node_text(&self, node: &Node, source: &str) -> String {
         source[node.byte_range()].to_string()
     }
@@ -295,6 +410,29 @@ impl ImportParser for JavaScriptParser {
         }
     }
 
+    fn is_barrel(&mut self, source: &str) -> bool {
+        match self.parser.parse(source, None) {
+            Some(tree) => is_barrel_tree(&tree),
+            None => false,
+        }
+    }
+
+    fn referenced_names(&mut self, source: &str) -> HashSet<String> {
+        let Some(tree) = self.parser.parse(source, None) else {
+            return HashSet::new();
+        };
+
+        let mut used = HashSet::new();
+        let mut renames = Vec::new();
+        self.collect_references(&tree.root_node(), source, &mut used, &mut renames);
+        for (name, local) in renames {
+            if used.contains(&local) {
+                used.insert(name);
+            }
+        }
+        used
+    }
+
     fn language(&self) -> Language {
         if self.is_typescript {
             Language::TypeScript
@@ -304,6 +442,23 @@ impl ImportParser for JavaScriptParser {
     }
 }
 
+/// True when every top-level statement is an `export ... from` / `export * from` re-export
+fn is_barrel_tree(tree: &tree_sitter::Tree) -> bool {
+    let root = tree.root_node();
+    let mut reexports = 0;
+
+    let mut cursor = root.walk();
+    for child in root.named_children(&mut cursor) {
+        match child.kind() {
+            "comment" => {}
+            "export_statement" if child.child_by_field_name("source").is_some() => reexports += 1,
+            _ => return false,
+        }
+    }
+
+    reexports > 0
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -347,6 +502,23 @@ mod tests {
 
         assert_eq!(imports.len(), 1);
         assert_eq!(imports[0].module, "fs");
+        assert!(imports[0].is_default);
+        assert_eq!(imports[0].items, vec!["fs".to_string()]);
+    }
+
+    #[test]
+    fn test_destructured_require() {
+        let mut parser = JavaScriptParser::new(false).unwrap();
+        let imports = parser.parse("const { a, b: c } = require('m');\nrequire('side-effect');");
+
+        assert_eq!(imports.len(), 2);
+        assert_eq!(imports[0].module, "m");
+        assert!(!imports[0].is_default);
+        assert_eq!(imports[0].items, vec!["a".to_string(), "b".to_string()]);
+
+        // A bare require binds nothing
+        assert_eq!(imports[1].module, "side-effect");
+        assert!(imports[1].items.is_empty());
     }
 
     #[test]
@@ -5,6 +5,7 @@ pub use javascript::JavaScriptParser;
 pub use python::PythonParser;
 
 use crate::models::{ImportStatement, Language};
+use std::collections::HashSet;
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -22,6 +23,20 @@ pub trait ImportParser {
     /// Parse source code and extract import statements
     fn parse(&mut self, source: &str) -> Vec<ImportStatement>;
 
+    /// Whether the file does nothing but re-export other modules (a barrel file)
+    fn is_barrel(&mut self, _source: &str) -> bool {
+        false
+    }
+
+    /// Names referenced outside import statements
+    ///
+    /// An import bound under a different local name (`from m import a as b`,
+    /// `import { a as b }`) counts the original name as referenced when the
+    /// local one is, so callers can match against `ImportStatement::items`.
+    fn referenced_names(&mut self, _source: &str) -> HashSet<String> {
+        HashSet::new()
+    }
+
     /// Get the language this parser handles
     fn language(&self) -> Language;
 }
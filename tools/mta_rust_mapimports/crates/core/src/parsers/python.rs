@@ -1,4 +1,5 @@
 use crate::models::{ImportStatement, ImportType, Language};
+use std::collections::HashSet;
 use tree_sitter::{Node, Parser};
 
 use super::{ImportParser, ParserError};
@@ -36,6 +37,9 @@ impl PythonParser {
             "import_from_statement" => {
                 self.parse_import_from_statement(node, source, imports);
             }
+            "try_statement" => {
+                self.traverse_try_statement(node, source, imports);
+            }
             _ => {
                 // Recurse into children
                 let mut cursor = node.walk();
@@ -46,6 +50,58 @@ impl PythonParser {
         }
     }
 
+    /// Flag imports in a `try` body and its `except ImportError` handlers as optional
+    ///
+    /// `try: import ujson as json` / `except ImportError: import json` yields two
+    /// optional imports grouped by the line of the `try` statement. Imports in
+    /// `else`/`finally` clauses or other handlers are recorded as usual.
+    fn traverse_try_statement(
+        &self,
+        node: &Node,
+        source: &str,
+        imports: &mut Vec<ImportStatement>,
+    ) {
+        let mut cursor = node.walk();
+        let children: Vec<Node> = node.children(&mut cursor).collect();
+        let guards_imports = children.iter().any(|child| {
+            child.kind() == "except_clause" && self.catches_import_error(child, source)
+        });
+        let group = node.start_position().row + 1;
+
+        for child in children {
+            let first = imports.len();
+            self.traverse_node(&child, source, imports);
+
+            let alternative = match child.kind() {
+                "block" => guards_imports,
+                "except_clause" => self.catches_import_error(&child, source),
+                _ => false,
+            };
+            if alternative {
+                for import in &mut imports[first..] {
+                    import.is_optional = true;
+                    // Keep the innermost try when fallbacks are nested
+                    import.optional_group.get_or_insert(group);
+                }
+            }
+        }
+    }
+
+    /// Whether an `except` clause names `ImportError` or `ModuleNotFoundError`
+    fn catches_import_error(&self, node: &Node, source: &str) -> bool {
+        let mut cursor = node.walk();
+        for child in node.named_children(&mut cursor) {
+            if child.kind() == "block" {
+                continue;
+            }
+            let text = self.get_node_text(&child, source);
+            if text.contains("ImportError") || text.contains("ModuleNotFoundError") {
+                return true;
+            }
+        }
+        false
+    }
+
     /// Parse `import x, y, z` or `import x as alias`
     fn parse_import_statement(
         &self,
@@ -67,6 +123,8 @@ impl PythonParser {
                         raw: self.get_node_text(node, source),
                         import_type: ImportType::Unknown,
                         alias: None,
+                        is_optional: false,
+                        optional_group: None,
                     });
                 }
                 "aliased_import" => {
@@ -80,6 +138,8 @@ impl PythonParser {
                         raw: self.get_node_text(node, source),
                         import_type: ImportType::Unknown,
                         alias,
+                        is_optional: false,
+                        optional_group: None,
                     });
                 }
                 _ => {}
@@ -140,6 +200,8 @@ impl PythonParser {
                 raw: self.get_node_text(node, source),
                 import_type: ImportType::Unknown,
                 alias,
+                is_optional: false,
+                optional_group: None,
             });
         }
     }
@@ -191,6 +253,39 @@ impl PythonParser {
         (name, alias)
     }
 
+    /// Collect identifiers outside imports, plus `(name, alias)` pairs of aliased from-imports
+    fn collect_references(
+        &self,
+        node: &Node,
+        source: &str,
+        used: &mut HashSet<String>,
+        renames: &mut Vec<(String, String)>,
+    ) {
+        match node.kind() {
+            // `import x as y` is matched by its alias directly
+            "import_statement" => {}
+            "import_from_statement" => {
+                let mut cursor = node.walk();
+                for child in node.named_children(&mut cursor) {
+                    if child.kind() == "aliased_import" {
+                        if let (name, Some(alias)) = self.parse_aliased_import(&child, source) {
+                            renames.push((name, alias));
+                        }
+                    }
+                }
+            }
+            "identifier" => {
+                used.insert(self.get_node_text(node, source));
+            }
+            _ => {
+                let mut cursor = node.walk();
+                for child in node.children(&mut cursor) {
+                    self.collect_references(&child, source, used, renames);
+                }
+            }
+        }
+    }
+
     fn get_node_text(&self, node: &Node, source: &str) -> String {
         source[node.byte_range()].to_string()
     }
@@ -204,6 +299,22 @@ impl ImportParser for PythonParser {
         }
     }
 
+    fn referenced_names(&mut self, source: &str) -> HashSet<String> {
+        let Some(tree) = self.parser.parse(source, None) else {
+            return HashSet::new();
+        };
+
+        let mut used = HashSet::new();
+        let mut renames = Vec::new();
+        self.collect_references(&tree.root_node(), source, &mut used, &mut renames);
+        for (name, alias) in renames {
+            if used.contains(&alias) {
+                used.insert(name);
+            }
+        }
+        used
+    }
+
     fn language(&self) -> Language {
         Language::Python
     }
@@ -264,4 +375,40 @@ mod tests {
         assert!(imports[0].items.contains(&"*".to_string()));
         assert!(imports[0].is_default);
     }
+
+    #[test]
+    fn test_optional_imports() {
+        let mut parser = PythonParser::new().unwrap();
+        let source = r#"import os
+
+try:
+    import ujson as json
+except ImportError:
+    import json
+
+try:
+    import yaml
+except ValueError:
+    pass
+"#;
+        let imports = parser.parse(source);
+
+        assert_eq!(imports.len(), 4);
+        assert!(!imports[0].is_optional);
+
+        let ujson = &imports[1];
+        assert_eq!(ujson.module, "ujson");
+        assert!(ujson.is_optional);
+        assert_eq!(ujson.optional_group, Some(3));
+
+        let json = &imports[2];
+        assert_eq!(json.module, "json");
+        assert!(json.is_optional);
+        assert_eq!(json.optional_group, ujson.optional_group);
+
+        // A try without an ImportError handler is not a fallback
+        assert_eq!(imports[3].module, "yaml");
+        assert!(!imports[3].is_optional);
+        assert_eq!(imports[3].optional_group, None);
+    }
 }
@@ -0,0 +1,313 @@
+use crate::models::{ImportMap, ImportType};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+/// An external import whose package is not on the approved allowlist
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PolicyViolation {
+    /// Relative path of the importing file
+    pub file: PathBuf,
+    /// Line number of the import statement
+    pub line: usize,
+    /// Full module path as written in the import
+    pub module: String,
+    /// Base package name checked against the allowlist
+    pub package: String,
+}
+
+/// Approved external dependencies for policy checks
+#[derive(Debug, Clone, Default)]
+pub struct DependencyAllowlist {
+    packages: HashSet<String>,
+}
+
+impl DependencyAllowlist {
+    /// Build an allowlist from package names
+    pub fn new<I, S>(packages: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        Self {
+            packages: packages
+                .into_iter()
+                .map(|p| normalize_package(p.as_ref()))
+                .filter(|p| !p.is_empty())
+                .collect(),
+        }
+    }
+
+    /// Load a newline-separated allowlist file (blank lines and `#` comments are skipped)
+    pub fn from_file(path: &Path) -> std::io::Result<Self> {
+        let content = fs::read_to_string(path)?;
+        Ok(Self::new(
+            content
+                .lines()
+                .map(|line| line.split('#').next().unwrap_or("").trim()),
+        ))
+    }
+
+    /// Check whether a base package name is approved
+    pub fn allows(&self, package: &str) -> bool {
+        self.packages.contains(&normalize_package(package))
+    }
+
+    /// Report every External import whose base package is not allowed.
+    /// Internal, local, stdlib and unknown imports are exempt.
+    pub fn find_violations(&self, import_map: &ImportMap) -> Vec<PolicyViolation> {
+        let mut violations = Vec::new();
+
+        for file in &import_map.files {
+            for import in &file.imports {
+                if import.import_type != ImportType::External {
+                    continue;
+                }
+
+                let package = base_package(&import.module);
+                if !self.allows(&package) {
+                    violations.push(PolicyViolation {
+                        file: file.path.clone(),
+                        line: import.line,
+                        module: import.module.clone(),
+                        package,
+                    });
+                }
+            }
+        }
+
+        violations.sort_by(|a, b| a.file.cmp(&b.file).then(a.line.cmp(&b.line)));
+        violations
+    }
+}
+
+/// An import placed after an import from a group that the policy orders later
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OrderViolation {
+    /// Relative path of the importing file
+    pub file: PathBuf,
+    /// Line number of the misplaced import
+    pub line: usize,
+    /// Full module path as written in the import
+    pub module: String,
+    /// Group of the misplaced import
+    pub group: ImportType,
+    /// Later-ordered group that already appeared above it
+    pub after_group: ImportType,
+    /// Line of the earlier import from `after_group`
+    pub after_line: usize,
+}
+
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum ImportGroupError {
+    #[error("Unknown import group '{0}' (expected stdlib, external, internal, local or unknown)")]
+    UnknownGroup(String),
+    #[error("Import group '{0}' is listed more than once")]
+    DuplicateGroup(String),
+    #[error("No import groups given")]
+    Empty,
+}
+
+/// Canonical order of import groups, e.g. `stdlib,internal,external,local`
+///
+/// Groups left out of the order (typically `unknown`) are not checked.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImportGroupOrder {
+    groups: Vec<ImportType>,
+}
+
+impl Default for ImportGroupOrder {
+    /// isort-style order: stdlib, third-party, first-party, then relative imports
+    fn default() -> Self {
+        Self::new(vec![
+            ImportType::Stdlib,
+            ImportType::External,
+            ImportType::Internal,
+            ImportType::Local,
+        ])
+    }
+}
+
+impl ImportGroupOrder {
+    pub fn new(groups: Vec<ImportType>) -> Self {
+        Self { groups }
+    }
+
+    /// Parse a comma-separated list of group names
+    pub fn parse(spec: &str) -> Result<Self, ImportGroupError> {
+        let mut groups = Vec::new();
+        for name in spec.split(',').map(str::trim).filter(|n| !n.is_empty()) {
+            let group = ImportType::from_name(name)
+                .ok_or_else(|| ImportGroupError::UnknownGroup(name.to_string()))?;
+            if groups.contains(&group) {
+                return Err(ImportGroupError::DuplicateGroup(name.to_string()));
+            }
+            groups.push(group);
+        }
+
+        if groups.is_empty() {
+            return Err(ImportGroupError::Empty);
+        }
+        Ok(Self::new(groups))
+    }
+
+    /// Groups in canonical order
+    pub fn groups(&self) -> &[ImportType] {
+        &self.groups
+    }
+
+    /// Report every import that follows an import from a later group.
+    /// Each violation names the transition, e.g. stdlib after external.
+    pub fn find_violations(&self, import_map: &ImportMap) -> Vec<OrderViolation> {
+        let mut violations = Vec::new();
+
+        for file in &import_map.files {
+            let mut imports: Vec<_> = file
+                .imports
+                .iter()
+                .filter_map(|import| self.rank(&import.import_type).map(|rank| (rank, import)))
+                .collect();
+            imports.sort_by_key(|(_, import)| import.line);
+
+            // Latest-ordered group seen so far, with the line that introduced it
+            let mut furthest: Option<(usize, &ImportType, usize)> = None;
+            for (rank, import) in imports {
+                match furthest {
+                    Some((max_rank, after_group, after_line)) if rank < max_rank => {
+                        violations.push(OrderViolation {
+                            file: file.path.clone(),
+                            line: import.line,
+                            module: import.module.clone(),
+                            group: import.import_type.clone(),
+                            after_group: after_group.clone(),
+                            after_line,
+                        });
+                    }
+                    Some((max_rank, _, _)) if rank == max_rank => {}
+                    _ => furthest = Some((rank, &import.import_type, import.line)),
+                }
+            }
+        }
+
+        violations.sort_by(|a, b| a.file.cmp(&b.file).then(a.line.cmp(&b.line)));
+        violations
+    }
+
+    fn rank(&self, group: &ImportType) -> Option<usize> {
+        self.groups.iter().position(|g| g == group)
+    }
+}
+
+/// Get the installable package name for a module path
+/// (`@scope/pkg/sub` -> `@scope/pkg`, `lodash/fp` -> `lodash`, `yaml.loader` -> `yaml`)
+pub fn base_package(module: &str) -> String {
+    if module.starts_with('@') {
+        return module.splitn(3, '/').take(2).collect::<Vec<_>>().join("/");
+    }
+
+    module
+        .split('/')
+        .next()
+        .unwrap_or(module)
+        .split('.')
+        .next()
+        .unwrap_or(module)
+        .to_string()
+}
+
+/// Package names compare case-insensitively with `-` and `_` treated alike
+pub(crate) fn normalize_package(name: &str) -> String {
+    name.trim().to_lowercase().replace('-', "_")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{ImportStatement, Language};
+    use crate::test_support::{import, import_map, source_file};
+
+    fn app_py(imports: Vec<ImportStatement>) -> ImportMap {
+        import_map(vec![source_file("app.py", Language::Python, imports)])
+    }
+
+    #[test]
+    fn test_base_package() {
+        assert_eq!(base_package("@scope/pkg/sub"), "@scope/pkg");
+        assert_eq!(base_package("lodash/fp"), "lodash");
+        assert_eq!(base_package("yaml.loader"), "yaml");
+    }
+
+    #[test]
+    fn test_find_violations() {
+        let map = app_py(vec![
+            import("requests", 1, ImportType::External),
+            import("numpy.linalg", 2, ImportType::External),
+            import("os", 3, ImportType::Stdlib),
+            import("my_pkg", 4, ImportType::Internal),
+        ]);
+        let allowlist = DependencyAllowlist::new(["requests"]);
+
+        let violations = allowlist.find_violations(&map);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].file, PathBuf::from("app.py"));
+        assert_eq!(violations[0].line, 2);
+        assert_eq!(violations[0].package, "numpy");
+    }
+
+    #[test]
+    fn test_import_group_order_violations() {
+        let map = app_py(vec![
+            import("my_pkg", 1, ImportType::Internal),
+            import("os", 2, ImportType::Stdlib),
+            import("requests", 3, ImportType::External),
+            import("weird", 4, ImportType::Unknown),
+            import("sys", 5, ImportType::Stdlib),
+        ]);
+
+        // Internal before stdlib is fine when internal comes first
+        let internal_first = ImportGroupOrder::parse("internal, stdlib, external, local").unwrap();
+        let violations = internal_first.find_violations(&map);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].line, 5);
+        assert_eq!(violations[0].after_group, ImportType::External);
+        assert_eq!(violations[0].after_line, 3);
+
+        // ...but not under a stdlib-first policy
+        let stdlib_first = ImportGroupOrder::parse("stdlib,internal,external,local").unwrap();
+        let violations = stdlib_first.find_violations(&map);
+        assert_eq!(violations.len(), 2);
+        assert_eq!(violations[0].module, "os");
+        assert_eq!(violations[0].group, ImportType::Stdlib);
+        assert_eq!(violations[0].after_group, ImportType::Internal);
+        assert_eq!(violations[0].after_line, 1);
+        assert_eq!(violations[1].module, "sys");
+        assert_eq!(violations[1].after_group, ImportType::External);
+    }
+
+    #[test]
+    fn test_import_group_order_parse_errors() {
+        assert_eq!(
+            ImportGroupOrder::parse("stdlib,vendor"),
+            Err(ImportGroupError::UnknownGroup("vendor".to_string()))
+        );
+        assert_eq!(
+            ImportGroupOrder::parse("stdlib,STDLIB"),
+            Err(ImportGroupError::DuplicateGroup("STDLIB".to_string()))
+        );
+        assert_eq!(ImportGroupOrder::parse(" , "), Err(ImportGroupError::Empty));
+    }
+
+    #[test]
+    fn test_allowlist_from_file() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("allowed.txt");
+        fs::write(&path, "# approved\nPyYAML\n\nlodash  # utilities\n").unwrap();
+
+        let allowlist = DependencyAllowlist::from_file(&path).unwrap();
+        assert!(allowlist.allows("pyyaml"));
+        assert!(allowlist.allows("lodash"));
+        assert!(!allowlist.allows("react"));
+    }
+}
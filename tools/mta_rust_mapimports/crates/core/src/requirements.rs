@@ -0,0 +1,343 @@
+//! PEP 508 requirement parsing for `requirements.txt` manifests
+//!
+//! Handles extras (`requests[security]`), version specifiers (`>=2.28,<3.0`),
+//! environment markers (`; python_version < "3.11"`), direct URL references
+//! (`pkg @ git+https://...`) and editable installs (`-e ./local/path`).
+//! The optional dependency groups of `pyproject.toml` are read here as well.
+
+use crate::models::DependencyInfo;
+use std::path::Path;
+
+/// Parse every requirement in a `requirements.txt` file
+///
+/// Comments, blank lines and pip options other than `-e`/`--editable`
+/// (`-r`, `--index-url`, ...) are skipped.
+pub fn parse_requirements(content: &str, source: &Path) -> Vec<DependencyInfo> {
+    content
+        .lines()
+        .filter_map(|line| parse_requirement(line, source))
+        .collect()
+}
+
+/// Parse a single `requirements.txt` line
+pub fn parse_requirement(line: &str, source: &Path) -> Option<DependencyInfo> {
+    let line = strip_comment(line).trim();
+    if line.is_empty() {
+        return None;
+    }
+
+    if let Some(target) = line
+        .strip_prefix("--editable")
+        .or_else(|| line.strip_prefix("-e"))
+    {
+        return parse_editable(target.trim_start_matches('=').trim(), source);
+    }
+    if line.starts_with('-') {
+        return None;
+    }
+
+    let (requirement, marker) = match line.split_once(';') {
+        Some((requirement, marker)) => (requirement.trim(), Some(marker.trim().to_string())),
+        None => (line, None),
+    };
+
+    let name_len = requirement
+        .find(|c: char| !(c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.')))
+        .unwrap_or(requirement.len());
+    let name = &requirement[..name_len];
+    if name.is_empty() {
+        return None;
+    }
+
+    let mut rest = requirement[name_len..].trim_start();
+    let mut extras = Vec::new();
+    if let Some(after) = rest.strip_prefix('[') {
+        let (list, tail) = after.split_once(']')?;
+        extras = list
+            .split(',')
+            .map(str::trim)
+            .filter(|e| !e.is_empty())
+            .map(str::to_string)
+            .collect();
+        rest = tail.trim_start();
+    }
+
+    let version = match rest.strip_prefix('@') {
+        // Direct reference: keep the URL as the version, like npm's git/file specs
+        Some(url) => url.trim().to_string(),
+        None => {
+            let spec: String = rest
+                .trim_start_matches('(')
+                .trim_end_matches(')')
+                .chars()
+                .filter(|c| !c.is_whitespace())
+                .collect();
+            if spec.is_empty() {
+                "*".to_string()
+            } else {
+                spec
+            }
+        }
+    };
+
+    Some(DependencyInfo {
+        name: name.to_string(),
+        version,
+        source: source.to_path_buf(),
+        is_dev: false,
+        is_workspace: false,
+        internal: false,
+        relative: false,
+        local_path: None,
+        extras,
+        marker: marker.filter(|m| !m.is_empty()),
+        group: None,
+    })
+}
+
+/// Parse the optional dependency groups of a `pyproject.toml`
+///
+/// Reads PEP 621 extras (`[project.optional-dependencies]`), Poetry groups
+/// (`[tool.poetry.group.<name>.dependencies]`) and the legacy
+/// `[tool.poetry.dev-dependencies]` table (group `dev`). Every dependency is
+/// marked `is_dev` and tagged with its group, for `PackageManifest::dev_dependencies`.
+/// Invalid TOML yields no dependencies.
+pub fn parse_pyproject_groups(content: &str, source: &Path) -> Vec<DependencyInfo> {
+    let Ok(doc) = content.parse::<toml::Table>() else {
+        return Vec::new();
+    };
+    let mut deps = Vec::new();
+
+    // PEP 621 extras: lists of PEP 508 strings
+    if let Some(extras) = lookup(&doc, &["project", "optional-dependencies"]) {
+        for (group, requirements) in extras {
+            for line in requirements.as_array().into_iter().flatten() {
+                if let Some(dep) = line.as_str().and_then(|l| parse_requirement(l, source)) {
+                    deps.push(in_group(dep, group));
+                }
+            }
+        }
+    }
+
+    // Poetry: `name = "^1.0"` or `name = { version = "^1.0", optional = true }`
+    let mut poetry_groups = Vec::new();
+    if let Some(legacy) = lookup(&doc, &["tool", "poetry", "dev-dependencies"]) {
+        poetry_groups.push(("dev", legacy));
+    }
+    if let Some(groups) = lookup(&doc, &["tool", "poetry", "group"]) {
+        for (group, spec) in groups {
+            if let Some(group_deps) = spec.get("dependencies").and_then(toml::Value::as_table) {
+                poetry_groups.push((group.as_str(), group_deps));
+            }
+        }
+    }
+    for (group, group_deps) in poetry_groups {
+        for (name, spec) in group_deps {
+            let version = match spec {
+                toml::Value::String(version) => version.as_str(),
+                toml::Value::Table(table) => table
+                    .get("version")
+                    .and_then(toml::Value::as_str)
+                    .unwrap_or("*"),
+                _ => "*",
+            };
+            let dep = DependencyInfo {
+                name: name.clone(),
+                version: version.to_string(),
+                source: source.to_path_buf(),
+                is_dev: false,
+                is_workspace: false,
+                internal: false,
+                relative: false,
+                local_path: None,
+                extras: vec![],
+                marker: None,
+                group: None,
+            };
+            deps.push(in_group(dep, group));
+        }
+    }
+
+    deps
+}
+
+/// Nested table at `path`, e.g. `["tool", "poetry", "group"]`
+fn lookup<'a>(table: &'a toml::Table, path: &[&str]) -> Option<&'a toml::Table> {
+    path.iter()
+        .try_fold(table, |table, key| table.get(*key)?.as_table())
+}
+
+/// Mark a dependency as declared by an optional/dev group
+fn in_group(mut dep: DependencyInfo, group: &str) -> DependencyInfo {
+    dep.is_dev = true;
+    dep.group = Some(group.to_string());
+    dep
+}
+
+/// Editable install: a local path (relative) or a VCS URL named by `#egg=`
+fn parse_editable(target: &str, source: &Path) -> Option<DependencyInfo> {
+    if target.is_empty() {
+        return None;
+    }
+
+    let (location, egg) = match target.split_once("#egg=") {
+        Some((location, egg)) => (location, Some(egg.split('&').next().unwrap_or(egg))),
+        None => (target, None),
+    };
+    let is_url = location.contains("://");
+
+    let name = match egg {
+        Some(egg) => egg.to_string(),
+        None if is_url => return None,
+        None => Path::new(location)
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_else(|| location.to_string()),
+    };
+
+    let local_path = if is_url {
+        None
+    } else {
+        let base = source.parent().unwrap_or_else(|| Path::new(""));
+        Some(base.join(location))
+    };
+
+    Some(DependencyInfo {
+        name,
+        version: location.to_string(),
+        source: source.to_path_buf(),
+        is_dev: false,
+        is_workspace: false,
+        internal: false,
+        relative: !is_url,
+        local_path,
+        extras: vec![],
+        marker: None,
+        group: None,
+    })
+}
+
+/// Drop a trailing `# comment` (pip requires whitespace before an inline `#`)
+fn strip_comment(line: &str) -> &str {
+    if line.trim_start().starts_with('#') {
+        return "";
+    }
+    match line.find(" #").or_else(|| line.find("\t#")) {
+        Some(idx) => &line[..idx],
+        None => line,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use std::path::PathBuf;
+
+    fn source() -> PathBuf {
+        PathBuf::from("/project/requirements.txt")
+    }
+
+    /// Parse, then check the result survives a JSON round trip unchanged
+    fn parse_round_trip(line: &str) -> DependencyInfo {
+        let dep = parse_requirement(line, &source()).expect("requirement");
+        let json = serde_json::to_string(&dep).unwrap();
+        let back: DependencyInfo = serde_json::from_str(&json).unwrap();
+        assert_eq!(serde_json::to_string(&back).unwrap(), json);
+        back
+    }
+
+    #[test]
+    fn test_extras_and_marker() {
+        let dep = parse_round_trip(r#"requests[security, socks]>=2.28, <3.0; python_version < "3.11""#);
+        assert_eq!(dep.name, "requests");
+        assert_eq!(dep.extras, ["security", "socks"]);
+        assert_eq!(dep.version, ">=2.28,<3.0");
+        assert_eq!(dep.marker.as_deref(), Some(r#"python_version < "3.11""#));
+        assert!(!dep.relative);
+
+        let dep = parse_round_trip("Django==4.2  # pinned for LTS");
+        assert_eq!(dep.version, "==4.2");
+        assert!(dep.extras.is_empty());
+        assert_eq!(dep.marker, None);
+
+        assert_eq!(parse_round_trip("numpy").version, "*");
+    }
+
+    #[test]
+    fn test_url_requirement() {
+        let dep = parse_round_trip(
+            "mylib[cli] @ git+https://github.com/acme/mylib.git@v1.2 ; sys_platform == 'linux'",
+        );
+        assert_eq!(dep.name, "mylib");
+        assert_eq!(dep.extras, ["cli"]);
+        assert_eq!(dep.version, "git+https://github.com/acme/mylib.git@v1.2");
+        assert_eq!(dep.marker.as_deref(), Some("sys_platform == 'linux'"));
+        assert!(!dep.relative);
+        assert_eq!(dep.local_path, None);
+    }
+
+    #[test]
+    fn test_editable_installs() {
+        let dep = parse_round_trip("-e ./local/path");
+        assert_eq!(dep.name, "path");
+        assert_eq!(dep.version, "./local/path");
+        assert!(dep.relative);
+        assert_eq!(dep.local_path, Some(PathBuf::from("/project/./local/path")));
+
+        let dep = parse_round_trip("--editable=git+https://github.com/acme/tool.git#egg=acme-tool");
+        assert_eq!(dep.name, "acme-tool");
+        assert!(!dep.relative);
+        assert_eq!(dep.local_path, None);
+    }
+
+    #[test]
+    fn test_pyproject_groups() {
+        let content = r#"
+[project]
+name = "service"
+dependencies = ["fastapi>=0.110"]
+
+[project.optional-dependencies]
+test = ["pytest>=8", "pytest-cov; python_version >= '3.9'"]
+docs = ["mkdocs"]
+
+[tool.poetry.dev-dependencies]
+black = "^24.0"
+
+[tool.poetry.group.lint.dependencies]
+ruff = { version = "^0.4", optional = true }
+"#;
+        let source = PathBuf::from("/project/pyproject.toml");
+        let deps: HashMap<String, DependencyInfo> = parse_pyproject_groups(content, &source)
+            .into_iter()
+            .map(|d| (d.name.clone(), d))
+            .collect();
+
+        // Main dependencies are left to the manifest parser
+        assert!(!deps.contains_key("fastapi"));
+        assert_eq!(deps.len(), 5);
+        assert!(deps.values().all(|d| d.is_dev && d.source == source));
+
+        assert_eq!(deps["pytest"].group.as_deref(), Some("test"));
+        assert_eq!(deps["pytest"].version, ">=8");
+        assert_eq!(
+            deps["pytest-cov"].marker.as_deref(),
+            Some("python_version >= '3.9'")
+        );
+        assert_eq!(deps["mkdocs"].group.as_deref(), Some("docs"));
+        assert_eq!(deps["black"].group.as_deref(), Some("dev"));
+        assert_eq!(deps["ruff"].version, "^0.4");
+        assert_eq!(deps["ruff"].group.as_deref(), Some("lint"));
+
+        assert!(parse_pyproject_groups("not = [valid", &source).is_empty());
+    }
+
+    #[test]
+    fn test_parse_requirements_skips_options() {
+        let content = "# tooling\n-r base.txt\n--index-url https://pypi.example.com\n\nflask>=2\n-e ../shared\n";
+        let deps = parse_requirements(content, &source());
+        let names: Vec<_> = deps.iter().map(|d| d.name.as_str()).collect();
+        assert_eq!(names, ["flask", "shared"]);
+    }
+}
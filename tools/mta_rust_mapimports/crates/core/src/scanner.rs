@@ -1,15 +1,21 @@
 use crate::categorizer::ImportCategorizer;
-use crate::config::{IgnoreFilter, ScanConfig};
+use crate::config::{IgnoreFilter, ProgressHook, ScanConfig};
+use crate::git::staged_files;
 use crate::manifest::find_manifests;
 use crate::models::{
-    DependencyInfo, ImportMap, ImportStats, Language, PackageManifest, ScanMetadata, SourceFile,
+    DependencyInfo, ImportMap, ImportMapStats, ImportStats, Language, PackageManifest,
+    ScanMetadata, ScanProgress, SourceFile, StreamRecord,
 };
+use crate::output::write_jsonl_record;
 use crate::parsers::create_parser;
 use rayon::prelude::*;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fs;
+use std::io::Write;
 use std::path::{Path, PathBuf};
-use std::time::Instant;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{mpsc, Mutex};
+use std::time::{Duration, Instant};
 use thiserror::Error;
 use walkdir::WalkDir;
 
@@ -21,6 +27,8 @@ pub enum ScanError {
     ConfigError(#[from] crate::config::ConfigError),
     #[error("Parser error: {0}")]
     ParserError(#[from] crate::parsers::ParserError),
+    #[error("Output error: {0}")]
+    OutputError(#[from] crate::output::FormatError),
 }
 
 /// Main scanner for analyzing imports across a project
@@ -46,69 +54,65 @@ impl ImportScanner {
         let manifests = find_manifests(&self.config.root);
 
         // 2. Create categorizer from manifests
-        let categorizer = ImportCategorizer::new(&manifests);
+        let categorizer =
+            ImportCategorizer::new(&manifests).with_rules(&self.config.category_rules);
 
         // 3. Find all source files
         let source_files = self.find_source_files()?;
+        let files_found = source_files.len();
+        let progress = self.progress_tracker(files_found);
+        let progress = progress.as_ref();
 
         // 4. Parse all files in parallel
         let files: Vec<SourceFile> = if self.config.threads == 1 {
-            // Sequential processing
+            // Sequential processing in sorted path order (deterministic for debugging)
             source_files
                 .into_iter()
-                .filter_map(|(path, lang)| self.parse_file(&path, &lang, &categorizer, &manifests))
+                .filter_map(|(path, lang)| {
+                    self.parse_tracked(&path, &lang, &categorizer, &manifests, progress)
+                })
                 .collect()
         } else {
             // Parallel processing with rayon
-            let pool = if self.config.threads > 0 {
-                rayon::ThreadPoolBuilder::new()
-                    .num_threads(self.config.threads)
-                    .build()
-                    .ok()
-            } else {
-                None
-            };
-
-            let result: Vec<SourceFile> = match pool {
+            let result: Vec<SourceFile> = match self.thread_pool() {
                 Some(pool) => pool.install(|| {
                     source_files
                         .par_iter()
                         .filter_map(|(path, lang)| {
-                            self.parse_file(path, lang, &categorizer, &manifests)
+                            self.parse_tracked(path, lang, &categorizer, &manifests, progress)
                         })
                         .collect()
                 }),
                 None => source_files
                     .par_iter()
                     .filter_map(|(path, lang)| {
-                        self.parse_file(path, lang, &categorizer, &manifests)
+                        self.parse_tracked(path, lang, &categorizer, &manifests, progress)
                     })
                     .collect(),
             };
             result
         };
 
-        // 5. Aggregate statistics
-        let stats = self.calculate_stats(&files);
+        // 5. Rewrite paths before serialization (e.g. strip a CI checkout prefix)
+        let mut files = files;
+        for file in &mut files {
+            self.rewrite_paths(file);
+        }
+        let mut manifests = manifests;
+        self.rewrite_manifest_paths(&mut manifests);
+
+        // 6. Aggregate statistics; files that failed to read or parse were dropped above
+        let mut stats = self.calculate_stats(&files);
+        stats.failed_files = files_found - files.len();
 
-        // 6. Collect external dependencies with versions
+        // 7. Collect external dependencies with versions
         let external_dependencies = self.collect_external_dependencies(&manifests);
 
-        // 7. Build metadata
-        let duration = start.elapsed();
-        let metadata = ScanMetadata {
-            scan_duration_ms: duration.as_millis() as u64,
-            files_per_second: if duration.as_secs_f64() > 0.0 {
-                files.len() as f64 / duration.as_secs_f64()
-            } else {
-                0.0
-            },
-            timestamp: chrono::Utc::now().to_rfc3339(),
-            tool_version: env!("CARGO_PKG_VERSION").to_string(),
-        };
+        // 8. Build metadata
+        let metadata = self.build_metadata(start, files.len());
 
         Ok(ImportMap {
-            root: self.config.root.clone(),
+            root: self.config.normalize_separators(&self.config.root),
             files,
             manifests,
             external_dependencies,
@@ -118,43 +122,232 @@ impl ImportScanner {
         })
     }
 
+    /// Scan the project, writing each file as a JSON Lines record as soon as it is parsed
+    ///
+    /// Neither paths nor parsed files are collected into a vector: the directory
+    /// walk feeds the parsers lazily, so memory use does not grow with project
+    /// size. After the last `{"type":"file", ...}` record a single
+    /// `{"type":"summary", ...}` record carries the stats, dependencies and metadata.
+    /// Files are written in completion order, or sorted path order with `threads == 1`.
+    /// Progress updates report the files found so far as `files_total`.
+    pub fn scan_streaming<W: Write>(&self, mut writer: W) -> Result<ImportMapStats, ScanError> {
+        let start = Instant::now();
+        let manifests = find_manifests(&self.config.root);
+        let categorizer =
+            ImportCategorizer::new(&manifests).with_rules(&self.config.category_rules);
+        let progress = self.config.progress.clone().map(ProgressTracker::streaming);
+        let progress = progress.as_ref();
+        let files_found = AtomicUsize::new(0);
+        let source_files = self.source_files()?.inspect(|_| {
+            files_found.fetch_add(1, Ordering::Relaxed);
+            if let Some(progress) = progress {
+                progress.file_found();
+            }
+        });
+
+        let mut stats = ImportStats::default();
+        let mut emit = |mut file: SourceFile| -> Result<(), ScanError> {
+            self.rewrite_paths(&mut file);
+            add_file_stats(&mut stats, &file);
+            write_jsonl_record(&mut writer, &StreamRecord::File(&file))?;
+            Ok(())
+        };
+
+        if self.config.threads == 1 {
+            for (path, lang) in source_files {
+                if let Some(file) =
+                    self.parse_tracked(&path, &lang, &categorizer, &manifests, progress)
+                {
+                    emit(file)?;
+                }
+            }
+        } else {
+            // Workers pull paths from the walk and parse in parallel while this
+            // thread serializes in arrival order
+            let (tx, rx) = mpsc::channel();
+            let (categorizer, manifests) = (&categorizer, &manifests);
+            std::thread::scope(|scope| -> Result<(), ScanError> {
+                scope.spawn(move || {
+                    let parse_all = move || {
+                        source_files.par_bridge().for_each_with(tx, |tx, (path, lang)| {
+                            if let Some(file) =
+                                self.parse_tracked(&path, &lang, categorizer, manifests, progress)
+                            {
+                                // Sending only fails once the writer has errored and hung up
+                                let _ = tx.send(file);
+                            }
+                        })
+                    };
+                    match self.thread_pool() {
+                        Some(pool) => pool.install(parse_all),
+                        None => parse_all(),
+                    }
+                });
+                rx.into_iter().try_for_each(&mut emit)
+            })?;
+        }
+        if let Some(progress) = progress {
+            progress.finish();
+        }
+
+        // Files that failed to read or parse were never emitted
+        stats.failed_files = files_found.into_inner() - stats.total_files;
+
+        let mut manifests = manifests;
+        self.rewrite_manifest_paths(&mut manifests);
+        let summary = ImportMapStats {
+            metadata: self.build_metadata(start, stats.total_files),
+            stats,
+            external_dependencies: self.collect_external_dependencies(&manifests),
+            internal_packages: categorizer.internal_packages(),
+        };
+        write_jsonl_record(
+            &mut writer,
+            &StreamRecord::Summary {
+                root: &self.config.normalize_separators(&self.config.root),
+                stats: &summary,
+            },
+        )?;
+        writer.flush()?;
+
+        Ok(summary)
+    }
+
+    /// Dedicated rayon pool when a fixed thread count is configured
+    fn thread_pool(&self) -> Option<rayon::ThreadPool> {
+        if self.config.threads > 0 {
+            rayon::ThreadPoolBuilder::new()
+                .num_threads(self.config.threads)
+                .build()
+                .ok()
+        } else {
+            None
+        }
+    }
+
+    /// Rewrite paths before serialization (e.g. strip a CI checkout prefix)
+    fn rewrite_paths(&self, file: &mut SourceFile) {
+        self.rewrite_path(&mut file.path);
+        self.rewrite_path(&mut file.absolute_path);
+    }
+
+    /// Rewrite a manifest's path and the source and local paths of its dependencies
+    ///
+    /// Only done after parsing: categorization matches files against the
+    /// manifests' on-disk locations.
+    fn rewrite_manifest_paths(&self, manifests: &mut [PackageManifest]) {
+        for manifest in manifests {
+            self.rewrite_path(&mut manifest.path);
+            let deps = manifest.dependencies.values_mut();
+            for dep in deps.chain(manifest.dev_dependencies.values_mut()) {
+                self.rewrite_path(&mut dep.source);
+                if let Some(ref mut local_path) = dep.local_path {
+                    self.rewrite_path(local_path);
+                }
+            }
+        }
+    }
+
+    fn rewrite_path(&self, path: &mut PathBuf) {
+        if self.config.strip_prefix.is_some() || self.config.forward_slashes {
+            *path = self.config.transform_path(path);
+        }
+    }
+
+    /// Timing and provenance for a scan that started at `start`
+    fn build_metadata(&self, start: Instant, file_count: usize) -> ScanMetadata {
+        let duration = if self.config.reproducible {
+            Duration::ZERO
+        } else {
+            start.elapsed()
+        };
+        ScanMetadata {
+            scan_duration_ms: duration.as_millis() as u64,
+            files_per_second: if duration.as_secs_f64() > 0.0 {
+                file_count as f64 / duration.as_secs_f64()
+            } else {
+                0.0
+            },
+            timestamp: self.config.metadata_timestamp(),
+            tool_version: env!("CARGO_PKG_VERSION").to_string(),
+        }
+    }
+
     /// Find all source files matching the language filter
     fn find_source_files(&self) -> Result<Vec<(PathBuf, Language)>, ScanError> {
-        let mut files = Vec::new();
+        Ok(self.source_files()?.collect())
+    }
+
+    /// Lazily walk the root, yielding source files matching the filters as they are found
+    ///
+    /// With `threads == 1` each directory's entries are read sorted by name, so files
+    /// arrive in sorted path order while only one directory listing is buffered at a time.
+    fn source_files(
+        &self,
+    ) -> Result<impl Iterator<Item = (PathBuf, Language)> + Send + '_, ScanError> {
+        let mut walker = WalkDir::new(&self.config.root);
+        if let Some(depth) = self.config.max_directory_depth {
+            walker = walker.max_depth(depth);
+        }
+        if self.config.threads == 1 {
+            walker = walker.sort_by_file_name();
+        }
+
+        // Intersect with the git index when only staged files are wanted
+        let staged: Option<HashSet<PathBuf>> = if self.config.staged_only {
+            Some(
+                staged_files(&self.config.root)?
+                    .iter()
+                    .filter_map(|p| p.canonicalize().ok())
+                    .collect(),
+            )
+        } else {
+            None
+        };
 
-        for entry in WalkDir::new(&self.config.root)
+        Ok(walker
             .into_iter()
             .filter_map(|e| e.ok())
-        {
-            let path = entry.path();
+            .filter_map(move |entry| {
+                let path = entry.path();
 
-            // Skip directories
-            if entry.file_type().is_dir() {
-                continue;
-            }
+                // Skip directories
+                if entry.file_type().is_dir() {
+                    return None;
+                }
 
-            // Check ignore filter
-            if self.ignore_filter.should_ignore(path, false) {
-                continue;
-            }
+                // Check ignore filter
+                if self.ignore_filter.should_ignore(path, false) {
+                    return None;
+                }
 
-            // Check language filter
-            if !self
-                .ignore_filter
-                .matches_language_filter(path, &self.config.language_filter)
-            {
-                continue;
-            }
+                // Check language filter
+                if !self
+                    .ignore_filter
+                    .matches_language_filter(path, &self.config.language_filter)
+                {
+                    return None;
+                }
 
-            // Get language from extension
-            if let Some(ext) = path.extension() {
-                if let Some(lang) = Language::from_extension(&ext.to_string_lossy()) {
-                    files.push((path.to_path_buf(), lang));
+                // Check include patterns
+                if !self.ignore_filter.matches_include_patterns(path) {
+                    return None;
+                }
+
+                if let Some(ref staged) = staged {
+                    let is_staged = path
+                        .canonicalize()
+                        .map(|p| staged.contains(&p))
+                        .unwrap_or(false);
+                    if !is_staged {
+                        return None;
+                    }
                 }
-            }
-        }
 
-        Ok(files)
+                // Get language from extension
+                let lang = Language::from_extension(&path.extension()?.to_string_lossy())?;
+                Some((entry.into_path(), lang))
+            }))
     }
 
     /// Parse a single source file
@@ -173,6 +366,7 @@ impl ImportScanner {
 
         // Parse imports
         let mut imports = parser.parse(&content);
+        let is_barrel = parser.is_barrel(&content);
 
         // Categorize each import
         for import in &mut imports {
@@ -194,9 +388,34 @@ impl ImportScanner {
             language: language.clone(),
             imports,
             package,
+            is_barrel,
         })
     }
 
+    /// Parse a single source file and count it towards scan progress
+    fn parse_tracked(
+        &self,
+        path: &Path,
+        language: &Language,
+        categorizer: &ImportCategorizer,
+        manifests: &[PackageManifest],
+        progress: Option<&ProgressTracker>,
+    ) -> Option<SourceFile> {
+        let file = self.parse_file(path, language, categorizer, manifests);
+        if let Some(progress) = progress {
+            progress.file_done(path.strip_prefix(&self.config.root).unwrap_or(path));
+        }
+        file
+    }
+
+    /// Progress tracker for `files_total` files, when a callback is configured
+    fn progress_tracker(&self, files_total: usize) -> Option<ProgressTracker> {
+        self.config
+            .progress
+            .clone()
+            .map(|hook| ProgressTracker::new(hook, files_total))
+    }
+
     /// Find which package a file belongs to
     fn find_package_for_file(&self, file_path: &Path, manifests: &[PackageManifest]) -> Option<String> {
         let file_path_str = file_path.to_string_lossy();
@@ -216,28 +435,9 @@ impl ImportScanner {
     /// Calculate import statistics
     fn calculate_stats(&self, files: &[SourceFile]) -> ImportStats {
         let mut stats = ImportStats::default();
-
-        stats.total_files = files.len();
-
         for file in files {
-            match file.language {
-                Language::Python => stats.python_files += 1,
-                Language::JavaScript => stats.javascript_files += 1,
-                Language::TypeScript => stats.typescript_files += 1,
-            }
-
-            for import in &file.imports {
-                stats.total_imports += 1;
-                match import.import_type {
-                    crate::models::ImportType::External => stats.external_imports += 1,
-                    crate::models::ImportType::Internal => stats.internal_imports += 1,
-                    crate::models::ImportType::Local => stats.local_imports += 1,
-                    crate::models::ImportType::Stdlib => stats.stdlib_imports += 1,
-                    crate::models::ImportType::Unknown => stats.unknown_imports += 1,
-                }
-            }
+            add_file_stats(&mut stats, file);
         }
-
         stats
     }
 
@@ -265,9 +465,104 @@ impl ImportScanner {
     }
 }
 
+/// Files between progress updates while streaming, when the total is not known up front
+const STREAMING_PROGRESS_INTERVAL: usize = 100;
+
+/// Counts finished files and forwards throttled updates to the progress callback
+struct ProgressTracker {
+    hook: ProgressHook,
+    /// Files found so far; final before parsing starts unless `streaming`
+    files_total: AtomicUsize,
+    /// Whether the walk is still finding files while they are parsed
+    streaming: bool,
+    /// Report every `interval` files (about 100 updates per scan)
+    interval: usize,
+    /// Files done and the last one finished; held while invoking the callback so
+    /// `files_done` arrives in increasing order
+    files_done: Mutex<(usize, PathBuf)>,
+}
+
+impl ProgressTracker {
+    fn new(hook: ProgressHook, files_total: usize) -> Self {
+        Self {
+            hook,
+            files_total: AtomicUsize::new(files_total),
+            streaming: false,
+            interval: (files_total / 100).max(1),
+            files_done: Mutex::new((0, PathBuf::new())),
+        }
+    }
+
+    /// Tracker whose total grows via `file_found` while the walk is still running
+    fn streaming(hook: ProgressHook) -> Self {
+        Self {
+            hook,
+            files_total: AtomicUsize::new(0),
+            streaming: true,
+            interval: STREAMING_PROGRESS_INTERVAL,
+            files_done: Mutex::new((0, PathBuf::new())),
+        }
+    }
+
+    /// Record one file found by the walk (streaming scans only)
+    fn file_found(&self) {
+        self.files_total.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Record one finished file; always reports the last one of a non-streaming scan
+    fn file_done(&self, path: &Path) {
+        let mut files_done = self.files_done.lock().unwrap_or_else(|e| e.into_inner());
+        files_done.0 += 1;
+        files_done.1 = path.to_path_buf();
+        let is_last = !self.streaming && files_done.0 == self.files_total.load(Ordering::Relaxed);
+        if files_done.0.is_multiple_of(self.interval) || is_last {
+            self.report(files_done.0, path);
+        }
+    }
+
+    /// Report the final count of a streaming scan once the walk is exhausted
+    fn finish(&self) {
+        let files_done = self.files_done.lock().unwrap_or_else(|e| e.into_inner());
+        if self.streaming && !files_done.0.is_multiple_of(self.interval) {
+            self.report(files_done.0, &files_done.1);
+        }
+    }
+
+    fn report(&self, files_done: usize, path: &Path) {
+        (self.hook.0)(ScanProgress {
+            files_done,
+            files_total: self.files_total.load(Ordering::Relaxed),
+            current_path: path.to_path_buf(),
+        });
+    }
+}
+
+/// Add one file's language and import categories to running totals
+fn add_file_stats(stats: &mut ImportStats, file: &SourceFile) {
+    stats.total_files += 1;
+    match file.language {
+        Language::Python => stats.python_files += 1,
+        Language::JavaScript => stats.javascript_files += 1,
+        Language::TypeScript => stats.typescript_files += 1,
+    }
+
+    for import in &file.imports {
+        stats.total_imports += 1;
+        match import.import_type {
+            crate::models::ImportType::External => stats.external_imports += 1,
+            crate::models::ImportType::Internal => stats.internal_imports += 1,
+            crate::models::ImportType::Local => stats.local_imports += 1,
+            crate::models::ImportType::Stdlib => stats.stdlib_imports += 1,
+            crate::models::ImportType::Unknown => stats.unknown_imports += 1,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::models::ImportType;
+    use std::sync::Arc;
 
     #[test]
     fn test_scanner_creation() {
@@ -275,4 +570,361 @@ mod tests {
         let scanner = ImportScanner::new(config);
         assert!(scanner.is_ok());
     }
+
+    #[test]
+    fn test_max_directory_depth() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let root = dir.path().to_path_buf();
+        fs::write(root.join("top.py"), "import os\n").unwrap();
+        fs::create_dir(root.join("nested")).unwrap();
+        fs::write(root.join("nested").join("inner.py"), "import sys\n").unwrap();
+
+        let recursive = ImportScanner::new(ScanConfig::new(root.clone()))
+            .unwrap()
+            .scan()
+            .unwrap();
+        assert_eq!(recursive.files.len(), 2);
+
+        let shallow = ImportScanner::new(ScanConfig::new(root).with_max_directory_depth(1))
+            .unwrap()
+            .scan()
+            .unwrap();
+        assert_eq!(shallow.files.len(), 1);
+        assert_eq!(shallow.files[0].path, PathBuf::from("top.py"));
+    }
+
+    #[test]
+    fn test_path_transform() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let root = dir.path().to_path_buf();
+        fs::create_dir(root.join("src")).unwrap();
+        fs::write(root.join("src").join("app.py"), "import os\n").unwrap();
+
+        let config = ScanConfig::new(root.clone()).with_path_transform(root.to_string_lossy().into_owned());
+        let result = ImportScanner::new(config).unwrap().scan().unwrap();
+
+        assert_eq!(result.files.len(), 1);
+        assert_eq!(result.files[0].absolute_path, PathBuf::from("src/app.py"));
+        assert_eq!(result.files[0].path, PathBuf::from("src/app.py"));
+    }
+
+    #[test]
+    fn test_path_transform_rewrites_manifests() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let root = dir.path().to_path_buf();
+        fs::write(root.join("requirements.txt"), "requests>=2.28\n").unwrap();
+        fs::write(root.join("app.py"), "import requests\n").unwrap();
+
+        let config = ScanConfig::new(root.clone()).with_path_transform(root.to_string_lossy().into_owned());
+        let result = ImportScanner::new(config).unwrap().scan().unwrap();
+
+        assert_eq!(result.manifests[0].path, PathBuf::from("requirements.txt"));
+        assert_eq!(result.external_dependencies["requests"].source, PathBuf::from("requirements.txt"));
+        // Categorization still saw the on-disk manifest
+        assert_eq!(result.files[0].imports[0].import_type, ImportType::External);
+    }
+
+    #[test]
+    fn test_serial_scan_output_is_sorted() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let root = dir.path().to_path_buf();
+        fs::write(root.join("requirements.txt"), "requests\nhttpx\nattrs\n").unwrap();
+        fs::write(root.join("c.py"), "import requests\n").unwrap();
+        fs::write(root.join("a.js"), "import fs from 'fs';\n").unwrap();
+        fs::create_dir(root.join("b")).unwrap();
+        fs::write(root.join("b").join("mod.py"), "import attrs\n").unwrap();
+
+        let result = ImportScanner::new(ScanConfig::new(root).with_threads(1))
+            .unwrap()
+            .scan()
+            .unwrap();
+
+        let paths: Vec<PathBuf> = result.files.iter().map(|f| f.path.clone()).collect();
+        assert_eq!(
+            paths,
+            vec![PathBuf::from("a.js"), PathBuf::from("b").join("mod.py"), PathBuf::from("c.py")]
+        );
+
+        // Dependency maps serialize in name order whatever the hash order
+        let json = serde_json::to_string(&result.manifests[0]).unwrap();
+        let positions: Vec<usize> = ["\"attrs\"", "\"httpx\"", "\"requests\""]
+            .iter()
+            .map(|name| json.find(name).unwrap())
+            .collect();
+        assert!(positions.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn test_staged_only() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let root = dir.path().to_path_buf();
+        let git = |args: &[&str]| {
+            let status = std::process::Command::new("git")
+                .args(args)
+                .current_dir(&root)
+                .status()
+                .unwrap();
+            assert!(status.success());
+        };
+
+        git(&["init", "-q"]);
+        fs::write(root.join("staged.py"), "import os\nimport sys\n").unwrap();
+        fs::write(root.join("unstaged.py"), "import json\nimport re\n").unwrap();
+        git(&["add", "staged.py"]);
+
+        let result = ImportScanner::new(ScanConfig::new(root.clone()).with_staged_only(true))
+            .unwrap()
+            .scan()
+            .unwrap();
+        assert_eq!(result.files.len(), 1);
+        assert_eq!(result.files[0].path, PathBuf::from("staged.py"));
+    }
+
+    #[test]
+    fn test_staged_only_rename() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let root = dir.path().to_path_buf();
+        let git = |args: &[&str]| {
+            let status = std::process::Command::new("git")
+                .args(args)
+                .current_dir(&root)
+                .status()
+                .unwrap();
+            assert!(status.success());
+        };
+
+        git(&["init", "-q"]);
+        git(&["config", "user.name", "Test"]);
+        git(&["config", "user.email", "test@example.com"]);
+        fs::write(root.join("old.py"), "import os\nimport sys\n").unwrap();
+        fs::write(root.join("other.py"), "import json\nimport re\n").unwrap();
+        git(&["add", "."]);
+        git(&["commit", "-q", "-m", "Add files"]);
+        git(&["mv", "old.py", "new.py"]);
+
+        // Only the new side of a staged rename is scanned
+        let result = ImportScanner::new(ScanConfig::new(root.clone()).with_staged_only(true))
+            .unwrap()
+            .scan()
+            .unwrap();
+        assert_eq!(result.files.len(), 1);
+        assert_eq!(result.files[0].path, PathBuf::from("new.py"));
+    }
+
+    #[test]
+    fn test_fixed_timestamp() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let root = dir.path().to_path_buf();
+        fs::write(root.join("app.py"), "import os\n").unwrap();
+
+        let run = || {
+            let config = ScanConfig::new(root.clone())
+                .with_fixed_timestamp(Some("2024-01-01T00:00:00+00:00".to_string()))
+                .with_reproducible(true);
+            ImportScanner::new(config).unwrap().scan().unwrap().metadata
+        };
+
+        let first = run();
+        assert_eq!(first.timestamp, "2024-01-01T00:00:00+00:00");
+        assert_eq!(first.scan_duration_ms, 0);
+        assert_eq!(first, run());
+    }
+
+    #[test]
+    fn test_barrel_files() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let root = dir.path().to_path_buf();
+        fs::write(
+            root.join("index.ts"),
+            "// Public API\nexport * from './client';\nexport { Config } from './config';\nexport type { Options } from './types';\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("mixed.ts"),
+            "export { Config } from './config';\nexport const VERSION = '1.0';\n",
+        )
+        .unwrap();
+
+        let result = ImportScanner::new(ScanConfig::new(root).with_threads(1))
+            .unwrap()
+            .scan()
+            .unwrap();
+        let barrel = |name: &str| {
+            result
+                .files
+                .iter()
+                .find(|f| f.path == Path::new(name))
+                .map(|f| f.is_barrel)
+                .unwrap()
+        };
+        assert!(barrel("index.ts"));
+        assert!(!barrel("mixed.ts"));
+
+        let barrels = result.filter_to_barrels();
+        assert_eq!(barrels.files.len(), 1);
+        assert_eq!(barrels.stats.total_files, 1);
+    }
+
+    #[test]
+    fn test_manifest_dependencies_categorize_imports() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let root = dir.path().to_path_buf();
+        fs::write(
+            root.join("pyproject.toml"),
+            "[project]\nname = \"service\"\ndependencies = [\"httpx>=0.24\"]\n\n\
+             [project.optional-dependencies]\ntest = [\"pytest>=8\"]\n",
+        )
+        .unwrap();
+        fs::write(root.join("test_app.py"), "import httpx\nimport pytest\nimport mystery\n").unwrap();
+
+        let result = ImportScanner::new(ScanConfig::new(root)).unwrap().scan().unwrap();
+        let types: Vec<(&str, &ImportType)> = result.files[0]
+            .imports
+            .iter()
+            .map(|i| (i.module.as_str(), &i.import_type))
+            .collect();
+        assert_eq!(
+            types,
+            [
+                ("httpx", &ImportType::External),
+                ("pytest", &ImportType::External),
+                ("mystery", &ImportType::Unknown),
+            ]
+        );
+        assert_eq!(result.manifests.len(), 1);
+        assert!(result.external_dependencies.contains_key("pytest"));
+    }
+
+    #[test]
+    fn test_scan_streaming_writes_files_then_summary() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let root = dir.path().to_path_buf();
+        fs::write(root.join("b.py"), "import os\nimport requests\n").unwrap();
+        fs::write(root.join("a.js"), "import fs from 'fs';\n").unwrap();
+
+        let mut buf = Vec::new();
+        let summary = ImportScanner::new(ScanConfig::new(root).with_threads(1))
+            .unwrap()
+            .scan_streaming(&mut buf)
+            .unwrap();
+        assert_eq!(summary.stats.total_files, 2);
+        assert_eq!(summary.stats.total_imports, 3);
+
+        let records: Vec<serde_json::Value> = String::from_utf8(buf)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(records.len(), 3);
+        assert_eq!(records[0]["type"], "file");
+        assert_eq!(records[0]["path"], "a.js");
+        assert_eq!(records[1]["path"], "b.py");
+        assert_eq!(records[2]["type"], "summary");
+        assert_eq!(records[2]["stats"]["total_files"], 2);
+    }
+
+    #[test]
+    fn test_scan_streaming_matches_eager_scan() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let root = dir.path().to_path_buf();
+        fs::write(root.join("main.py"), "import os\nimport requests\n").unwrap();
+        fs::write(root.join("a.js"), "import fs from 'fs';\n").unwrap();
+        fs::create_dir_all(root.join("pkg").join("sub")).unwrap();
+        fs::write(root.join("pkg").join("mod.py"), "from . import sibling\n").unwrap();
+        fs::write(root.join("pkg").join("sub").join("deep.ts"), "import x from './x';\n").unwrap();
+
+        for threads in [1, 4] {
+            let scanner =
+                ImportScanner::new(ScanConfig::new(root.clone()).with_threads(threads)).unwrap();
+            let mut eager: Vec<serde_json::Value> = scanner
+                .scan()
+                .unwrap()
+                .files
+                .iter()
+                .map(|f| serde_json::to_value(f).unwrap())
+                .collect();
+
+            let mut buf = Vec::new();
+            scanner.scan_streaming(&mut buf).unwrap();
+            let mut streamed: Vec<serde_json::Value> = String::from_utf8(buf)
+                .unwrap()
+                .lines()
+                .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap())
+                .filter(|record| record["type"] == "file")
+                .map(|mut record| {
+                    record.as_object_mut().unwrap().remove("type");
+                    record
+                })
+                .collect();
+
+            // Parallel scans emit in completion order
+            if threads > 1 {
+                let by_path = |f: &serde_json::Value| f["path"].as_str().unwrap().to_string();
+                eager.sort_by_key(by_path);
+                streamed.sort_by_key(by_path);
+            }
+            assert_eq!(eager.len(), 4);
+            assert_eq!(eager, streamed);
+        }
+    }
+
+    #[test]
+    fn test_scan_streaming_walks_lazily() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let root = dir.path().to_path_buf();
+        for d in 0..20 {
+            let sub = root.join(format!("pkg{:02}", d));
+            fs::create_dir(&sub).unwrap();
+            for f in 0..30 {
+                fs::write(sub.join(format!("mod{:02}.py", f)), "import os\n").unwrap();
+            }
+        }
+
+        let updates = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&updates);
+        let config = ScanConfig::new(root)
+            .with_threads(1)
+            .with_progress(Arc::new(move |p: ScanProgress| {
+                sink.lock().unwrap().push((p.files_done, p.files_total));
+            }));
+        let summary = ImportScanner::new(config)
+            .unwrap()
+            .scan_streaming(std::io::sink())
+            .unwrap();
+        assert_eq!(summary.stats.total_files, 600);
+
+        // Parsing starts while most of the tree is still unwalked: only the
+        // directory being read is buffered ahead of the parser
+        let updates = updates.lock().unwrap();
+        let (done, found) = updates[0];
+        assert_eq!(done, 100);
+        assert!(found <= done + 30, "walk ran ahead to {} files", found);
+        assert_eq!(*updates.last().unwrap(), (600, 600));
+    }
+
+    #[test]
+    fn test_progress_callback() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let root = dir.path().to_path_buf();
+        for i in 0..8 {
+            fs::write(root.join(format!("mod{}.py", i)), "import os\n").unwrap();
+        }
+
+        let updates = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&updates);
+        let config = ScanConfig::new(root)
+            .with_threads(4)
+            .with_progress(Arc::new(move |p: ScanProgress| {
+                sink.lock().unwrap().push((p.files_done, p.files_total, p.current_path));
+            }));
+        ImportScanner::new(config).unwrap().scan().unwrap();
+
+        let updates = updates.lock().unwrap();
+        assert!(!updates.is_empty());
+        assert!(updates.windows(2).all(|w| w[0].0 < w[1].0));
+        assert_eq!(updates.last().unwrap().0, 8);
+        assert!(updates.iter().all(|(_, total, path)| {
+            *total == 8 && path.extension().is_some_and(|ext| ext == "py")
+        }));
+    }
 }
@@ -0,0 +1,144 @@
+use crate::categorizer::ImportCategorizer;
+use crate::models::{ImportMap, ImportStatement, ImportType, Language, SourceFile};
+use crate::policy::base_package;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+/// A project module whose name hides a standard library or builtin module
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ShadowedImport {
+    /// Relative path of the importing file
+    pub file: PathBuf,
+    /// Line number of the import statement
+    pub line: usize,
+    /// Stdlib/builtin module name the imported project module collides with
+    pub shadowed_name: String,
+}
+
+impl ImportMap {
+    /// Find imports of project modules named like a stdlib/builtin module
+    ///
+    /// Internal imports are checked by their base package. Python local imports
+    /// are checked by the module they name (`.logging`, `from . import json`),
+    /// and so are absolute imports that resolve to a scanned module next to the
+    /// importing file, since Python puts the script's directory first on
+    /// `sys.path` (`import logging` beside a `logging.py`). JS relative imports
+    /// are always explicit paths and never shadow a builtin.
+    pub fn find_shadowing(&self) -> Vec<ShadowedImport> {
+        let categorizer = ImportCategorizer::new(&[]);
+        let python_modules: HashSet<PathBuf> = self
+            .files
+            .iter()
+            .filter(|f| f.language == Language::Python)
+            .filter_map(|f| python_module_path(&f.path))
+            .collect();
+
+        let mut shadowed = Vec::new();
+        for file in &self.files {
+            for import in &file.imports {
+                for name in shadow_candidates(file, import, &python_modules) {
+                    if categorizer.is_builtin_name(&name, &file.language) {
+                        shadowed.push(ShadowedImport {
+                            file: file.path.clone(),
+                            line: import.line,
+                            shadowed_name: name,
+                        });
+                    }
+                }
+            }
+        }
+
+        shadowed
+    }
+}
+
+/// Module names an import brings in from the project itself
+fn shadow_candidates(
+    file: &SourceFile,
+    import: &ImportStatement,
+    python_modules: &HashSet<PathBuf>,
+) -> Vec<String> {
+    match (&import.import_type, &file.language) {
+        (ImportType::Internal, _) => vec![base_package(&import.module)],
+        (ImportType::Local, Language::Python) => {
+            let module = import.module.trim_start_matches('.');
+            if module.is_empty() {
+                // `from . import logging` names sibling modules in its items
+                import.items.iter().filter(|i| *i != "*").cloned().collect()
+            } else {
+                vec![module.rsplit('.').next().unwrap_or(module).to_string()]
+            }
+        }
+        (ImportType::Stdlib, Language::Python) => {
+            let name = base_package(&import.module);
+            let sibling = file.path.with_file_name(&name);
+            if python_modules.contains(&sibling) {
+                vec![name]
+            } else {
+                vec![]
+            }
+        }
+        _ => vec![],
+    }
+}
+
+/// Importable module path of a Python file: `app/logging.py` and
+/// `app/logging/__init__.py` are both `app/logging`
+fn python_module_path(path: &Path) -> Option<PathBuf> {
+    let stem = path.file_stem()?;
+    if stem == "__init__" {
+        path.parent().map(Path::to_path_buf)
+    } else {
+        Some(path.with_file_name(stem))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{import, import_map, source_file};
+
+    fn from_import(module: &str, items: &[&str], line: usize) -> ImportStatement {
+        ImportStatement {
+            items: items.iter().map(|i| i.to_string()).collect(),
+            ..import(module, line, ImportType::Local)
+        }
+    }
+
+    #[test]
+    fn test_find_shadowing() {
+        let map = import_map(vec![
+            source_file(
+                "app/main.py",
+                Language::Python,
+                vec![
+                    import("logging", 1, ImportType::Stdlib),
+                    import("json", 2, ImportType::Stdlib),
+                    from_import(".", &["os", "helpers"], 3),
+                    from_import(".helpers", &["run"], 4),
+                ],
+            ),
+            source_file("app/logging.py", Language::Python, vec![]),
+            source_file("app/helpers.py", Language::Python, vec![]),
+            source_file("lib/json.py", Language::Python, vec![]),
+        ]);
+
+        // `json.py` lives in another directory, so `import json` still reaches the stdlib
+        assert_eq!(
+            map.find_shadowing(),
+            vec![
+                ShadowedImport {
+                    file: PathBuf::from("app/main.py"),
+                    line: 1,
+                    shadowed_name: "logging".to_string(),
+                },
+                ShadowedImport {
+                    file: PathBuf::from("app/main.py"),
+                    line: 3,
+                    shadowed_name: "os".to_string(),
+                },
+            ]
+        );
+    }
+}
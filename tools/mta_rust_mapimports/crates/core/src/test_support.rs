@@ -0,0 +1,55 @@
+//! Fixture builders shared by the unit tests
+//!
+//! Each builder fills in the fields a test rarely cares about; tests that need a
+//! different value override it with struct update syntax.
+
+use crate::models::{
+    ImportMap, ImportStatement, ImportStats, ImportType, Language, ScanMetadata, SourceFile,
+};
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+/// A plain `import <module>` at column 0 with no imported items
+pub(crate) fn import(module: &str, line: usize, import_type: ImportType) -> ImportStatement {
+    ImportStatement {
+        module: module.to_string(),
+        items: vec![],
+        is_default: false,
+        line,
+        column: 0,
+        raw: format!("import {}", module),
+        import_type,
+        alias: None,
+        is_optional: false,
+        optional_group: None,
+    }
+}
+
+/// A file at `path` under `/project`, outside any package
+pub(crate) fn source_file(
+    path: &str,
+    language: Language,
+    imports: Vec<ImportStatement>,
+) -> SourceFile {
+    SourceFile {
+        path: PathBuf::from(path),
+        absolute_path: PathBuf::from("/project").join(path),
+        language,
+        imports,
+        package: None,
+        is_barrel: false,
+    }
+}
+
+/// A scan of `/project` that found `files` and no manifests
+pub(crate) fn import_map(files: Vec<SourceFile>) -> ImportMap {
+    ImportMap {
+        root: PathBuf::from("/project"),
+        files,
+        manifests: vec![],
+        external_dependencies: HashMap::new(),
+        internal_packages: vec![],
+        stats: ImportStats::default(),
+        metadata: ScanMetadata::default(),
+    }
+}
@@ -0,0 +1,182 @@
+use crate::models::{ImportMap, ImportStats, ImportStatement, SourceFile};
+use crate::parsers::create_parser;
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+impl ImportMap {
+    /// Find imports whose bound names are never referenced in the rest of the file
+    ///
+    /// Each file is re-read from the scan root and re-parsed to collect the
+    /// identifiers used outside import statements. An import is reported when any
+    /// of its `items` or its `alias` is never referenced. Side-effect imports
+    /// (`import './styles.css'`, bare `import antigravity`), wildcards, re-exports,
+    /// `__future__` imports and barrel files bind nothing to check and are skipped,
+    /// as are files that can no longer be read (e.g. scanned from an archive).
+    pub fn find_unused_imports(&self) -> Vec<(PathBuf, ImportStatement)> {
+        let mut unused = Vec::new();
+        for file in &self.files {
+            if file.is_barrel || !file.imports.iter().any(|i| !bound_names(i).is_empty()) {
+                continue;
+            }
+            let Some(used) = referenced_names(&self.root, file) else {
+                continue;
+            };
+
+            for import in &file.imports {
+                if bound_names(import).iter().any(|name| !used.contains(*name)) {
+                    unused.push((file.path.clone(), import.clone()));
+                }
+            }
+        }
+
+        unused
+    }
+
+    /// Filter to only show imports reported by `find_unused_imports`
+    pub fn filter_to_unused(&self) -> Self {
+        let mut files: Vec<SourceFile> = Vec::new();
+        for (path, import) in self.find_unused_imports() {
+            match files.last_mut() {
+                Some(file) if file.path == path => file.imports.push(import),
+                _ => {
+                    if let Some(source) = self.files.iter().find(|f| f.path == path) {
+                        files.push(SourceFile {
+                            imports: vec![import],
+                            ..source.clone()
+                        });
+                    }
+                }
+            }
+        }
+
+        let unused_count = files.iter().map(|f| f.imports.len()).sum();
+
+        ImportMap {
+            root: self.root.clone(),
+            files,
+            manifests: vec![],
+            external_dependencies: HashMap::new(),
+            internal_packages: vec![],
+            stats: ImportStats {
+                total_imports: unused_count,
+                ..ImportStats::default()
+            },
+            metadata: self.metadata.clone(),
+        }
+    }
+}
+
+/// Local names an import statement binds that the file is expected to use
+fn bound_names(import: &ImportStatement) -> Vec<&str> {
+    if import.module == "__future__" || import.raw.trim_start().starts_with("export") {
+        return vec![];
+    }
+
+    import
+        .items
+        .iter()
+        .filter(|item| *item != "*")
+        .chain(import.alias.iter())
+        .map(String::as_str)
+        .collect()
+}
+
+/// Identifiers referenced outside the file's imports, or `None` if it can't be re-read
+///
+/// The file is resolved from `root` and its root-relative `path`: `absolute_path`
+/// may already have been rewritten by `--strip-prefix`.
+fn referenced_names(root: &Path, file: &SourceFile) -> Option<HashSet<String>> {
+    let source = fs::read_to_string(root.join(&file.path)).ok()?;
+    let mut parser = create_parser(&file.language).ok()?;
+    Some(parser.referenced_names(&source))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::ScanConfig;
+    use crate::scanner::ImportScanner;
+    use std::fs;
+    use tempfile::TempDir;
+
+    /// Unused imports of a scanned project as `(file, line)` pairs
+    fn unused_lines(files: &[(&str, &str)]) -> Vec<(String, usize)> {
+        unused_lines_with(files, |config| config)
+    }
+
+    /// `unused_lines` with the scan config adjusted by `configure`
+    fn unused_lines_with(
+        files: &[(&str, &str)],
+        configure: impl FnOnce(ScanConfig) -> ScanConfig,
+    ) -> Vec<(String, usize)> {
+        let dir = TempDir::new().unwrap();
+        for (path, source) in files {
+            fs::write(dir.path().join(path), source).unwrap();
+        }
+
+        let config = configure(ScanConfig::new(dir.path().to_path_buf()));
+        let map = ImportScanner::new(config).unwrap().scan().unwrap();
+        let mut unused: Vec<(String, usize)> = map
+            .find_unused_imports()
+            .into_iter()
+            .map(|(path, import)| (path.display().to_string(), import.line))
+            .collect();
+        unused.sort();
+        unused
+    }
+
+    #[test]
+    fn test_find_unused_imports_python() {
+        let source = "\
+import antigravity
+import numpy as np
+import pandas as pd
+from typing import List, Optional
+from os import path as osp
+from __future__ import annotations
+
+def mean(values: List[float]) -> float:
+    return np.mean(values) + len(osp.sep)
+";
+        assert_eq!(
+            unused_lines(&[("stats.py", source)]),
+            vec![("stats.py".to_string(), 3), ("stats.py".to_string(), 4)]
+        );
+    }
+
+    #[test]
+    fn test_find_unused_imports_javascript() {
+        let source = "\
+import './styles.css';
+import React from 'react';
+import { useState as useLocalState, useMemo } from 'react';
+import * as path from 'path';
+const { join: joinPath } = require('path');
+const fs = require('fs');
+export { helper } from './helper';
+
+export function App() {
+  const [value] = useLocalState(joinPath('a', 'b'));
+  return path.basename(value);
+}
+";
+        assert_eq!(
+            unused_lines(&[("app.js", source)]),
+            vec![
+                ("app.js".to_string(), 2),
+                ("app.js".to_string(), 3),
+                ("app.js".to_string(), 6),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_find_unused_imports_with_strip_prefix() {
+        let source = "import numpy as np\nfrom os import path\n\nprint(path.sep)\n";
+        let unused = unused_lines_with(&[("cli.py", source)], |config| {
+            let prefix = config.root.display().to_string();
+            config.with_path_transform(prefix)
+        });
+        assert_eq!(unused, vec![("cli.py".to_string(), 1)]);
+    }
+}
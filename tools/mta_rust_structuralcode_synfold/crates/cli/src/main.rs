@@ -1,11 +1,17 @@
 use clap::{Parser, Subcommand, ValueEnum};
 use indicatif::{ProgressBar, ProgressStyle};
 use synfold_core::{
-    format_output, format_output_grouped, render_file, render_file_ansi, FoldFilter, FoldScanner,
-    Language, OutputFormat, PreviewMode, ScanConfig,
+    format_entry_points, format_output_grouped_with_indent, format_output_with_indent,
+    format_suppressions, format_vscode_folding_ranges, render_file, render_file_ansi,
+    to_html_diff, to_json_with_indent, to_lsp_folding_ranges, to_toml,
+    write_run_summary, write_stream_record, EditorConfigDefaults, FoldDiff, FoldFilter, FoldMap,
+    FoldScanner, FoldMode, FoldOrder, FoldType, GroupedFoldMap, IndentStyle, Language,
+    OutputFormat, PreviewMode, ScanConfig, SourceFile, StreamRecord,
 };
+use std::collections::HashSet;
 use std::fs;
-use std::path::PathBuf;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
 use std::time::Duration;
 
 #[derive(Parser)]
@@ -38,15 +44,19 @@ pub struct Args {
     #[arg(short, long)]
     pub output: Option<PathBuf>,
 
-    /// Only scan specific language
-    #[arg(long, value_enum)]
-    pub language: Option<LanguageFilter>,
+    /// Only scan specific languages (comma-separated or repeated, e.g. `python,typescript`)
+    #[arg(long, value_enum, value_delimiter = ',', action = clap::ArgAction::Append)]
+    pub language: Vec<LanguageFilter>,
 
     /// Additional ignore patterns (gitignore style)
     #[arg(long, action = clap::ArgAction::Append)]
     pub ignore: Vec<String>,
 
-    /// Ignore file path (defaults to .gitignore)
+    /// Only scan files matching these globs, relative to the scan root
+    #[arg(long, action = clap::ArgAction::Append)]
+    pub include: Vec<String>,
+
+    /// Ignore file path (defaults to .gitignore; .synfoldignore is layered on top)
     #[arg(long)]
     pub ignore_file: Option<PathBuf>,
 
@@ -54,14 +64,34 @@ pub struct Args {
     #[arg(long)]
     pub include_deps: bool,
 
-    /// Minimum lines for a region to be foldable
-    #[arg(long, default_value_t = 4)]
-    pub min_lines: usize,
+    /// Only scan files directly in the root directory (no subdirectories)
+    #[arg(long)]
+    pub no_recursion: bool,
+
+    /// Maximum directory depth to walk (1 = root directory only)
+    #[arg(long, conflicts_with = "no_recursion")]
+    pub max_directory_depth: Option<usize>,
+
+    /// Minimum lines for a region to be foldable [default: 4, or `mta_min_fold_lines` in .editorconfig]
+    #[arg(long)]
+    pub min_lines: Option<usize>,
+
+    /// Minimum lines for comment/docstring folds, independent of --min-lines
+    #[arg(long)]
+    pub min_doc_lines: Option<usize>,
+
+    /// Minimum calls in a multi-line method chain for it to fold (at least 2)
+    #[arg(long, default_value_t = 3)]
+    pub min_chain_depth: usize,
 
     /// Use flat output structure (not grouped by language)
     #[arg(long)]
     pub flat: bool,
 
+    /// Nest folds under the innermost fold containing them (class > method > literal)
+    #[arg(long)]
+    pub nested: bool,
+
     /// Disable syntax highlighting in ANSI output
     #[arg(long)]
     pub no_color: bool,
@@ -74,7 +104,7 @@ pub struct Args {
     #[arg(long, default_value_t = 0)]
     pub threads: usize,
 
-    /// Fold only specific types (comma-separated: block,import,arglist,chain,literal,comment,doc,class,array,object)
+    /// Fold only specific types (comma-separated: block,import,arglist,chain,literal,comment,doc,class,array,object,property,region)
     #[arg(long)]
     pub fold_types: Option<String>,
 
@@ -85,6 +115,86 @@ pub struct Args {
     /// Preview mode for fold summaries
     #[arg(long, value_enum, default_value_t = PreviewModeArg::Flow)]
     pub preview_mode: PreviewModeArg,
+
+    /// Where Python function folds begin
+    #[arg(long, value_enum, default_value_t = FoldModeArg::Body)]
+    pub fold_mode: FoldModeArg,
+
+    /// Order of each file's folds: by position, or as the parser found them (for debugging)
+    #[arg(long, value_enum, default_value_t = FoldOrderArg::Source)]
+    pub fold_order: FoldOrderArg,
+
+    /// Merge adjacent folds of these types into one region (comma-separated, e.g. block,comment)
+    #[arg(long)]
+    pub merge_adjacent: Option<String>,
+
+    /// Maximum lines between folds merged by --merge-adjacent
+    #[arg(long, default_value_t = 1)]
+    pub merge_gap: usize,
+
+    /// Append child counts to object/array/class previews, e.g. `{ a, b, c, d, +4 more } (8 props)`
+    #[arg(long)]
+    pub closing_context: bool,
+
+    /// Indentation for JSON output: 2, 4 or tab
+    #[arg(long, default_value = "2")]
+    pub json_indent: IndentStyle,
+
+    /// Remove this leading prefix from every file path in the output
+    #[arg(long)]
+    pub strip_prefix: Option<String>,
+
+    /// Pin the metadata timestamp (RFC 3339); defaults to SOURCE_DATE_EPOCH, then the current time
+    #[arg(long, value_name = "RFC3339")]
+    pub timestamp: Option<String>,
+
+    /// Record a zero scan duration so repeated runs produce identical output
+    #[arg(long)]
+    pub reproducible: bool,
+
+    /// Keep at most N files in the output; stats still cover every scanned file
+    #[arg(long, value_name = "N")]
+    pub max_output_files: Option<usize>,
+
+    /// Cache parsed folds in this JSON file and reuse them for unchanged files
+    #[arg(long, value_name = "PATH")]
+    pub cache: Option<PathBuf>,
+
+    /// Add a per-file `line_index` mapping each fold start line to its type, end line and preview
+    #[arg(long)]
+    pub line_indexed: bool,
+
+    /// Print an aggregate histogram of tree-sitter node kinds instead of folds (debug)
+    #[arg(long)]
+    pub histogram: bool,
+
+    /// Stream typed JSON lines as files are scanned: a header, one record per file, then a summary
+    #[arg(long, conflicts_with = "histogram")]
+    pub json_stream: bool,
+
+    /// List likely entry points (`__main__` guards, package.json main/bin, main.py/index.js) instead of folds, in --format
+    #[arg(long, conflicts_with_all = ["histogram", "json_stream"])]
+    pub entry_points: bool,
+
+    /// List `@ts-ignore`, `@ts-expect-error` and `# type: ignore` comments instead of folds, in --format
+    #[arg(long, conflicts_with_all = ["histogram", "json_stream", "entry_points"])]
+    pub suppressions: bool,
+
+    /// Only analyze files staged in git (for pre-commit hooks)
+    #[arg(long)]
+    pub staged: bool,
+
+    /// Annotate each fold with the newest commit date of its lines (libgit2 blame; slow on big repos)
+    #[arg(long)]
+    pub blame: bool,
+
+    /// After writing output, print a one-line JSON run summary to stderr
+    #[arg(long)]
+    pub emit_run_summary: bool,
+
+    /// Verify that grouped per-language stats reconcile with the flat stats (fails on mismatch)
+    #[arg(long)]
+    pub self_check: bool,
 }
 
 #[derive(Subcommand)]
@@ -120,27 +230,62 @@ pub enum Commands {
         /// Minimum lines for folding
         #[arg(long, default_value_t = 4)]
         min_lines: usize,
+
+        /// Apply the largest folds first until the output fits in N lines
+        #[arg(long)]
+        line_budget: Option<usize>,
+
+        /// Write a JSON source map (rendered line -> original line range) to this file
+        #[arg(long, value_name = "PATH")]
+        source_map: Option<PathBuf>,
     },
 
     /// List all foldable regions in a file
     List {
         /// File to analyze
-        file: PathBuf,
+        #[arg(required_unless_present = "source", conflicts_with = "source")]
+        file: Option<PathBuf>,
+
+        /// Analyze this inline source code instead of a file
+        #[arg(long)]
+        source: Option<String>,
+
+        /// Language of the inline --source code
+        #[arg(long, value_enum, default_value_t = SourceLanguageArg::Python, requires = "source")]
+        source_language: SourceLanguageArg,
 
         /// Output format
-        #[arg(short, long, value_enum, default_value_t = OutputFormatArg::Json)]
-        format: OutputFormatArg,
+        #[arg(short, long, value_enum, default_value_t = ListFormatArg::Json)]
+        format: ListFormatArg,
+
+        /// Print VS Code folding ranges (`[{ start, end, kind }]`, 0-indexed lines) instead of --format
+        #[arg(long, conflicts_with = "format")]
+        vscode: bool,
 
         /// Preview mode for fold summaries
         #[arg(long, value_enum, default_value_t = PreviewModeArg::Flow)]
         preview_mode: PreviewModeArg,
     },
+
+    /// Compare two scans and write an HTML report of added, removed and changed folds
+    DiffHtml {
+        /// Older scan: a project directory or a saved JSON scan (flat or grouped)
+        before: PathBuf,
+
+        /// Newer scan: a project directory or a saved JSON scan (flat or grouped)
+        after: PathBuf,
+
+        /// Output file (defaults to stdout)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
 }
 
 #[derive(ValueEnum, Clone, Debug)]
 pub enum OutputFormatArg {
     Json,
     Yaml,
+    Toml,
     Summary,
     Ansi,
 }
@@ -150,19 +295,69 @@ impl From<OutputFormatArg> for OutputFormat {
         match arg {
             OutputFormatArg::Json => OutputFormat::Json,
             OutputFormatArg::Yaml => OutputFormat::Yaml,
+            OutputFormatArg::Toml => OutputFormat::Toml,
             OutputFormatArg::Summary => OutputFormat::Summary,
             OutputFormatArg::Ansi => OutputFormat::Ansi,
         }
     }
 }
 
+/// Output format of the `list` subcommand
+#[derive(ValueEnum, Clone, Debug)]
+pub enum ListFormatArg {
+    Json,
+    Yaml,
+    Toml,
+    Summary,
+    Ansi,
+    /// LSP `FoldingRange[]` JSON (0-indexed lines)
+    Lsp,
+}
+
 #[derive(ValueEnum, Clone, Debug)]
 pub enum LanguageFilter {
     Python,
+    #[value(name = "javascript")]
     JavaScript,
+    #[value(name = "typescript")]
     TypeScript,
     /// Alias for JS + TS
     Node,
+    #[value(name = "csharp")]
+    CSharp,
+    Rust,
+    Scala,
+    /// Fenced code blocks in Markdown docs (never scanned otherwise)
+    Markdown,
+}
+
+/// Language of inline `--source` code
+#[derive(ValueEnum, Clone, Debug)]
+pub enum SourceLanguageArg {
+    Python,
+    #[value(name = "javascript")]
+    JavaScript,
+    #[value(name = "typescript")]
+    TypeScript,
+    #[value(name = "csharp")]
+    CSharp,
+    Rust,
+    Scala,
+    Markdown,
+}
+
+impl From<SourceLanguageArg> for Language {
+    fn from(arg: SourceLanguageArg) -> Self {
+        match arg {
+            SourceLanguageArg::Python => Language::Python,
+            SourceLanguageArg::JavaScript => Language::JavaScript,
+            SourceLanguageArg::TypeScript => Language::TypeScript,
+            SourceLanguageArg::CSharp => Language::CSharp,
+            SourceLanguageArg::Rust => Language::Rust,
+            SourceLanguageArg::Scala => Language::Scala,
+            SourceLanguageArg::Markdown => Language::Markdown,
+        }
+    }
 }
 
 #[derive(ValueEnum, Clone, Debug, Default)]
@@ -176,6 +371,8 @@ pub enum PreviewModeArg {
     Flow,
     /// First N chars of actual source code
     Source,
+    /// First and last line of bodies and multi-line strings: "def foo(): x = 1 … return x"
+    Context,
 }
 
 impl From<PreviewModeArg> for PreviewMode {
@@ -185,6 +382,43 @@ impl From<PreviewModeArg> for PreviewMode {
             PreviewModeArg::Names => PreviewMode::Names,
             PreviewModeArg::Flow => PreviewMode::Flow,
             PreviewModeArg::Source => PreviewMode::Source,
+            PreviewModeArg::Context => PreviewMode::Context,
+        }
+    }
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug, Default)]
+pub enum FoldModeArg {
+    /// Fold the whole body after the signature (default)
+    #[default]
+    Body,
+    /// Keep a leading docstring visible and fold the rest of the body
+    SignatureAndDoc,
+}
+
+impl From<FoldModeArg> for FoldMode {
+    fn from(arg: FoldModeArg) -> Self {
+        match arg {
+            FoldModeArg::Body => FoldMode::Body,
+            FoldModeArg::SignatureAndDoc => FoldMode::SignatureAndDoc,
+        }
+    }
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug, Default)]
+pub enum FoldOrderArg {
+    /// By start position (default)
+    #[default]
+    Source,
+    /// In the parser's traversal order
+    Discovery,
+}
+
+impl From<FoldOrderArg> for FoldOrder {
+    fn from(arg: FoldOrderArg) -> Self {
+        match arg {
+            FoldOrderArg::Source => FoldOrder::Source,
+            FoldOrderArg::Discovery => FoldOrder::Discovery,
         }
     }
 }
@@ -201,41 +435,47 @@ fn main() -> anyhow::Result<()> {
             file,
             ansi,
             min_lines,
-        }) => run_render(file.clone(), *ansi, *min_lines, &args),
-        Some(Commands::List { file, format, preview_mode }) => run_list(file.clone(), format.clone(), preview_mode.clone(), &args),
+            line_budget,
+            source_map,
+        }) => run_render(
+            file.clone(),
+            *ansi,
+            *min_lines,
+            *line_budget,
+            source_map.as_ref(),
+            &args,
+        ),
+        Some(Commands::List {
+            file,
+            source,
+            source_language,
+            format,
+            vscode,
+            preview_mode,
+        }) => {
+            let source_file = list_source_file(
+                file.clone(),
+                source.clone(),
+                source_language.clone(),
+                preview_mode.clone(),
+                &args,
+            )?;
+            if *vscode {
+                println!("{}", format_vscode_folding_ranges(&source_file)?);
+                Ok(())
+            } else {
+                run_list(&source_file, file.as_deref(), source.as_deref(), format.clone(), &args)
+            }
+        }
+        Some(Commands::DiffHtml { before, after, output }) => {
+            run_diff_html(before, after, output.as_deref(), &args)
+        }
         None => run_scan(&args),
     }
 }
 
 fn run_scan(args: &Args) -> anyhow::Result<()> {
-    // Convert language filter
-    let language_filter = args.language.as_ref().map(|l| match l {
-        LanguageFilter::Python => vec![Language::Python],
-        LanguageFilter::JavaScript => vec![Language::JavaScript],
-        LanguageFilter::TypeScript => vec![Language::TypeScript],
-        LanguageFilter::Node => vec![Language::JavaScript, Language::TypeScript],
-    });
-
-    // Parse fold type filters
-    let fold_filter = build_fold_filter(&args.fold_types, &args.no_fold);
-
-    // Build config
-    let mut config = ScanConfig::new(args.path.clone())
-        .with_ignore_patterns(args.ignore.clone())
-        .with_include_deps(args.include_deps)
-        .with_threads(args.threads)
-        .with_min_fold_lines(args.min_lines)
-        .with_fold_filter(fold_filter)
-        .with_syntax_highlight(!args.no_color)
-        .with_preview_mode(args.preview_mode.clone().into());
-
-    if let Some(languages) = language_filter {
-        config = config.with_language_filter(languages);
-    }
-
-    if let Some(ref ignore_file) = args.ignore_file {
-        config = config.with_ignore_file(ignore_file.clone());
-    }
+    let config = scan_config(args)?;
 
     // Show progress if verbose
     let spinner = if args.verbose {
@@ -253,8 +493,27 @@ fn run_scan(args: &Args) -> anyhow::Result<()> {
     };
 
     // Create scanner and run
+    let json_indent = config.json_indent;
+    let root = config.normalize_separators(&config.root);
     let scanner = FoldScanner::new(config)?;
-    let result = scanner.scan()?;
+    let result = if args.json_stream {
+        let mut out: Box<dyn Write> = match args.output {
+            Some(ref path) => Box::new(BufWriter::new(fs::File::create(path)?)),
+            None => Box::new(std::io::stdout().lock()),
+        };
+        write_stream_record(&mut out, &StreamRecord::Header { root: &root, metadata_pending: true })?;
+        let result = scanner.scan_streaming(|file| {
+            write_stream_record(&mut out, &StreamRecord::File(file)).map_err(std::io::Error::other)
+        })?;
+        write_stream_record(
+            &mut out,
+            &StreamRecord::Summary { stats: &result.stats, metadata: &result.metadata },
+        )?;
+        out.flush()?;
+        result
+    } else {
+        scanner.scan()?
+    };
 
     if let Some(ref pb) = spinner {
         pb.finish_with_message(format!(
@@ -263,21 +522,45 @@ fn run_scan(args: &Args) -> anyhow::Result<()> {
         ));
     }
 
-    // Format output (grouped by default, flat with --flat flag)
-    let output = if args.flat {
-        format_output(&result, args.format.clone().into())?
-    } else {
-        format_output_grouped(&result, args.format.clone().into())?
-    };
+    if args.self_check {
+        result
+            .verify_grouping_consistency()
+            .map_err(|e| anyhow::anyhow!("Self-check failed: {}", e))?;
+    }
 
-    // Write output
-    if let Some(ref path) = args.output {
-        fs::write(path, &output)?;
-        if args.verbose {
-            eprintln!("Output written to: {}", path.display());
+    if args.json_stream {
+        // Records were already written while scanning
+    } else if args.histogram {
+        for (kind, count) in result.node_kind_histogram() {
+            println!("{:>8}  {}", count, kind);
         }
     } else {
-        println!("{}", output);
+        let format = args.format.clone().into();
+        let output = if args.entry_points {
+            format_entry_points(&result, format, json_indent)?
+        } else if args.suppressions {
+            format_suppressions(&result, format, json_indent)?
+        } else if args.flat {
+            // Format output (grouped by default, flat with --flat flag)
+            format_output_with_indent(&result, format, json_indent)?
+        } else {
+            format_output_grouped_with_indent(&result, format, json_indent)?
+        };
+
+        // Write output
+        if let Some(ref path) = args.output {
+            fs::write(path, &output)?;
+            if args.verbose {
+                eprintln!("Output written to: {}", path.display());
+            }
+        } else {
+            println!("{}", output);
+        }
+    }
+
+    // Run telemetry goes to stderr so stdout stays machine-readable
+    if args.emit_run_summary {
+        write_run_summary(&mut std::io::stderr(), &result.run_summary(0))?;
     }
 
     Ok(())
@@ -290,17 +573,36 @@ fn run_analyze(
     preview_mode: PreviewModeArg,
     args: &Args,
 ) -> anyhow::Result<()> {
-    let config = ScanConfig::new(path)
-        .with_min_fold_lines(args.min_lines)
+    let mut config = base_config(path, args)?
         .with_threads(args.threads)
-        .with_preview_mode(preview_mode.into());
+        .with_preview_mode(preview_mode.into())
+        .with_closing_context(args.closing_context)
+        .with_nested_folds(args.nested)
+        .with_line_index(args.line_indexed);
+
+    if let Some(lines) = args.min_doc_lines {
+        config = config.with_min_doc_lines(lines);
+    }
+
+    if let Some(ref types) = args.merge_adjacent {
+        config = config.with_merge_adjacent(parse_fold_types(types)?, args.merge_gap);
+    }
+
+    if let Some(depth) = directory_depth(args) {
+        config = config.with_max_directory_depth(depth);
+    }
 
+    config = config
+        .with_fixed_timestamp(args.timestamp.clone())
+        .with_reproducible(args.reproducible);
+
+    let json_indent = config.json_indent;
     let scanner = FoldScanner::new(config)?;
     let result = scanner.scan()?;
 
     // Use specified format, or ANSI for terminal if not specified
     let output_format: OutputFormat = format.into();
-    let output = format_output_grouped(&result, output_format)?;
+    let output = format_output_grouped_with_indent(&result, output_format, json_indent)?;
 
     // Write output
     if let Some(ref path) = output_file {
@@ -312,17 +614,43 @@ fn run_analyze(
         println!("{}", output);
     }
 
+    if args.emit_run_summary {
+        write_run_summary(&mut std::io::stderr(), &result.run_summary(0))?;
+    }
+
     Ok(())
 }
 
-fn run_render(file: PathBuf, ansi: bool, min_lines: usize, args: &Args) -> anyhow::Result<()> {
+fn run_render(
+    file: PathBuf,
+    ansi: bool,
+    min_lines: usize,
+    line_budget: Option<usize>,
+    source_map: Option<&PathBuf>,
+    args: &Args,
+) -> anyhow::Result<()> {
     let fold_filter = build_fold_filter(&args.fold_types, &args.no_fold);
 
-    let config = ScanConfig::default()
+    let mut config = ScanConfig::default()
         .with_min_fold_lines(min_lines)
+        .with_min_chain_depth(args.min_chain_depth)
         .with_fold_filter(fold_filter)
         .with_syntax_highlight(!args.no_color)
-        .with_preview_mode(args.preview_mode.clone().into());
+        .with_preview_mode(args.preview_mode.clone().into())
+        .with_fold_mode(args.fold_mode.into())
+        .with_closing_context(args.closing_context);
+
+    if let Some(lines) = args.min_doc_lines {
+        config = config.with_min_doc_lines(lines);
+    }
+
+    if let Some(ref types) = args.merge_adjacent {
+        config = config.with_merge_adjacent(parse_fold_types(types)?, args.merge_gap);
+    }
+
+    if let Some(budget) = line_budget {
+        config = config.with_line_budget(budget);
+    }
 
     let rendered = if ansi || (atty::is(atty::Stream::Stdout) && !args.no_color) {
         render_file_ansi(&file, &config)?
@@ -332,28 +660,76 @@ fn run_render(file: PathBuf, ansi: bool, min_lines: usize, args: &Args) -> anyho
 
     println!("{}", rendered.content);
 
+    if let Some(path) = source_map {
+        let json = serde_json::to_string_pretty(&rendered.source_map)?;
+        fs::write(path, json)?;
+    }
+
     if args.verbose {
         eprintln!(
-            "\n--- {} folds applied, {} lines hidden ---",
-            rendered.fold_count, rendered.lines_hidden
+            "\n--- {} of {} folds applied, {} lines hidden ---",
+            rendered.folds_applied, rendered.fold_count, rendered.lines_hidden
         );
     }
 
     Ok(())
 }
 
-fn run_list(file: PathBuf, format: OutputFormatArg, preview_mode: PreviewModeArg, args: &Args) -> anyhow::Result<()> {
-    let config = ScanConfig::default()
-        .with_min_fold_lines(args.min_lines)
-        .with_preview_mode(preview_mode.into());
+/// Analyze the file (or inline `--source` code) for the `list` subcommand
+fn list_source_file(
+    file: Option<PathBuf>,
+    source: Option<String>,
+    source_language: SourceLanguageArg,
+    preview_mode: PreviewModeArg,
+    args: &Args,
+) -> anyhow::Result<SourceFile> {
+    let root = file.as_deref().map_or_else(|| PathBuf::from("."), project_root);
+    let mut config = base_config(root, args)?
+        .with_preview_mode(preview_mode.into())
+        .with_closing_context(args.closing_context)
+        .with_nested_folds(args.nested);
+
+    if let Some(lines) = args.min_doc_lines {
+        config = config.with_min_doc_lines(lines);
+    }
 
-    let scanner = FoldScanner::new(config.clone())?;
-    let source_file = scanner.scan_file(&file)?;
+    if let Some(ref types) = args.merge_adjacent {
+        config = config.with_merge_adjacent(parse_fold_types(types)?, args.merge_gap);
+    }
 
+    let scanner = FoldScanner::new(config)?;
+    match (file, source) {
+        (Some(file), None) => Ok(scanner.scan_file(&file)?),
+        (None, Some(code)) => {
+            Ok(scanner.scan_source(&code, source_language.into(), PathBuf::from("<source>"))?)
+        }
+        (Some(_), Some(_)) => anyhow::bail!("--source cannot be combined with a file path"),
+        (None, None) => anyhow::bail!("a file path or --source is required"),
+    }
+}
+
+/// Print a listed file; `file` or the inline `source` is re-read only for the
+/// LSP format, whose UTF-16 columns need the text
+fn run_list(
+    source_file: &SourceFile,
+    file: Option<&Path>,
+    source: Option<&str>,
+    format: ListFormatArg,
+    args: &Args,
+) -> anyhow::Result<()> {
     let output = match format {
-        OutputFormatArg::Json => serde_json::to_string_pretty(&source_file)?,
-        OutputFormatArg::Yaml => serde_yaml::to_string(&source_file)?,
-        OutputFormatArg::Summary | OutputFormatArg::Ansi => {
+        ListFormatArg::Json => to_json_with_indent(source_file, args.json_indent)?,
+        ListFormatArg::Yaml => serde_yaml::to_string(source_file)?,
+        ListFormatArg::Toml => to_toml(source_file)?,
+        ListFormatArg::Lsp => {
+            let text = match (source, file) {
+                (Some(code), _) => code.to_string(),
+                (None, Some(file)) => fs::read_to_string(file)?,
+                (None, None) => anyhow::bail!("a file path or --source is required"),
+            };
+            to_lsp_folding_ranges(source_file, &text)?
+        }
+        ListFormatArg::Summary | ListFormatArg::Ansi => {
             let mut out = String::new();
             out.push_str(&format!(
                 "File: {}\nLanguage: {:?}\nLine Count: {}\nFolds: {}\n\n",
@@ -385,34 +761,189 @@ fn run_list(file: PathBuf, format: OutputFormatArg, preview_mode: PreviewModeArg
     Ok(())
 }
 
-fn build_fold_filter(include: &Option<String>, exclude: &Option<String>) -> FoldFilter {
-    let mut filter = if include.is_some() {
-        // Start with nothing enabled
-        FoldFilter::default()
+/// Resolve the directory walk depth from --no-recursion / --max-directory-depth
+fn directory_depth(args: &Args) -> Option<usize> {
+    if args.no_recursion {
+        Some(1)
+    } else {
+        args.max_directory_depth
+    }
+}
+
+fn run_diff_html(
+    before: &Path,
+    after: &Path,
+    output: Option<&Path>,
+    args: &Args,
+) -> anyhow::Result<()> {
+    let diff = FoldDiff::between(&load_scan_files(before, args)?, &load_scan_files(after, args)?);
+    let html = to_html_diff(&diff);
+
+    if let Some(path) = output {
+        fs::write(path, &html)?;
+        if args.verbose {
+            eprintln!("Diff report written to: {}", path.display());
+        }
     } else {
-        // Start with defaults
-        FoldFilter::default_set()
+        print!("{}", html);
+    }
+
+    Ok(())
+}
+
+/// Files of a scan: scan a directory now, or load a saved flat or grouped JSON scan
+fn load_scan_files(path: &Path, args: &Args) -> anyhow::Result<Vec<SourceFile>> {
+    if path.is_dir() {
+        let mut config = base_config(path.to_path_buf(), args)?
+            .with_threads(args.threads)
+            .with_preview_mode(args.preview_mode.clone().into());
+
+        if let Some(lines) = args.min_doc_lines {
+            config = config.with_min_doc_lines(lines);
+        }
+
+        return Ok(FoldScanner::new(config)?.scan()?.files);
+    }
+
+    let value: serde_json::Value = serde_json::from_str(&fs::read_to_string(path)?)?;
+    let files = if value.get("files").is_some() {
+        serde_json::from_value::<FoldMap>(value)?.files
+    } else {
+        serde_json::from_value::<GroupedFoldMap>(value)?.into_files()
     };
+    Ok(files)
+}
 
-    // Enable specific types
-    if let Some(ref types) = include {
-        for t in types.split(',') {
-            match t.trim() {
-                "block" => filter.fold_blocks = true,
-                "import" => filter.fold_imports = true,
-                "arglist" => filter.fold_arglists = true,
-                "chain" => filter.fold_chains = true,
-                "literal" => filter.fold_literals = true,
-                "comment" => filter.fold_comments = true,
-                "doc" => filter.fold_docs = true,
-                "class" => filter.fold_classes = true,
-                "array" => filter.fold_arrays = true,
-                "object" => filter.fold_objects = true,
-                "all" => filter = FoldFilter::all(),
-                _ => {}
+/// Build the configuration for the default scan command
+fn scan_config(args: &Args) -> anyhow::Result<ScanConfig> {
+    let mut config = base_config(args.path.clone(), args)?
+        .with_ignore_patterns(args.ignore.clone())
+        .with_include_patterns(args.include.clone())
+        .with_include_deps(args.include_deps)
+        .with_staged_only(args.staged)
+        .with_blame(args.blame)
+        .with_threads(args.threads)
+        .with_syntax_highlight(!args.no_color)
+        .with_preview_mode(args.preview_mode.clone().into())
+        .with_closing_context(args.closing_context)
+        .with_nested_folds(args.nested)
+        .with_node_histogram(args.histogram)
+        .with_suppressions(args.suppressions)
+        .with_line_index(args.line_indexed);
+
+    if let Some(lines) = args.min_doc_lines {
+        config = config.with_min_doc_lines(lines);
+    }
+
+    if let Some(ref types) = args.merge_adjacent {
+        config = config.with_merge_adjacent(parse_fold_types(types)?, args.merge_gap);
+    }
+
+    if let Some(languages) = language_filter(&args.language) {
+        config = config.with_language_filter(languages);
+    }
+
+    if let Some(ref ignore_file) = args.ignore_file {
+        config = config.with_ignore_file(ignore_file.clone());
+    }
+
+    if let Some(depth) = directory_depth(args) {
+        config = config.with_max_directory_depth(depth);
+    }
+
+    if let Some(ref cache) = args.cache {
+        config = config.with_cache(cache.clone());
+    }
+
+    if let Some(max) = args.max_output_files {
+        config = config.with_max_output_files(max);
+    }
+
+    Ok(config
+        .with_fixed_timestamp(args.timestamp.clone())
+        .with_reproducible(args.reproducible))
+}
+
+/// Languages selected by `--language` (None = every language)
+///
+/// Filters combine, so `--language python,node` scans Python, JavaScript and TypeScript.
+fn language_filter(filters: &[LanguageFilter]) -> Option<Vec<Language>> {
+    if filters.is_empty() {
+        return None;
+    }
+
+    let mut languages = Vec::new();
+    for filter in filters {
+        let selected: &[Language] = match filter {
+            LanguageFilter::Python => &[Language::Python],
+            LanguageFilter::JavaScript => &[Language::JavaScript],
+            LanguageFilter::TypeScript => &[Language::TypeScript],
+            LanguageFilter::Node => &[Language::JavaScript, Language::TypeScript],
+            LanguageFilter::CSharp => &[Language::CSharp],
+            LanguageFilter::Rust => &[Language::Rust],
+            LanguageFilter::Scala => &[Language::Scala],
+            LanguageFilter::Markdown => &[Language::Markdown],
+        };
+        for language in selected {
+            if !languages.contains(language) {
+                languages.push(language.clone());
             }
         }
     }
+    Some(languages)
+}
+
+/// Nearest directory above `file` holding an `.editorconfig`, so `list` picks up
+/// the listed file's project settings; `.` when there is none
+fn project_root(file: &Path) -> PathBuf {
+    file.ancestors()
+        .skip(1)
+        .map(|dir| if dir.as_os_str().is_empty() { Path::new(".") } else { dir })
+        .find(|dir| dir.join(".editorconfig").is_file())
+        .map_or_else(|| PathBuf::from("."), Path::to_path_buf)
+}
+
+/// Scan config for `root` with `.editorconfig` fold defaults overridden by
+/// `--min-lines`, `--fold-types` and `--no-fold`, plus `--strip-prefix`
+fn base_config(root: PathBuf, args: &Args) -> anyhow::Result<ScanConfig> {
+    let defaults = EditorConfigDefaults::load(&root)?;
+    let fold_types = args.fold_types.clone().or_else(|| defaults.fold_types.clone());
+
+    let mut config = ScanConfig::new(root)
+        .with_editorconfig_defaults(&defaults)
+        .with_fold_filter(build_fold_filter(&fold_types, &args.no_fold))
+        .with_fold_mode(args.fold_mode.into())
+        .with_fold_order(args.fold_order.into())
+        .with_min_chain_depth(args.min_chain_depth)
+        .with_json_indent(args.json_indent);
+    if let Some(lines) = args.min_lines {
+        config = config.with_min_fold_lines(lines);
+    }
+    if let Some(ref prefix) = args.strip_prefix {
+        config = config.with_path_transform(prefix.clone());
+    }
+    Ok(config)
+}
+
+/// Parse the comma-separated fold type names of --merge-adjacent (as accepted by
+/// --fold-types); unknown names are an error rather than silently dropped
+fn parse_fold_types(types: &str) -> anyhow::Result<HashSet<FoldType>> {
+    for name in types.split(',').map(str::trim).filter(|name| !name.is_empty()) {
+        let known = name == "all"
+            || FoldType::ALL.iter().any(|fold_type| fold_type.as_str() == name);
+        if !known {
+            anyhow::bail!("unknown fold type '{}' in --merge-adjacent", name);
+        }
+    }
+    Ok(FoldFilter::from_type_names(types).enabled_types().into_iter().collect())
+}
+
+fn build_fold_filter(include: &Option<String>, exclude: &Option<String>) -> FoldFilter {
+    // Only the listed types, or the defaults
+    let mut filter = match include {
+        Some(ref types) => FoldFilter::from_type_names(types),
+        None => FoldFilter::default_set(),
+    };
 
     // Exclude specific types
     if let Some(ref types) = exclude {
@@ -428,6 +959,9 @@ fn build_fold_filter(include: &Option<String>, exclude: &Option<String>) -> Fold
                 "class" => filter.fold_classes = false,
                 "array" => filter.fold_arrays = false,
                 "object" => filter.fold_objects = false,
+                "property" => filter.fold_properties = false,
+                "region" => filter.fold_regions = false,
+                "jsx" => filter.fold_jsx = false,
                 _ => {}
             }
         }
@@ -435,3 +969,144 @@ fn build_fold_filter(include: &Option<String>, exclude: &Option<String>) -> Fold
 
     filter
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn list_args(argv: &[&str]) -> anyhow::Result<SourceFile> {
+        let args = Args::try_parse_from(argv)?;
+        match args.command {
+            Some(Commands::List {
+                ref file,
+                ref source,
+                ref source_language,
+                ref preview_mode,
+                ..
+            }) => list_source_file(
+                file.clone(),
+                source.clone(),
+                source_language.clone(),
+                preview_mode.clone(),
+                &args,
+            ),
+            _ => anyhow::bail!("expected the list subcommand"),
+        }
+    }
+
+    #[test]
+    fn test_list_inline_source() {
+        let source_file = list_args(&[
+            "synfold",
+            "--min-lines",
+            "2",
+            "list",
+            "--source",
+            "def f():\n    pass\n    pass",
+            "--source-language",
+            "python",
+        ])
+        .unwrap();
+
+        assert_eq!(source_file.language, Language::Python);
+        assert_eq!(source_file.folds.len(), 1);
+        assert_eq!(source_file.folds[0].start_line, 2);
+    }
+
+    #[test]
+    fn test_list_reads_project_editorconfig() {
+        let dir = tempfile::TempDir::new().unwrap();
+        fs::write(dir.path().join(".editorconfig"), "[*]\nmta_min_fold_lines = 2\n").unwrap();
+        fs::create_dir(dir.path().join("src")).unwrap();
+        let file = dir.path().join("src/app.py");
+        fs::write(&file, "def f():\n    a = 1\n    return a\n").unwrap();
+
+        // The listed file's project, not the working directory, supplies the defaults
+        assert_eq!(project_root(&file), dir.path());
+        let source_file = list_args(&["synfold", "list", file.to_str().unwrap()]).unwrap();
+        assert_eq!(source_file.folds.len(), 1);
+        assert_eq!(source_file.path, Path::new("src/app.py"));
+
+        // Explicit flags still win
+        let source_file =
+            list_args(&["synfold", "--min-lines", "4", "list", file.to_str().unwrap()]).unwrap();
+        assert!(source_file.folds.is_empty());
+    }
+
+    #[test]
+    fn test_parse_fold_types() {
+        let types = parse_fold_types("block, jsx,property").unwrap();
+        assert_eq!(
+            types,
+            HashSet::from([FoldType::Block, FoldType::JsxElement, FoldType::Property])
+        );
+        assert_eq!(parse_fold_types("all").unwrap().len(), FoldType::ALL.len());
+
+        let err = parse_fold_types("block,blocks").unwrap_err();
+        assert!(err.to_string().contains("'blocks'"));
+    }
+
+    #[test]
+    fn test_list_rejects_source_with_path() {
+        let result = list_args(&["synfold", "list", "main.py", "--source", "x = 1"]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_list_lsp_format() {
+        let args = Args::try_parse_from(["synfold", "list", "main.py", "--format", "lsp"]).unwrap();
+        assert!(matches!(
+            args.command,
+            Some(Commands::List { format: ListFormatArg::Lsp, .. })
+        ));
+
+        // The LSP shape only describes a single file
+        assert!(Args::try_parse_from(["synfold", "--format", "lsp"]).is_err());
+    }
+
+    #[test]
+    fn test_list_vscode_flag() {
+        let args = Args::try_parse_from(["synfold", "list", "main.py", "--vscode"]).unwrap();
+        assert!(matches!(args.command, Some(Commands::List { vscode: true, .. })));
+
+        // --vscode replaces --format rather than combining with it
+        let argv = ["synfold", "list", "main.py", "--vscode", "--format", "lsp"];
+        assert!(Args::try_parse_from(argv).is_err());
+    }
+
+    #[test]
+    fn test_combined_language_filter() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let root = dir.path().to_str().unwrap();
+        fs::write(dir.path().join("app.py"), "def f():\n    pass\n").unwrap();
+        fs::write(dir.path().join("index.js"), "function f() {\n  return 1;\n}\n").unwrap();
+        fs::write(dir.path().join("types.ts"), "function g(): number {\n  return 1;\n}\n").unwrap();
+
+        let scan = |argv: &[&str]| {
+            let config = scan_config(&Args::try_parse_from(argv).unwrap()).unwrap();
+            let mut languages: Vec<Language> = FoldScanner::new(config)
+                .unwrap()
+                .scan()
+                .unwrap()
+                .files
+                .into_iter()
+                .map(|f| f.language)
+                .collect();
+            languages.sort_by_key(|l| l.as_str());
+            languages
+        };
+
+        let expected = vec![Language::Python, Language::TypeScript];
+        assert_eq!(scan(&["synfold", root, "--language", "python,typescript"]), expected);
+        assert_eq!(
+            scan(&["synfold", root, "--language", "python", "--language", "typescript"]),
+            expected
+        );
+        assert_eq!(scan(&["synfold", root]).len(), 3);
+
+        assert_eq!(
+            language_filter(&[LanguageFilter::Node, LanguageFilter::TypeScript]),
+            Some(vec![Language::JavaScript, Language::TypeScript])
+        );
+    }
+}
@@ -1,6 +1,9 @@
-use crate::models::{FoldFilter, Language, PreviewMode};
+use crate::models::{FoldFilter, FoldMode, FoldOrder, FoldType, Language, OverlapPolicy, PreviewMode};
+use crate::output::IndentStyle;
 use globset::{Glob, GlobSet, GlobSetBuilder};
 use ignore::gitignore::{Gitignore, GitignoreBuilder};
+use ignore::Match;
+use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
 use thiserror::Error;
 
@@ -14,6 +17,9 @@ pub enum ConfigError {
     IoError(#[from] std::io::Error),
 }
 
+/// File name suffixes of build artifacts that are never worth folding
+pub const DEFAULT_ARTIFACT_SUFFIXES: [&str; 4] = [".pyc", ".pyo", ".map", ".min.js"];
+
 /// Configuration for fold scanning
 #[derive(Debug, Clone)]
 pub struct ScanConfig {
@@ -23,6 +29,8 @@ pub struct ScanConfig {
     pub language_filter: Option<Vec<Language>>,
     /// Additional ignore patterns (glob style)
     pub ignore_patterns: Vec<String>,
+    /// Include patterns relative to root (glob style, empty = every file)
+    pub include_patterns: Vec<String>,
     /// Custom ignore file path
     pub ignore_file: Option<PathBuf>,
     /// Include node_modules/.venv in scan
@@ -31,16 +39,70 @@ pub struct ScanConfig {
     pub threads: usize,
     /// Minimum lines for a block to be foldable
     pub min_fold_lines: usize,
+    /// Per-language overrides of `min_fold_lines` (unlisted languages use the global value)
+    pub per_language_min_lines: HashMap<Language, usize>,
+    /// Minimum lines for comment and doc comment folds (None = any multi-line comment)
+    pub min_doc_lines: Option<usize>,
     /// Maximum lines for inline folding (arg lists, etc.)
     pub max_inline_fold: usize,
+    /// Minimum number of calls in a multi-line chain for a `ChainedCall` fold
+    pub min_chain_depth: usize,
     /// Which fold types to enable
     pub fold_filter: FoldFilter,
     /// Show syntax highlighting in ANSI output
     pub syntax_highlight: bool,
     /// Custom queries directory
     pub queries_dir: Option<PathBuf>,
+    /// Compile each tree-sitter query once per scanner instead of once per use
+    pub cache_queries: bool,
     /// Preview mode for fold summaries
     pub preview_mode: PreviewMode,
+    /// Where function block folds begin (after the signature or after the docstring)
+    pub fold_mode: FoldMode,
+    /// Order of each file's folds: by position (default) or as the parser found them
+    pub fold_order: FoldOrder,
+    /// Maximum directory depth to walk (None = unlimited, 1 = root only)
+    pub max_directory_depth: Option<usize>,
+    /// Indentation for pretty-printed JSON output
+    pub json_indent: IndentStyle,
+    /// Target line count for rendering (None = apply every fold)
+    pub line_budget: Option<usize>,
+    /// Record a per-file histogram of tree-sitter node kinds
+    pub node_histogram: bool,
+    /// Leading path prefix removed from every file's path/absolute_path
+    pub strip_prefix: Option<PathBuf>,
+    /// Write serialized paths with `/` separators regardless of platform
+    pub forward_slashes: bool,
+    /// Pinned metadata timestamp (overrides `SOURCE_DATE_EPOCH` and the clock)
+    pub fixed_timestamp: Option<String>,
+    /// Record a zero scan duration in metadata
+    pub reproducible: bool,
+    /// Only scan files staged in the git index
+    pub staged_only: bool,
+    /// Annotate folds with the latest commit date of their lines (`git blame`)
+    pub blame: bool,
+    /// Append direct child counts to object/array/class fold previews
+    pub closing_context: bool,
+    /// Fold types whose adjacent sibling folds are merged into one region (empty = off)
+    pub merge_fold_types: HashSet<FoldType>,
+    /// Maximum lines between two folds for them to count as adjacent
+    pub merge_max_gap: usize,
+    /// Emit folds as a tree (`children`) instead of a flat list
+    pub nested_folds: bool,
+    /// JSON file caching parsed folds between scans (None = always re-parse)
+    pub cache_path: Option<PathBuf>,
+    /// Maximum number of files kept in the output (None = unlimited)
+    pub max_output_files: Option<usize>,
+    /// Consumer-defined labels written as each fold's `label` in serialized output
+    pub fold_type_map: HashMap<FoldType, String>,
+    /// Record `@ts-ignore` / `@ts-expect-error` / `# type: ignore` comments per file
+    pub suppressions: bool,
+    /// How partially overlapping folds of a file are resolved (default: keep both)
+    pub dedup_overlaps: OverlapPolicy,
+    /// Emit a per-file `line_index` of fold start lines
+    pub line_indexed: bool,
+    /// File name suffixes skipped before language detection (compiled/minified output)
+    pub artifact_suffixes: Vec<String>,
 }
 
 impl Default for ScanConfig {
@@ -49,15 +111,43 @@ impl Default for ScanConfig {
             root: PathBuf::from("."),
             language_filter: None,
             ignore_patterns: vec![],
+            include_patterns: vec![],
             ignore_file: None,
             include_deps: false,
             threads: 0,
             min_fold_lines: 4,
+            per_language_min_lines: HashMap::new(),
+            min_doc_lines: None,
             max_inline_fold: 120,
+            min_chain_depth: 3,
             fold_filter: FoldFilter::default_set(),
             syntax_highlight: true,
             queries_dir: None,
+            cache_queries: true,
             preview_mode: PreviewMode::default(),
+            fold_mode: FoldMode::default(),
+            fold_order: FoldOrder::default(),
+            max_directory_depth: None,
+            json_indent: IndentStyle::default(),
+            line_budget: None,
+            node_histogram: false,
+            strip_prefix: None,
+            forward_slashes: true,
+            fixed_timestamp: None,
+            reproducible: false,
+            staged_only: false,
+            blame: false,
+            closing_context: false,
+            merge_fold_types: HashSet::new(),
+            merge_max_gap: 0,
+            nested_folds: false,
+            cache_path: None,
+            max_output_files: None,
+            fold_type_map: HashMap::new(),
+            suppressions: false,
+            dedup_overlaps: OverlapPolicy::default(),
+            line_indexed: false,
+            artifact_suffixes: DEFAULT_ARTIFACT_SUFFIXES.iter().map(|s| s.to_string()).collect(),
         }
     }
 }
@@ -80,11 +170,25 @@ impl ScanConfig {
         self
     }
 
+    /// Only scan files matching at least one of these globs, relative to root;
+    /// the ignore and language filters still apply
+    pub fn with_include_patterns(mut self, patterns: Vec<String>) -> Self {
+        self.include_patterns = patterns;
+        self
+    }
+
     pub fn with_ignore_file(mut self, path: PathBuf) -> Self {
         self.ignore_file = Some(path);
         self
     }
 
+    /// Replace the artifact suffixes skipped by every scan (default:
+    /// `DEFAULT_ARTIFACT_SUFFIXES`); an empty list scans minified files too
+    pub fn with_artifact_suffixes(mut self, suffixes: Vec<String>) -> Self {
+        self.artifact_suffixes = suffixes;
+        self
+    }
+
     pub fn with_include_deps(mut self, include: bool) -> Self {
         self.include_deps = include;
         self
@@ -95,11 +199,49 @@ impl ScanConfig {
         self
     }
 
+    /// Apply fold defaults from `.editorconfig`; explicit builder calls afterwards win
+    pub fn with_editorconfig_defaults(mut self, defaults: &EditorConfigDefaults) -> Self {
+        if let Some(lines) = defaults.min_fold_lines {
+            self.min_fold_lines = lines;
+        }
+        if let Some(ref types) = defaults.fold_types {
+            self.fold_filter = FoldFilter::from_type_names(types);
+        }
+        self
+    }
+
     pub fn with_min_fold_lines(mut self, lines: usize) -> Self {
         self.min_fold_lines = lines;
         self
     }
 
+    /// Override `min_fold_lines` for specific languages, e.g. smaller Python blocks
+    /// than brace-delimited JS ones; other languages keep the global value
+    pub fn with_per_language_min_lines(mut self, min_lines: HashMap<Language, usize>) -> Self {
+        self.per_language_min_lines = min_lines;
+        self
+    }
+
+    /// Minimum block fold size for `language`, falling back to `min_fold_lines`
+    pub fn min_fold_lines_for(&self, language: &Language) -> usize {
+        self.per_language_min_lines
+            .get(language)
+            .copied()
+            .unwrap_or(self.min_fold_lines)
+    }
+
+    /// Threshold for `Comment`/`DocComment` folds, independent of `min_fold_lines`
+    pub fn with_min_doc_lines(mut self, lines: usize) -> Self {
+        self.min_doc_lines = Some(lines);
+        self
+    }
+
+    /// Fold multi-line call chains of at least `depth` calls (default 3, minimum 2)
+    pub fn with_min_chain_depth(mut self, depth: usize) -> Self {
+        self.min_chain_depth = depth.max(2);
+        self
+    }
+
     pub fn with_fold_filter(mut self, filter: FoldFilter) -> Self {
         self.fold_filter = filter;
         self
@@ -115,35 +257,326 @@ impl ScanConfig {
         self
     }
 
+    /// Compile each tree-sitter query once per scanner (default) instead of once per use
+    pub fn with_query_cache(mut self, enabled: bool) -> Self {
+        self.cache_queries = enabled;
+        self
+    }
+
     pub fn with_preview_mode(mut self, mode: PreviewMode) -> Self {
         self.preview_mode = mode;
         self
     }
+
+    /// Choose where function block folds begin; `SignatureAndDoc` keeps a
+    /// leading docstring outside the fold
+    pub fn with_fold_mode(mut self, mode: FoldMode) -> Self {
+        self.fold_mode = mode;
+        self
+    }
+
+    /// List folds in source order (default) or in parser discovery order
+    pub fn with_fold_order(mut self, order: FoldOrder) -> Self {
+        self.fold_order = order;
+        self
+    }
+
+    /// Merge sibling folds of `fold_types` separated by at most `max_gap` lines
+    ///
+    /// Two consecutive short functions or comment blocks then collapse as one
+    /// region whose preview combines theirs.
+    pub fn with_merge_adjacent(mut self, fold_types: HashSet<FoldType>, max_gap: usize) -> Self {
+        self.merge_fold_types = fold_types;
+        self.merge_max_gap = max_gap;
+        self
+    }
+
+    /// Nest each file's folds under the innermost fold containing them (default: flat)
+    pub fn with_nested_folds(mut self, enabled: bool) -> Self {
+        self.nested_folds = enabled;
+        self
+    }
+
+    /// Hint at what a collapsed fold hides, e.g. `{ a, b, c, d, +4 more } (8 props)`
+    pub fn with_closing_context(mut self, enabled: bool) -> Self {
+        self.closing_context = enabled;
+        self
+    }
+
+    pub fn with_max_directory_depth(mut self, depth: usize) -> Self {
+        self.max_directory_depth = Some(depth);
+        self
+    }
+
+    pub fn with_json_indent(mut self, indent: IndentStyle) -> Self {
+        self.json_indent = indent;
+        self
+    }
+
+    pub fn with_line_budget(mut self, budget: usize) -> Self {
+        self.line_budget = Some(budget);
+        self
+    }
+
+    pub fn with_node_histogram(mut self, enabled: bool) -> Self {
+        self.node_histogram = enabled;
+        self
+    }
+
+    pub fn with_path_transform(mut self, prefix_to_strip: String) -> Self {
+        self.strip_prefix = Some(PathBuf::from(prefix_to_strip));
+        self
+    }
+
+    /// Restrict the scan to files staged in git (`git diff --cached`)
+    pub fn with_staged_only(mut self, enabled: bool) -> Self {
+        self.staged_only = enabled;
+        self
+    }
+
+    /// Set each fold's `last_modified` from `git blame` (one blame per file;
+    /// skipped outside git repositories)
+    pub fn with_blame(mut self, enabled: bool) -> Self {
+        self.blame = enabled;
+        self
+    }
+
+    /// Normalize serialized paths to forward slashes (default: true)
+    pub fn with_forward_slashes(mut self, enabled: bool) -> Self {
+        self.forward_slashes = enabled;
+        self
+    }
+
+    /// Pin the metadata timestamp (RFC 3339) for reproducible output
+    pub fn with_fixed_timestamp(mut self, timestamp: Option<String>) -> Self {
+        self.fixed_timestamp = timestamp;
+        self
+    }
+
+    /// Zero the scan duration in metadata so repeated runs are byte-identical
+    pub fn with_reproducible(mut self, enabled: bool) -> Self {
+        self.reproducible = enabled;
+        self
+    }
+
+    /// Reuse folds of files whose mtime and size are unchanged since the last scan,
+    /// persisting them in a JSON cache at `path`
+    pub fn with_cache(mut self, path: PathBuf) -> Self {
+        self.cache_path = Some(path);
+        self
+    }
+
+    /// Keep at most `max` files in the result, flagging `metadata.truncated` when cut
+    pub fn with_max_output_files(mut self, max: usize) -> Self {
+        self.max_output_files = Some(max);
+        self
+    }
+
+    /// Label fold types in serialized output (e.g. `Block` -> `"code"`);
+    /// unmapped folds get no `label` and fold logic is unaffected
+    pub fn with_fold_type_map(mut self, map: HashMap<FoldType, String>) -> Self {
+        self.fold_type_map = map;
+        self
+    }
+
+    /// Record type-checker suppression comments in `SourceFile::suppressions`
+    pub fn with_suppressions(mut self, enabled: bool) -> Self {
+        self.suppressions = enabled;
+        self
+    }
+
+    /// Resolve folds that share bytes without nesting (e.g. a chain and an arglist)
+    /// with `policy`, so renderers never see crossing ranges
+    pub fn with_dedup_overlaps(mut self, policy: OverlapPolicy) -> Self {
+        self.dedup_overlaps = policy;
+        self
+    }
+
+    /// Add a `line_index` to each file mapping fold start lines to their folds,
+    /// so editors can answer "does a fold start on line N?" without scanning `folds`
+    pub fn with_line_index(mut self, enabled: bool) -> Self {
+        self.line_indexed = enabled;
+        self
+    }
+
+    /// Stable hash of every setting that changes parsed folds, used to invalidate caches
+    pub fn fold_config_hash(&self) -> u64 {
+        let mut merge_types: Vec<String> =
+            self.merge_fold_types.iter().map(|t| format!("{:?}", t)).collect();
+        merge_types.sort();
+        let mut per_language_min_lines: Vec<String> = self
+            .per_language_min_lines
+            .iter()
+            .map(|(language, lines)| format!("{:?}={}", language, lines))
+            .collect();
+        per_language_min_lines.sort();
+        let key = format!(
+            "{:?}|{}|{:?}|{:?}|{}|{}|{:?}|{:?}|{:?}|{}|{:?}|{}|{}|{}|{}|{:?}",
+            self.fold_filter,
+            self.min_fold_lines,
+            per_language_min_lines,
+            self.min_doc_lines,
+            self.max_inline_fold,
+            self.min_chain_depth,
+            self.preview_mode,
+            self.fold_mode,
+            self.fold_order,
+            self.closing_context,
+            merge_types,
+            self.merge_max_gap,
+            self.nested_folds,
+            self.node_histogram,
+            self.suppressions,
+            self.dedup_overlaps,
+        );
+
+        // FNV-1a, so the hash is identical across runs and builds
+        key.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+        })
+    }
+
+    /// Timestamp for scan metadata: the pinned value, then `SOURCE_DATE_EPOCH`, then now
+    pub fn metadata_timestamp(&self) -> String {
+        if let Some(ref timestamp) = self.fixed_timestamp {
+            return timestamp.clone();
+        }
+        std::env::var("SOURCE_DATE_EPOCH")
+            .ok()
+            .and_then(|epoch| epoch.trim().parse::<i64>().ok())
+            .and_then(|secs| chrono::DateTime::from_timestamp(secs, 0))
+            .map(|dt| dt.to_rfc3339())
+            .unwrap_or_else(|| chrono::Utc::now().to_rfc3339())
+    }
+
+    /// Whether the file name ends with a configured artifact suffix (case-insensitive)
+    pub fn is_artifact(&self, path: &Path) -> bool {
+        let Some(name) = path.file_name() else {
+            return false;
+        };
+        let name = name.to_string_lossy().to_lowercase();
+        self.artifact_suffixes
+            .iter()
+            .any(|suffix| name.ends_with(&suffix.to_lowercase()))
+    }
+
+    /// Apply the configured path transform; paths outside the prefix are unchanged
+    pub fn transform_path(&self, path: &Path) -> PathBuf {
+        let path = match self.strip_prefix {
+            Some(ref prefix) => path.strip_prefix(prefix).unwrap_or(path),
+            None => path,
+        };
+        self.normalize_separators(path)
+    }
+
+    /// Rewrite `\` separators to `/` when `forward_slashes` is enabled
+    pub fn normalize_separators(&self, path: &Path) -> PathBuf {
+        if self.forward_slashes {
+            PathBuf::from(path.to_string_lossy().replace('\\', "/"))
+        } else {
+            path.to_path_buf()
+        }
+    }
+}
+
+/// Fold defaults from the `[*]` section of a project's `.editorconfig`
+///
+/// ```ini
+/// [*]
+/// mta_fold_types = block,import
+/// mta_min_fold_lines = 8
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EditorConfigDefaults {
+    /// `mta_fold_types`: comma-separated fold type names, as accepted by `--fold-types`
+    pub fold_types: Option<String>,
+    /// `mta_min_fold_lines`
+    pub min_fold_lines: Option<usize>,
+}
+
+impl EditorConfigDefaults {
+    /// Read `<root>/.editorconfig`; a missing file yields no defaults
+    pub fn load(root: &Path) -> Result<Self, ConfigError> {
+        let path = root.join(".editorconfig");
+        if !path.is_file() {
+            return Ok(Self::default());
+        }
+        Ok(Self::parse(&std::fs::read_to_string(path)?))
+    }
+
+    /// Parse `.editorconfig` content, reading `mta_*` keys from the `[*]` section only
+    pub fn parse(content: &str) -> Self {
+        let mut defaults = Self::default();
+        let mut in_all_files = false;
+
+        for line in content.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') || line.starts_with(';') {
+                continue;
+            }
+            if let Some(section) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+                in_all_files = section.trim() == "*";
+                continue;
+            }
+            if !in_all_files {
+                continue;
+            }
+
+            let Some((key, value)) = line.split_once('=') else {
+                continue;
+            };
+            let value = value.trim();
+            match key.trim().to_lowercase().as_str() {
+                "mta_fold_types" if !value.is_empty() => {
+                    defaults.fold_types = Some(value.to_lowercase());
+                }
+                "mta_min_fold_lines" => {
+                    if let Ok(lines) = value.parse() {
+                        defaults.min_fold_lines = Some(lines);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        defaults
+    }
 }
 
+/// Tool-specific ignore file read from the project root on top of `.gitignore`
+pub const TOOL_IGNORE_FILE: &str = ".synfoldignore";
+
 /// Filter for ignoring files and directories
 pub struct IgnoreFilter {
     gitignore: Option<Gitignore>,
     custom_globs: GlobSet,
     default_ignores: GlobSet,
+    /// Include globs (None = include everything)
+    include_globs: Option<GlobSet>,
+    /// Root that include globs are relative to
+    root: PathBuf,
 }
 
 impl IgnoreFilter {
     pub fn new(config: &ScanConfig) -> Result<Self, ConfigError> {
-        // Load .gitignore if present
-        let gitignore = if let Some(ref ignore_file) = config.ignore_file {
-            let mut builder = GitignoreBuilder::new(&config.root);
-            builder.add(ignore_file);
-            Some(builder.build()?)
+        // Layer the tool ignore file over .gitignore (or the configured ignore file)
+        let base_ignore = config
+            .ignore_file
+            .clone()
+            .unwrap_or_else(|| config.root.join(".gitignore"));
+        let layers: Vec<PathBuf> = [base_ignore, config.root.join(TOOL_IGNORE_FILE)]
+            .into_iter()
+            .filter(|path| path.exists())
+            .collect();
+        let gitignore = if layers.is_empty() {
+            None
         } else {
-            let gitignore_path = config.root.join(".gitignore");
-            if gitignore_path.exists() {
-                let mut builder = GitignoreBuilder::new(&config.root);
-                builder.add(&gitignore_path);
-                Some(builder.build()?)
-            } else {
-                None
+            // Later files win, so a `!pattern` in the tool file re-includes a gitignored path
+            let mut builder = GitignoreBuilder::new(&config.root);
+            for layer in &layers {
+                builder.add(layer);
             }
+            Some(builder.build()?)
         };
 
         // Build custom ignore globs
@@ -153,6 +586,17 @@ impl IgnoreFilter {
         }
         let custom_globs = custom_builder.build()?;
 
+        // Build include globs
+        let include_globs = if config.include_patterns.is_empty() {
+            None
+        } else {
+            let mut include_builder = GlobSetBuilder::new();
+            for pattern in &config.include_patterns {
+                include_builder.add(Glob::new(pattern)?);
+            }
+            Some(include_builder.build()?)
+        };
+
         // Default ignores (unless include_deps is true)
         let mut default_builder = GlobSetBuilder::new();
         if !config.include_deps {
@@ -177,31 +621,41 @@ impl IgnoreFilter {
             gitignore,
             custom_globs,
             default_ignores,
+            include_globs,
+            root: config.root.clone(),
         })
     }
 
     /// Check if a path should be ignored
+    ///
+    /// Layers apply in increasing precedence: built-in excludes, `.gitignore`,
+    /// `.synfoldignore`, then `--ignore` patterns. A `!pattern` in either ignore
+    /// file re-includes a path excluded by an earlier layer.
     pub fn should_ignore(&self, path: &Path, is_dir: bool) -> bool {
         let path_str = path.to_string_lossy();
 
         // Check default ignores
-        if self.default_ignores.is_match(&*path_str) {
-            return true;
-        }
+        let mut ignored = self.default_ignores.is_match(&*path_str);
 
-        // Check custom patterns
-        if self.custom_globs.is_match(&*path_str) {
-            return true;
-        }
-
-        // Check gitignore
+        // Check .gitignore and the tool ignore file
         if let Some(ref gi) = self.gitignore {
-            if gi.matched(path, is_dir).is_ignore() {
-                return true;
+            match gi.matched(path, is_dir) {
+                Match::Ignore(_) => ignored = true,
+                Match::Whitelist(_) => ignored = false,
+                Match::None => {}
             }
         }
 
-        false
+        // Check custom patterns
+        ignored || self.custom_globs.is_match(&*path_str)
+    }
+
+    /// Check if a file matches the include patterns (always true when there are none)
+    pub fn matches_include_patterns(&self, path: &Path) -> bool {
+        match self.include_globs {
+            Some(ref globs) => globs.is_match(path.strip_prefix(&self.root).unwrap_or(path)),
+            None => true,
+        }
     }
 
     /// Check if a file extension matches the language filter
@@ -251,4 +705,81 @@ mod tests {
         assert_eq!(config.threads, 4);
         assert_eq!(config.min_fold_lines, 3);
     }
+
+    #[test]
+    fn test_editorconfig_defaults() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join(".editorconfig"),
+            "root = true\n\n[*.md]\nmta_min_fold_lines = 2\n\n[*]\nindent_style = space\nmta_min_fold_lines = 8\nmta_fold_types = block, import\n",
+        )
+        .unwrap();
+
+        let defaults = EditorConfigDefaults::load(dir.path()).unwrap();
+        assert_eq!(defaults.min_fold_lines, Some(8));
+
+        let config = ScanConfig::new(dir.path().to_path_buf()).with_editorconfig_defaults(&defaults);
+        assert_eq!(config.min_fold_lines, 8);
+        assert!(config.fold_filter.fold_blocks && config.fold_filter.fold_imports);
+        assert!(!config.fold_filter.fold_comments);
+
+        // Explicit settings override the .editorconfig defaults
+        let config = config.with_min_fold_lines(3);
+        assert_eq!(config.min_fold_lines, 3);
+    }
+
+    #[test]
+    fn test_fold_filter_enabled_types() {
+        let mut filter = FoldFilter::default_set();
+        assert_eq!(
+            filter.enabled_types(),
+            vec![
+                FoldType::Block,
+                FoldType::Import,
+                FoldType::Literal,
+                FoldType::Comment,
+                FoldType::ArrayLiteral,
+                FoldType::ObjectLiteral,
+            ]
+        );
+
+        filter.toggle(FoldType::Comment);
+        filter.toggle(FoldType::ClassBody);
+        assert!(!filter.should_fold(&FoldType::Comment));
+        assert!(filter.should_fold(&FoldType::ClassBody));
+        assert_eq!(filter.enabled_types().len(), 6);
+
+        assert_eq!(FoldFilter::all().enabled_types(), FoldType::ALL.to_vec());
+    }
+
+    #[test]
+    fn test_forward_slashes() {
+        let windows_path = PathBuf::from("src\\pkg\\module.py");
+
+        let config = ScanConfig::default();
+        assert!(config.forward_slashes);
+        assert_eq!(config.transform_path(&windows_path), PathBuf::from("src/pkg/module.py"));
+
+        let config = ScanConfig::default().with_forward_slashes(false);
+        assert_eq!(config.transform_path(&windows_path), windows_path);
+    }
+
+    #[test]
+    fn test_tool_ignore_file_overrides_gitignore() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join(".gitignore"), "*.gen.py\n").unwrap();
+        std::fs::write(dir.path().join(TOOL_IGNORE_FILE), "!keep.gen.py\n").unwrap();
+        let keep = dir.path().join("keep.gen.py");
+        let other = dir.path().join("other.gen.py");
+
+        let filter = IgnoreFilter::new(&ScanConfig::new(dir.path().to_path_buf())).unwrap();
+        assert!(!filter.should_ignore(&keep, false));
+        assert!(filter.should_ignore(&other, false));
+
+        // `--ignore` patterns still take precedence over the ignore files
+        let config = ScanConfig::new(dir.path().to_path_buf())
+            .with_ignore_patterns(vec!["**/keep.gen.py".to_string()]);
+        let filter = IgnoreFilter::new(&config).unwrap();
+        assert!(filter.should_ignore(&keep, false));
+    }
 }
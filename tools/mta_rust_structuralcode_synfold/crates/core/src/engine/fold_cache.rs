@@ -0,0 +1,174 @@
+use crate::models::{FoldRegion, Language, SourceFile, Suppression};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::time::UNIX_EPOCH;
+
+/// On-disk cache contents; entries are discarded when the header does not match
+#[derive(Serialize, Deserialize)]
+struct CacheFile {
+    tool_version: String,
+    config_hash: u64,
+    entries: HashMap<PathBuf, CacheEntry>,
+}
+
+/// Parse results for one file, valid while its mtime and size are unchanged
+#[derive(Clone, Serialize, Deserialize)]
+struct CacheEntry {
+    mtime_secs: u64,
+    mtime_nanos: u32,
+    size: u64,
+    line_count: usize,
+    folds: Vec<FoldRegion>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    node_kinds: BTreeMap<String, usize>,
+    #[serde(default)]
+    main_guard: bool,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    suppressions: Vec<Suppression>,
+}
+
+/// File modification time and size used to detect changes
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) struct FileStamp {
+    mtime_secs: u64,
+    mtime_nanos: u32,
+    size: u64,
+}
+
+impl FileStamp {
+    pub(crate) fn of(path: &Path) -> Option<Self> {
+        let metadata = fs::metadata(path).ok()?;
+        let mtime = metadata.modified().ok()?.duration_since(UNIX_EPOCH).ok()?;
+        Some(Self {
+            mtime_secs: mtime.as_secs(),
+            mtime_nanos: mtime.subsec_nanos(),
+            size: metadata.len(),
+        })
+    }
+}
+
+/// Persistent per-file fold cache for incremental scans
+///
+/// Keyed by canonical path. Only files looked up since the last `save` are
+/// written back, so deleted files drop out of the cache.
+pub(crate) struct FoldCache {
+    path: PathBuf,
+    config_hash: u64,
+    entries: Mutex<HashMap<PathBuf, CacheEntry>>,
+    seen: Mutex<HashSet<PathBuf>>,
+    hits: AtomicUsize,
+    misses: AtomicUsize,
+}
+
+impl FoldCache {
+    /// Load the cache at `path`; a missing, unreadable or stale cache starts empty
+    pub(crate) fn load(path: &Path, config_hash: u64) -> Self {
+        let entries = fs::read_to_string(path)
+            .ok()
+            .and_then(|content| serde_json::from_str::<CacheFile>(&content).ok())
+            .filter(|cache| {
+                cache.tool_version == env!("CARGO_PKG_VERSION") && cache.config_hash == config_hash
+            })
+            .map(|cache| cache.entries)
+            .unwrap_or_default();
+
+        Self {
+            path: path.to_path_buf(),
+            config_hash,
+            entries: Mutex::new(entries),
+            seen: Mutex::new(HashSet::new()),
+            hits: AtomicUsize::new(0),
+            misses: AtomicUsize::new(0),
+        }
+    }
+
+    /// Cached result for `path` if the file is unchanged since it was stored
+    pub(crate) fn get(
+        &self,
+        path: &Path,
+        relative_path: &Path,
+        language: &Language,
+        stamp: FileStamp,
+    ) -> Option<SourceFile> {
+        let key = cache_key(path);
+        self.seen.lock().unwrap_or_else(|e| e.into_inner()).insert(key.clone());
+
+        let entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        let entry = entries.get(&key).filter(|entry| {
+            entry.mtime_secs == stamp.mtime_secs
+                && entry.mtime_nanos == stamp.mtime_nanos
+                && entry.size == stamp.size
+        });
+        let Some(entry) = entry else {
+            self.misses.fetch_add(1, Ordering::Relaxed);
+            return None;
+        };
+        self.hits.fetch_add(1, Ordering::Relaxed);
+
+        Some(SourceFile {
+            path: relative_path.to_path_buf(),
+            absolute_path: path.to_path_buf(),
+            language: language.clone(),
+            folds: entry.folds.clone(),
+            line_count: entry.line_count,
+            parsed: true,
+            error: None,
+            node_kinds: entry.node_kinds.clone(),
+            byte_count: entry.size as usize,
+            main_guard: entry.main_guard,
+            suppressions: entry.suppressions.clone(),
+            line_index: BTreeMap::new(),
+        })
+    }
+
+    /// Store a freshly parsed file
+    pub(crate) fn insert(&self, path: &Path, stamp: FileStamp, file: &SourceFile) {
+        let entry = CacheEntry {
+            mtime_secs: stamp.mtime_secs,
+            mtime_nanos: stamp.mtime_nanos,
+            size: stamp.size,
+            line_count: file.line_count,
+            folds: file.folds.clone(),
+            node_kinds: file.node_kinds.clone(),
+            main_guard: file.main_guard,
+            suppressions: file.suppressions.clone(),
+        };
+        self.entries
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(cache_key(path), entry);
+    }
+
+    /// Hit and miss counts since the last call, resetting both to zero
+    pub(crate) fn take_counts(&self) -> (usize, usize) {
+        (
+            self.hits.swap(0, Ordering::Relaxed),
+            self.misses.swap(0, Ordering::Relaxed),
+        )
+    }
+
+    /// Write entries for the files looked up since the last save
+    pub(crate) fn save(&self) -> std::io::Result<()> {
+        let seen = std::mem::take(&mut *self.seen.lock().unwrap_or_else(|e| e.into_inner()));
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        entries.retain(|key, _| seen.contains(key));
+
+        let cache = CacheFile {
+            tool_version: env!("CARGO_PKG_VERSION").to_string(),
+            config_hash: self.config_hash,
+            entries: entries.clone(),
+        };
+        if let Some(parent) = self.path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&self.path, serde_json::to_string(&cache)?)
+    }
+}
+
+fn cache_key(path: &Path) -> PathBuf {
+    path.canonicalize().unwrap_or_else(|_| path.to_path_buf())
+}
@@ -0,0 +1,90 @@
+//! Git helpers: restricting a scan to staged files and blaming fold lines, both
+//! through libgit2 rather than a spawned `git` process
+
+use git2::{Delta, DiffFindOptions, ErrorCode, Oid, Repository};
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Absolute paths of files staged in the git index of the repository containing `dir`
+///
+/// Diffs the index against `HEAD` (or against nothing on an unborn branch) with
+/// rename detection, and keeps added, modified, copied and renamed files, so
+/// deleted files and the old side of renames are excluded. Names are resolved
+/// against the repository's work tree.
+pub fn staged_files(dir: &Path) -> io::Result<Vec<PathBuf>> {
+    let repo = Repository::discover(dir).map_err(io::Error::other)?;
+    let workdir = repo
+        .workdir()
+        .ok_or_else(|| io::Error::other("bare repository has no work tree"))?;
+    let head = match repo.head() {
+        Ok(head) => Some(head.peel_to_tree().map_err(io::Error::other)?),
+        // Before the first commit everything in the index is new
+        Err(e) if e.code() == ErrorCode::UnbornBranch => None,
+        Err(e) => return Err(io::Error::other(e)),
+    };
+    let mut diff = repo
+        .diff_tree_to_index(head.as_ref(), None, None)
+        .map_err(io::Error::other)?;
+    diff.find_similar(Some(DiffFindOptions::new().renames(true)))
+        .map_err(io::Error::other)?;
+
+    Ok(diff
+        .deltas()
+        .filter(|delta| {
+            matches!(
+                delta.status(),
+                Delta::Added | Delta::Modified | Delta::Copied | Delta::Renamed
+            )
+        })
+        .filter_map(|delta| delta.new_file().path())
+        .map(|path| workdir.join(path))
+        .collect())
+}
+
+/// Whether `dir` lies inside a git work tree
+pub fn is_work_tree(dir: &Path) -> bool {
+    Repository::discover(dir).is_ok_and(|repo| repo.workdir().is_some())
+}
+
+/// Commit time (Unix seconds) of each line of `file`, blamed with libgit2
+///
+/// Entry `i` is line `i + 1`. The working-tree contents are blamed, so lines not
+/// committed yet are `None`; a file git does not track is an error. Blaming walks
+/// the file's history in-process, so the cost grows with the number of commits
+/// touching the file rather than with a `git` process spawned per file.
+pub fn line_commit_times(file: &Path) -> io::Result<Vec<Option<i64>>> {
+    let repo =
+        Repository::discover(file.parent().unwrap_or(Path::new("."))).map_err(io::Error::other)?;
+    let workdir = repo
+        .workdir()
+        .ok_or_else(|| io::Error::other("bare repository has no work tree"))?;
+    let relative = fs::canonicalize(file)?
+        .strip_prefix(fs::canonicalize(workdir)?)
+        .map_err(io::Error::other)?
+        .to_path_buf();
+    let contents = fs::read(file)?;
+    let committed = repo.blame_file(&relative, None).map_err(io::Error::other)?;
+    let blame = committed
+        .blame_buffer(&contents)
+        .map_err(io::Error::other)?;
+
+    let mut commit_times: HashMap<Oid, Option<i64>> = HashMap::new();
+    let mut times = Vec::new();
+    for hunk in blame.iter() {
+        let id = hunk.final_commit_id();
+        let time = *commit_times.entry(id).or_insert_with(|| {
+            // The all-zero id marks lines changed in the working tree
+            (!id.is_zero())
+                .then(|| repo.find_commit(id).ok())
+                .flatten()
+                .map(|commit| commit.time().seconds())
+        });
+        let start = hunk.final_start_line().saturating_sub(1);
+        times.resize(start, None);
+        times.extend(std::iter::repeat_n(time, hunk.lines_in_hunk()));
+    }
+
+    Ok(times)
+}
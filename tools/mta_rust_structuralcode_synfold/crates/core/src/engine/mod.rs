@@ -1,5 +1,9 @@
+mod fold_cache;
+pub mod git;
+mod query_cache;
 mod renderer;
 mod scanner;
 
-pub use renderer::{render_file, render_file_ansi, Renderer};
+pub use query_cache::QueryCache;
+pub use renderer::{compute_fold_ranges, render_file, render_file_ansi, Renderer};
 pub use scanner::{FoldScanner, ScanError};
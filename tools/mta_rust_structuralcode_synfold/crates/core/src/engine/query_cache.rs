@@ -0,0 +1,127 @@
+use crate::models::Language;
+use crate::parsers::{tree_sitter_language, ParserError};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use tree_sitter::Query;
+
+/// Compiled tree-sitter queries keyed by (language, query source)
+///
+/// Compiling a query is far more expensive than running it, so a scan shares one
+/// cache across all files and worker threads and each query compiles once. With
+/// caching disabled every lookup compiles a fresh query.
+pub struct QueryCache {
+    enabled: bool,
+    queries: Mutex<HashMap<(Language, String), Arc<Query>>>,
+    compiled: AtomicUsize,
+}
+
+impl QueryCache {
+    pub fn new(enabled: bool) -> Self {
+        Self {
+            enabled,
+            queries: Mutex::new(HashMap::new()),
+            compiled: AtomicUsize::new(0),
+        }
+    }
+
+    /// Get the compiled query for `source`, compiling it on first use
+    pub fn get(&self, language: &Language, source: &str) -> Result<Arc<Query>, ParserError> {
+        if !self.enabled {
+            return self.compile(language, source).map(Arc::new);
+        }
+
+        // Compile under the lock so concurrent first lookups still compile once
+        let mut queries = self.queries.lock().unwrap_or_else(|e| e.into_inner());
+        let key = (language.clone(), source.to_string());
+        if let Some(query) = queries.get(&key) {
+            return Ok(Arc::clone(query));
+        }
+        let query = Arc::new(self.compile(language, source)?);
+        queries.insert(key, Arc::clone(&query));
+        Ok(query)
+    }
+
+    /// Number of queries compiled so far (cache misses)
+    pub fn compiled_count(&self) -> usize {
+        self.compiled.load(Ordering::Relaxed)
+    }
+
+    /// Number of cached queries
+    pub fn len(&self) -> usize {
+        self.queries.lock().map(|q| q.len()).unwrap_or_else(|e| e.into_inner().len())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn compile(&self, language: &Language, source: &str) -> Result<Query, ParserError> {
+        let grammar = tree_sitter_language(language)
+            .ok_or_else(|| ParserError::UnsupportedLanguage(language.clone()))?;
+        let query = Query::new(&grammar, source)
+            .map_err(|e| ParserError::QueryError(e.to_string()))?;
+        self.compiled.fetch_add(1, Ordering::Relaxed);
+        Ok(query)
+    }
+}
+
+impl Default for QueryCache {
+    fn default() -> Self {
+        Self::new(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rayon::prelude::*;
+
+    const FUNCTIONS: &str = "(function_definition body: (block) @fold.block)";
+
+    #[test]
+    fn test_query_compiled_once_across_files() {
+        let cache = QueryCache::default();
+
+        // Simulate a parallel scan where every file asks for the same queries
+        let queries: Vec<_> = (0..500)
+            .into_par_iter()
+            .map(|_| cache.get(&Language::Python, FUNCTIONS).unwrap())
+            .collect();
+
+        assert_eq!(cache.compiled_count(), 1);
+        assert_eq!(cache.len(), 1);
+        assert!(queries.iter().all(|q| Arc::ptr_eq(q, &queries[0])));
+        assert_eq!(queries[0].capture_names(), ["fold.block"]);
+
+        // Same source for another language is a separate entry
+        cache
+            .get(&Language::Rust, "(function_item body: (block) @fold.block)")
+            .unwrap();
+        assert_eq!(cache.compiled_count(), 2);
+    }
+
+    #[test]
+    fn test_query_cache_disabled() {
+        let cache = QueryCache::new(false);
+        for _ in 0..3 {
+            cache.get(&Language::Python, FUNCTIONS).unwrap();
+        }
+        assert_eq!(cache.compiled_count(), 3);
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn test_query_errors() {
+        let cache = QueryCache::default();
+        assert!(matches!(
+            cache.get(&Language::Python, "(not_a_node) @x"),
+            Err(ParserError::QueryError(_))
+        ));
+        assert!(matches!(
+            cache.get(&Language::Markdown, FUNCTIONS),
+            Err(ParserError::UnsupportedLanguage(Language::Markdown))
+        ));
+        assert_eq!(cache.compiled_count(), 0);
+    }
+}
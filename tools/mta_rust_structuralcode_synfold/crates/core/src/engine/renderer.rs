@@ -1,5 +1,5 @@
 use crate::config::ScanConfig;
-use crate::models::{FoldRegion, FoldType, RenderedFile};
+use crate::models::{FoldRange, FoldRegion, FoldType, RenderSegment, RenderedFile, SourceFile};
 use ropey::Rope;
 use std::fs;
 use std::path::Path;
@@ -24,21 +24,9 @@ impl Renderer {
         let rope = Rope::from_str(source);
         let mut result = String::with_capacity(source.len());
 
-        // Sort folds by start position, largest first (for nested handling)
-        let mut sorted_folds: Vec<&FoldRegion> = folds.iter().collect();
-        sorted_folds.sort_by_key(|f| (f.start_byte, -(f.end_byte as i64)));
-
-        // Filter to only non-overlapping, outermost folds
-        let active_folds = self.filter_overlapping_folds(&sorted_folds);
-
         let mut current_byte = 0;
 
-        for fold in active_folds {
-            // Check if this fold should be applied based on config
-            if !self.config.fold_filter.should_fold(&fold.fold_type) {
-                continue;
-            }
-
+        for fold in self.applied_folds(source, folds) {
             // Skip if fold starts before current position (nested/overlapping)
             if fold.start_byte < current_byte {
                 continue;
@@ -75,18 +63,9 @@ impl Renderer {
         let rope = Rope::from_str(source);
         let mut result = String::with_capacity(source.len());
 
-        let mut sorted_folds: Vec<&FoldRegion> = folds.iter().collect();
-        sorted_folds.sort_by_key(|f| (f.start_byte, -(f.end_byte as i64)));
-
-        let active_folds = self.filter_overlapping_folds(&sorted_folds);
-
         let mut current_byte = 0;
 
-        for fold in active_folds {
-            if !self.config.fold_filter.should_fold(&fold.fold_type) {
-                continue;
-            }
-
+        for fold in self.applied_folds(source, folds) {
             if fold.start_byte < current_byte {
                 continue;
             }
@@ -113,6 +92,107 @@ impl Renderer {
         result
     }
 
+    /// Select the folds that rendering will apply, sorted by start position
+    ///
+    /// Without a line budget this is every outermost fold allowed by the fold
+    /// filter. With a budget, folds are applied greedily (largest first,
+    /// skipping any that overlap an applied fold) until the rendered output
+    /// fits or no remaining fold hides any lines.
+    pub fn applied_folds<'a>(&self, source: &str, folds: &'a [FoldRegion]) -> Vec<&'a FoldRegion> {
+        let Some(budget) = self.config.line_budget else {
+            // Sort folds by start position, largest first (for nested handling)
+            let mut sorted_folds: Vec<&FoldRegion> = folds.iter().collect();
+            sorted_folds.sort_by_key(|f| (f.start_byte, -(f.end_byte as i64)));
+
+            // Filter to only non-overlapping, outermost folds
+            let mut active_folds = self.filter_overlapping_folds(&sorted_folds);
+            active_folds.retain(|f| self.config.fold_filter.should_fold(&f.fold_type));
+            return active_folds;
+        };
+
+        let mut candidates: Vec<&FoldRegion> = folds
+            .iter()
+            .filter(|f| self.config.fold_filter.should_fold(&f.fold_type))
+            .collect();
+        candidates.sort_by_key(|f| (std::cmp::Reverse(f.line_count), f.start_byte));
+
+        let mut rendered_lines = source.lines().count();
+        let mut applied: Vec<&FoldRegion> = Vec::new();
+
+        for fold in candidates {
+            if rendered_lines <= budget {
+                break;
+            }
+
+            // A fold collapses its span onto a single line
+            let saved = fold.line_count.saturating_sub(1);
+            if saved == 0 {
+                continue;
+            }
+
+            let overlaps = applied
+                .iter()
+                .any(|f| fold.start_byte < f.end_byte && f.start_byte < fold.end_byte);
+            if overlaps {
+                continue;
+            }
+
+            applied.push(fold);
+            rendered_lines -= saved;
+        }
+
+        applied.sort_by_key(|f| f.start_byte);
+        applied
+    }
+
+    /// Map each line of the rendered output back to the original lines it shows
+    ///
+    /// Applies the same folds as [`Renderer::render`]. A placeholder line covers
+    /// the whole folded range, including the text sharing its first and last line;
+    /// every other rendered line maps to exactly one original line.
+    pub fn source_map(&self, source: &str, folds: &[FoldRegion]) -> Vec<RenderSegment> {
+        let mut segments: Vec<RenderSegment> = Vec::new();
+
+        let mut line = 1;
+        let mut current_byte = 0;
+
+        for fold in self.applied_folds(source, folds) {
+            // Skip if fold starts before current position (nested/overlapping)
+            if fold.start_byte < current_byte {
+                continue;
+            }
+            current_byte = fold.end_byte;
+
+            match segments.last_mut() {
+                // A second fold starting on the line where the previous one ended
+                // shares its rendered line
+                Some(last) if last.is_fold_placeholder && fold.start_line <= last.original_end => {
+                    last.original_end = last.original_end.max(fold.end_line);
+                }
+                _ => {
+                    while line < fold.start_line {
+                        push_segment(&mut segments, line, line, false);
+                        line += 1;
+                    }
+                    // Multi-line previews (source mode) span several rendered lines
+                    let placeholder_lines = self.format_placeholder(fold).matches('\n').count() + 1;
+                    for _ in 0..placeholder_lines {
+                        push_segment(&mut segments, fold.start_line, fold.end_line, true);
+                    }
+                }
+            }
+            line = fold.end_line + 1;
+        }
+
+        let total_lines = source.lines().count();
+        while line <= total_lines {
+            push_segment(&mut segments, line, line, false);
+            line += 1;
+        }
+
+        segments
+    }
+
     /// Filter out overlapping folds, keeping only outermost ones
     fn filter_overlapping_folds<'a>(&self, folds: &[&'a FoldRegion]) -> Vec<&'a FoldRegion> {
         let mut result: Vec<&FoldRegion> = Vec::new();
@@ -190,10 +270,47 @@ impl Renderer {
             FoldType::ClassBody => Color::Blue,
             FoldType::ArrayLiteral => Color::Cyan,
             FoldType::ObjectLiteral => Color::Cyan,
+            FoldType::Region => Color::Magenta,
+            FoldType::JsxElement => Color::Yellow,
+            FoldType::Property => Color::Blue,
         }
     }
 }
 
+/// Ranges hidden when every fold in `file` is applied, without rendering any text
+///
+/// Ranges are sorted, de-duplicated and non-overlapping: when two folds overlap
+/// the outermost (earliest starting, then longest) one wins.
+pub fn compute_fold_ranges(file: &SourceFile) -> Vec<FoldRange> {
+    let mut folds: Vec<&FoldRegion> = file
+        .folds
+        .iter()
+        .filter(|f| f.end_byte > f.start_byte)
+        .collect();
+    folds.sort_by_key(|f| (f.start_byte, std::cmp::Reverse(f.end_byte)));
+
+    let mut kept: Vec<&FoldRegion> = Vec::new();
+    for fold in folds {
+        // Kept folds are disjoint and sorted, so only the last one can overlap
+        if kept.last().is_some_and(|last| last.overlaps(fold)) {
+            continue;
+        }
+        kept.push(fold);
+    }
+
+    kept.into_iter().map(FoldRange::from).collect()
+}
+
+/// Append the next rendered line to a source map
+fn push_segment(segments: &mut Vec<RenderSegment>, start: usize, end: usize, placeholder: bool) {
+    segments.push(RenderSegment {
+        rendered_line: segments.len() + 1,
+        original_start: start,
+        original_end: end,
+        is_fold_placeholder: placeholder,
+    });
+}
+
 /// Render a file with folds applied (convenience function)
 pub fn render_file(path: &Path, config: &ScanConfig) -> Result<RenderedFile, std::io::Error> {
     let content = fs::read_to_string(path)?;
@@ -221,7 +338,9 @@ pub fn render_file(path: &Path, config: &ScanConfig) -> Result<RenderedFile, std
         path: path.to_path_buf(),
         content: rendered,
         fold_count: folds.len(),
+        folds_applied: renderer.applied_folds(&content, &folds).len(),
         lines_hidden,
+        source_map: renderer.source_map(&content, &folds),
     })
 }
 
@@ -252,7 +371,9 @@ pub fn render_file_ansi(path: &Path, config: &ScanConfig) -> Result<RenderedFile
         path: path.to_path_buf(),
         content: rendered,
         fold_count: folds.len(),
+        folds_applied: renderer.applied_folds(&content, &folds).len(),
         lines_hidden,
+        source_map: renderer.source_map(&content, &folds),
     })
 }
 
@@ -286,4 +407,99 @@ mod tests {
         assert!(result.contains("/*"));
         assert!(!result.contains("line1"));
     }
+
+    #[test]
+    fn test_render_line_budget() {
+        // Ten 10-line functions: 100 lines in total
+        let mut source = String::new();
+        for i in 0..10 {
+            source.push_str(&format!("def func_{}(x):\n", i));
+            for j in 0..8 {
+                source.push_str(&format!("    x = x + {}\n", j));
+            }
+            source.push_str("    return x\n");
+        }
+        assert_eq!(source.lines().count(), 100);
+
+        let config = test_config().with_line_budget(30);
+        let mut parser = crate::parsers::create_parser(&crate::models::Language::Python).unwrap();
+        let folds = parser.parse(&source, &config);
+
+        let renderer = Renderer::new(config);
+        let applied = renderer.applied_folds(&source, &folds);
+        let result = renderer.render(&source, &folds);
+
+        assert!(result.lines().count() <= 30);
+        assert!(!applied.is_empty());
+        // Greedy selection stops once the budget is met
+        assert!(applied.len() < folds.len());
+        for pair in applied.windows(2) {
+            assert!(pair[0].end_byte <= pair[1].start_byte);
+        }
+    }
+
+    #[test]
+    fn test_compute_fold_ranges() {
+        let file = SourceFile {
+            path: "app.py".into(),
+            absolute_path: "/project/app.py".into(),
+            language: crate::models::Language::Python,
+            folds: vec![
+                FoldRegion::new(FoldType::Block, 40, 90, 5, 9, 0, 0),
+                // Outer fold containing the one above
+                FoldRegion::new(FoldType::ClassBody, 20, 120, 3, 12, 0, 0),
+                // Duplicate of the outer fold
+                FoldRegion::new(FoldType::ClassBody, 20, 120, 3, 12, 0, 0),
+                // Starts inside the outer fold but runs past it
+                FoldRegion::new(FoldType::Comment, 110, 130, 11, 13, 0, 0),
+                FoldRegion::new(FoldType::Import, 0, 15, 1, 2, 0, 0),
+                FoldRegion::new(FoldType::Block, 140, 200, 14, 20, 0, 0),
+            ],
+            line_count: 20,
+            parsed: true,
+            error: None,
+            node_kinds: Default::default(),
+            byte_count: 200,
+            main_guard: false,
+            suppressions: vec![],
+            line_index: Default::default(),
+        };
+
+        let ranges = compute_fold_ranges(&file);
+        let bytes: Vec<_> = ranges.iter().map(FoldRange::bytes).collect();
+        let lines: Vec<_> = ranges.iter().map(FoldRange::lines).collect();
+        assert_eq!(bytes, vec![(0, 15), (20, 120), (140, 200)]);
+        assert_eq!(lines, vec![(1, 2), (3, 12), (14, 20)]);
+    }
+
+    #[test]
+    fn test_source_map() {
+        let renderer = Renderer::new(test_config());
+        let source = "import os\n\ndef run():\n    a = 1\n    b = 2\n    return a + b\n\nprint(run())\n";
+
+        // Fold the function body (lines 4-6) onto the `def` line
+        let start = source.find("\n    a").unwrap();
+        let end = source.find("a + b").unwrap() + "a + b".len();
+        let fold = FoldRegion::new(FoldType::Block, start, end, 3, 6, 10, 16);
+
+        let rendered = renderer.render(source, std::slice::from_ref(&fold));
+        let map = renderer.source_map(source, &[fold]);
+
+        assert_eq!(map.len(), rendered.lines().count());
+        assert_eq!(
+            map[2],
+            RenderSegment {
+                rendered_line: 3,
+                original_start: 3,
+                original_end: 6,
+                is_fold_placeholder: true,
+            }
+        );
+        assert!(rendered.lines().nth(2).unwrap().starts_with("def run():/*"));
+
+        // Lines around the fold map one-to-one, shifted past the hidden lines
+        assert_eq!((map[1].original_start, map[1].is_fold_placeholder), (2, false));
+        assert_eq!((map[4].rendered_line, map[4].original_start, map[4].original_end), (5, 8, 8));
+        assert_eq!(map.iter().filter(|s| s.is_fold_placeholder).count(), 1);
+    }
 }
@@ -1,10 +1,15 @@
+use super::fold_cache::{FileStamp, FoldCache};
+use super::git::{is_work_tree, line_commit_times, staged_files};
+use super::QueryCache;
 use crate::config::{IgnoreFilter, ScanConfig};
-use crate::models::{FoldMap, FoldStats, Language, ScanMetadata, SourceFile};
-use crate::parsers::create_parser;
+use crate::models::{FoldMap, FoldRegion, FoldStats, Language, ScanMetadata, SourceFile};
+use crate::parsers::{create_parser, FoldParser};
 use rayon::prelude::*;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::fs;
 use std::path::{Path, PathBuf};
-use std::time::Instant;
+use std::sync::mpsc;
+use std::time::{Duration, Instant};
 use thiserror::Error;
 use walkdir::WalkDir;
 
@@ -22,17 +27,35 @@ pub enum ScanError {
 pub struct FoldScanner {
     config: ScanConfig,
     ignore_filter: IgnoreFilter,
+    query_cache: QueryCache,
+    fold_cache: Option<FoldCache>,
+    /// `config.blame`, switched off when the root is not in a git work tree
+    blame: bool,
 }
 
 impl FoldScanner {
     pub fn new(config: ScanConfig) -> Result<Self, ScanError> {
         let ignore_filter = IgnoreFilter::new(&config)?;
+        let query_cache = QueryCache::new(config.cache_queries);
+        let fold_cache = config
+            .cache_path
+            .as_ref()
+            .map(|path| FoldCache::load(path, config.fold_config_hash()));
+        let blame = config.blame && is_work_tree(&config.root);
         Ok(Self {
             config,
             ignore_filter,
+            query_cache,
+            fold_cache,
+            blame,
         })
     }
 
+    /// Compiled tree-sitter queries shared by every file in this scanner's scans
+    pub fn query_cache(&self) -> &QueryCache {
+        &self.query_cache
+    }
+
     /// Scan the project and return the fold map
     pub fn scan(&self) -> Result<FoldMap, ScanError> {
         let start = Instant::now();
@@ -42,21 +65,15 @@ impl FoldScanner {
 
         // Parse all files in parallel
         let files: Vec<SourceFile> = if self.config.threads == 1 {
+            // Strictly serial, in sorted path order, for reproducible debugging
+            let mut source_files = source_files;
+            source_files.sort_by(|a, b| a.0.cmp(&b.0));
             source_files
                 .into_iter()
                 .filter_map(|(path, lang)| self.parse_file(&path, &lang))
                 .collect()
         } else {
-            let pool = if self.config.threads > 0 {
-                rayon::ThreadPoolBuilder::new()
-                    .num_threads(self.config.threads)
-                    .build()
-                    .ok()
-            } else {
-                None
-            };
-
-            match pool {
+            match self.thread_pool() {
                 Some(pool) => pool.install(|| {
                     source_files
                         .par_iter()
@@ -70,28 +87,284 @@ impl FoldScanner {
             }
         };
 
+        self.save_cache()?;
+        Ok(self.build_fold_map(files, start))
+    }
+
+    /// Scan the project, handing each file to `on_file` as soon as it is parsed
+    ///
+    /// Files arrive in completion order (sorted path order with `threads == 1`)
+    /// already post-processed: blamed, paths rewritten, line-indexed and relabelled.
+    /// The returned FoldMap holds the files in the order they were emitted. An
+    /// error from `on_file` stops the scan.
+    pub fn scan_streaming<F>(&self, mut on_file: F) -> Result<FoldMap, ScanError>
+    where
+        F: FnMut(&SourceFile) -> std::io::Result<()>,
+    {
+        let start = Instant::now();
+        let mut source_files = self.find_source_files()?;
+        let mut files = Vec::with_capacity(source_files.len());
+
+        if self.config.threads == 1 {
+            source_files.sort_by(|a, b| a.0.cmp(&b.0));
+            for (path, lang) in &source_files {
+                if let Some(mut file) = self.parse_file(path, lang) {
+                    self.finish_file(&mut file);
+                    on_file(&file)?;
+                    files.push(file);
+                }
+            }
+        } else {
+            let (tx, rx) = mpsc::channel();
+            std::thread::scope(|scope| -> Result<(), ScanError> {
+                scope.spawn(move || {
+                    let parse_all = || {
+                        source_files.par_iter().for_each_with(tx, |tx, (path, lang)| {
+                            if let Some(file) = self.parse_file(path, lang) {
+                                // The receiver is dropped when on_file fails; remaining files are discarded
+                                let _ = tx.send(file);
+                            }
+                        })
+                    };
+                    match self.thread_pool() {
+                        Some(pool) => pool.install(parse_all),
+                        None => parse_all(),
+                    }
+                });
+
+                for mut file in rx {
+                    self.finish_file(&mut file);
+                    on_file(&file)?;
+                    files.push(file);
+                }
+                Ok(())
+            })?;
+        }
+
+        self.save_cache()?;
+        Ok(self.summarize(files, start))
+    }
+
+    /// Persist the incremental fold cache, if enabled
+    fn save_cache(&self) -> Result<(), ScanError> {
+        if let Some(ref cache) = self.fold_cache {
+            cache.save()?;
+        }
+        Ok(())
+    }
+
+    /// Dedicated thread pool when a thread count is configured (None = rayon's global pool)
+    fn thread_pool(&self) -> Option<rayon::ThreadPool> {
+        if self.config.threads > 0 {
+            rayon::ThreadPoolBuilder::new()
+                .num_threads(self.config.threads)
+                .build()
+                .ok()
+        } else {
+            None
+        }
+    }
+
+    /// Scan exactly the provided (path, source) pairs without touching the filesystem
+    ///
+    /// Language is detected from each path's extension; paths with unsupported
+    /// extensions, outside the language filter or outside the include patterns
+    /// are skipped, as are Markdown docs unless the language filter asks for them.
+    pub fn scan_with_contents(&self, contents: HashMap<PathBuf, String>) -> Result<FoldMap, ScanError> {
+        let start = Instant::now();
+
+        let mut sources: Vec<(PathBuf, String)> = contents.into_iter().collect();
+        sources.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let files: Vec<SourceFile> = sources
+            .iter()
+            .filter(|(path, _)| {
+                !self.config.is_artifact(path)
+                    && self
+                        .ignore_filter
+                        .matches_language_filter(path, &self.config.language_filter)
+                    && self.ignore_filter.matches_include_patterns(path)
+            })
+            .filter_map(|(path, source)| {
+                let ext = path.extension()?.to_string_lossy().to_string();
+                let lang = Language::from_extension(&ext)?;
+                if lang == Language::Markdown && !self.markdown_requested() {
+                    return None;
+                }
+                Some(self.analyze_source(path, &lang, source))
+            })
+            .collect();
+
+        Ok(self.build_fold_map(files, start))
+    }
+
+    /// Assemble a FoldMap with statistics and timing metadata
+    fn build_fold_map(&self, mut files: Vec<SourceFile>, start: Instant) -> FoldMap {
+        for file in &mut files {
+            self.finish_file(file);
+        }
+        self.summarize(files, start)
+    }
+
+    /// Run the post-processing steps on a parsed file, in order
+    ///
+    /// All of them happen after caching, so the cache always holds the canonical
+    /// paths and fold types and never a stale blame. Blaming reads the file from
+    /// disk, so it runs before the paths are rewritten.
+    fn finish_file(&self, file: &mut SourceFile) {
+        if self.blame {
+            Self::blame_folds(file);
+        }
+        self.rewrite_paths(file);
+        self.index_lines(file);
+        self.relabel_folds(file);
+    }
+
+    /// Rewrite paths before serialization (strip a CI checkout prefix, normalize separators)
+    fn rewrite_paths(&self, file: &mut SourceFile) {
+        if self.config.strip_prefix.is_some() || self.config.forward_slashes {
+            file.path = self.config.transform_path(&file.path);
+            file.absolute_path = self.config.transform_path(&file.absolute_path);
+        }
+    }
+
+    /// Build the start-line lookup table when `line_indexed` is set
+    fn index_lines(&self, file: &mut SourceFile) {
+        if self.config.line_indexed {
+            file.line_index = file.build_line_index();
+        }
+    }
+
+    /// Apply the configured output labels from `fold_type_map` to every fold
+    fn relabel_folds(&self, file: &mut SourceFile) {
+        if self.config.fold_type_map.is_empty() {
+            return;
+        }
+        for fold in &mut file.folds {
+            self.relabel_fold(fold);
+        }
+    }
+
+    /// Set `last_modified` on every fold to the newest commit time of its lines
+    ///
+    /// Files git cannot blame (untracked, or read from outside the work tree) and
+    /// folds made only of uncommitted lines are left without a date.
+    fn blame_folds(file: &mut SourceFile) {
+        fn annotate(fold: &mut FoldRegion, times: &[Option<i64>]) {
+            let lines = times
+                .get(fold.start_line.saturating_sub(1)..fold.end_line.min(times.len()))
+                .unwrap_or_default();
+            fold.last_modified = lines
+                .iter()
+                .flatten()
+                .max()
+                .and_then(|&secs| chrono::DateTime::from_timestamp(secs, 0))
+                .map(|dt| dt.to_rfc3339());
+            for child in &mut fold.children {
+                annotate(child, times);
+            }
+        }
+
+        let Ok(times) = line_commit_times(&file.absolute_path) else {
+            return;
+        };
+        for fold in &mut file.folds {
+            annotate(fold, &times);
+        }
+    }
+
+    /// Set the output label of a fold and its nested children from `fold_type_map`
+    fn relabel_fold(&self, fold: &mut FoldRegion) {
+        fold.label = self.config.fold_type_map.get(&fold.fold_type).cloned();
+        for child in &mut fold.children {
+            self.relabel_fold(child);
+        }
+    }
+
+    /// Compute statistics and timing metadata for files already post-processed
+    fn summarize(&self, mut files: Vec<SourceFile>, start: Instant) -> FoldMap {
         // Calculate statistics
         let stats = self.calculate_stats(&files);
 
         // Build metadata
-        let duration = start.elapsed();
-        let metadata = ScanMetadata {
+        let duration = if self.config.reproducible {
+            Duration::ZERO
+        } else {
+            start.elapsed()
+        };
+        let total_bytes: usize = files.iter().map(|f| f.byte_count).sum();
+        let (cache_hits, cache_misses) = match self.fold_cache {
+            Some(ref cache) => {
+                let (hits, misses) = cache.take_counts();
+                (Some(hits), Some(misses))
+            }
+            None => (None, None),
+        };
+        let mut metadata = ScanMetadata {
             scan_duration_ms: duration.as_millis() as u64,
             files_per_second: if duration.as_secs_f64() > 0.0 {
                 files.len() as f64 / duration.as_secs_f64()
             } else {
                 0.0
             },
-            timestamp: chrono::Utc::now().to_rfc3339(),
+            total_bytes,
+            mb_per_second: if duration.as_secs_f64() > 0.0 {
+                total_bytes as f64 / (1024.0 * 1024.0) / duration.as_secs_f64()
+            } else {
+                0.0
+            },
+            timestamp: self.config.metadata_timestamp(),
             tool_version: env!("CARGO_PKG_VERSION").to_string(),
+            cache_hits,
+            cache_misses,
+            truncated: false,
+            total_files_before_truncation: None,
         };
 
-        Ok(FoldMap {
-            root: self.config.root.clone(),
+        // Cap the serialized file list; stats and metadata above cover every file
+        if let Some(max) = self.config.max_output_files {
+            if files.len() > max {
+                metadata.truncated = true;
+                metadata.total_files_before_truncation = Some(files.len());
+                files.truncate(max);
+            }
+        }
+
+        FoldMap {
+            root: self.config.normalize_separators(&self.config.root),
             files,
             stats,
             metadata,
-        })
+            package_entry_points: self.package_entry_points(),
+        }
+    }
+
+    /// Files named by `main`/`bin` in the root package.json, relative to the root
+    fn package_entry_points(&self) -> Vec<PathBuf> {
+        let Ok(content) = fs::read_to_string(self.config.root.join("package.json")) else {
+            return vec![];
+        };
+        let Ok(manifest) = serde_json::from_str::<serde_json::Value>(&content) else {
+            return vec![];
+        };
+
+        let mut entries: Vec<&str> = Vec::new();
+        if let Some(main) = manifest.get("main").and_then(|v| v.as_str()) {
+            entries.push(main);
+        }
+        // `bin` is either a single path or a map of command name -> path
+        match manifest.get("bin") {
+            Some(serde_json::Value::String(bin)) => entries.push(bin),
+            Some(serde_json::Value::Object(bins)) => {
+                entries.extend(bins.values().filter_map(|v| v.as_str()))
+            }
+            _ => {}
+        }
+
+        entries
+            .into_iter()
+            .map(|entry| PathBuf::from(entry.trim_start_matches("./")))
+            .collect()
     }
 
     /// Scan a single file
@@ -116,14 +389,24 @@ impl FoldScanner {
         })
     }
 
+    /// Markdown docs are only scanned when requested explicitly via the language filter
+    fn markdown_requested(&self) -> bool {
+        self.config
+            .language_filter
+            .as_ref()
+            .is_some_and(|languages| languages.contains(&Language::Markdown))
+    }
+
     /// Find all source files matching the language filter
     fn find_source_files(&self) -> Result<Vec<(PathBuf, Language)>, ScanError> {
         let mut files = Vec::new();
 
-        for entry in WalkDir::new(&self.config.root)
-            .into_iter()
-            .filter_map(|e| e.ok())
-        {
+        let mut walker = WalkDir::new(&self.config.root);
+        if let Some(depth) = self.config.max_directory_depth {
+            walker = walker.max_depth(depth);
+        }
+
+        for entry in walker.into_iter().filter_map(|e| e.ok()) {
             let path = entry.path();
 
             // Skip directories
@@ -136,6 +419,11 @@ impl FoldScanner {
                 continue;
             }
 
+            // Skip compiled and minified output (`app.min.js` would pass as JavaScript)
+            if self.config.is_artifact(path) {
+                continue;
+            }
+
             // Check language filter
             if !self
                 .ignore_filter
@@ -144,19 +432,52 @@ impl FoldScanner {
                 continue;
             }
 
+            // Check include patterns
+            if !self.ignore_filter.matches_include_patterns(path) {
+                continue;
+            }
+
             // Get language from extension
             if let Some(ext) = path.extension() {
                 if let Some(lang) = Language::from_extension(&ext.to_string_lossy()) {
+                    if lang == Language::Markdown && !self.markdown_requested() {
+                        continue;
+                    }
                     files.push((path.to_path_buf(), lang));
                 }
             }
         }
 
+        // Intersect with the git index when only staged files are wanted
+        if self.config.staged_only {
+            let staged: HashSet<PathBuf> = staged_files(&self.config.root)?
+                .iter()
+                .filter_map(|p| p.canonicalize().ok())
+                .collect();
+            files.retain(|(path, _)| {
+                path.canonicalize()
+                    .map(|p| staged.contains(&p))
+                    .unwrap_or(false)
+            });
+        }
+
         Ok(files)
     }
 
     /// Parse a single source file
     fn parse_file(&self, path: &Path, language: &Language) -> Option<SourceFile> {
+        // Unchanged files reuse cached folds without reading or parsing
+        let cached = self
+            .fold_cache
+            .as_ref()
+            .and_then(|cache| Some((cache, FileStamp::of(path)?)));
+        if let Some((cache, stamp)) = cached {
+            let relative_path = path.strip_prefix(&self.config.root).unwrap_or(path);
+            if let Some(file) = cache.get(path, relative_path, language, stamp) {
+                return Some(file);
+            }
+        }
+
         // Read file content
         let content = match fs::read_to_string(path) {
             Ok(c) => c,
@@ -172,33 +493,74 @@ impl FoldScanner {
                     line_count: 0,
                     parsed: false,
                     error: Some(e.to_string()),
+                    node_kinds: BTreeMap::new(),
+                    byte_count: 0,
+                    main_guard: false,
+                    suppressions: vec![],
+                    line_index: BTreeMap::new(),
                 });
             }
         };
 
-        let line_count = content.lines().count();
-
-        // Create parser for this language
-        let mut parser = match create_parser(language) {
-            Ok(p) => p,
-            Err(e) => {
-                return Some(SourceFile {
-                    path: path
-                        .strip_prefix(&self.config.root)
-                        .unwrap_or(path)
-                        .to_path_buf(),
-                    absolute_path: path.to_path_buf(),
-                    language: language.clone(),
-                    folds: vec![],
-                    line_count,
-                    parsed: false,
-                    error: Some(e.to_string()),
-                });
+        let file = self.analyze_source(path, language, &content);
+        if let Some((cache, stamp)) = cached {
+            if file.parsed {
+                cache.insert(path, stamp, &file);
             }
-        };
+        }
+        Some(file)
+    }
 
-        // Parse folds
-        let folds = parser.parse(&content, &self.config);
+    /// Fold source text already in memory (editor buffers), attributing it to `path`
+    ///
+    /// Skips the filesystem read and extension detection; `path` only labels the
+    /// result. The configured fold filter, `min_fold_lines` and preview mode apply
+    /// as in a scan. Unlike a scan, which records the failure in the file's `error`,
+    /// a parser that cannot be created is returned as an error.
+    pub fn scan_source(
+        &self,
+        source: &str,
+        language: Language,
+        path: PathBuf,
+    ) -> Result<SourceFile, ScanError> {
+        let parser = create_parser(&language)?;
+        Ok(self.fold_source(parser, &path, &language, source))
+    }
+
+    /// Analyze source text for a scan; a parser that cannot be created is reported
+    /// in the file's `error` with `parsed: false`
+    fn analyze_source(&self, path: &Path, language: &Language, content: &str) -> SourceFile {
+        match create_parser(language) {
+            Ok(parser) => self.fold_source(parser, path, language, content),
+            Err(e) => SourceFile {
+                path: path
+                    .strip_prefix(&self.config.root)
+                    .unwrap_or(path)
+                    .to_path_buf(),
+                absolute_path: path.to_path_buf(),
+                language: language.clone(),
+                folds: vec![],
+                line_count: content.lines().count(),
+                parsed: false,
+                error: Some(e.to_string()),
+                node_kinds: BTreeMap::new(),
+                byte_count: content.len(),
+                main_guard: false,
+                suppressions: vec![],
+                line_index: BTreeMap::new(),
+            },
+        }
+    }
+
+    /// Fold `content` with an already created parser
+    fn fold_source(
+        &self,
+        mut parser: Box<dyn FoldParser>,
+        path: &Path,
+        language: &Language,
+        content: &str,
+    ) -> SourceFile {
+        let line_count = content.lines().count();
 
         // Calculate relative path
         let relative_path = path
@@ -206,7 +568,26 @@ impl FoldScanner {
             .unwrap_or(path)
             .to_path_buf();
 
-        Some(SourceFile {
+        // Parse folds
+        let mut folds = self.config.dedup_overlaps.resolve(parser.parse(content, &self.config));
+        if self.config.nested_folds {
+            folds = FoldRegion::nest(folds);
+        }
+        self.config.fold_order.apply(&mut folds);
+
+        let node_kinds = if self.config.node_histogram {
+            parser.node_kinds(content)
+        } else {
+            BTreeMap::new()
+        };
+
+        let suppressions = if self.config.suppressions {
+            parser.suppressions(content)
+        } else {
+            vec![]
+        };
+
+        SourceFile {
             path: relative_path,
             absolute_path: path.to_path_buf(),
             language: language.clone(),
@@ -214,7 +595,12 @@ impl FoldScanner {
             line_count,
             parsed: true,
             error: None,
-        })
+            node_kinds,
+            byte_count: content.len(),
+            main_guard: *language == Language::Python && has_main_guard(content),
+            suppressions,
+            line_index: BTreeMap::new(),
+        }
     }
 
     /// Calculate fold statistics
@@ -228,18 +614,38 @@ impl FoldScanner {
                 Language::Python => stats.python_files += 1,
                 Language::JavaScript => stats.javascript_files += 1,
                 Language::TypeScript => stats.typescript_files += 1,
+                Language::CSharp => stats.csharp_files += 1,
+                Language::Rust => stats.rust_files += 1,
+                Language::Scala => stats.scala_files += 1,
+                Language::Markdown => stats.markdown_files += 1,
             }
 
             stats.total_lines += file.line_count;
 
             for fold in &file.folds {
-                stats.add_fold(&fold.fold_type);
-                stats.foldable_lines += fold.line_count;
+                Self::count_fold(&mut stats, fold);
             }
         }
 
         stats
     }
+
+    /// Count a fold and its nested children (present with nested folds enabled)
+    fn count_fold(stats: &mut FoldStats, fold: &FoldRegion) {
+        stats.add_fold(&fold.fold_type);
+        stats.foldable_lines += fold.line_count;
+        for child in &fold.children {
+            Self::count_fold(stats, child);
+        }
+    }
+}
+
+/// Whether Python source has a top-level `if __name__ == "__main__":` guard
+fn has_main_guard(content: &str) -> bool {
+    content.lines().any(|line| {
+        let line = line.trim_end();
+        line.starts_with("if __name__") && line.contains("__main__") && line.ends_with(':')
+    })
 }
 
 #[cfg(test)]
@@ -252,4 +658,937 @@ mod tests {
         let scanner = FoldScanner::new(config);
         assert!(scanner.is_ok());
     }
+
+    #[test]
+    fn test_max_directory_depth() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let root = dir.path().to_path_buf();
+        fs::write(root.join("top.py"), "import os\nimport sys\n").unwrap();
+        fs::create_dir(root.join("nested")).unwrap();
+        fs::write(root.join("nested").join("inner.py"), "import os\n").unwrap();
+
+        let recursive = FoldScanner::new(ScanConfig::new(root.clone()))
+            .unwrap()
+            .scan()
+            .unwrap();
+        assert_eq!(recursive.files.len(), 2);
+
+        let shallow = FoldScanner::new(ScanConfig::new(root).with_max_directory_depth(1))
+            .unwrap()
+            .scan()
+            .unwrap();
+        assert_eq!(shallow.files.len(), 1);
+        assert_eq!(shallow.files[0].path, PathBuf::from("top.py"));
+    }
+
+    #[test]
+    fn test_skips_artifacts() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let root = dir.path().to_path_buf();
+        let function = "function f() {\n  return 1;\n}\n";
+        fs::write(root.join("app.js"), function).unwrap();
+        fs::write(root.join("app.min.js"), function).unwrap();
+        fs::write(root.join("app.js.map"), "{\"version\":3}").unwrap();
+        fs::write(root.join("mod.pyc"), [0x6f, 0x0d, 0x0d, 0x0a]).unwrap();
+
+        let scan = |config: ScanConfig| -> Vec<PathBuf> {
+            let mut paths: Vec<PathBuf> = FoldScanner::new(config)
+                .unwrap()
+                .scan()
+                .unwrap()
+                .files
+                .into_iter()
+                .map(|f| f.path)
+                .collect();
+            paths.sort();
+            paths
+        };
+
+        assert_eq!(scan(ScanConfig::new(root.clone())), vec![PathBuf::from("app.js")]);
+        assert!(ScanConfig::default().is_artifact(Path::new("lib/Vendor.MIN.JS")));
+
+        // An empty list turns the skip off, so the minified bundle is folded too
+        assert_eq!(
+            scan(ScanConfig::new(root).with_artifact_suffixes(vec![])),
+            vec![PathBuf::from("app.js"), PathBuf::from("app.min.js")]
+        );
+    }
+
+    #[test]
+    fn test_serial_scan_is_path_ordered() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let root = dir.path().to_path_buf();
+        fs::write(root.join("c.py"), "import os\nimport sys\n").unwrap();
+        fs::write(root.join("a.js"), "function f() {\n  return 1;\n}\n").unwrap();
+        fs::create_dir(root.join("b")).unwrap();
+        fs::write(root.join("b").join("mod.py"), "def g():\n    pass\n    pass\n").unwrap();
+
+        let scanner = FoldScanner::new(ScanConfig::new(root).with_threads(1)).unwrap();
+        let expected = vec![
+            PathBuf::from("a.js"),
+            PathBuf::from("b").join("mod.py"),
+            PathBuf::from("c.py"),
+        ];
+
+        let scanned: Vec<PathBuf> =
+            scanner.scan().unwrap().files.into_iter().map(|f| f.path).collect();
+        assert_eq!(scanned, expected);
+
+        let mut streamed = Vec::new();
+        scanner
+            .scan_streaming(|file| {
+                streamed.push(file.path.clone());
+                Ok(())
+            })
+            .unwrap();
+        assert_eq!(streamed, expected);
+    }
+
+    #[test]
+    fn test_path_transform() {
+        let root = PathBuf::from("/home/runner/work/repo/repo");
+        let mut contents = HashMap::new();
+        contents.insert(root.join("src").join("app.py"), "import os\n".to_string());
+        contents.insert(PathBuf::from("/elsewhere/lib.py"), "import sys\n".to_string());
+
+        let config = ScanConfig::new(root).with_path_transform("/home/runner/work/repo/repo/".to_string());
+        let result = FoldScanner::new(config).unwrap().scan_with_contents(contents).unwrap();
+
+        let absolute: Vec<PathBuf> = result.files.iter().map(|f| f.absolute_path.clone()).collect();
+        assert_eq!(absolute, vec![PathBuf::from("/elsewhere/lib.py"), PathBuf::from("src/app.py")]);
+    }
+
+    #[test]
+    fn test_node_histogram() {
+        let root = PathBuf::from("/virtual");
+        let mut contents = HashMap::new();
+        contents.insert(root.join("a.py"), "def f():\n    pass\n\ndef g():\n    pass\n".to_string());
+        contents.insert(root.join("b.py"), "def h():\n    pass\n".to_string());
+
+        let plain = FoldScanner::new(ScanConfig::new(root.clone()))
+            .unwrap()
+            .scan_with_contents(contents.clone())
+            .unwrap();
+        assert!(plain.files.iter().all(|f| f.node_kinds.is_empty()));
+
+        let result = FoldScanner::new(ScanConfig::new(root).with_node_histogram(true))
+            .unwrap()
+            .scan_with_contents(contents)
+            .unwrap();
+        assert_eq!(result.files[0].node_kinds.get("function_definition"), Some(&2));
+
+        let histogram = result.node_kind_histogram();
+        let functions = histogram.iter().find(|(kind, _)| kind == "function_definition");
+        assert_eq!(functions.map(|(_, count)| *count), Some(3));
+    }
+
+    #[test]
+    fn test_scan_with_contents() {
+        let root = PathBuf::from("/virtual/project");
+        let mut contents = HashMap::new();
+        contents.insert(
+            root.join("app.py"),
+            "def main():\n    a = 1\n    b = 2\n    return a + b\n".to_string(),
+        );
+        contents.insert(
+            root.join("src").join("index.js"),
+            "function main() {\n  const a = 1;\n  const b = 2;\n  return a + b;\n}\n".to_string(),
+        );
+        contents.insert(root.join("README.md"), "# Not source\n".to_string());
+
+        let scanner =
+            FoldScanner::new(ScanConfig::new(root.clone()).with_min_fold_lines(2)).unwrap();
+        let result = scanner.scan_with_contents(contents.clone()).unwrap();
+
+        let paths: Vec<PathBuf> = result.files.iter().map(|f| f.path.clone()).collect();
+        assert_eq!(paths, vec![PathBuf::from("app.py"), PathBuf::from("src/index.js")]);
+        assert!(result.files.iter().all(|f| f.parsed && !f.folds.is_empty()));
+        assert_eq!(result.stats.python_files, 1);
+        assert_eq!(result.stats.javascript_files, 1);
+
+        // Markdown is opt-in, as when walking the filesystem
+        let config = ScanConfig::new(root).with_language_filter(vec![Language::Markdown]);
+        let docs = FoldScanner::new(config).unwrap().scan_with_contents(contents).unwrap();
+        let paths: Vec<PathBuf> = docs.files.iter().map(|f| f.path.clone()).collect();
+        assert_eq!(paths, vec![PathBuf::from("README.md")]);
+    }
+
+    #[test]
+    fn test_include_patterns() {
+        let root = PathBuf::from("/virtual/project");
+        let contents = HashMap::from([
+            (root.join("app.py"), "x = 1\n".to_string()),
+            (root.join("src").join("index.js"), "const a = 1;\n".to_string()),
+            (root.join("src").join("tasks.py"), "y = 2\n".to_string()),
+        ]);
+
+        // An empty include list scans everything
+        let scanner = FoldScanner::new(ScanConfig::new(root.clone())).unwrap();
+        assert_eq!(scanner.scan_with_contents(contents.clone()).unwrap().files.len(), 3);
+
+        // Include patterns and the language filter must both match
+        let config = ScanConfig::new(root)
+            .with_include_patterns(vec!["src/**".to_string()])
+            .with_language_filter(vec![Language::JavaScript]);
+        let result = FoldScanner::new(config).unwrap().scan_with_contents(contents).unwrap();
+
+        let paths: Vec<PathBuf> = result.files.iter().map(|f| f.path.clone()).collect();
+        assert_eq!(paths, vec![PathBuf::from("src/index.js")]);
+    }
+
+    #[test]
+    fn test_staged_only() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let root = dir.path().to_path_buf();
+        let git = |args: &[&str]| {
+            let status = std::process::Command::new("git")
+                .args(args)
+                .current_dir(&root)
+                .status()
+                .unwrap();
+            assert!(status.success());
+        };
+
+        git(&["init", "-q"]);
+        fs::write(root.join("staged.py"), "import os\nimport sys\n").unwrap();
+        fs::write(root.join("unstaged.py"), "import json\nimport re\n").unwrap();
+        git(&["add", "staged.py"]);
+
+        let result = FoldScanner::new(ScanConfig::new(root.clone()).with_staged_only(true))
+            .unwrap()
+            .scan()
+            .unwrap();
+        assert_eq!(result.files.len(), 1);
+        assert_eq!(result.files[0].path, PathBuf::from("staged.py"));
+    }
+
+    #[test]
+    fn test_staged_only_rename() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let root = dir.path().to_path_buf();
+        let git = |args: &[&str]| {
+            let status = std::process::Command::new("git")
+                .args(args)
+                .current_dir(&root)
+                .status()
+                .unwrap();
+            assert!(status.success());
+        };
+
+        git(&["init", "-q"]);
+        git(&["config", "user.name", "Test"]);
+        git(&["config", "user.email", "test@example.com"]);
+        fs::write(root.join("old.py"), "import os\nimport sys\n").unwrap();
+        fs::write(root.join("other.py"), "import json\nimport re\n").unwrap();
+        git(&["add", "."]);
+        git(&["commit", "-q", "-m", "Add files"]);
+        git(&["mv", "old.py", "new.py"]);
+
+        // Only the new side of a staged rename is scanned
+        let result = FoldScanner::new(ScanConfig::new(root.clone()).with_staged_only(true))
+            .unwrap()
+            .scan()
+            .unwrap();
+        assert_eq!(result.files.len(), 1);
+        assert_eq!(result.files[0].path, PathBuf::from("new.py"));
+    }
+
+    #[test]
+    fn test_blame() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let root = dir.path().to_path_buf();
+        let git = |args: &[&str]| {
+            let status = std::process::Command::new("git")
+                .args(args)
+                .current_dir(&root)
+                .env("GIT_AUTHOR_DATE", "2024-03-05T06:07:08Z")
+                .env("GIT_COMMITTER_DATE", "2024-03-05T06:07:08Z")
+                .status()
+                .unwrap();
+            assert!(status.success());
+        };
+
+        git(&["init", "-q"]);
+        git(&["config", "user.name", "Test"]);
+        git(&["config", "user.email", "test@example.com"]);
+        fs::write(
+            root.join("app.py"),
+            "def run():\n    a = 1\n    b = 2\n    c = 3\n    return a\n",
+        )
+        .unwrap();
+        git(&["add", "app.py"]);
+        git(&["commit", "-q", "-m", "Add app"]);
+        // Uncommitted files are scanned but get no date
+        fs::write(
+            root.join("draft.py"),
+            "def draft():\n    a = 1\n    b = 2\n    c = 3\n    return a\n",
+        )
+        .unwrap();
+
+        let result = FoldScanner::new(ScanConfig::new(root.clone()).with_blame(true))
+            .unwrap()
+            .scan()
+            .unwrap();
+        let last_modified = |name: &str| {
+            let file = result.files.iter().find(|f| f.path == Path::new(name)).unwrap();
+            file.folds[0].last_modified.clone()
+        };
+        assert_eq!(last_modified("app.py").as_deref(), Some("2024-03-05T06:07:08+00:00"));
+        assert_eq!(last_modified("draft.py"), None);
+
+        // Off by default
+        let plain = FoldScanner::new(ScanConfig::new(root)).unwrap().scan().unwrap();
+        assert!(plain.files.iter().all(|f| f.folds[0].last_modified.is_none()));
+    }
+
+    #[test]
+    fn test_fixed_timestamp() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let root = dir.path().to_path_buf();
+        fs::write(root.join("app.py"), "import os\nimport sys\n").unwrap();
+
+        let run = || {
+            let config = ScanConfig::new(root.clone())
+                .with_fixed_timestamp(Some("2024-01-01T00:00:00+00:00".to_string()))
+                .with_reproducible(true);
+            FoldScanner::new(config).unwrap().scan().unwrap().metadata
+        };
+
+        let first = run();
+        assert_eq!(first.timestamp, "2024-01-01T00:00:00+00:00");
+        assert_eq!(first.scan_duration_ms, 0);
+        assert_eq!(first, run());
+    }
+
+    #[test]
+    fn test_total_bytes() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let root = dir.path().to_path_buf();
+        let sources = [
+            ("app.py", "import os\nimport sys\n"),
+            ("index.js", "function f() {\n  return 1;\n}\n"),
+            ("notes.txt", "not scanned\n"),
+        ];
+        for (name, source) in sources {
+            fs::write(root.join(name), source).unwrap();
+        }
+
+        let result = FoldScanner::new(ScanConfig::new(root)).unwrap().scan().unwrap();
+
+        let expected: usize = sources[..2].iter().map(|(_, source)| source.len()).sum();
+        assert_eq!(result.metadata.total_bytes, expected);
+        assert!(result.metadata.mb_per_second >= 0.0);
+    }
+
+    #[test]
+    fn test_nested_folds() {
+        use crate::models::{FoldFilter, FoldType};
+
+        let source = r#"class Service:
+    def handler(self):
+        config = {
+            "a": 1,
+            "b": 2,
+        }
+        return config
+"#;
+        let contents = HashMap::from([(PathBuf::from("service.py"), source.to_string())]);
+        let config = ScanConfig::default()
+            .with_min_fold_lines(2)
+            .with_fold_filter(FoldFilter::all());
+
+        let flat = FoldScanner::new(config.clone())
+            .unwrap()
+            .scan_with_contents(contents.clone())
+            .unwrap();
+        let nested = FoldScanner::new(config.with_nested_folds(true))
+            .unwrap()
+            .scan_with_contents(contents)
+            .unwrap();
+
+        let folds = &nested.files[0].folds;
+        assert_eq!(folds.len(), 1);
+        let class = &folds[0];
+        assert_eq!(class.fold_type, FoldType::ClassBody);
+        let method = class
+            .children
+            .iter()
+            .find(|f| f.fold_type == FoldType::Block)
+            .expect("method body nested in class");
+        assert!(method
+            .children
+            .iter()
+            .any(|f| f.fold_type == FoldType::ObjectLiteral));
+
+        // Nesting only changes the shape, not what is counted
+        assert!(flat.files[0].folds.len() > 1);
+        assert_eq!(nested.stats.total_folds, flat.stats.total_folds);
+        assert_eq!(nested.stats.foldable_lines, flat.stats.foldable_lines);
+
+        // Walking the tree visits every fold the flat scan found, parents first
+        assert_eq!(nested.iter_folds().count(), flat.iter_folds().count());
+        assert!(nested
+            .iter_folds()
+            .all(|(file, _)| file.path == Path::new("service.py")));
+        let walked: Vec<_> = nested.iter_folds().map(|(_, f)| f.start_byte).collect();
+        let mut sorted = walked.clone();
+        sorted.sort();
+        assert_eq!(walked, sorted);
+        assert_eq!(class.descendants().count(), nested.iter_folds().count() - 1);
+        assert!(class
+            .descendants()
+            .any(|f| f.fold_type == FoldType::ObjectLiteral));
+        assert!(method.descendants().all(|f| method.contains(f)));
+    }
+
+    #[test]
+    fn test_fold_type_map() {
+        use crate::models::{FoldFilter, FoldMap, FoldType};
+
+        let source = r#"# Service wiring
+# shared by every handler
+# keep in sync with routes
+class Service:
+    """Handles requests.
+
+    Stateless.
+    """
+
+    def handler(self):
+        config = {
+            "a": 1,
+            "b": 2,
+        }
+        return config
+"#;
+        let contents = HashMap::from([(PathBuf::from("service.py"), source.to_string())]);
+        let map = HashMap::from([
+            (FoldType::Block, "code".to_string()),
+            (FoldType::ClassBody, "code".to_string()),
+            (FoldType::Comment, "doc".to_string()),
+            (FoldType::DocComment, "doc".to_string()),
+        ]);
+        let config = ScanConfig::default()
+            .with_min_fold_lines(2)
+            .with_fold_filter(FoldFilter::all())
+            .with_nested_folds(true);
+
+        let plain = FoldScanner::new(config.clone())
+            .unwrap()
+            .scan_with_contents(contents.clone())
+            .unwrap();
+        let mapped = FoldScanner::new(config.with_fold_type_map(map))
+            .unwrap()
+            .scan_with_contents(contents)
+            .unwrap();
+
+        fn labels(value: &serde_json::Value, out: &mut Vec<String>) {
+            for fold in value.as_array().unwrap() {
+                let label = fold.get("label").unwrap_or(&fold["fold_type"]);
+                out.push(label.as_str().unwrap().to_string());
+                if let Some(children) = fold.get("children") {
+                    labels(children, out);
+                }
+            }
+        }
+        let json = serde_json::to_value(&mapped).unwrap();
+        let mut mapped_labels = Vec::new();
+        labels(&json["files"][0]["folds"], &mut mapped_labels);
+
+        assert!(mapped_labels.iter().any(|l| l == "code"));
+        assert!(mapped_labels.iter().any(|l| l == "doc"));
+        // Unmapped types keep their default label
+        assert!(mapped_labels.iter().any(|l| l == "object_literal"));
+        for default in ["block", "class_body", "comment", "doc_comment"] {
+            assert!(!mapped_labels.iter().any(|l| l == default), "{} not relabeled", default);
+        }
+
+        // Internal fold types and statistics are unchanged
+        assert_eq!(mapped.stats.total_folds, plain.stats.total_folds);
+        assert_eq!(mapped.stats.block_folds, plain.stats.block_folds);
+        assert_eq!(mapped.files[0].folds[0].fold_type, plain.files[0].folds[0].fold_type);
+
+        // Labeled output parses back with both the type and the label intact
+        let parsed: FoldMap = serde_json::from_value(json).unwrap();
+        assert_eq!(parsed.files[0].folds[0].fold_type, mapped.files[0].folds[0].fold_type);
+        assert_eq!(parsed.files[0].folds[0].label, mapped.files[0].folds[0].label);
+        assert!(plain.files[0].folds.iter().all(|f| f.label.is_none()));
+    }
+
+    #[test]
+    fn test_scan_source() {
+        use crate::models::{FoldFilter, FoldType};
+
+        // No file exists at this path and the extension is ignored
+        let path = PathBuf::from("buffers/untitled-1");
+        let source = r#"import os
+import sys
+
+def short():
+    pass
+
+def long():
+    a = 1
+    b = 2
+    return a + b
+"#;
+        let config = ScanConfig::default()
+            .with_min_fold_lines(3)
+            .with_fold_filter(FoldFilter::from_type_names("block"));
+
+        let file = FoldScanner::new(config)
+            .unwrap()
+            .scan_source(source, Language::Python, path.clone())
+            .unwrap();
+        assert_eq!(file.path, path);
+        assert_eq!(file.language, Language::Python);
+        assert_eq!(file.line_count, 10);
+        assert!(file.parsed);
+        assert!(file.error.is_none());
+
+        // Only the Block filter applies, and `short` is under min_fold_lines
+        assert_eq!(file.folds.len(), 1);
+        assert_eq!(file.folds[0].fold_type, FoldType::Block);
+        assert_eq!(file.folds[0].start_line, 8);
+    }
+
+    #[test]
+    fn test_per_language_min_lines() {
+        use crate::models::{FoldFilter, FoldType};
+
+        let python = "def short():\n    pass\ndef medium():\n    x = 1\n    y = 2\n    z = 3\n    return x\n";
+        let javascript = "function medium() {\n  a();\n  b();\n}\n\n\
+            function long() {\n  a();\n  b();\n  c();\n  d();\n  e();\n  f();\n}\n";
+
+        let config = ScanConfig::default()
+            .with_min_fold_lines(20)
+            .with_per_language_min_lines(HashMap::from([
+                (Language::Python, 3),
+                (Language::JavaScript, 6),
+            ]))
+            .with_fold_filter(FoldFilter::from_type_names("block"));
+        let scanner = FoldScanner::new(config).unwrap();
+
+        // A 4-line body passes Python's threshold of 3
+        let py = scanner
+            .scan_source(python, Language::Python, PathBuf::from("app.py"))
+            .unwrap();
+        assert_eq!(py.folds.len(), 1);
+        assert_eq!(py.folds[0].fold_type, FoldType::Block);
+        assert_eq!(py.folds[0].start_line, 4);
+
+        // ...but the same size is under JavaScript's threshold of 6
+        let js = scanner
+            .scan_source(javascript, Language::JavaScript, PathBuf::from("app.js"))
+            .unwrap();
+        assert_eq!(js.folds.len(), 1);
+        assert_eq!(js.folds[0].start_line, 6);
+
+        // Languages without an override keep the global value
+        let ts = scanner
+            .scan_source(javascript, Language::TypeScript, PathBuf::from("app.ts"))
+            .unwrap();
+        assert!(ts.folds.is_empty());
+
+        // Part of the cache key, regardless of map iteration order
+        let per_language = |entries: Vec<(Language, usize)>| {
+            ScanConfig::default()
+                .with_per_language_min_lines(entries.into_iter().collect())
+                .fold_config_hash()
+        };
+        assert_ne!(per_language(vec![(Language::Python, 3)]), ScanConfig::default().fold_config_hash());
+        assert_eq!(
+            per_language(vec![(Language::Python, 3), (Language::Rust, 8)]),
+            per_language(vec![(Language::Rust, 8), (Language::Python, 3)])
+        );
+    }
+
+    #[test]
+    fn test_line_index() {
+        use crate::models::FoldFilter;
+
+        let source = "import os\nimport sys\nimport json\nimport re\n\n\
+            class Store:\n    def load(self):\n        a = 1\n        b = 2\n        c = 3\n        return a\n";
+        let contents = || HashMap::from([(PathBuf::from("store.py"), source.to_string())]);
+
+        let config = ScanConfig::default()
+            .with_fold_filter(FoldFilter::from_type_names("import,class,block"))
+            .with_nested_folds(true)
+            .with_line_index(true);
+        let result = FoldScanner::new(config).unwrap().scan_with_contents(contents()).unwrap();
+        let file = &result.files[0];
+
+        let folds: Vec<&FoldRegion> = result.iter_folds().map(|(_, fold)| fold).collect();
+        assert!(folds.len() >= 3);
+        // Folds sharing a start line collapse into one entry, the outermost fold
+        for fold in &folds {
+            let entry = &file.line_index[&fold.start_line];
+            let outer = folds.iter().find(|f| f.start_line == fold.start_line).unwrap();
+            assert_eq!(entry.fold_type, outer.fold_type);
+            assert_eq!(entry.end_line, outer.end_line);
+            assert_eq!(entry.preview, outer.preview);
+        }
+        let lines: Vec<usize> = file.line_index.keys().copied().collect();
+        assert!(lines.is_sorted());
+
+        let plain = FoldScanner::new(ScanConfig::default())
+            .unwrap()
+            .scan_with_contents(contents())
+            .unwrap();
+        assert!(plain.files[0].line_index.is_empty());
+    }
+
+    #[test]
+    fn test_fold_order() {
+        use crate::models::{FoldFilter, FoldOrder, FoldType};
+
+        // `parameters` is visited after its function's body fold is pushed, but starts first
+        let source = "class Service:\n    def start(\n        self,\n        port,\n    ):\n        \
+            a = 1\n        b = 2\n        c = 3\n        return a\n\n    def stop(self):\n        \
+            a = 1\n        b = 2\n        c = 3\n        return a\n";
+        let order = |fold_order: FoldOrder| {
+            let config = ScanConfig::default()
+                .with_fold_filter(FoldFilter::from_type_names("class,block,arglist"))
+                .with_fold_order(fold_order);
+            let file = FoldScanner::new(config)
+                .unwrap()
+                .scan_source(source, Language::Python, PathBuf::from("service.py"))
+                .unwrap();
+            file.folds
+                .iter()
+                .map(|f| (f.fold_type.clone(), f.start_line))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            order(FoldOrder::Source),
+            vec![
+                (FoldType::ClassBody, 2),
+                (FoldType::ArgList, 2),
+                (FoldType::Block, 6),
+                (FoldType::Block, 12),
+            ]
+        );
+        assert_eq!(
+            order(FoldOrder::Discovery),
+            vec![
+                (FoldType::ClassBody, 2),
+                (FoldType::Block, 6),
+                (FoldType::ArgList, 2),
+                (FoldType::Block, 12),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_density_histogram() {
+        use crate::models::{density_histogram, FoldFilter};
+
+        let config = ScanConfig::default().with_fold_filter(FoldFilter::from_type_names("block"));
+        let scanner = FoldScanner::new(config).unwrap();
+
+        // 4 of 5 lines fold: 80%, which lands in the top bucket
+        let dense = scanner
+            .scan_source(
+                "def f():\n    a = 1\n    b = 2\n    c = 3\n    return a\n",
+                Language::Python,
+                PathBuf::from("dense.py"),
+            )
+            .unwrap();
+        let flat = scanner
+            .scan_source("x = 1\ny = 2\n", Language::Python, PathBuf::from("flat.py"))
+            .unwrap();
+        let mut empty = flat.clone();
+        empty.line_count = 0;
+
+        assert_eq!(dense.foldable_lines(), 4);
+        assert_eq!(
+            density_histogram([&dense, &flat, &empty], 5),
+            vec![(0.0, 0.2, 1), (0.2, 0.4, 0), (0.4, 0.6, 0), (0.6, 0.8, 0), (0.8, 1.0, 1)]
+        );
+    }
+
+    #[test]
+    fn test_suppressions() {
+        use crate::models::SuppressionKind;
+
+        let contents = HashMap::from([
+            (
+                PathBuf::from("app.py"),
+                "import legacy  # type: ignore[import]\n# type: string\nx = 1\n".to_string(),
+            ),
+            (
+                PathBuf::from("view.ts"),
+                "// @ts-ignore\nconst a: number = 'a';\n\n/* @ts-expect-error */\nfoo();\n"
+                    .to_string(),
+            ),
+        ]);
+        let config = ScanConfig::default().with_suppressions(true);
+
+        let result = FoldScanner::new(config)
+            .unwrap()
+            .scan_with_contents(contents.clone())
+            .unwrap();
+        let found: Vec<(String, usize, SuppressionKind)> = result
+            .suppressions()
+            .map(|(file, s)| (file.path.display().to_string(), s.line, s.kind))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                ("app.py".to_string(), 1, SuppressionKind::TypeIgnore),
+                ("view.ts".to_string(), 1, SuppressionKind::TsIgnore),
+                ("view.ts".to_string(), 4, SuppressionKind::TsExpectError),
+            ]
+        );
+
+        // Off by default
+        let plain = FoldScanner::new(ScanConfig::default())
+            .unwrap()
+            .scan_with_contents(contents)
+            .unwrap();
+        assert_eq!(plain.suppressions().count(), 0);
+    }
+
+    #[test]
+    fn test_dedup_overlaps() {
+        use crate::models::{FoldRegion, FoldType, OverlapPolicy};
+
+        // A chain and an arglist sharing bytes 40..60, plus a literal nested in the chain
+        let folds = vec![
+            FoldRegion::new(FoldType::ChainedCall, 10, 60, 2, 6, 4, 10),
+            FoldRegion::new(FoldType::Literal, 12, 20, 2, 3, 6, 2),
+            FoldRegion::new(FoldType::ArgList, 40, 80, 5, 9, 8, 5),
+        ];
+        let types = |folds: Vec<FoldRegion>| -> Vec<FoldType> {
+            folds.into_iter().map(|f| f.fold_type).collect()
+        };
+
+        assert_eq!(OverlapPolicy::default().resolve(folds.clone()).len(), 3);
+        assert_eq!(
+            types(OverlapPolicy::Larger.resolve(folds.clone())),
+            vec![FoldType::ChainedCall, FoldType::Literal]
+        );
+        assert_eq!(
+            types(OverlapPolicy::TypePriority(vec![FoldType::ArgList]).resolve(folds)),
+            vec![FoldType::Literal, FoldType::ArgList]
+        );
+
+        // Wired into the scan through ScanConfig and part of the cache key
+        let config = ScanConfig::default().with_dedup_overlaps(OverlapPolicy::Larger);
+        assert_ne!(config.fold_config_hash(), ScanConfig::default().fold_config_hash());
+    }
+
+    #[test]
+    fn test_folds_to_hide_except() {
+        use crate::models::{FoldFilter, FoldRegion, FoldType};
+
+        // `Before` spans lines 1-8; `Around` has methods at lines 11, 15, 19, 23 and 27
+        let mut source = String::from(
+            "class Before:\n    def a(self):\n        x = 1\n        return x\n\n    \
+             def b(self):\n        y = 2\n        return y\n\nclass Around:\n",
+        );
+        for i in 0..5 {
+            source.push_str(&format!(
+                "    def m{}(self):\n        z = {}\n        return z\n\n",
+                i, i
+            ));
+        }
+        let contents = HashMap::from([(PathBuf::from("sections.py"), source)]);
+        // Class bodies are not in the default fold set
+        let config = ScanConfig::default()
+            .with_min_fold_lines(2)
+            .with_fold_filter(FoldFilter::from_type_names("block,class"));
+        let result = FoldScanner::new(config).unwrap().scan_with_contents(contents).unwrap();
+        let file = &result.files[0];
+
+        let keep = 20..26;
+        let overlaps_keep = |f: &FoldRegion| f.end_line >= keep.start && f.start_line < keep.end;
+        let hidden = file.folds_to_hide_except(keep.clone());
+
+        assert!(file.folds.iter().any(&overlaps_keep));
+        assert!(hidden.iter().all(|f| !overlaps_keep(f)));
+        // Every fold outside the kept lines is hidden by exactly one selected fold
+        for fold in file.folds.iter().filter(|f| !overlaps_keep(f)) {
+            assert_eq!(hidden.iter().filter(|h| h.contains(fold)).count(), 1);
+        }
+        // `Before` folds as a whole rather than method by method
+        let classes: Vec<_> = hidden.iter().filter(|f| f.fold_type == FoldType::ClassBody).collect();
+        assert_eq!(classes.len(), 1);
+        assert!(classes[0].end_line < 10);
+        assert_eq!(hidden.len(), 4);
+    }
+
+    #[test]
+    fn test_entry_points() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let root = dir.path().to_path_buf();
+        fs::write(
+            root.join("cli.py"),
+            "def run():\n    pass\n\nif __name__ == \"__main__\":\n    run()\n",
+        )
+        .unwrap();
+        fs::write(root.join("helpers.py"), "def helper():\n    pass\n").unwrap();
+        fs::create_dir(root.join("lib")).unwrap();
+        fs::write(root.join("lib").join("server.js"), "module.exports = {};\n").unwrap();
+        fs::write(root.join("lib").join("routes.js"), "module.exports = [];\n").unwrap();
+        fs::write(
+            root.join("package.json"),
+            r#"{ "name": "app", "main": "./lib/server.js" }"#,
+        )
+        .unwrap();
+
+        let result = FoldScanner::new(ScanConfig::new(root)).unwrap().scan().unwrap();
+
+        assert_eq!(
+            result.entry_points(),
+            vec![PathBuf::from("cli.py"), PathBuf::from("lib/server.js")]
+        );
+    }
+
+    #[test]
+    fn test_grouping_consistency() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let root = dir.path().to_path_buf();
+        fs::write(
+            root.join("app.py"),
+            "import os\nimport sys\n\ndef f():\n    pass\n    pass\n",
+        )
+        .unwrap();
+        fs::write(root.join("index.js"), "import fs from 'fs';\nimport path from 'path';\n")
+            .unwrap();
+        fs::write(root.join("types.ts"), "function g() {\n  return 1;\n}\n").unwrap();
+
+        let mut result = FoldScanner::new(ScanConfig::new(root).with_min_fold_lines(2))
+            .unwrap()
+            .scan()
+            .unwrap();
+        assert!(result.stats.total_folds > 0);
+        assert_eq!(result.verify_grouping_consistency(), Ok(()));
+
+        // Drift in the flat stats is reported field by field
+        result.stats.import_folds += 1;
+        let err = result.verify_grouping_consistency().unwrap_err();
+        assert!(err.contains("import_folds"));
+        assert!(!err.contains("block_folds"));
+    }
+
+    #[test]
+    fn test_scan_streaming_envelope() {
+        use crate::models::StreamRecord;
+        use crate::output::write_stream_record;
+
+        let dir = tempfile::TempDir::new().unwrap();
+        let root = dir.path().to_path_buf();
+        fs::write(root.join("a.py"), "import os\nimport sys\n").unwrap();
+        fs::write(root.join("b.js"), "import fs from 'fs';\nimport path from 'path';\n").unwrap();
+        fs::write(root.join("c.py"), "def f():\n    pass\n").unwrap();
+
+        let config = ScanConfig::new(root.clone()).with_threads(2);
+        let scanner = FoldScanner::new(config).unwrap();
+
+        let mut out = Vec::new();
+        write_stream_record(&mut out, &StreamRecord::Header { root: &root, metadata_pending: true }).unwrap();
+        let result = scanner
+            .scan_streaming(|file| {
+                write_stream_record(&mut out, &StreamRecord::File(file)).map_err(std::io::Error::other)
+            })
+            .unwrap();
+        write_stream_record(
+            &mut out,
+            &StreamRecord::Summary { stats: &result.stats, metadata: &result.metadata },
+        )
+        .unwrap();
+
+        let records: Vec<serde_json::Value> = String::from_utf8(out)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(records.len(), 5);
+        assert_eq!(records[0]["type"], "header");
+        assert_eq!(records[0]["metadata_pending"], true);
+        assert!(records[1..4].iter().all(|r| r["type"] == "file" && r["path"].is_string()));
+        assert_eq!(records[4]["type"], "summary");
+        assert_eq!(records[4]["stats"]["total_files"], 3);
+        assert_eq!(result.files.len(), 3);
+    }
+
+    #[test]
+    fn test_incremental_cache() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let root = dir.path().join("src");
+        fs::create_dir(&root).unwrap();
+        let cache_path = dir.path().join("cache").join("folds.json");
+        fs::write(root.join("a.py"), "def f():\n    x = 1\n    y = 2\n    return x + y\n").unwrap();
+        fs::write(root.join("b.js"), "function g() {\n  let a = 1;\n  let b = 2;\n  return a;\n}\n").unwrap();
+
+        let config = || ScanConfig::new(root.clone()).with_threads(1).with_cache(cache_path.clone());
+        let scan = |config: ScanConfig| FoldScanner::new(config).unwrap().scan().unwrap();
+
+        let first = scan(config());
+        assert_eq!(first.metadata.cache_hits, Some(0));
+        assert_eq!(first.metadata.cache_misses, Some(2));
+        assert!(cache_path.exists());
+
+        let second = scan(config());
+        assert_eq!(second.metadata.cache_hits, Some(2));
+        assert_eq!(second.metadata.cache_misses, Some(0));
+        assert_eq!(
+            serde_json::to_value(&first.files).unwrap(),
+            serde_json::to_value(&second.files).unwrap()
+        );
+
+        // The line index is not cached; it is rebuilt for files served from the cache
+        let indexed = scan(config().with_line_index(true));
+        assert_eq!(indexed.metadata.cache_hits, Some(2));
+        assert!(indexed.files.iter().any(|file| !file.line_index.is_empty()));
+        assert!(indexed.files.iter().all(|file| file.line_index == file.build_line_index()));
+
+        // A changed file is re-parsed; the other is still served from the cache
+        fs::write(root.join("a.py"), "def f():\n    return 1\n").unwrap();
+        let third = scan(config());
+        assert_eq!(third.metadata.cache_hits, Some(1));
+        assert_eq!(third.metadata.cache_misses, Some(1));
+
+        // Fold-affecting config changes invalidate the whole cache
+        let fourth = scan(config().with_min_fold_lines(2));
+        assert_eq!(fourth.metadata.cache_hits, Some(0));
+        assert_eq!(fourth.metadata.cache_misses, Some(2));
+
+        // Without a cache the metadata carries no counts
+        let plain = scan(ScanConfig::new(root.clone()));
+        assert_eq!(plain.metadata.cache_hits, None);
+    }
+
+    #[test]
+    fn test_max_output_files() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let root = dir.path().to_path_buf();
+        for name in ["a.py", "b.py", "c.py", "d.py"] {
+            fs::write(root.join(name), "def f():\n    pass\n").unwrap();
+        }
+
+        let config = ScanConfig::new(root.clone()).with_threads(1);
+        let result = FoldScanner::new(config.clone().with_max_output_files(2))
+            .unwrap()
+            .scan()
+            .unwrap();
+        assert_eq!(result.files.len(), 2);
+        assert!(result.metadata.truncated);
+        assert_eq!(result.metadata.total_files_before_truncation, Some(4));
+        assert_eq!(result.stats.total_files, 4);
+        assert_eq!(result.stats.python_files, 4);
+
+        let json = serde_json::to_value(result.to_grouped()).unwrap();
+        assert_eq!(json["metadata"]["truncated"], true);
+
+        let result = FoldScanner::new(config.with_max_output_files(4))
+            .unwrap()
+            .scan()
+            .unwrap();
+        assert_eq!(result.files.len(), 4);
+        assert!(!result.metadata.truncated);
+        assert_eq!(result.metadata.total_files_before_truncation, None);
+    }
 }
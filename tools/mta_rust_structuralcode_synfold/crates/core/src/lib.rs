@@ -1,16 +1,19 @@
 //! Synfold Core Library
 //!
 //! A structural code folding library using Tree-sitter AST analysis.
-//! Supports Python and Node.js (JavaScript/TypeScript) applications.
+//! Supports Python, Node.js (JavaScript/TypeScript), C#, Rust and Scala applications.
 //!
 //! # Features
 //!
 //! - Parse Python code to identify foldable regions (functions, classes, imports, etc.)
 //! - Parse JavaScript/TypeScript code with full ES6+ and TypeScript support
+//! - Parse C# classes, members, namespaces and using directives
+//! - Parse Rust functions, impl/struct/enum bodies, use blocks and macro bodies
+//! - Parse Scala class/object/trait bodies, method bodies and import blocks
 //! - Intelligent folding based on syntax structure, not line-based heuristics
 //! - Configurable minimum fold lines and fold type filters
 //! - Output in JSON, YAML, or ANSI-colored terminal format
-//! - Grouped output by language (python/nodejs)
+//! - Grouped output by language (python/nodejs/csharp/rust/scala)
 //!
 //! # Example
 //!
@@ -33,8 +36,18 @@ pub mod output;
 pub mod parsers;
 
 // Re-exports for convenience
-pub use config::ScanConfig;
-pub use engine::{render_file, render_file_ansi, FoldScanner, Renderer, ScanError};
+pub use config::{EditorConfigDefaults, ScanConfig, DEFAULT_ARTIFACT_SUFFIXES};
+pub use engine::{
+    compute_fold_ranges, render_file, render_file_ansi, FoldScanner, QueryCache, Renderer,
+    ScanError,
+};
 pub use models::*;
-pub use output::{format_output, format_output_grouped, format_summary, FormatError, OutputFormat};
-pub use parsers::{create_parser, FoldParser, ParserError};
+pub use output::{
+    format_entry_points, format_output, format_output_grouped, format_output_grouped_with_config,
+    format_output_grouped_with_indent, format_output_with_config, format_output_with_indent,
+    format_summary, format_suppressions, format_vscode_folding_ranges, lsp_folding_ranges,
+    to_html_diff, to_json_with_indent, to_lsp_folding_ranges, to_toml, write_run_summary,
+    write_stream_record, FormatError, IndentStyle, LspFoldingRange, OutputFormat,
+    VsCodeFoldingRange,
+};
+pub use parsers::{create_parser, tree_sitter_language, FoldParser, ParserError};
@@ -1,5 +1,8 @@
+use crate::output::{lsp_kind, VsCodeFoldingRange};
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::ops::Range;
+use std::path::{Path, PathBuf};
 
 /// Type of foldable code region
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -25,9 +28,32 @@ pub enum FoldType {
     ArrayLiteral,
     /// Object/dict literals
     ObjectLiteral,
+    /// `# region` ... `# endregion` pragma comments
+    Region,
+    /// Multi-line JSX/TSX elements (`<div>` ... `</div>`)
+    JsxElement,
+    /// Python `@property` getter grouped with its setter/deleter
+    Property,
 }
 
 impl FoldType {
+    /// Every fold type, in declaration order
+    pub const ALL: [FoldType; 13] = [
+        FoldType::Block,
+        FoldType::Import,
+        FoldType::ArgList,
+        FoldType::ChainedCall,
+        FoldType::Literal,
+        FoldType::Comment,
+        FoldType::DocComment,
+        FoldType::ClassBody,
+        FoldType::ArrayLiteral,
+        FoldType::ObjectLiteral,
+        FoldType::Region,
+        FoldType::JsxElement,
+        FoldType::Property,
+    ];
+
     pub fn as_str(&self) -> &'static str {
         match self {
             FoldType::Block => "block",
@@ -40,6 +66,9 @@ impl FoldType {
             FoldType::ClassBody => "class",
             FoldType::ArrayLiteral => "array",
             FoldType::ObjectLiteral => "object",
+            FoldType::Region => "region",
+            FoldType::JsxElement => "jsx",
+            FoldType::Property => "property",
         }
     }
 }
@@ -57,6 +86,117 @@ pub enum PreviewMode {
     Flow,
     /// First N chars of actual source code
     Source,
+    /// First and last line of bodies and multi-line strings: "def foo(): x = 1 … return x"
+    Context,
+}
+
+/// Where a function's block fold begins
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FoldMode {
+    /// Fold the whole body, leaving only the signature visible (default)
+    #[default]
+    Body,
+    /// Start the fold after a leading docstring, so the signature and the
+    /// docstring stay visible
+    SignatureAndDoc,
+}
+
+/// Order of the folds listed for each file
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FoldOrder {
+    /// By start position, outer folds before the folds they contain (default)
+    #[default]
+    Source,
+    /// The order the parser's tree walk found them in, for debugging fold discovery
+    Discovery,
+}
+
+impl FoldOrder {
+    /// Reorder folds (and nested children) by this order
+    ///
+    /// Folds are produced in source order, so `Source` leaves them untouched.
+    pub fn apply(&self, folds: &mut [FoldRegion]) {
+        if *self == FoldOrder::Discovery {
+            folds.sort_by_key(|f| f.discovery_index);
+            for fold in folds.iter_mut() {
+                self.apply(&mut fold.children);
+            }
+        }
+    }
+}
+
+/// Which fold survives when two folds overlap without one containing the other
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum OverlapPolicy {
+    /// Emit both folds (default)
+    #[default]
+    KeepBoth,
+    /// Keep the fold whose type comes first in the list (unlisted types rank last);
+    /// equal ranks fall back to the larger fold
+    TypePriority(Vec<FoldType>),
+    /// Keep the fold spanning more bytes; on a tie the earlier fold wins
+    Larger,
+}
+
+impl OverlapPolicy {
+    /// Drop the losing side of every partial overlap in a flat fold list
+    ///
+    /// Nested folds (one range inside the other) are never touched. A fold is
+    /// kept only if it beats every already-kept fold it partially overlaps.
+    pub fn resolve(&self, folds: Vec<FoldRegion>) -> Vec<FoldRegion> {
+        if *self == OverlapPolicy::KeepBoth {
+            return folds;
+        }
+
+        let mut kept: Vec<FoldRegion> = Vec::with_capacity(folds.len());
+        for fold in folds {
+            let rivals: Vec<usize> = kept
+                .iter()
+                .enumerate()
+                .filter(|(_, k)| partially_overlap(k, &fold))
+                .map(|(i, _)| i)
+                .collect();
+
+            if rivals.iter().all(|&i| self.prefers(&fold, &kept[i])) {
+                for i in rivals.into_iter().rev() {
+                    kept.remove(i);
+                }
+                kept.push(fold);
+            }
+        }
+
+        kept.sort_by_key(|f| (f.start_byte, std::cmp::Reverse(f.end_byte)));
+        kept
+    }
+
+    /// Whether `challenger` should replace the already-kept `incumbent`
+    fn prefers(&self, challenger: &FoldRegion, incumbent: &FoldRegion) -> bool {
+        let size = |f: &FoldRegion| f.end_byte - f.start_byte;
+        match self {
+            OverlapPolicy::KeepBoth => true,
+            OverlapPolicy::Larger => size(challenger) > size(incumbent),
+            OverlapPolicy::TypePriority(order) => {
+                let rank = |f: &FoldRegion| {
+                    order.iter().position(|t| *t == f.fold_type).unwrap_or(order.len())
+                };
+                match rank(challenger).cmp(&rank(incumbent)) {
+                    std::cmp::Ordering::Less => true,
+                    std::cmp::Ordering::Greater => false,
+                    std::cmp::Ordering::Equal => size(challenger) > size(incumbent),
+                }
+            }
+        }
+    }
+}
+
+/// Whether two folds share bytes without either containing the other
+fn partially_overlap(a: &FoldRegion, b: &FoldRegion) -> bool {
+    let shares_bytes = a.start_byte < b.end_byte && b.start_byte < a.end_byte;
+    let nested = (a.start_byte <= b.start_byte && b.end_byte <= a.end_byte)
+        || (b.start_byte <= a.start_byte && a.end_byte <= b.end_byte);
+    shares_bytes && !nested
 }
 
 impl PreviewMode {
@@ -66,6 +206,7 @@ impl PreviewMode {
             PreviewMode::Names => "names",
             PreviewMode::Flow => "flow",
             PreviewMode::Source => "source",
+            PreviewMode::Context => "context",
         }
     }
 }
@@ -77,6 +218,11 @@ pub enum Language {
     Python,
     JavaScript,
     TypeScript,
+    CSharp,
+    Rust,
+    Scala,
+    /// Markdown documents; only fenced code blocks are analyzed
+    Markdown,
 }
 
 impl Language {
@@ -85,6 +231,23 @@ impl Language {
             "py" | "pyi" => Some(Language::Python),
             "js" | "mjs" | "cjs" | "jsx" => Some(Language::JavaScript),
             "ts" | "mts" | "cts" | "tsx" => Some(Language::TypeScript),
+            "cs" => Some(Language::CSharp),
+            "rs" => Some(Language::Rust),
+            "scala" | "sc" => Some(Language::Scala),
+            "md" | "markdown" => Some(Language::Markdown),
+            _ => None,
+        }
+    }
+
+    /// Map a fenced code block info string (```` ```py ````) to a parseable language
+    pub fn from_fence_info(info: &str) -> Option<Self> {
+        match info.to_lowercase().as_str() {
+            "python" | "py" | "python3" => Some(Language::Python),
+            "javascript" | "js" | "jsx" | "mjs" | "cjs" | "node" => Some(Language::JavaScript),
+            "typescript" | "ts" | "tsx" => Some(Language::TypeScript),
+            "csharp" | "cs" | "c#" => Some(Language::CSharp),
+            "rust" | "rs" => Some(Language::Rust),
+            "scala" | "sc" => Some(Language::Scala),
             _ => None,
         }
     }
@@ -94,6 +257,10 @@ impl Language {
             Language::Python => "python",
             Language::JavaScript => "javascript",
             Language::TypeScript => "typescript",
+            Language::CSharp => "csharp",
+            Language::Rust => "rust",
+            Language::Scala => "scala",
+            Language::Markdown => "markdown",
         }
     }
 }
@@ -117,15 +284,36 @@ pub struct FoldRegion {
     pub end_column: usize,
     /// Number of lines spanned
     pub line_count: usize,
+    /// Nesting depth among the file's folds (0 = top-level)
+    #[serde(default)]
+    pub depth: usize,
     /// Preview text (first N chars or signature)
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub preview: Option<String>,
     /// Whether this fold is currently applied
     #[serde(default)]
     pub is_folded: bool,
+    /// Whether any line in the fold's range is blank (whitespace only)
+    #[serde(default)]
+    pub has_blank_lines: bool,
     /// Nested folds within this region
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub children: Vec<FoldRegion>,
+    /// Language of the fenced code block this fold was found in (Markdown only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub embedded_language: Option<Language>,
+    /// First content line of that fenced code block (Markdown only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub block_start_line: Option<usize>,
+    /// Consumer label for `fold_type` (set from `ScanConfig::fold_type_map`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    /// Most recent commit date (RFC 3339) of the lines in this fold (`ScanConfig::with_blame`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_modified: Option<String>,
+    /// Position in the parser's traversal, before sorting (see `FoldOrder::Discovery`)
+    #[serde(skip)]
+    pub discovery_index: usize,
 }
 
 impl FoldRegion {
@@ -153,10 +341,51 @@ impl FoldRegion {
             start_column,
             end_column,
             line_count,
+            depth: 0,
             preview: None,
             is_folded: false,
+            has_blank_lines: false,
             children: Vec::new(),
+            embedded_language: None,
+            block_start_line: None,
+            label: None,
+            last_modified: None,
+            discovery_index: 0,
+        }
+    }
+
+    /// Nest a flat fold list into a tree
+    ///
+    /// Folds are sorted by `(start_byte, -end_byte)` and each one becomes a child
+    /// of the innermost fold that contains it, so collapsing a class fold also
+    /// covers its methods and their nested literals.
+    pub fn nest(mut folds: Vec<FoldRegion>) -> Vec<FoldRegion> {
+        /// Attach a finished fold to the innermost open fold, or to the roots
+        fn attach(open: &mut [FoldRegion], roots: &mut Vec<FoldRegion>, fold: FoldRegion) {
+            match open.last_mut() {
+                Some(parent) => parent.children.push(fold),
+                None => roots.push(fold),
+            }
         }
+
+        folds.sort_by_key(|f| (f.start_byte, std::cmp::Reverse(f.end_byte)));
+
+        let mut roots = Vec::new();
+        // Folds whose range may still contain upcoming folds, outermost first
+        let mut open: Vec<FoldRegion> = Vec::new();
+        for fold in folds {
+            while open.last().is_some_and(|parent| !parent.contains(&fold)) {
+                if let Some(done) = open.pop() {
+                    attach(&mut open, &mut roots, done);
+                }
+            }
+            open.push(fold);
+        }
+        while let Some(done) = open.pop() {
+            attach(&mut open, &mut roots, done);
+        }
+
+        roots
     }
 
     /// Check if this region contains another
@@ -168,6 +397,102 @@ impl FoldRegion {
     pub fn overlaps(&self, other: &FoldRegion) -> bool {
         self.start_byte < other.end_byte && self.end_byte > other.start_byte
     }
+
+    /// Every fold nested under this one, depth-first in source order (excluding `self`)
+    pub fn descendants(&self) -> impl Iterator<Item = &FoldRegion> {
+        FoldWalk::new(&self.children)
+    }
+}
+
+/// Pre-order walk over a fold forest and all nested `children`
+struct FoldWalk<'a> {
+    /// Folds still to visit, next on top
+    stack: Vec<&'a FoldRegion>,
+}
+
+impl<'a> FoldWalk<'a> {
+    fn new(roots: &'a [FoldRegion]) -> Self {
+        Self {
+            stack: roots.iter().rev().collect(),
+        }
+    }
+}
+
+impl<'a> Iterator for FoldWalk<'a> {
+    type Item = &'a FoldRegion;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let fold = self.stack.pop()?;
+        self.stack.extend(fold.children.iter().rev());
+        Some(fold)
+    }
+}
+
+/// Span of source hidden when a fold is applied, in both byte and line space
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FoldRange {
+    /// Start byte offset (inclusive)
+    pub start_byte: usize,
+    /// End byte offset (exclusive)
+    pub end_byte: usize,
+    /// Start line (1-indexed)
+    pub start_line: usize,
+    /// End line (1-indexed, inclusive)
+    pub end_line: usize,
+}
+
+impl FoldRange {
+    /// `(start_byte, end_byte)`
+    pub fn bytes(&self) -> (usize, usize) {
+        (self.start_byte, self.end_byte)
+    }
+
+    /// `(start_line, end_line)`
+    pub fn lines(&self) -> (usize, usize) {
+        (self.start_line, self.end_line)
+    }
+}
+
+impl From<&FoldRegion> for FoldRange {
+    fn from(fold: &FoldRegion) -> Self {
+        Self {
+            start_byte: fold.start_byte,
+            end_byte: fold.end_byte,
+            start_line: fold.start_line,
+            end_line: fold.end_line,
+        }
+    }
+}
+
+/// Kind of type-checker suppression comment
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SuppressionKind {
+    /// `// @ts-ignore`
+    TsIgnore,
+    /// `// @ts-expect-error`
+    TsExpectError,
+    /// `# type: ignore` (optionally with `[codes]`)
+    TypeIgnore,
+}
+
+impl SuppressionKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SuppressionKind::TsIgnore => "@ts-ignore",
+            SuppressionKind::TsExpectError => "@ts-expect-error",
+            SuppressionKind::TypeIgnore => "type: ignore",
+        }
+    }
+}
+
+/// A type-checker suppression comment found in a file
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Suppression {
+    /// Line of the comment (1-indexed)
+    pub line: usize,
+    /// Which suppression the comment contains
+    pub kind: SuppressionKind,
 }
 
 /// A source file with its fold regions
@@ -188,6 +513,96 @@ pub struct SourceFile {
     /// Parse error message if any
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub error: Option<String>,
+    /// Count of each tree-sitter node kind (only with node histograms enabled)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub node_kinds: BTreeMap<String, usize>,
+    /// Size of the source text in bytes
+    #[serde(default)]
+    pub byte_count: usize,
+    /// Python file with a top-level `if __name__ == "__main__":` guard
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub main_guard: bool,
+    /// Type-checker suppression comments (only with suppression detection enabled)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub suppressions: Vec<Suppression>,
+    /// Fold-start line -> fold, for constant-time lookups (only with line indexing enabled)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub line_index: BTreeMap<usize, FoldRef>,
+}
+
+/// Compact reference to a fold, keyed by its start line in `SourceFile::line_index`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FoldRef {
+    /// Type of fold
+    pub fold_type: FoldType,
+    /// End line (1-indexed)
+    pub end_line: usize,
+    /// Preview text (first N chars or signature)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub preview: Option<String>,
+}
+
+impl SourceFile {
+    /// Lines covered by this file's folds, nested `children` included
+    ///
+    /// Matches how `foldable_lines` is counted in the scan stats, so nested
+    /// folds count their lines again.
+    pub fn foldable_lines(&self) -> usize {
+        FoldWalk::new(&self.folds).map(|fold| fold.line_count).sum()
+    }
+
+    /// Map each fold's start line to a [`FoldRef`], nested folds included
+    ///
+    /// When several folds start on the same line the outermost one wins.
+    pub fn build_line_index(&self) -> BTreeMap<usize, FoldRef> {
+        let mut index = BTreeMap::new();
+        for fold in FoldWalk::new(&self.folds) {
+            index.entry(fold.start_line).or_insert_with(|| FoldRef {
+                fold_type: fold.fold_type.clone(),
+                end_line: fold.end_line,
+                preview: fold.preview.clone(),
+            });
+        }
+        index
+    }
+
+    /// The file's folds (including nested children) as VS Code folding ranges
+    pub fn to_vscode_folding_ranges(&self) -> Vec<VsCodeFoldingRange> {
+        FoldWalk::new(&self.folds)
+            .map(|fold| VsCodeFoldingRange {
+                start: fold.start_line.saturating_sub(1),
+                end: fold.end_line.saturating_sub(1),
+                kind: lsp_kind(&fold.fold_type),
+            })
+            .collect()
+    }
+
+    /// Minimal set of folds that hides everything foldable outside `keep`
+    ///
+    /// `keep` is a half-open range of 1-indexed lines (`20..26` keeps lines 20-25
+    /// visible). Folds touching it are skipped; of the rest, larger folds win and
+    /// any fold inside an already selected one is dropped. Returned in source order.
+    pub fn folds_to_hide_except(&self, keep: Range<usize>) -> Vec<&FoldRegion> {
+        let mut candidates: Vec<&FoldRegion> = FoldWalk::new(&self.folds)
+            .filter(|fold| fold.end_line < keep.start || fold.start_line >= keep.end)
+            .collect();
+        candidates.sort_by_key(|fold| {
+            (
+                std::cmp::Reverse(fold.line_count),
+                std::cmp::Reverse(fold.end_byte - fold.start_byte),
+                fold.start_byte,
+            )
+        });
+
+        let mut selected: Vec<&FoldRegion> = Vec::new();
+        for fold in candidates {
+            if !selected.iter().any(|outer| outer.contains(fold)) {
+                selected.push(fold);
+            }
+        }
+        selected.sort_by_key(|fold| fold.start_byte);
+        selected
+    }
 }
 
 /// Statistics about fold analysis
@@ -205,9 +620,23 @@ pub struct FoldStats {
     pub class_folds: usize,
     pub array_folds: usize,
     pub object_folds: usize,
+    #[serde(default)]
+    pub region_folds: usize,
+    #[serde(default)]
+    pub jsx_folds: usize,
+    #[serde(default)]
+    pub property_folds: usize,
     pub python_files: usize,
     pub javascript_files: usize,
     pub typescript_files: usize,
+    #[serde(default)]
+    pub csharp_files: usize,
+    #[serde(default)]
+    pub rust_files: usize,
+    #[serde(default)]
+    pub scala_files: usize,
+    #[serde(default)]
+    pub markdown_files: usize,
     pub total_lines: usize,
     pub foldable_lines: usize,
 }
@@ -226,17 +655,38 @@ impl FoldStats {
             FoldType::ClassBody => self.class_folds += 1,
             FoldType::ArrayLiteral => self.array_folds += 1,
             FoldType::ObjectLiteral => self.object_folds += 1,
+            FoldType::Region => self.region_folds += 1,
+            FoldType::JsxElement => self.jsx_folds += 1,
+            FoldType::Property => self.property_folds += 1,
         }
     }
 }
 
 /// Scan metadata
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ScanMetadata {
     pub scan_duration_ms: u64,
     pub files_per_second: f64,
+    /// Source bytes read across all scanned files
+    #[serde(default)]
+    pub total_bytes: usize,
+    /// Size-normalized throughput (MiB of source per second)
+    #[serde(default)]
+    pub mb_per_second: f64,
     pub timestamp: String,
     pub tool_version: String,
+    /// Files whose folds were reused from the incremental cache (cache enabled only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cache_hits: Option<usize>,
+    /// Files parsed because they were new or changed (cache enabled only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cache_misses: Option<usize>,
+    /// `files` was cut to `ScanConfig::max_output_files` entries
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub truncated: bool,
+    /// Number of scanned files before truncation (only when truncated)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub total_files_before_truncation: Option<usize>,
 }
 
 impl Default for ScanMetadata {
@@ -244,14 +694,20 @@ impl Default for ScanMetadata {
         Self {
             scan_duration_ms: 0,
             files_per_second: 0.0,
+            total_bytes: 0,
+            mb_per_second: 0.0,
             timestamp: chrono::Utc::now().to_rfc3339(),
             tool_version: env!("CARGO_PKG_VERSION").to_string(),
+            cache_hits: None,
+            cache_misses: None,
+            truncated: false,
+            total_files_before_truncation: None,
         }
     }
 }
 
 /// Language-specific section of the fold map
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct LanguageSection {
     /// Source files for this language
     pub files: Vec<SourceFile>,
@@ -259,6 +715,12 @@ pub struct LanguageSection {
     pub stats: LanguageFoldStats,
 }
 
+impl LanguageSection {
+    pub fn is_empty(&self) -> bool {
+        self.files.is_empty()
+    }
+}
+
 /// Statistics for a single language
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct LanguageFoldStats {
@@ -274,10 +736,47 @@ pub struct LanguageFoldStats {
     pub class_folds: usize,
     pub array_folds: usize,
     pub object_folds: usize,
+    #[serde(default)]
+    pub region_folds: usize,
+    #[serde(default)]
+    pub jsx_folds: usize,
+    #[serde(default)]
+    pub property_folds: usize,
     pub total_lines: usize,
     pub foldable_lines: usize,
 }
 
+/// One-line run telemetry written to stderr by `--emit-run-summary`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RunSummary {
+    /// Files scanned
+    pub files: usize,
+    /// Files that failed to parse
+    pub errors: usize,
+    /// Scan duration in milliseconds
+    pub duration_ms: u64,
+    /// Process exit code the CLI returns
+    pub exit: i32,
+}
+
+/// One typed record of the `--json-stream` output
+///
+/// A stream is a `header`, then one `file` record per analyzed file in scan
+/// order, then a trailing `summary` with the stats and metadata.
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum StreamRecord<'a> {
+    /// Opens the stream; stats and metadata arrive with the summary
+    Header { root: &'a Path, metadata_pending: bool },
+    /// A single analyzed file
+    File(&'a SourceFile),
+    /// Closes the stream
+    Summary {
+        stats: &'a FoldStats,
+        metadata: &'a ScanMetadata,
+    },
+}
+
 /// Aggregated fold analysis results
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FoldMap {
@@ -289,10 +788,92 @@ pub struct FoldMap {
     pub stats: FoldStats,
     /// Scan metadata
     pub metadata: ScanMetadata,
+    /// Files referenced by `main`/`bin` in the root package.json (relative to root)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub package_entry_points: Vec<PathBuf>,
 }
 
+/// File names that conventionally mark an entry point
+const ENTRY_POINT_FILE_NAMES: &[&str] = &["main.py", "__main__.py", "index.js", "index.ts"];
+
 impl FoldMap {
-    /// Convert to grouped format (python/nodejs sections)
+    /// Likely entry points, sorted by path
+    ///
+    /// Combines Python files with a `__main__` guard, package.json `main`/`bin`
+    /// targets, and files conventionally named `main.py`/`index.js`.
+    pub fn entry_points(&self) -> Vec<PathBuf> {
+        let mut entries: Vec<PathBuf> = self
+            .files
+            .iter()
+            .filter(|file| {
+                file.main_guard
+                    || self.package_entry_points.contains(&file.path)
+                    || file
+                        .path
+                        .file_name()
+                        .and_then(|name| name.to_str())
+                        .is_some_and(|name| ENTRY_POINT_FILE_NAMES.contains(&name))
+            })
+            .map(|file| file.path.clone())
+            .collect();
+        entries.sort();
+        entries
+    }
+
+    /// Every suppression comment in the map paired with its file, in file order
+    pub fn suppressions(&self) -> impl Iterator<Item = (&SourceFile, &Suppression)> {
+        self.files
+            .iter()
+            .flat_map(|file| file.suppressions.iter().map(move |s| (file, s)))
+    }
+
+    /// Every fold in the map paired with its file, including nested `children`
+    ///
+    /// Files are visited in order and each file's folds depth-first, so a parent
+    /// fold is always yielded before the folds nested inside it.
+    pub fn iter_folds(&self) -> impl Iterator<Item = (&SourceFile, &FoldRegion)> {
+        self.files
+            .iter()
+            .flat_map(|file| FoldWalk::new(&file.folds).map(move |fold| (file, fold)))
+    }
+
+    /// Aggregate per-file node kind counts, most frequent first
+    pub fn node_kind_histogram(&self) -> Vec<(String, usize)> {
+        let mut totals: BTreeMap<String, usize> = BTreeMap::new();
+        for file in &self.files {
+            for (kind, count) in &file.node_kinds {
+                *totals.entry(kind.clone()).or_insert(0) += count;
+            }
+        }
+
+        let mut histogram: Vec<(String, usize)> = totals.into_iter().collect();
+        histogram.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        histogram
+    }
+
+    /// Bucket files by fold density (`foldable_lines / line_count`)
+    ///
+    /// See [`density_histogram`].
+    pub fn density_histogram(&self, buckets: usize) -> Vec<(f64, f64, usize)> {
+        density_histogram(&self.files, buckets)
+    }
+
+    /// Summarize this scan for `--emit-run-summary`
+    pub fn run_summary(&self, exit: i32) -> RunSummary {
+        RunSummary {
+            files: self.files.len(),
+            errors: self.files.iter().filter(|f| f.error.is_some()).count(),
+            duration_ms: self.metadata.scan_duration_ms,
+            exit,
+        }
+    }
+
+    /// Structural changes from this scan to `other` (see [`FoldDiff::between`])
+    pub fn diff(&self, other: &FoldMap) -> FoldDiff {
+        FoldDiff::between(&self.files, &other.files)
+    }
+
+    /// Convert to grouped format (python/nodejs/csharp/rust/scala sections)
     pub fn to_grouped(&self) -> GroupedFoldMap {
         // Separate files by language
         let python_files: Vec<SourceFile> = self
@@ -309,9 +890,41 @@ impl FoldMap {
             .cloned()
             .collect();
 
+        let csharp_files: Vec<SourceFile> = self
+            .files
+            .iter()
+            .filter(|f| f.language == Language::CSharp)
+            .cloned()
+            .collect();
+
+        let rust_files: Vec<SourceFile> = self
+            .files
+            .iter()
+            .filter(|f| f.language == Language::Rust)
+            .cloned()
+            .collect();
+
         // Calculate stats for each language
         let python_stats = Self::calculate_language_stats(&python_files);
         let nodejs_stats = Self::calculate_language_stats(&nodejs_files);
+        let csharp_stats = Self::calculate_language_stats(&csharp_files);
+        let rust_stats = Self::calculate_language_stats(&rust_files);
+
+        let scala_files: Vec<SourceFile> = self
+            .files
+            .iter()
+            .filter(|f| f.language == Language::Scala)
+            .cloned()
+            .collect();
+        let scala_stats = Self::calculate_language_stats(&scala_files);
+
+        let markdown_files: Vec<SourceFile> = self
+            .files
+            .iter()
+            .filter(|f| f.language == Language::Markdown)
+            .cloned()
+            .collect();
+        let markdown_stats = Self::calculate_language_stats(&markdown_files);
 
         GroupedFoldMap {
             root: self.root.clone(),
@@ -323,6 +936,22 @@ impl FoldMap {
                 files: nodejs_files,
                 stats: nodejs_stats,
             },
+            csharp: LanguageSection {
+                files: csharp_files,
+                stats: csharp_stats,
+            },
+            rust: LanguageSection {
+                files: rust_files,
+                stats: rust_stats,
+            },
+            scala: LanguageSection {
+                files: scala_files,
+                stats: scala_stats,
+            },
+            markdown: LanguageSection {
+                files: markdown_files,
+                stats: markdown_stats,
+            },
             metadata: self.metadata.clone(),
         }
     }
@@ -334,28 +963,105 @@ impl FoldMap {
         for file in files {
             stats.total_lines += file.line_count;
             for fold in &file.folds {
-                stats.total_folds += 1;
-                stats.foldable_lines += fold.line_count;
-                match fold.fold_type {
-                    FoldType::Block => stats.block_folds += 1,
-                    FoldType::Import => stats.import_folds += 1,
-                    FoldType::ArgList => stats.arglist_folds += 1,
-                    FoldType::ChainedCall => stats.chain_folds += 1,
-                    FoldType::Literal => stats.literal_folds += 1,
-                    FoldType::Comment => stats.comment_folds += 1,
-                    FoldType::DocComment => stats.doc_folds += 1,
-                    FoldType::ClassBody => stats.class_folds += 1,
-                    FoldType::ArrayLiteral => stats.array_folds += 1,
-                    FoldType::ObjectLiteral => stats.object_folds += 1,
-                }
+                Self::count_language_fold(&mut stats, fold);
             }
         }
 
         stats
     }
+
+    /// Count a fold and its nested children (present with nested folds enabled)
+    fn count_language_fold(stats: &mut LanguageFoldStats, fold: &FoldRegion) {
+        stats.total_folds += 1;
+        stats.foldable_lines += fold.line_count;
+        match fold.fold_type {
+            FoldType::Block => stats.block_folds += 1,
+            FoldType::Import => stats.import_folds += 1,
+            FoldType::ArgList => stats.arglist_folds += 1,
+            FoldType::ChainedCall => stats.chain_folds += 1,
+            FoldType::Literal => stats.literal_folds += 1,
+            FoldType::Comment => stats.comment_folds += 1,
+            FoldType::DocComment => stats.doc_folds += 1,
+            FoldType::ClassBody => stats.class_folds += 1,
+            FoldType::ArrayLiteral => stats.array_folds += 1,
+            FoldType::ObjectLiteral => stats.object_folds += 1,
+            FoldType::Region => stats.region_folds += 1,
+            FoldType::JsxElement => stats.jsx_folds += 1,
+            FoldType::Property => stats.property_folds += 1,
+        }
+        for child in &fold.children {
+            Self::count_language_fold(stats, child);
+        }
+    }
+
+    /// Check that the per-language sections of the grouped view add up to the flat stats
+    ///
+    /// Recomputes the grouped stats and reports every counter that disagrees with
+    /// `self.stats`, e.g. `total_folds: flat 12 != grouped 11`. Truncated maps are not
+    /// checked, since their flat stats also cover the files that were dropped.
+    pub fn verify_grouping_consistency(&self) -> Result<(), String> {
+        if self.metadata.truncated {
+            return Ok(());
+        }
+        let grouped = self.to_grouped();
+        let sections = [
+            &grouped.python,
+            &grouped.nodejs,
+            &grouped.csharp,
+            &grouped.rust,
+            &grouped.scala,
+            &grouped.markdown,
+        ];
+        let sum = |field: fn(&LanguageFoldStats) -> usize| -> usize {
+            sections.iter().map(|section| field(&section.stats)).sum()
+        };
+
+        let flat = &self.stats;
+        let checks = [
+            ("total_files", flat.total_files, sum(|s| s.total_files)),
+            ("total_folds", flat.total_folds, sum(|s| s.total_folds)),
+            ("block_folds", flat.block_folds, sum(|s| s.block_folds)),
+            ("import_folds", flat.import_folds, sum(|s| s.import_folds)),
+            ("arglist_folds", flat.arglist_folds, sum(|s| s.arglist_folds)),
+            ("chain_folds", flat.chain_folds, sum(|s| s.chain_folds)),
+            ("literal_folds", flat.literal_folds, sum(|s| s.literal_folds)),
+            ("comment_folds", flat.comment_folds, sum(|s| s.comment_folds)),
+            ("doc_folds", flat.doc_folds, sum(|s| s.doc_folds)),
+            ("class_folds", flat.class_folds, sum(|s| s.class_folds)),
+            ("array_folds", flat.array_folds, sum(|s| s.array_folds)),
+            ("object_folds", flat.object_folds, sum(|s| s.object_folds)),
+            ("region_folds", flat.region_folds, sum(|s| s.region_folds)),
+            ("jsx_folds", flat.jsx_folds, sum(|s| s.jsx_folds)),
+            ("property_folds", flat.property_folds, sum(|s| s.property_folds)),
+            ("total_lines", flat.total_lines, sum(|s| s.total_lines)),
+            ("foldable_lines", flat.foldable_lines, sum(|s| s.foldable_lines)),
+            ("python_files", flat.python_files, grouped.python.stats.total_files),
+            (
+                "nodejs_files",
+                flat.javascript_files + flat.typescript_files,
+                grouped.nodejs.stats.total_files,
+            ),
+            ("csharp_files", flat.csharp_files, grouped.csharp.stats.total_files),
+            ("rust_files", flat.rust_files, grouped.rust.stats.total_files),
+            ("scala_files", flat.scala_files, grouped.scala.stats.total_files),
+            ("markdown_files", flat.markdown_files, grouped.markdown.stats.total_files),
+        ];
+
+        let mismatches: Vec<String> = checks
+            .iter()
+            .filter(|(_, flat, grouped)| flat != grouped)
+            .map(|(name, flat, grouped)| format!("{}: flat {} != grouped {}", name, flat, grouped))
+            .collect();
+
+        if mismatches.is_empty() {
+            Ok(())
+        } else {
+            Err(mismatches.join("; "))
+        }
+    }
 }
 
-/// Grouped fold map with separate sections for Python and Node.js
+/// Grouped fold map with separate sections for Python, Node.js, C#, Rust and Scala
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GroupedFoldMap {
     /// Project root path
@@ -364,17 +1070,197 @@ pub struct GroupedFoldMap {
     pub python: LanguageSection,
     /// Node.js (JavaScript + TypeScript) folds
     pub nodejs: LanguageSection,
+    /// C# folds
+    pub csharp: LanguageSection,
+    /// Rust folds
+    #[serde(default)]
+    pub rust: LanguageSection,
+    /// Scala folds (only present when Scala was scanned)
+    #[serde(default, skip_serializing_if = "LanguageSection::is_empty")]
+    pub scala: LanguageSection,
+    /// Folds in Markdown fenced code blocks (only present when Markdown was scanned)
+    #[serde(default, skip_serializing_if = "LanguageSection::is_empty")]
+    pub markdown: LanguageSection,
     /// Scan metadata
     pub metadata: ScanMetadata,
 }
 
+impl GroupedFoldMap {
+    /// Bucket files of every language section by fold density (see [`density_histogram`])
+    pub fn density_histogram(&self, buckets: usize) -> Vec<(f64, f64, usize)> {
+        let sections = [
+            &self.python,
+            &self.nodejs,
+            &self.csharp,
+            &self.rust,
+            &self.scala,
+            &self.markdown,
+        ];
+        density_histogram(sections.into_iter().flat_map(|s| &s.files), buckets)
+    }
+
+    /// All files across the language sections
+    pub fn into_files(self) -> Vec<SourceFile> {
+        let mut files = self.python.files;
+        files.extend(self.nodejs.files);
+        files.extend(self.csharp.files);
+        files.extend(self.rust.files);
+        files.extend(self.scala.files);
+        files.extend(self.markdown.files);
+        files
+    }
+}
+
+/// Split `[0, 1]` into `buckets` equal ranges and count the files whose fold
+/// density falls in each, as `(low, high, count)`
+///
+/// A file's density is its [`SourceFile::foldable_lines`] over its `line_count`,
+/// capped at 1.0 since nested folds are counted again. A density on a bucket
+/// boundary goes to the higher bucket. Files with no lines are left out.
+pub fn density_histogram<'a>(
+    files: impl IntoIterator<Item = &'a SourceFile>,
+    buckets: usize,
+) -> Vec<(f64, f64, usize)> {
+    if buckets == 0 {
+        return vec![];
+    }
+
+    let mut counts = vec![0; buckets];
+    for file in files.into_iter().filter(|f| f.line_count > 0) {
+        let density = (file.foldable_lines() as f64 / file.line_count as f64).min(1.0);
+        let bucket = ((density * buckets as f64) as usize).min(buckets - 1);
+        counts[bucket] += 1;
+    }
+
+    counts
+        .into_iter()
+        .enumerate()
+        .map(|(i, count)| (i as f64 / buckets as f64, (i + 1) as f64 / buckets as f64, count))
+        .collect()
+}
+
+/// A fold present in both scans whose size changed
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FoldChange {
+    pub before: FoldRegion,
+    pub after: FoldRegion,
+}
+
+/// Fold changes within a single file
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FileFoldDiff {
+    /// Relative path of the file
+    pub path: PathBuf,
+    /// Folds only present in the newer scan
+    pub added: Vec<FoldRegion>,
+    /// Folds only present in the older scan
+    pub removed: Vec<FoldRegion>,
+    /// Folds present in both whose line count changed
+    pub changed: Vec<FoldChange>,
+}
+
+impl FileFoldDiff {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// Structural difference between two scans, grouped by file
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FoldDiff {
+    /// Files with at least one added, removed or changed fold, sorted by path
+    pub files: Vec<FileFoldDiff>,
+}
+
+impl FoldDiff {
+    /// Compare the folds of two scans, matching files by relative path
+    ///
+    /// Folds are matched by type and preview (in source order), so a function
+    /// that merely moved is unchanged while one that grew or shrank is changed.
+    pub fn between(before: &[SourceFile], after: &[SourceFile]) -> Self {
+        let none: &[FoldRegion] = &[];
+        let mut paths: BTreeMap<&Path, (&[FoldRegion], &[FoldRegion])> = BTreeMap::new();
+        for file in before {
+            paths.entry(file.path.as_path()).or_insert((none, none)).0 = file.folds.as_slice();
+        }
+        for file in after {
+            paths.entry(file.path.as_path()).or_insert((none, none)).1 = file.folds.as_slice();
+        }
+
+        let files = paths
+            .into_iter()
+            .map(|(path, (old, new))| Self::diff_file(path, old, new))
+            .filter(|file| !file.is_empty())
+            .collect();
+
+        Self { files }
+    }
+
+    fn diff_file(path: &Path, old: &[FoldRegion], new: &[FoldRegion]) -> FileFoldDiff {
+        let key = |fold: &FoldRegion| {
+            (fold.fold_type.clone(), fold.preview.clone().unwrap_or_default())
+        };
+
+        let mut unmatched: HashMap<(FoldType, String), VecDeque<&FoldRegion>> = HashMap::new();
+        for fold in old {
+            unmatched.entry(key(fold)).or_default().push_back(fold);
+        }
+
+        let mut diff = FileFoldDiff {
+            path: path.to_path_buf(),
+            ..Default::default()
+        };
+        for fold in new {
+            match unmatched.get_mut(&key(fold)).and_then(|queue| queue.pop_front()) {
+                Some(before) if before.line_count != fold.line_count => {
+                    diff.changed.push(FoldChange {
+                        before: before.clone(),
+                        after: fold.clone(),
+                    });
+                }
+                Some(_) => {}
+                None => diff.added.push(fold.clone()),
+            }
+        }
+
+        diff.removed = unmatched.into_values().flatten().cloned().collect();
+        diff.removed.sort_by_key(|fold| (fold.start_byte, fold.end_byte));
+        diff
+    }
+
+    /// Total number of added, removed and changed folds
+    pub fn change_count(&self) -> usize {
+        self.files
+            .iter()
+            .map(|file| file.added.len() + file.removed.len() + file.changed.len())
+            .sum()
+    }
+}
+
 /// Rendered output for a single file
 #[derive(Debug, Clone)]
 pub struct RenderedFile {
     pub path: PathBuf,
     pub content: String,
     pub fold_count: usize,
+    /// Number of folds actually applied to the rendered content
+    pub folds_applied: usize,
     pub lines_hidden: usize,
+    /// One segment per rendered line, mapping it back to the original source
+    pub source_map: Vec<RenderSegment>,
+}
+
+/// Where a line of rendered output came from in the original file
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RenderSegment {
+    /// Line in the rendered output (1-indexed)
+    pub rendered_line: usize,
+    /// First original line covered by this rendered line (1-indexed)
+    pub original_start: usize,
+    /// Last original line covered by this rendered line (inclusive)
+    pub original_end: usize,
+    /// Whether the rendered line contains a fold placeholder
+    pub is_fold_placeholder: bool,
 }
 
 /// Configuration for which fold types to apply
@@ -390,6 +1276,12 @@ pub struct FoldFilter {
     pub fold_classes: bool,
     pub fold_arrays: bool,
     pub fold_objects: bool,
+    /// Group Python `@property` getters with their `@name.setter`/`@name.deleter`
+    pub fold_properties: bool,
+    /// `# region` ... `# endregion` pragma comments (`//#region` in JS/TS)
+    pub fold_regions: bool,
+    /// Multi-line JSX/TSX elements
+    pub fold_jsx: bool,
 }
 
 impl FoldFilter {
@@ -406,6 +1298,9 @@ impl FoldFilter {
             fold_classes: true,
             fold_arrays: true,
             fold_objects: true,
+            fold_properties: true,
+            fold_regions: true,
+            fold_jsx: true,
         }
     }
 
@@ -422,9 +1317,39 @@ impl FoldFilter {
             fold_classes: false,
             fold_arrays: true,
             fold_objects: true,
+            fold_properties: false,
+            fold_regions: false,
+            fold_jsx: false,
         }
     }
 
+    /// Filter enabling only the comma-separated fold type names (as for `--fold-types`)
+    ///
+    /// `all` enables everything; unknown names are ignored.
+    pub fn from_type_names(names: &str) -> Self {
+        let mut filter = Self::default();
+        for name in names.split(',') {
+            match name.trim() {
+                "block" => filter.fold_blocks = true,
+                "import" => filter.fold_imports = true,
+                "arglist" => filter.fold_arglists = true,
+                "chain" => filter.fold_chains = true,
+                "literal" => filter.fold_literals = true,
+                "comment" => filter.fold_comments = true,
+                "doc" => filter.fold_docs = true,
+                "class" => filter.fold_classes = true,
+                "array" => filter.fold_arrays = true,
+                "object" => filter.fold_objects = true,
+                "property" => filter.fold_properties = true,
+                "region" => filter.fold_regions = true,
+                "jsx" => filter.fold_jsx = true,
+                "all" => filter = Self::all(),
+                _ => {}
+            }
+        }
+        filter
+    }
+
     /// Check if a fold type should be applied
     pub fn should_fold(&self, fold_type: &FoldType) -> bool {
         match fold_type {
@@ -438,6 +1363,38 @@ impl FoldFilter {
             FoldType::ClassBody => self.fold_classes,
             FoldType::ArrayLiteral => self.fold_arrays,
             FoldType::ObjectLiteral => self.fold_objects,
+            FoldType::Region => self.fold_regions,
+            FoldType::JsxElement => self.fold_jsx,
+            FoldType::Property => self.fold_properties,
         }
     }
+
+    /// Fold types this filter applies, in `FoldType::ALL` order
+    pub fn enabled_types(&self) -> Vec<FoldType> {
+        FoldType::ALL
+            .iter()
+            .filter(|fold_type| self.should_fold(fold_type))
+            .cloned()
+            .collect()
+    }
+
+    /// Enable a fold type if it is disabled, or disable it if it is enabled
+    pub fn toggle(&mut self, fold_type: FoldType) {
+        let flag = match fold_type {
+            FoldType::Block => &mut self.fold_blocks,
+            FoldType::Import => &mut self.fold_imports,
+            FoldType::ArgList => &mut self.fold_arglists,
+            FoldType::ChainedCall => &mut self.fold_chains,
+            FoldType::Literal => &mut self.fold_literals,
+            FoldType::Comment => &mut self.fold_comments,
+            FoldType::DocComment => &mut self.fold_docs,
+            FoldType::ClassBody => &mut self.fold_classes,
+            FoldType::ArrayLiteral => &mut self.fold_arrays,
+            FoldType::ObjectLiteral => &mut self.fold_objects,
+            FoldType::Region => &mut self.fold_regions,
+            FoldType::JsxElement => &mut self.fold_jsx,
+            FoldType::Property => &mut self.fold_properties,
+        };
+        *flag = !*flag;
+    }
 }
@@ -0,0 +1,161 @@
+use crate::models::{FoldDiff, FoldRegion};
+use std::fmt::Write;
+
+const STYLE: &str = "\
+body { font-family: -apple-system, Segoe UI, sans-serif; margin: 2rem; color: #1f2328; }
+h1 { font-size: 1.4rem; }
+h2 { font-size: 1rem; font-family: monospace; margin-top: 1.5rem; }
+ul { list-style: none; padding: 0; }
+li { font-family: monospace; padding: 0.2rem 0.5rem; margin: 0.1rem 0; border-radius: 3px; }
+li.added { background: #dafbe1; }
+li.removed { background: #ffebe9; }
+li.changed { background: #fff8c5; }
+.marker { font-weight: bold; margin-right: 0.5rem; }
+.type { color: #57606a; margin-right: 0.5rem; }
+";
+
+/// Render a fold diff as a self-contained HTML page
+///
+/// Added folds are shown in green (`+`), removed folds in red (`-`) and folds
+/// whose size changed in yellow (`~`), grouped by file.
+pub fn to_html_diff(diff: &FoldDiff) -> String {
+    let mut html = String::new();
+    html.push_str("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n");
+    html.push_str("<title>Fold diff</title>\n");
+    let _ = writeln!(html, "<style>\n{}</style>\n</head>\n<body>", STYLE);
+
+    let _ = writeln!(
+        html,
+        "<h1>Fold diff: {} files, {} changes</h1>",
+        diff.files.len(),
+        diff.change_count()
+    );
+
+    if diff.files.is_empty() {
+        html.push_str("<p>No structural changes.</p>\n");
+    }
+
+    for file in &diff.files {
+        let path = escape(&file.path.display().to_string());
+        let _ = writeln!(html, "<section>\n<h2>{}</h2>\n<ul>", path);
+        for fold in &file.added {
+            let _ = writeln!(
+                html,
+                "<li class=\"added\"><span class=\"marker\">+</span>{}</li>",
+                describe(fold)
+            );
+        }
+        for fold in &file.removed {
+            let _ = writeln!(
+                html,
+                "<li class=\"removed\"><span class=\"marker\">-</span>{}</li>",
+                describe(fold)
+            );
+        }
+        for change in &file.changed {
+            let _ = writeln!(
+                html,
+                "<li class=\"changed\"><span class=\"marker\">~</span>{} ({} &rarr; {} lines)</li>",
+                describe(&change.after),
+                change.before.line_count,
+                change.after.line_count
+            );
+        }
+        html.push_str("</ul>\n</section>\n");
+    }
+
+    html.push_str("</body>\n</html>\n");
+    html
+}
+
+/// One-line description of a fold: type, line range and preview
+fn describe(fold: &FoldRegion) -> String {
+    let mut text = format!(
+        "<span class=\"type\">{}</span>L{}-{}",
+        fold.fold_type.as_str(),
+        fold.start_line,
+        fold.end_line
+    );
+    if let Some(ref preview) = fold.preview {
+        let _ = write!(text, " <code>{}</code>", escape(preview));
+    }
+    text
+}
+
+/// Escape text for inclusion in HTML
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{FoldType, Language, SourceFile};
+    use std::collections::BTreeMap;
+    use std::path::PathBuf;
+
+    fn fold(fold_type: FoldType, start_line: usize, end_line: usize, preview: &str) -> FoldRegion {
+        let mut fold =
+            FoldRegion::new(fold_type, start_line * 10, end_line * 10, start_line, end_line, 0, 0);
+        fold.preview = Some(preview.to_string());
+        fold
+    }
+
+    fn file(folds: Vec<FoldRegion>) -> SourceFile {
+        SourceFile {
+            path: PathBuf::from("app.py"),
+            absolute_path: PathBuf::from("/project/app.py"),
+            language: Language::Python,
+            folds,
+            line_count: 40,
+            parsed: true,
+            error: None,
+            node_kinds: BTreeMap::new(),
+            byte_count: 0,
+            main_guard: false,
+            suppressions: vec![],
+            line_index: BTreeMap::new(),
+        }
+    }
+
+    #[test]
+    fn test_to_html_diff() {
+        let before = file(vec![
+            fold(FoldType::Block, 1, 5, "def keep()"),
+            fold(FoldType::Block, 7, 10, "def grow()"),
+            fold(FoldType::Block, 12, 15, "def old_helper()"),
+        ]);
+        let after = file(vec![
+            fold(FoldType::Block, 1, 5, "def keep()"),
+            fold(FoldType::Block, 7, 14, "def grow()"),
+            fold(FoldType::Block, 16, 20, "def <new>()"),
+        ]);
+
+        let diff = FoldDiff::between(&[before], &[after]);
+        assert_eq!(diff.change_count(), 3);
+
+        let html = to_html_diff(&diff);
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("<h2>app.py</h2>"));
+        assert!(html.contains(
+            "<li class=\"added\"><span class=\"marker\">+</span><span class=\"type\">block</span>L16-20 <code>def &lt;new&gt;()</code></li>"
+        ));
+        assert!(html.contains(
+            "<li class=\"removed\"><span class=\"marker\">-</span><span class=\"type\">block</span>L12-15 <code>def old_helper()</code></li>"
+        ));
+        assert!(html.contains("<li class=\"changed\"><span class=\"marker\">~</span>"));
+        assert!(html.contains("(4 &rarr; 8 lines)"));
+        assert!(!html.contains("def keep()"));
+    }
+}
@@ -1,5 +1,8 @@
-use crate::models::FoldMap;
-use super::FormatError;
+use crate::models::{FoldMap, RunSummary, StreamRecord};
+use super::{FormatError, IndentStyle};
+use serde::Serialize;
+use serde_json::ser::{PrettyFormatter, Serializer};
+use std::io::Write;
 
 /// Convert FoldMap to pretty-printed JSON
 pub fn to_json(fold_map: &FoldMap) -> Result<String, FormatError> {
@@ -12,10 +15,44 @@ pub fn to_json_compact(fold_map: &FoldMap) -> Result<String, FormatError> {
     serde_json::to_string(fold_map).map_err(FormatError::from)
 }
 
+/// Serialize any value to pretty-printed JSON using the given indentation
+pub fn to_json_with_indent<T: Serialize + ?Sized>(
+    value: &T,
+    indent: IndentStyle,
+) -> Result<String, FormatError> {
+    let mut buf = Vec::new();
+    let formatter = PrettyFormatter::with_indent(indent.as_bytes());
+    let mut serializer = Serializer::with_formatter(&mut buf, formatter);
+    value.serialize(&mut serializer)?;
+    Ok(String::from_utf8_lossy(&buf).into_owned())
+}
+
+/// Write a run summary as a single JSON line, e.g. to stderr for `--emit-run-summary`
+pub fn write_run_summary<W: Write>(
+    writer: &mut W,
+    summary: &RunSummary,
+) -> Result<(), FormatError> {
+    serde_json::to_writer(&mut *writer, summary)?;
+    writeln!(writer).map_err(serde_json::Error::io)?;
+    Ok(())
+}
+
+/// Write one `--json-stream` record as a single JSON line
+pub fn write_stream_record<W: Write>(
+    writer: &mut W,
+    record: &StreamRecord,
+) -> Result<(), FormatError> {
+    serde_json::to_writer(&mut *writer, record)?;
+    writeln!(writer).map_err(serde_json::Error::io)?;
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config::ScanConfig;
     use crate::models::{FoldStats, ScanMetadata};
+    use crate::output::{format_output_with_config, OutputFormat};
     use std::path::PathBuf;
 
     #[test]
@@ -25,10 +62,60 @@ mod tests {
             files: vec![],
             stats: FoldStats::default(),
             metadata: ScanMetadata::default(),
+            package_entry_points: vec![],
         };
 
         let json = to_json(&fold_map).unwrap();
         assert!(json.contains("\"root\""));
         assert!(json.contains("\"files\""));
     }
+
+    #[test]
+    fn test_to_json_with_indent() {
+        let value = serde_json::json!({ "root": { "files": [] } });
+
+        // `files` is nested one level below `root`, so it is indented twice
+        let json = to_json_with_indent(&value, IndentStyle::FourSpaces).unwrap();
+        assert!(json.contains("\n        \"files\""));
+
+        let json = to_json_with_indent(&value, IndentStyle::Tab).unwrap();
+        assert!(json.contains("\n\t\t\"files\""));
+
+        assert_eq!("tab".parse(), Ok(IndentStyle::Tab));
+        assert!("3".parse::<IndentStyle>().is_err());
+    }
+
+    #[test]
+    fn test_format_output_with_config() {
+        let fold_map = FoldMap {
+            root: PathBuf::from("/test"),
+            files: vec![],
+            stats: FoldStats::default(),
+            metadata: ScanMetadata::default(),
+            package_entry_points: vec![],
+        };
+
+        let config = ScanConfig::default().with_json_indent(IndentStyle::FourSpaces);
+        let json = format_output_with_config(&fold_map, OutputFormat::Json, &config).unwrap();
+        assert!(json.contains("\n    \"root\""));
+    }
+
+    #[test]
+    fn test_write_run_summary() {
+        let summary = RunSummary {
+            files: 3,
+            errors: 1,
+            duration_ms: 42,
+            exit: 0,
+        };
+
+        let mut stderr = Vec::new();
+        write_run_summary(&mut stderr, &summary).unwrap();
+
+        let text = String::from_utf8(stderr).unwrap();
+        assert_eq!(text.lines().count(), 1);
+        let parsed: RunSummary = serde_json::from_str(text.trim_end()).unwrap();
+        assert_eq!(parsed, summary);
+        assert!(text.starts_with("{\"files\":3,\"errors\":1,\"duration_ms\":42,\"exit\":0}"));
+    }
 }
@@ -0,0 +1,198 @@
+use super::FormatError;
+use crate::models::{FoldRegion, FoldType, SourceFile};
+use serde::Serialize;
+
+/// One entry of an LSP `textDocument/foldingRange` response
+///
+/// Lines are 0-indexed and `end_line` is the last line of the range, as in the
+/// LSP `FoldingRange` type. Characters count UTF-16 code units, the protocol's
+/// default position encoding.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LspFoldingRange {
+    pub start_line: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_character: Option<usize>,
+    pub end_line: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_character: Option<usize>,
+    /// `"comment"`, `"imports"` or `"region"`; omitted for every other fold type
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kind: Option<&'static str>,
+}
+
+impl LspFoldingRange {
+    fn from_fold(fold: &FoldRegion, source: &str) -> Self {
+        Self {
+            start_line: fold.start_line.saturating_sub(1),
+            start_character: utf16_column(source, fold.start_byte),
+            end_line: fold.end_line.saturating_sub(1),
+            end_character: utf16_column(source, fold.end_byte),
+            kind: lsp_kind(&fold.fold_type),
+        }
+    }
+}
+
+/// UTF-16 column of a byte offset: code units between the line start and `byte`
+///
+/// `None` when `byte` is past the end of `source` or inside a character (the
+/// source changed since the scan), which LSP reads as "whole line".
+fn utf16_column(source: &str, byte: usize) -> Option<usize> {
+    let before = source.get(..byte)?;
+    let line_start = before.rfind('\n').map_or(0, |newline| newline + 1);
+    Some(before[line_start..].encode_utf16().count())
+}
+
+/// Map a fold type to one of the LSP `FoldingRangeKind` values
+pub(crate) fn lsp_kind(fold_type: &FoldType) -> Option<&'static str> {
+    match fold_type {
+        FoldType::Comment | FoldType::DocComment => Some("comment"),
+        FoldType::Import => Some("imports"),
+        FoldType::Region => Some("region"),
+        _ => None,
+    }
+}
+
+/// Collect a file's folds (including nested children) as LSP folding ranges
+///
+/// `source` is the file's text, used to convert byte columns to UTF-16.
+pub fn lsp_folding_ranges(file: &SourceFile, source: &str) -> Vec<LspFoldingRange> {
+    fn collect(folds: &[FoldRegion], source: &str, out: &mut Vec<LspFoldingRange>) {
+        for fold in folds {
+            out.push(LspFoldingRange::from_fold(fold, source));
+            collect(&fold.children, source, out);
+        }
+    }
+
+    let mut ranges = Vec::new();
+    collect(&file.folds, source, &mut ranges);
+    ranges
+}
+
+/// Serialize a file's folds as a JSON `FoldingRange[]` array
+pub fn to_lsp_folding_ranges(file: &SourceFile, source: &str) -> Result<String, FormatError> {
+    serde_json::to_string_pretty(&lsp_folding_ranges(file, source)).map_err(FormatError::from)
+}
+
+/// One range for a VS Code `FoldingRangeProvider`
+///
+/// Mirrors `new vscode.FoldingRange(start, end, kind)`: 0-indexed first and last
+/// lines, and a `FoldingRangeKind` name that is omitted for plain code folds.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct VsCodeFoldingRange {
+    pub start: usize,
+    pub end: usize,
+    /// `"comment"`, `"imports"` or `"region"`; omitted for every other fold type
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kind: Option<&'static str>,
+}
+
+/// Serialize a file's folds as a JSON array of VS Code folding ranges
+pub fn format_vscode_folding_ranges(file: &SourceFile) -> Result<String, FormatError> {
+    serde_json::to_string_pretty(&file.to_vscode_folding_ranges()).map_err(FormatError::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Language;
+    use std::collections::BTreeMap;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_to_lsp_folding_ranges() {
+        let source = "import os\nimport sys\n\n# région\n# end\ns = \"😀😀\"\n";
+        let literal_start = source.find('"').unwrap();
+        let file = SourceFile {
+            path: PathBuf::from("app.py"),
+            absolute_path: PathBuf::from("/test/app.py"),
+            language: Language::Python,
+            folds: vec![
+                FoldRegion::new(FoldType::Import, 0, 20, 1, 2, 0, 10),
+                FoldRegion::new(FoldType::Comment, 22, 37, 4, 5, 0, 5),
+                FoldRegion::new(FoldType::Literal, literal_start, source.len() - 1, 6, 6, 4, 14),
+            ],
+            line_count: 6,
+            parsed: true,
+            error: None,
+            node_kinds: BTreeMap::new(),
+            byte_count: source.len(),
+            main_guard: false,
+            suppressions: vec![],
+            line_index: BTreeMap::new(),
+        };
+
+        let ranges = lsp_folding_ranges(&file, source);
+        assert_eq!(
+            ranges[0],
+            LspFoldingRange {
+                start_line: 0,
+                start_character: Some(0),
+                end_line: 1,
+                end_character: Some(10),
+                kind: Some("imports"),
+            }
+        );
+        assert_eq!((ranges[1].start_line, ranges[1].end_line), (3, 4));
+        assert_eq!(ranges[1].kind, Some("comment"));
+        // Byte column 14 is 10 UTF-16 code units: each emoji is 4 bytes but 2 units
+        assert_eq!((ranges[2].start_character, ranges[2].end_character), (Some(4), Some(10)));
+        assert_eq!(ranges[2].kind, None);
+
+        let json = to_lsp_folding_ranges(&file, source).unwrap();
+        let json: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(json[0]["startLine"], 0);
+        assert_eq!(json[0]["endCharacter"], 10);
+        assert_eq!(json[0]["kind"], "imports");
+        assert!(json[2].get("kind").is_none());
+
+        // Offsets that no longer fit the source fall back to whole lines
+        assert_eq!(utf16_column("é", 1), None);
+        assert_eq!(utf16_column("x", 5), None);
+    }
+
+    #[test]
+    fn test_to_vscode_folding_ranges() {
+        let file = SourceFile {
+            path: PathBuf::from("app.py"),
+            absolute_path: PathBuf::from("/test/app.py"),
+            language: Language::Python,
+            folds: vec![
+                FoldRegion::new(FoldType::Import, 0, 30, 1, 3, 0, 14),
+                FoldRegion::new(FoldType::DocComment, 32, 60, 4, 6, 4, 7),
+                FoldRegion::new(FoldType::Block, 62, 90, 7, 9, 8, 12),
+                FoldRegion::new(FoldType::Comment, 92, 110, 10, 11, 0, 9),
+                FoldRegion::new(FoldType::Region, 112, 150, 12, 14, 0, 12),
+            ],
+            line_count: 14,
+            parsed: true,
+            error: None,
+            node_kinds: BTreeMap::new(),
+            byte_count: 150,
+            main_guard: false,
+            suppressions: vec![],
+            line_index: BTreeMap::new(),
+        };
+
+        let ranges: Vec<(usize, usize, Option<&str>)> = file
+            .to_vscode_folding_ranges()
+            .into_iter()
+            .map(|r| (r.start, r.end, r.kind))
+            .collect();
+        assert_eq!(
+            ranges,
+            vec![
+                (0, 2, Some("imports")),
+                (3, 5, Some("comment")),
+                (6, 8, None),
+                (9, 10, Some("comment")),
+                (11, 13, Some("region")),
+            ]
+        );
+
+        let json: serde_json::Value =
+            serde_json::from_str(&format_vscode_folding_ranges(&file).unwrap()).unwrap();
+        assert_eq!(json[0], serde_json::json!({ "start": 0, "end": 2, "kind": "imports" }));
+        assert_eq!(json[2], serde_json::json!({ "start": 6, "end": 8 }));
+    }
+}
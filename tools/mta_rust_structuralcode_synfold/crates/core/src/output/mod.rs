@@ -1,16 +1,70 @@
+mod html;
 mod json;
+mod lsp;
+mod toml;
 mod yaml;
 
-pub use json::to_json;
+pub use html::to_html_diff;
+pub use json::{to_json, to_json_with_indent, write_run_summary, write_stream_record};
+pub use lsp::{
+    format_vscode_folding_ranges, lsp_folding_ranges, to_lsp_folding_ranges, LspFoldingRange,
+    VsCodeFoldingRange,
+};
+pub(crate) use lsp::lsp_kind;
+pub use self::toml::to_toml;
 pub use yaml::to_yaml;
 
-use crate::models::{FoldMap, GroupedFoldMap};
+use crate::config::ScanConfig;
+use crate::models::{FoldMap, GroupedFoldMap, SuppressionKind};
+use serde::Serialize;
+use std::path::Path;
+
+/// Number of equal-width buckets in the summary's fold density histogram (0-20%, ... 80-100%)
+const DENSITY_BUCKETS: usize = 5;
+
+/// Indentation style for pretty-printed JSON output
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum IndentStyle {
+    /// Two spaces (default)
+    #[default]
+    TwoSpaces,
+    /// Four spaces
+    FourSpaces,
+    /// One tab character
+    Tab,
+}
+
+impl IndentStyle {
+    /// Indent bytes for one nesting level
+    pub fn as_bytes(&self) -> &'static [u8] {
+        match self {
+            IndentStyle::TwoSpaces => b"  ",
+            IndentStyle::FourSpaces => b"    ",
+            IndentStyle::Tab => b"\t",
+        }
+    }
+}
+
+impl std::str::FromStr for IndentStyle {
+    type Err = String;
+
+    /// Parse the `--json-indent` values `2`, `4` and `tab`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "2" => Ok(IndentStyle::TwoSpaces),
+            "4" => Ok(IndentStyle::FourSpaces),
+            "tab" => Ok(IndentStyle::Tab),
+            _ => Err(format!("invalid indent '{}' (expected 2, 4 or tab)", s)),
+        }
+    }
+}
 
 /// Output format options
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum OutputFormat {
     Json,
     Yaml,
+    Toml,
     Summary,
     Ansi,
 }
@@ -20,12 +74,13 @@ pub fn format_output(fold_map: &FoldMap, format: OutputFormat) -> Result<String,
     match format {
         OutputFormat::Json => to_json(fold_map),
         OutputFormat::Yaml => to_yaml(fold_map),
+        OutputFormat::Toml => to_toml(fold_map),
         OutputFormat::Summary => Ok(format_summary(fold_map)),
         OutputFormat::Ansi => Ok(format_summary_ansi(fold_map)),
     }
 }
 
-/// Format a FoldMap as grouped by language (python/nodejs sections)
+/// Format a FoldMap as grouped by language (python/nodejs/csharp/rust/scala sections)
 pub fn format_output_grouped(
     fold_map: &FoldMap,
     format: OutputFormat,
@@ -34,11 +89,128 @@ pub fn format_output_grouped(
     match format {
         OutputFormat::Json => to_json_grouped(&grouped),
         OutputFormat::Yaml => to_yaml_grouped(&grouped),
+        OutputFormat::Toml => to_toml(&grouped),
         OutputFormat::Summary => Ok(format_summary_grouped(&grouped)),
         OutputFormat::Ansi => Ok(format_summary_grouped_ansi(&grouped)),
     }
 }
 
+/// Format a FoldMap (flat structure) using a custom JSON indentation
+pub fn format_output_with_indent(
+    fold_map: &FoldMap,
+    format: OutputFormat,
+    indent: IndentStyle,
+) -> Result<String, FormatError> {
+    match format {
+        OutputFormat::Json => to_json_with_indent(fold_map, indent),
+        _ => format_output(fold_map, format),
+    }
+}
+
+/// Format a FoldMap grouped by language using a custom JSON indentation
+pub fn format_output_grouped_with_indent(
+    fold_map: &FoldMap,
+    format: OutputFormat,
+    indent: IndentStyle,
+) -> Result<String, FormatError> {
+    match format {
+        OutputFormat::Json => to_json_with_indent(&fold_map.to_grouped(), indent),
+        _ => format_output_grouped(fold_map, format),
+    }
+}
+
+/// Format a FoldMap (flat structure) with the JSON indentation from `ScanConfig::with_json_indent`
+pub fn format_output_with_config(
+    fold_map: &FoldMap,
+    format: OutputFormat,
+    config: &ScanConfig,
+) -> Result<String, FormatError> {
+    format_output_with_indent(fold_map, format, config.json_indent)
+}
+
+/// Format a FoldMap grouped by language with the JSON indentation from `ScanConfig::with_json_indent`
+pub fn format_output_grouped_with_config(
+    fold_map: &FoldMap,
+    format: OutputFormat,
+    config: &ScanConfig,
+) -> Result<String, FormatError> {
+    format_output_grouped_with_indent(fold_map, format, config.json_indent)
+}
+
+/// Format `FoldMap::entry_points` (`--entry-points`)
+///
+/// Structured formats write an `entry_points` list; `Summary` and `Ansi` print
+/// one path per line.
+pub fn format_entry_points(
+    fold_map: &FoldMap,
+    format: OutputFormat,
+    indent: IndentStyle,
+) -> Result<String, FormatError> {
+    #[derive(Serialize)]
+    struct EntryPoints {
+        entry_points: Vec<std::path::PathBuf>,
+    }
+
+    let entries = EntryPoints { entry_points: fold_map.entry_points() };
+    format_listing(&entries, format, indent, || {
+        let lines: Vec<String> =
+            entries.entry_points.iter().map(|path| path.display().to_string()).collect();
+        lines.join("\n")
+    })
+}
+
+/// Format `FoldMap::suppressions` (`--suppressions`)
+///
+/// Structured formats write a `suppressions` list of `{path, line, kind}`;
+/// `Summary` and `Ansi` print `path:line: kind` lines.
+pub fn format_suppressions(
+    fold_map: &FoldMap,
+    format: OutputFormat,
+    indent: IndentStyle,
+) -> Result<String, FormatError> {
+    #[derive(Serialize)]
+    struct Record<'a> {
+        path: &'a Path,
+        line: usize,
+        kind: SuppressionKind,
+    }
+
+    #[derive(Serialize)]
+    struct Suppressions<'a> {
+        suppressions: Vec<Record<'a>>,
+    }
+
+    let records = Suppressions {
+        suppressions: fold_map
+            .suppressions()
+            .map(|(file, s)| Record { path: &file.path, line: s.line, kind: s.kind })
+            .collect(),
+    };
+    format_listing(&records, format, indent, || {
+        let lines: Vec<String> = records
+            .suppressions
+            .iter()
+            .map(|r| format!("{}:{}: {}", r.path.display(), r.line, r.kind.as_str()))
+            .collect();
+        lines.join("\n")
+    })
+}
+
+/// Serialize a listing in a structured format, or render it as plain text lines
+fn format_listing<T: Serialize>(
+    value: &T,
+    format: OutputFormat,
+    indent: IndentStyle,
+    text: impl FnOnce() -> String,
+) -> Result<String, FormatError> {
+    match format {
+        OutputFormat::Json => to_json_with_indent(value, indent),
+        OutputFormat::Yaml => serde_yaml::to_string(value).map_err(FormatError::from),
+        OutputFormat::Toml => to_toml(value),
+        OutputFormat::Summary | OutputFormat::Ansi => Ok(text()),
+    }
+}
+
 fn to_json_grouped(grouped: &GroupedFoldMap) -> Result<String, FormatError> {
     serde_json::to_string_pretty(grouped).map_err(FormatError::from)
 }
@@ -134,13 +306,137 @@ fn format_summary_grouped(grouped: &GroupedFoldMap) -> String {
     }
     output.push('\n');
 
+    // C# section
+    output.push_str("## C#\n");
+    output.push_str(&format!(
+        "Files: {} | Lines: {} | Foldable: {}\n\
+         Folds: {} (blocks: {}, imports: {}, literals: {}, comments: {})\n",
+        grouped.csharp.stats.total_files,
+        grouped.csharp.stats.total_lines,
+        grouped.csharp.stats.foldable_lines,
+        grouped.csharp.stats.total_folds,
+        grouped.csharp.stats.block_folds,
+        grouped.csharp.stats.import_folds,
+        grouped.csharp.stats.literal_folds,
+        grouped.csharp.stats.comment_folds,
+    ));
+
+    if !grouped.csharp.files.is_empty() {
+        let mut files_by_folds: Vec<_> = grouped
+            .csharp
+            .files
+            .iter()
+            .filter(|f| !f.folds.is_empty())
+            .collect();
+        files_by_folds.sort_by(|a, b| b.folds.len().cmp(&a.folds.len()));
+
+        if !files_by_folds.is_empty() {
+            output.push_str("Top files by folds:\n");
+            for file in files_by_folds.iter().take(5) {
+                output.push_str(&format!(
+                    "  {} ({} folds, {} lines)\n",
+                    file.path.display(),
+                    file.folds.len(),
+                    file.line_count
+                ));
+            }
+        }
+    }
+    output.push('\n');
+
+    // Rust section
+    output.push_str("## Rust\n");
+    output.push_str(&format!(
+        "Files: {} | Lines: {} | Foldable: {}\n\
+         Folds: {} (blocks: {}, imports: {}, literals: {}, comments: {})\n",
+        grouped.rust.stats.total_files,
+        grouped.rust.stats.total_lines,
+        grouped.rust.stats.foldable_lines,
+        grouped.rust.stats.total_folds,
+        grouped.rust.stats.block_folds,
+        grouped.rust.stats.import_folds,
+        grouped.rust.stats.literal_folds,
+        grouped.rust.stats.comment_folds,
+    ));
+
+    if !grouped.rust.files.is_empty() {
+        let mut files_by_folds: Vec<_> = grouped
+            .rust
+            .files
+            .iter()
+            .filter(|f| !f.folds.is_empty())
+            .collect();
+        files_by_folds.sort_by(|a, b| b.folds.len().cmp(&a.folds.len()));
+
+        if !files_by_folds.is_empty() {
+            output.push_str("Top files by folds:\n");
+            for file in files_by_folds.iter().take(5) {
+                output.push_str(&format!(
+                    "  {} ({} folds, {} lines)\n",
+                    file.path.display(),
+                    file.folds.len(),
+                    file.line_count
+                ));
+            }
+        }
+    }
+    output.push('\n');
+
+    // Scala section (only when Scala was scanned)
+    if !grouped.scala.is_empty() {
+        output.push_str("## Scala\n");
+        output.push_str(&format!(
+            "Files: {} | Lines: {} | Foldable: {}\n\
+             Folds: {} (blocks: {}, imports: {}, literals: {}, comments: {})\n\n",
+            grouped.scala.stats.total_files,
+            grouped.scala.stats.total_lines,
+            grouped.scala.stats.foldable_lines,
+            grouped.scala.stats.total_folds,
+            grouped.scala.stats.block_folds,
+            grouped.scala.stats.import_folds,
+            grouped.scala.stats.literal_folds,
+            grouped.scala.stats.comment_folds,
+        ));
+    }
+
+    // Markdown section (only when Markdown docs were scanned)
+    if !grouped.markdown.is_empty() {
+        output.push_str("## Markdown (fenced code blocks)\n");
+        output.push_str(&format!(
+            "Files: {} | Lines: {} | Foldable: {}\n\
+             Folds: {} (blocks: {}, imports: {}, literals: {}, comments: {})\n\n",
+            grouped.markdown.stats.total_files,
+            grouped.markdown.stats.total_lines,
+            grouped.markdown.stats.foldable_lines,
+            grouped.markdown.stats.total_folds,
+            grouped.markdown.stats.block_folds,
+            grouped.markdown.stats.import_folds,
+            grouped.markdown.stats.literal_folds,
+            grouped.markdown.stats.comment_folds,
+        ));
+    }
+
+    // Fold density histogram
+    output.push_str("## Fold Density\n");
+    for (low, high, count) in grouped.density_histogram(DENSITY_BUCKETS) {
+        output.push_str(&format!(
+            "  {:>3}-{:>3}%: {} files\n",
+            (low * 100.0).round(),
+            (high * 100.0).round(),
+            count
+        ));
+    }
+    output.push('\n');
+
     // Metadata
     output.push_str(&format!(
-        "Scan Duration: {}ms ({:.2} files/sec)\n\
+        "Scan Duration: {}ms ({:.2} files/sec, {} bytes, {:.2} MB/sec)\n\
          Timestamp: {}\n\
          Tool Version: {}\n",
         grouped.metadata.scan_duration_ms,
         grouped.metadata.files_per_second,
+        grouped.metadata.total_bytes,
+        grouped.metadata.mb_per_second,
         grouped.metadata.timestamp,
         grouped.metadata.tool_version
     ));
@@ -157,6 +453,8 @@ fn format_summary_grouped_ansi(grouped: &GroupedFoldMap) -> String {
     let cyan = "\x1b[36m";
     let green = "\x1b[32m";
     let yellow = "\x1b[33m";
+    let magenta = "\x1b[35m";
+    let red = "\x1b[31m";
     let dim = "\x1b[2m";
 
     output.push_str(&format!(
@@ -254,14 +552,162 @@ fn format_summary_grouped_ansi(grouped: &GroupedFoldMap) -> String {
     }
     output.push('\n');
 
+    // C# section
+    output.push_str(&format!(
+        "{}{}## C#{}\n",
+        bold, magenta, reset
+    ));
+    output.push_str(&format!(
+        "{}Files:{} {} | {}Lines:{} {} | {}Foldable:{} {}\n\
+         {}Folds:{} {} (blocks: {}, imports: {}, literals: {}, comments: {})\n",
+        dim, reset, grouped.csharp.stats.total_files,
+        dim, reset, grouped.csharp.stats.total_lines,
+        dim, reset, grouped.csharp.stats.foldable_lines,
+        dim, reset, grouped.csharp.stats.total_folds,
+        grouped.csharp.stats.block_folds,
+        grouped.csharp.stats.import_folds,
+        grouped.csharp.stats.literal_folds,
+        grouped.csharp.stats.comment_folds,
+    ));
+
+    if !grouped.csharp.files.is_empty() {
+        let mut files_by_folds: Vec<_> = grouped
+            .csharp
+            .files
+            .iter()
+            .filter(|f| !f.folds.is_empty())
+            .collect();
+        files_by_folds.sort_by(|a, b| b.folds.len().cmp(&a.folds.len()));
+
+        if !files_by_folds.is_empty() {
+            output.push_str(&format!("{}Top files by folds:{}\n", dim, reset));
+            for file in files_by_folds.iter().take(5) {
+                output.push_str(&format!(
+                    "  {}{}{} ({}{} folds{}, {} lines)\n",
+                    yellow,
+                    file.path.display(),
+                    reset,
+                    cyan,
+                    file.folds.len(),
+                    reset,
+                    file.line_count
+                ));
+            }
+        }
+    }
+    output.push('\n');
+
+    // Rust section
+    output.push_str(&format!(
+        "{}{}## Rust{}\n",
+        bold, red, reset
+    ));
+    output.push_str(&format!(
+        "{}Files:{} {} | {}Lines:{} {} | {}Foldable:{} {}\n\
+         {}Folds:{} {} (blocks: {}, imports: {}, literals: {}, comments: {})\n",
+        dim, reset, grouped.rust.stats.total_files,
+        dim, reset, grouped.rust.stats.total_lines,
+        dim, reset, grouped.rust.stats.foldable_lines,
+        dim, reset, grouped.rust.stats.total_folds,
+        grouped.rust.stats.block_folds,
+        grouped.rust.stats.import_folds,
+        grouped.rust.stats.literal_folds,
+        grouped.rust.stats.comment_folds,
+    ));
+
+    if !grouped.rust.files.is_empty() {
+        let mut files_by_folds: Vec<_> = grouped
+            .rust
+            .files
+            .iter()
+            .filter(|f| !f.folds.is_empty())
+            .collect();
+        files_by_folds.sort_by(|a, b| b.folds.len().cmp(&a.folds.len()));
+
+        if !files_by_folds.is_empty() {
+            output.push_str(&format!("{}Top files by folds:{}\n", dim, reset));
+            for file in files_by_folds.iter().take(5) {
+                output.push_str(&format!(
+                    "  {}{}{} ({}{} folds{}, {} lines)\n",
+                    yellow,
+                    file.path.display(),
+                    reset,
+                    cyan,
+                    file.folds.len(),
+                    reset,
+                    file.line_count
+                ));
+            }
+        }
+    }
+    output.push('\n');
+
+    // Scala section (only when Scala was scanned)
+    if !grouped.scala.is_empty() {
+        output.push_str(&format!(
+            "{}{}## Scala{}\n",
+            bold, red, reset
+        ));
+        output.push_str(&format!(
+            "{}Files:{} {} | {}Lines:{} {} | {}Foldable:{} {}\n\
+             {}Folds:{} {} (blocks: {}, imports: {}, literals: {}, comments: {})\n\n",
+            dim, reset, grouped.scala.stats.total_files,
+            dim, reset, grouped.scala.stats.total_lines,
+            dim, reset, grouped.scala.stats.foldable_lines,
+            dim, reset, grouped.scala.stats.total_folds,
+            grouped.scala.stats.block_folds,
+            grouped.scala.stats.import_folds,
+            grouped.scala.stats.literal_folds,
+            grouped.scala.stats.comment_folds,
+        ));
+    }
+
+    // Markdown section (only when Markdown docs were scanned)
+    if !grouped.markdown.is_empty() {
+        output.push_str(&format!(
+            "{}{}## Markdown (fenced code blocks){}\n",
+            bold, cyan, reset
+        ));
+        output.push_str(&format!(
+            "{}Files:{} {} | {}Lines:{} {} | {}Foldable:{} {}\n\
+             {}Folds:{} {} (blocks: {}, imports: {}, literals: {}, comments: {})\n\n",
+            dim, reset, grouped.markdown.stats.total_files,
+            dim, reset, grouped.markdown.stats.total_lines,
+            dim, reset, grouped.markdown.stats.foldable_lines,
+            dim, reset, grouped.markdown.stats.total_folds,
+            grouped.markdown.stats.block_folds,
+            grouped.markdown.stats.import_folds,
+            grouped.markdown.stats.literal_folds,
+            grouped.markdown.stats.comment_folds,
+        ));
+    }
+
+    // Fold density histogram
+    output.push_str(&format!("{}{}## Fold Density{}\n", bold, magenta, reset));
+    for (low, high, count) in grouped.density_histogram(DENSITY_BUCKETS) {
+        output.push_str(&format!(
+            "  {}{:>3}-{:>3}%:{} {}{}{} files\n",
+            dim,
+            (low * 100.0).round(),
+            (high * 100.0).round(),
+            reset,
+            cyan,
+            count,
+            reset
+        ));
+    }
+    output.push('\n');
+
     // Metadata
     output.push_str(&format!(
-        "{}Scan Duration:{} {}ms ({:.2} files/sec)\n\
+        "{}Scan Duration:{} {}ms ({:.2} files/sec, {} bytes, {:.2} MB/sec)\n\
          {}Timestamp:{} {}\n\
          {}Tool Version:{} {}\n",
         dim, reset,
         grouped.metadata.scan_duration_ms,
         grouped.metadata.files_per_second,
+        grouped.metadata.total_bytes,
+        grouped.metadata.mb_per_second,
         dim, reset,
         grouped.metadata.timestamp,
         dim, reset,
@@ -287,11 +733,17 @@ pub fn format_summary(fold_map: &FoldMap) -> String {
         "Files Scanned: {}\n\
          - Python: {}\n\
          - JavaScript: {}\n\
-         - TypeScript: {}\n\n",
+         - TypeScript: {}\n\
+         - C#: {}\n\
+         - Rust: {}\n\
+         - Scala: {}\n\n",
         fold_map.stats.total_files,
         fold_map.stats.python_files,
         fold_map.stats.javascript_files,
-        fold_map.stats.typescript_files
+        fold_map.stats.typescript_files,
+        fold_map.stats.csharp_files,
+        fold_map.stats.rust_files,
+        fold_map.stats.scala_files
     ));
 
     output.push_str(&format!(
@@ -316,7 +768,10 @@ pub fn format_summary(fold_map: &FoldMap) -> String {
          - Doc Comments: {}\n\
          - Classes: {}\n\
          - Arrays: {}\n\
-         - Objects: {}\n\n",
+         - Objects: {}\n\
+         - Regions: {}\n\
+         - JSX Elements: {}\n\
+         - Properties: {}\n\n",
         fold_map.stats.total_folds,
         fold_map.stats.block_folds,
         fold_map.stats.import_folds,
@@ -327,16 +782,21 @@ pub fn format_summary(fold_map: &FoldMap) -> String {
         fold_map.stats.doc_folds,
         fold_map.stats.class_folds,
         fold_map.stats.array_folds,
-        fold_map.stats.object_folds
+        fold_map.stats.object_folds,
+        fold_map.stats.region_folds,
+        fold_map.stats.jsx_folds,
+        fold_map.stats.property_folds
     ));
 
     // Metadata
     output.push_str(&format!(
-        "Scan Duration: {}ms ({:.2} files/sec)\n\
+        "Scan Duration: {}ms ({:.2} files/sec, {} bytes, {:.2} MB/sec)\n\
          Timestamp: {}\n\
          Tool Version: {}\n",
         fold_map.metadata.scan_duration_ms,
         fold_map.metadata.files_per_second,
+        fold_map.metadata.total_bytes,
+        fold_map.metadata.mb_per_second,
         fold_map.metadata.timestamp,
         fold_map.metadata.tool_version
     ));
@@ -361,12 +821,15 @@ fn format_summary_ansi(fold_map: &FoldMap) -> String {
     ));
 
     output.push_str(&format!(
-        "{}Files Scanned:{} {} (Python: {}, JavaScript: {}, TypeScript: {})\n\n",
+        "{}Files Scanned:{} {} (Python: {}, JavaScript: {}, TypeScript: {}, C#: {}, Rust: {}, Scala: {})\n\n",
         dim, reset,
         fold_map.stats.total_files,
         fold_map.stats.python_files,
         fold_map.stats.javascript_files,
-        fold_map.stats.typescript_files
+        fold_map.stats.typescript_files,
+        fold_map.stats.csharp_files,
+        fold_map.stats.rust_files,
+        fold_map.stats.scala_files
     ));
 
     output.push_str(&format!(
@@ -398,10 +861,11 @@ fn format_summary_ansi(fold_map: &FoldMap) -> String {
     ));
 
     output.push_str(&format!(
-        "{}Scan:{} {}ms ({:.2} files/sec)\n",
+        "{}Scan:{} {}ms ({:.2} files/sec, {:.2} MB/sec)\n",
         dim, reset,
         fold_map.metadata.scan_duration_ms,
         fold_map.metadata.files_per_second,
+        fold_map.metadata.mb_per_second,
     ));
 
     output
@@ -413,4 +877,87 @@ pub enum FormatError {
     JsonError(#[from] serde_json::Error),
     #[error("YAML serialization error: {0}")]
     YamlError(#[from] serde_yaml::Error),
+    #[error("TOML serialization error: {0}")]
+    TomlError(#[from] ::toml::ser::Error),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{FoldStats, Language, ScanMetadata, SourceFile, Suppression};
+    use std::collections::BTreeMap;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_format_entry_points() {
+        let file = SourceFile {
+            path: PathBuf::from("src/main.py"),
+            absolute_path: PathBuf::from("/test/src/main.py"),
+            language: Language::Python,
+            folds: vec![],
+            line_count: 5,
+            parsed: true,
+            error: None,
+            node_kinds: BTreeMap::new(),
+            byte_count: 40,
+            main_guard: false,
+            suppressions: vec![],
+            line_index: BTreeMap::new(),
+        };
+        let fold_map = FoldMap {
+            root: PathBuf::from("/test"),
+            files: vec![file],
+            stats: FoldStats::default(),
+            metadata: ScanMetadata::default(),
+            package_entry_points: vec![],
+        };
+        let indent = IndentStyle::default();
+
+        let text = format_entry_points(&fold_map, OutputFormat::Summary, indent).unwrap();
+        assert_eq!(text, "src/main.py");
+
+        let json = format_entry_points(&fold_map, OutputFormat::Json, indent).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["entry_points"][0], "src/main.py");
+
+        let yaml = format_entry_points(&fold_map, OutputFormat::Yaml, indent).unwrap();
+        assert_eq!(yaml.trim(), "entry_points:\n- src/main.py");
+    }
+
+    #[test]
+    fn test_format_suppressions() {
+        let file = SourceFile {
+            path: PathBuf::from("src/app.ts"),
+            absolute_path: PathBuf::from("/test/src/app.ts"),
+            language: Language::TypeScript,
+            folds: vec![],
+            line_count: 5,
+            parsed: true,
+            error: None,
+            node_kinds: BTreeMap::new(),
+            byte_count: 40,
+            main_guard: false,
+            suppressions: vec![Suppression { line: 3, kind: SuppressionKind::TsIgnore }],
+            line_index: BTreeMap::new(),
+        };
+        let fold_map = FoldMap {
+            root: PathBuf::from("/test"),
+            files: vec![file],
+            stats: FoldStats::default(),
+            metadata: ScanMetadata::default(),
+            package_entry_points: vec![],
+        };
+        let indent = IndentStyle::default();
+
+        let text = format_suppressions(&fold_map, OutputFormat::Summary, indent).unwrap();
+        assert_eq!(text, "src/app.ts:3: @ts-ignore");
+
+        let json = format_suppressions(&fold_map, OutputFormat::Json, indent).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["suppressions"][0]["kind"], "ts_ignore");
+        assert_eq!(value["suppressions"][0]["line"], 3);
+
+        let toml = format_suppressions(&fold_map, OutputFormat::Toml, indent).unwrap();
+        assert!(toml.contains("[[suppressions]]"));
+    }
 }
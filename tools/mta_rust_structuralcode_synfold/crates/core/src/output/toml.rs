@@ -0,0 +1,65 @@
+use super::FormatError;
+use serde::Serialize;
+
+/// Serialize any value to TOML
+///
+/// Lists of structs such as `files` become `[[files]]` arrays of tables and
+/// nested structs such as `stats` become `[stats]` tables. The top-level value
+/// must be a struct or map; values TOML cannot represent (e.g. maps with
+/// non-string keys) produce `FormatError::TomlError`.
+pub fn to_toml<T: Serialize + ?Sized>(value: &T) -> Result<String, FormatError> {
+    ::toml::to_string_pretty(value).map_err(FormatError::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{
+        FoldMap, FoldRegion, FoldStats, FoldType, Language, ScanMetadata, SourceFile,
+    };
+    use std::collections::{BTreeMap, HashMap};
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_to_toml() {
+        let mut file = SourceFile {
+            path: PathBuf::from("app.py"),
+            absolute_path: PathBuf::from("/test/app.py"),
+            language: Language::Python,
+            folds: vec![FoldRegion::new(FoldType::Block, 0, 40, 1, 5, 0, 0)],
+            line_count: 5,
+            parsed: true,
+            error: None,
+            node_kinds: BTreeMap::new(),
+            byte_count: 40,
+            main_guard: false,
+            suppressions: vec![],
+            line_index: BTreeMap::new(),
+        };
+        file.line_index = file.build_line_index();
+        let fold_map = FoldMap {
+            root: PathBuf::from("/test"),
+            files: vec![file],
+            stats: FoldStats::default(),
+            metadata: ScanMetadata::default(),
+            package_entry_points: vec![],
+        };
+
+        let toml = to_toml(&fold_map).unwrap();
+        assert!(toml.contains("root = \"/test\""));
+        assert!(toml.contains("[[files]]"));
+        assert!(toml.contains("[[files.folds]]"));
+        assert!(toml.contains("[stats]"));
+        // Line numbers serialize as table keys
+        assert!(toml.contains("[files.line_index.1]"));
+    }
+
+    #[test]
+    fn test_to_toml_unrepresentable() {
+        let keyed: HashMap<(u8, u8), usize> = HashMap::from([((1, 2), 3)]);
+        assert!(matches!(to_toml(&keyed), Err(FormatError::TomlError(_))));
+
+        // TOML documents must be tables
+        assert!(matches!(to_toml(&[1, 2]), Err(FormatError::TomlError(_))));
+    }
+}
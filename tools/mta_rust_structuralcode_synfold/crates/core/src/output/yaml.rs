@@ -19,6 +19,7 @@ mod tests {
             files: vec![],
             stats: FoldStats::default(),
             metadata: ScanMetadata::default(),
+            package_entry_points: vec![],
         };
 
         let yaml = to_yaml(&fold_map).unwrap();
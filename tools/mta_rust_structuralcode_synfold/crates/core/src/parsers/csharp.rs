@@ -0,0 +1,425 @@
+use crate::config::ScanConfig;
+use crate::models::{FoldRegion, FoldType, Language, PreviewMode};
+use tree_sitter::{Node, Parser};
+
+use super::{
+    assign_fold_depths, collect_region_folds, count_node_kinds, mark_blank_lines,
+    merge_adjacent_folds, record_discovery_order, FoldParser, ParserError,
+};
+use std::collections::BTreeMap;
+
+pub struct CSharpParser {
+    parser: Parser,
+}
+
+impl CSharpParser {
+    pub fn new() -> Result<Self, ParserError> {
+        let mut parser = Parser::new();
+        parser
+            .set_language(&tree_sitter_c_sharp::LANGUAGE.into())
+            .map_err(|e| ParserError::InitError(e.to_string()))?;
+
+        Ok(Self { parser })
+    }
+
+    /// Extract fold regions from the parse tree
+    fn extract_folds(
+        &self,
+        source: &str,
+        tree: &tree_sitter::Tree,
+        config: &ScanConfig,
+    ) -> Vec<FoldRegion> {
+        let mut folds = Vec::new();
+        let root = tree.root_node();
+
+        self.traverse_node(&root, source, &mut folds, config);
+        if config.fold_filter.fold_regions {
+            folds.extend(collect_region_folds(tree, source, &["comment", "preproc_region", "preproc_endregion"]));
+        }
+
+        record_discovery_order(&mut folds);
+
+        // Sort by start position and filter by min_fold_lines
+        folds.sort_by_key(|f| (f.start_byte, -(f.end_byte as i64)));
+
+        // Apply min_fold_lines filter for block-type folds
+        let min_fold_lines = config.min_fold_lines_for(&self.language());
+        let mut folds: Vec<FoldRegion> = folds
+            .into_iter()
+            .filter(|f| match f.fold_type {
+                FoldType::Block | FoldType::ClassBody => f.line_count >= min_fold_lines,
+                FoldType::Import => f.line_count >= 2,
+                FoldType::Comment => f.line_count >= config.min_doc_lines.unwrap_or(1),
+                FoldType::DocComment => f.line_count >= config.min_doc_lines.unwrap_or(2),
+                _ => true,
+            })
+            .collect();
+
+        assign_fold_depths(&mut folds);
+        let mut folds = merge_adjacent_folds(folds, config);
+        mark_blank_lines(&mut folds, source);
+        folds
+    }
+
+    fn traverse_node(
+        &self,
+        node: &Node,
+        source: &str,
+        folds: &mut Vec<FoldRegion>,
+        config: &ScanConfig,
+    ) {
+        let kind = node.kind();
+
+        match kind {
+            // Type declarations
+            "class_declaration" | "interface_declaration" | "struct_declaration"
+            | "record_declaration" => {
+                if config.fold_filter.fold_classes {
+                    if let Some(body) = node.child_by_field_name("body") {
+                        if let Some(mut f) = self.create_fold(&body, FoldType::ClassBody) {
+                            f.preview = Some(self.generate_signature_preview(
+                                node,
+                                &body,
+                                source,
+                                config.preview_mode,
+                            ));
+                            folds.push(f);
+                        }
+                    }
+                }
+            }
+
+            // Namespaces, members with bodies, and properties with accessor lists
+            "namespace_declaration"
+            | "method_declaration"
+            | "constructor_declaration"
+            | "property_declaration" => {
+                if config.fold_filter.fold_blocks {
+                    let body = node
+                        .child_by_field_name("body")
+                        .or_else(|| node.child_by_field_name("accessors"));
+                    if let Some(body) = body {
+                        if let Some(mut f) = self.create_fold(&body, FoldType::Block) {
+                            f.preview = Some(self.generate_signature_preview(
+                                node,
+                                &body,
+                                source,
+                                config.preview_mode,
+                            ));
+                            folds.push(f);
+                        }
+                    }
+                }
+            }
+
+            // Using directives (consecutive block)
+            "using_directive" => {
+                if config.fold_filter.fold_imports && !self.follows_using(node) {
+                    if let Some(f) = self.collect_using_block(node, source, config) {
+                        folds.push(f);
+                    }
+                }
+            }
+
+            // Parameter lists spanning several lines
+            "parameter_list" => {
+                if config.fold_filter.fold_arglists
+                    && node.end_position().row > node.start_position().row
+                {
+                    if let Some(f) = self.create_fold(node, FoldType::ArgList) {
+                        folds.push(f);
+                    }
+                }
+            }
+
+            // Comments: block comments fold individually, `///` runs fold as doc comments
+            "comment" => {
+                let text = self.get_node_text(node, source);
+                if text.starts_with("///") {
+                    if config.fold_filter.fold_docs && !self.continues_doc_comment(node, source) {
+                        if let Some(f) = self.collect_doc_comment(node, source, config) {
+                            folds.push(f);
+                        }
+                    }
+                } else if config.fold_filter.fold_comments
+                    && node.end_position().row > node.start_position().row
+                {
+                    if let Some(f) = self.create_fold(node, FoldType::Comment) {
+                        folds.push(f);
+                    }
+                }
+            }
+
+            _ => {}
+        }
+
+        // Recurse into children
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            self.traverse_node(&child, source, folds, config);
+        }
+    }
+
+    fn create_fold(&self, node: &Node, fold_type: FoldType) -> Option<FoldRegion> {
+        Some(FoldRegion::new(
+            fold_type,
+            node.start_byte(),
+            node.end_byte(),
+            node.start_position().row + 1,
+            node.end_position().row + 1,
+            node.start_position().column,
+            node.end_position().column,
+        ))
+    }
+
+    fn get_node_text(&self, node: &Node, source: &str) -> String {
+        source[node.byte_range()].to_string()
+    }
+
+    /// Declaration text from its start up to the body, collapsed to one line
+    fn get_signature(&self, node: &Node, body: &Node, source: &str) -> String {
+        source[node.start_byte()..body.start_byte()]
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// Whether the previous sibling (ignoring comments) is also a using directive
+    fn follows_using(&self, node: &Node) -> bool {
+        let mut prev = node.prev_sibling();
+        while let Some(ps) = prev {
+            match ps.kind() {
+                "using_directive" => return true,
+                "comment" => prev = ps.prev_sibling(),
+                _ => return false,
+            }
+        }
+        false
+    }
+
+    fn collect_using_block(
+        &self,
+        start_node: &Node,
+        source: &str,
+        config: &ScanConfig,
+    ) -> Option<FoldRegion> {
+        let mut end_node = *start_node;
+        let mut namespaces = vec![self.using_target(start_node, source)];
+
+        // Walk forward to find consecutive using directives
+        let mut next = start_node.next_sibling();
+        while let Some(ns) = next {
+            if ns.kind() == "using_directive" {
+                namespaces.push(self.using_target(&ns, source));
+                end_node = ns;
+                next = ns.next_sibling();
+            } else if ns.kind() == "comment" {
+                // Allow comments between usings
+                next = ns.next_sibling();
+            } else {
+                break;
+            }
+        }
+
+        if namespaces.len() < 2 {
+            return None;
+        }
+
+        let mut fold = FoldRegion::new(
+            FoldType::Import,
+            start_node.start_byte(),
+            end_node.end_byte(),
+            start_node.start_position().row + 1,
+            end_node.end_position().row + 1,
+            start_node.start_position().column,
+            end_node.end_position().column,
+        );
+        fold.preview = Some(match config.preview_mode {
+            PreviewMode::Minimal => format!("{} usings", namespaces.len()),
+            PreviewMode::Names | PreviewMode::Flow | PreviewMode::Context => {
+                if namespaces.len() <= 5 {
+                    namespaces.join(", ")
+                } else {
+                    format!("{}, +{} more", namespaces[..4].join(", "), namespaces.len() - 4)
+                }
+            }
+            PreviewMode::Source => source[start_node.start_byte()..end_node.end_byte()].to_string(),
+        });
+        Some(fold)
+    }
+
+    /// Namespace named by a using directive (`using System.IO;` -> `System.IO`)
+    fn using_target(&self, node: &Node, source: &str) -> String {
+        let text = self.get_node_text(node, source);
+        text.trim()
+            .trim_start_matches("global ")
+            .trim_start_matches("using ")
+            .trim_start_matches("static ")
+            .trim_end_matches(';')
+            .trim()
+            .to_string()
+    }
+
+    /// Whether this `///` comment continues a doc comment on the previous line
+    fn continues_doc_comment(&self, node: &Node, source: &str) -> bool {
+        match node.prev_sibling() {
+            Some(ps) => {
+                ps.kind() == "comment"
+                    && ps.end_position().row + 1 == node.start_position().row
+                    && self.get_node_text(&ps, source).starts_with("///")
+            }
+            None => false,
+        }
+    }
+
+    fn collect_doc_comment(
+        &self,
+        start_node: &Node,
+        source: &str,
+        config: &ScanConfig,
+    ) -> Option<FoldRegion> {
+        let mut end_node = *start_node;
+        let mut next = start_node.next_sibling();
+        while let Some(ns) = next {
+            if ns.kind() == "comment"
+                && ns.start_position().row == end_node.end_position().row + 1
+                && self.get_node_text(&ns, source).starts_with("///")
+            {
+                end_node = ns;
+                next = ns.next_sibling();
+            } else {
+                break;
+            }
+        }
+
+        let mut fold = FoldRegion::new(
+            FoldType::DocComment,
+            start_node.start_byte(),
+            end_node.end_byte(),
+            start_node.start_position().row + 1,
+            end_node.end_position().row + 1,
+            start_node.start_position().column,
+            end_node.end_position().column,
+        );
+
+        let text = &source[start_node.start_byte()..end_node.end_byte()];
+        fold.preview = Some(match config.preview_mode {
+            PreviewMode::Source => text.to_string(),
+            _ => {
+                // First line of prose, skipping XML tags such as <summary>
+                let first = text
+                    .lines()
+                    .map(|l| l.trim().trim_start_matches("///").trim())
+                    .find(|l| !l.is_empty() && !l.starts_with('<'))
+                    .unwrap_or("");
+                format!("/// {}...", first)
+            }
+        });
+        Some(fold)
+    }
+
+    fn generate_signature_preview(
+        &self,
+        node: &Node,
+        body: &Node,
+        source: &str,
+        mode: PreviewMode,
+    ) -> String {
+        match mode {
+            PreviewMode::Source => self.get_node_text(body, source),
+            _ => self.get_signature(node, body, source),
+        }
+    }
+}
+
+impl FoldParser for CSharpParser {
+    fn parse(&mut self, source: &str, config: &ScanConfig) -> Vec<FoldRegion> {
+        match self.parser.parse(source, None) {
+            Some(tree) => self.extract_folds(source, &tree, config),
+            None => vec![],
+        }
+    }
+
+    fn node_kinds(&mut self, source: &str) -> BTreeMap<String, usize> {
+        match self.parser.parse(source, None) {
+            Some(tree) => count_node_kinds(&tree),
+            None => BTreeMap::new(),
+        }
+    }
+
+    fn language(&self) -> Language {
+        Language::CSharp
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn default_config() -> ScanConfig {
+        ScanConfig::default()
+            .with_min_fold_lines(2)
+            .with_fold_filter(crate::models::FoldFilter::all())
+    }
+
+    #[test]
+    fn test_class_with_methods() {
+        let mut parser = CSharpParser::new().unwrap();
+        let source = r#"
+public class Greeter
+{
+    public string Hello(string name)
+    {
+        var greeting = "Hello, " + name;
+        return greeting;
+    }
+
+    public int Count()
+    {
+        var total = 0;
+        return total;
+    }
+}
+"#;
+        let folds = parser.parse(source, &default_config());
+
+        let class_folds: Vec<_> = folds.iter().filter(|f| f.fold_type == FoldType::ClassBody).collect();
+        assert_eq!(class_folds.len(), 1);
+        assert_eq!(class_folds[0].preview.as_deref(), Some("public class Greeter"));
+
+        let method_folds: Vec<_> = folds.iter().filter(|f| f.fold_type == FoldType::Block).collect();
+        assert_eq!(method_folds.len(), 2);
+        assert!(method_folds.iter().all(|f| f.depth == 1));
+        assert_eq!(
+            method_folds[0].preview.as_deref(),
+            Some("public string Hello(string name)")
+        );
+    }
+
+    #[test]
+    fn test_using_block_fold() {
+        let mut parser = CSharpParser::new().unwrap();
+        let source = r#"using System;
+using System.Collections.Generic;
+using System.Linq;
+
+namespace App
+{
+    class Program
+    {
+    }
+}
+"#;
+        let config = default_config().with_preview_mode(PreviewMode::Names);
+        let folds = parser.parse(source, &config);
+
+        let imports: Vec<_> = folds.iter().filter(|f| f.fold_type == FoldType::Import).collect();
+        assert_eq!(imports.len(), 1);
+        assert_eq!(imports[0].start_line, 1);
+        assert_eq!(imports[0].end_line, 3);
+        assert_eq!(
+            imports[0].preview.as_deref(),
+            Some("System, System.Collections.Generic, System.Linq")
+        );
+    }
+}
@@ -1,8 +1,13 @@
 use crate::config::ScanConfig;
-use crate::models::{FoldRegion, FoldType, Language, PreviewMode};
+use crate::models::{FoldRegion, FoldType, Language, PreviewMode, Suppression};
 use tree_sitter::{Node, Parser};
 
-use super::{FoldParser, ParserError};
+use super::{
+    append_child_count, assign_fold_depths, count_direct_children, count_node_kinds,
+    chain_preview, chain_segment, collect_region_folds, collect_suppressions, first_last_lines,
+    mark_blank_lines, merge_adjacent_folds, record_discovery_order, FoldParser, ParserError,
+};
+use std::collections::BTreeMap;
 
 pub struct JavaScriptParser {
     parser: Parser,
@@ -39,22 +44,38 @@ impl JavaScriptParser {
         let root = tree.root_node();
 
         self.traverse_node(&root, source, &mut folds, config);
+        if config.fold_filter.fold_regions {
+            folds.extend(collect_region_folds(tree, source, &["comment"]));
+        }
+
+        record_discovery_order(&mut folds);
 
         // Sort by start position
         folds.sort_by_key(|f| (f.start_byte, -(f.end_byte as i64)));
 
         // Apply min_fold_lines filter for block-type folds
-        folds
+        let min_fold_lines = config.min_fold_lines_for(&self.language());
+        let mut folds: Vec<FoldRegion> = folds
             .into_iter()
             .filter(|f| match f.fold_type {
-                FoldType::Block | FoldType::ClassBody => f.line_count >= config.min_fold_lines,
+                FoldType::Block | FoldType::ClassBody | FoldType::JsxElement => {
+                    f.line_count >= min_fold_lines
+                }
                 FoldType::Import => f.line_count >= 2,
+                FoldType::Comment | FoldType::DocComment => {
+                    f.line_count >= config.min_doc_lines.unwrap_or(1)
+                }
                 FoldType::Literal | FoldType::ArrayLiteral | FoldType::ObjectLiteral => {
                     f.line_count >= 2
                 }
                 _ => true,
             })
-            .collect()
+            .collect();
+
+        assign_fold_depths(&mut folds);
+        let mut folds = merge_adjacent_folds(folds, config);
+        mark_blank_lines(&mut folds, source);
+        folds
     }
 
     fn traverse_node(
@@ -94,7 +115,12 @@ impl JavaScriptParser {
                     if let Some(body) = node.child_by_field_name("body") {
                         let fold = self.create_fold(&body, FoldType::ClassBody, source);
                         if let Some(mut f) = fold {
-                            f.preview = Some(self.get_class_signature(node, source));
+                            let signature = self.get_class_signature(node, source);
+                            f.preview = Some(if config.closing_context {
+                                append_child_count(signature, count_direct_children(&body), "members")
+                            } else {
+                                signature
+                            });
                             folds.push(f);
                         }
                     }
@@ -215,6 +241,7 @@ impl JavaScriptParser {
                                 source,
                                 f.line_count,
                                 config.preview_mode,
+                                config.closing_context,
                             ));
                             folds.push(f);
                         }
@@ -233,6 +260,7 @@ impl JavaScriptParser {
                                 source,
                                 f.line_count,
                                 config.preview_mode,
+                                config.closing_context,
                             ));
                             folds.push(f);
                         }
@@ -240,6 +268,20 @@ impl JavaScriptParser {
                 }
             }
 
+            // JSX elements (self-closing elements have no body to fold)
+            "jsx_element" => {
+                if config.fold_filter.fold_jsx {
+                    if node.end_position().row > node.start_position().row {
+                        let fold = self.create_fold(node, FoldType::JsxElement, source);
+                        if let Some(mut f) = fold {
+                            f.preview =
+                                Some(self.generate_jsx_preview(node, source, config.preview_mode));
+                            folds.push(f);
+                        }
+                    }
+                }
+            }
+
             // Chained method calls
             "call_expression" => {
                 if config.fold_filter.fold_chains {
@@ -249,7 +291,7 @@ impl JavaScriptParser {
                         || (parent.is_some() && parent.unwrap().kind() != "member_expression");
 
                     if is_outermost {
-                        if let Some(chain_fold) = self.detect_chain(node, source) {
+                        if let Some(chain_fold) = self.detect_chain(node, source, config) {
                             folds.push(chain_fold);
                         }
                     }
@@ -388,26 +430,40 @@ impl JavaScriptParser {
         }
     }
 
-    fn detect_chain(&self, node: &Node, _source: &str) -> Option<FoldRegion> {
-        // Count depth of chained calls
+    fn detect_chain(&self, node: &Node, source: &str, config: &ScanConfig) -> Option<FoldRegion> {
+        // Count depth of chained calls, collecting link names from the outside in
         let mut depth = 0;
-        let mut current = node.clone();
+        let mut segments = Vec::new();
+        let mut current = *node;
 
         while current.kind() == "call_expression" {
             depth += 1;
-            if let Some(func) = current.child_by_field_name("function") {
-                if func.kind() == "member_expression" {
-                    if let Some(obj) = func.child_by_field_name("object") {
-                        current = obj;
-                        continue;
-                    }
+            let Some(func) = current.child_by_field_name("function") else {
+                break;
+            };
+            match (
+                func.kind(),
+                func.child_by_field_name("object"),
+                func.child_by_field_name("property"),
+            ) {
+                ("member_expression", Some(object), Some(name)) => {
+                    segments.push(chain_segment(&name, source));
+                    current = object;
+                }
+                _ => {
+                    // Chain starts with a plain call: `foo().bar()`
+                    segments.push(chain_segment(&func, source));
+                    break;
                 }
             }
-            break;
+        }
+        if current.kind() != "call_expression" {
+            segments.push(chain_segment(&current, source));
         }
 
-        // Only fold chains with 3+ calls that span multiple lines
-        if depth >= 3 && node.end_position().row > node.start_position().row {
+        // Only fold chains of `min_chain_depth`+ calls that span multiple lines
+        if depth >= config.min_chain_depth && node.end_position().row > node.start_position().row {
+            segments.reverse();
             let mut fold = FoldRegion::new(
                 FoldType::ChainedCall,
                 node.start_byte(),
@@ -417,7 +473,7 @@ impl JavaScriptParser {
                 node.start_position().column,
                 node.end_position().column,
             );
-            fold.preview = Some(format!("...chain ({} calls)", depth));
+            fold.preview = Some(chain_preview(&segments, depth, config.preview_mode));
             Some(fold)
         } else {
             None
@@ -548,12 +604,17 @@ impl JavaScriptParser {
         }
     }
 
-    /// Extract key names from an object literal
-    fn extract_object_keys(&self, node: &Node, source: &str) -> Vec<String> {
+    /// Extract key names from an object literal, along with its direct child count
+    fn extract_object_keys(&self, node: &Node, source: &str) -> (Vec<String>, usize) {
         let mut keys = Vec::new();
+        let mut child_count = 0;
 
         let mut cursor = node.walk();
         for child in node.children(&mut cursor) {
+            // A spread adds an unknown number of properties, so it is not counted
+            if child.is_named() && !matches!(child.kind(), "comment" | "spread_element") {
+                child_count += 1;
+            }
             if child.kind() == "pair" {
                 if let Some(key) = child.child_by_field_name("key") {
                     let key_text = self.get_node_text(&key, source);
@@ -579,7 +640,7 @@ impl JavaScriptParser {
             }
         }
 
-        keys
+        (keys, child_count)
     }
 
     /// Generate preview based on mode
@@ -592,7 +653,7 @@ impl JavaScriptParser {
     ) -> String {
         match mode {
             PreviewMode::Minimal => format!("{} imports", import_count),
-            PreviewMode::Names | PreviewMode::Flow => {
+            PreviewMode::Names | PreviewMode::Flow | PreviewMode::Context => {
                 let modules = self.collect_import_modules(start_node, source);
                 if modules.is_empty() {
                     format!("{} imports", import_count)
@@ -628,6 +689,11 @@ impl JavaScriptParser {
                     format!("{} -> {}", signature, flow.join("/"))
                 }
             }
+            PreviewMode::Context => {
+                let body_text = self.get_node_text(body, source);
+                let inner = body_text.trim().trim_start_matches('{').trim_end_matches('}');
+                format!("{} {{ {} }}", signature, first_last_lines(inner))
+            }
             PreviewMode::Source => {
                 // Return full source of the function
                 self.get_node_text(node, source)
@@ -641,23 +707,26 @@ impl JavaScriptParser {
         source: &str,
         line_count: usize,
         mode: PreviewMode,
+        closing_context: bool,
     ) -> String {
-        match mode {
-            PreviewMode::Minimal => format!("{{...}} ({} lines)", line_count),
-            PreviewMode::Names | PreviewMode::Flow => {
-                let keys = self.extract_object_keys(node, source);
-                if keys.is_empty() {
-                    format!("{{...}} ({} lines)", line_count)
-                } else if keys.len() <= 5 {
-                    format!("{{ {} }}", keys.join(", "))
-                } else {
-                    format!("{{ {}, +{} more }}", keys[..4].join(", "), keys.len() - 4)
-                }
-            }
-            PreviewMode::Source => {
-                // Return full source of the object
-                self.get_node_text(node, source)
-            }
+        if mode == PreviewMode::Source {
+            // Return full source of the object
+            return self.get_node_text(node, source);
+        }
+
+        let (keys, child_count) = self.extract_object_keys(node, source);
+        let preview = if mode == PreviewMode::Minimal || keys.is_empty() {
+            format!("{{...}} ({} lines)", line_count)
+        } else if keys.len() <= 5 {
+            format!("{{ {} }}", keys.join(", "))
+        } else {
+            format!("{{ {}, +{} more }}", keys[..4].join(", "), keys.len() - 4)
+        };
+
+        if closing_context {
+            append_child_count(preview, child_count, "props")
+        } else {
+            preview
         }
     }
 
@@ -672,6 +741,11 @@ impl JavaScriptParser {
             PreviewMode::Minimal | PreviewMode::Names | PreviewMode::Flow => {
                 format!("\"...\" ({} lines)", line_count)
             }
+            PreviewMode::Context => {
+                // Opening and closing quote lines
+                let text = self.get_node_text(node, source);
+                format!("{} ({} lines)", first_last_lines(&text), line_count)
+            }
             PreviewMode::Source => {
                 self.get_node_text(node, source)
             }
@@ -686,8 +760,32 @@ impl JavaScriptParser {
         mode: PreviewMode,
     ) -> String {
         match mode {
-            PreviewMode::Minimal | PreviewMode::Names | PreviewMode::Flow => {
-                format!("`...` ({} lines)", line_count)
+            PreviewMode::Minimal => match self.get_template_tag(node, source) {
+                Some(tag) => format!("{}`...` ({} lines)", tag, line_count),
+                None => format!("`...` ({} lines)", line_count),
+            },
+            PreviewMode::Names | PreviewMode::Flow => match self.get_template_tag(node, source) {
+                Some(tag) => {
+                    // Show the leading keyword of the embedded language (e.g. `query`)
+                    let text = self.get_node_text(node, source);
+                    let first_word = text
+                        .trim_start_matches('`')
+                        .split_whitespace()
+                        .next()
+                        .unwrap_or("")
+                        .trim_end_matches('`')
+                        .to_string();
+                    format!("{}`{}...` ({} lines)", tag, first_word, line_count)
+                }
+                None => format!("`...` ({} lines)", line_count),
+            },
+            PreviewMode::Context => {
+                let text = self.get_node_text(node, source);
+                let preview = format!("{} ({} lines)", first_last_lines(&text), line_count);
+                match self.get_template_tag(node, source) {
+                    Some(tag) => format!("{}{}", tag, preview),
+                    None => preview,
+                }
             }
             PreviewMode::Source => {
                 self.get_node_text(node, source)
@@ -695,6 +793,23 @@ impl JavaScriptParser {
         }
     }
 
+    /// Get the tag of a tagged template literal (e.g. `gql`, `css`, `styled.div`)
+    fn get_template_tag(&self, node: &Node, source: &str) -> Option<String> {
+        let parent = node.parent()?;
+        if parent.kind() != "call_expression" {
+            return None;
+        }
+
+        // Tagged templates parse as calls whose arguments are the template itself
+        let arguments = parent.child_by_field_name("arguments")?;
+        if arguments.id() != node.id() {
+            return None;
+        }
+
+        let tag = parent.child_by_field_name("function")?;
+        Some(self.get_node_text(&tag, source))
+    }
+
     fn generate_jsdoc_preview(
         &self,
         node: &Node,
@@ -703,7 +818,10 @@ impl JavaScriptParser {
         mode: PreviewMode,
     ) -> String {
         match mode {
-            PreviewMode::Minimal | PreviewMode::Names | PreviewMode::Flow => {
+            PreviewMode::Minimal
+            | PreviewMode::Names
+            | PreviewMode::Flow
+            | PreviewMode::Context => {
                 format!("/**...*/ ({} lines)", line_count)
             }
             PreviewMode::Source => {
@@ -720,7 +838,10 @@ impl JavaScriptParser {
         mode: PreviewMode,
     ) -> String {
         match mode {
-            PreviewMode::Minimal | PreviewMode::Names | PreviewMode::Flow => {
+            PreviewMode::Minimal
+            | PreviewMode::Names
+            | PreviewMode::Flow
+            | PreviewMode::Context => {
                 format!("/*...*/ ({} lines)", line_count)
             }
             PreviewMode::Source => {
@@ -735,10 +856,19 @@ impl JavaScriptParser {
         source: &str,
         line_count: usize,
         mode: PreviewMode,
+        closing_context: bool,
     ) -> String {
         match mode {
-            PreviewMode::Minimal | PreviewMode::Names | PreviewMode::Flow => {
-                format!("[...] ({} lines)", line_count)
+            PreviewMode::Minimal
+            | PreviewMode::Names
+            | PreviewMode::Flow
+            | PreviewMode::Context => {
+                let preview = format!("[...] ({} lines)", line_count);
+                if closing_context {
+                    append_child_count(preview, count_direct_children(node), "items")
+                } else {
+                    preview
+                }
             }
             PreviewMode::Source => {
                 self.get_node_text(node, source)
@@ -746,6 +876,20 @@ impl JavaScriptParser {
         }
     }
 
+    /// Preview for a JSX element: `<Name …>` from its opening tag (`<>…</>` for fragments)
+    fn generate_jsx_preview(&self, node: &Node, source: &str, mode: PreviewMode) -> String {
+        let Some(opening) = node.child_by_field_name("open_tag") else {
+            return "<…>".to_string();
+        };
+        if mode == PreviewMode::Source {
+            return self.get_node_text(&opening, source);
+        }
+        match opening.child_by_field_name("name") {
+            Some(name) => format!("<{} …>", self.get_node_text(&name, source)),
+            None => "<>…</>".to_string(),
+        }
+    }
+
     /// Get the full source text of an import block
     fn get_import_block_source(&self, start_node: &Node, source: &str) -> String {
         let mut end_node = start_node.clone();
@@ -777,6 +921,20 @@ impl FoldParser for JavaScriptParser {
         }
     }
 
+    fn node_kinds(&mut self, source: &str) -> BTreeMap<String, usize> {
+        match self.parser.parse(source, None) {
+            Some(tree) => count_node_kinds(&tree),
+            None => BTreeMap::new(),
+        }
+    }
+
+    fn suppressions(&mut self, source: &str) -> Vec<Suppression> {
+        match self.parser.parse(source, None) {
+            Some(tree) => collect_suppressions(&tree, source, &["comment"]),
+            None => Vec::new(),
+        }
+    }
+
     fn language(&self) -> Language {
         if self.is_typescript {
             Language::TypeScript
@@ -811,6 +969,37 @@ function hello() {
         assert!(folds.iter().any(|f| f.fold_type == FoldType::Block));
     }
 
+    #[test]
+    fn test_min_chain_depth() {
+        let source = r#"
+const names = users
+    .filter(u => u.active)
+    .map(u => u.name);
+"#;
+        let chains = |config: ScanConfig| -> Vec<FoldRegion> {
+            let mut parser = JavaScriptParser::new(false).unwrap();
+            parser
+                .parse(source, &config)
+                .into_iter()
+                .filter(|f| f.fold_type == FoldType::ChainedCall)
+                .collect()
+        };
+
+        // Two calls are below the default threshold of three
+        assert!(chains(default_config()).is_empty());
+
+        let folds = chains(default_config().with_min_chain_depth(2));
+        assert_eq!(folds.len(), 1);
+        assert_eq!(folds[0].preview.as_deref(), Some("...chain (2 calls)"));
+
+        let folds = chains(
+            default_config()
+                .with_min_chain_depth(2)
+                .with_preview_mode(PreviewMode::Names),
+        );
+        assert_eq!(folds[0].preview.as_deref(), Some("users.filter…map"));
+    }
+
     #[test]
     fn test_arrow_function_fold() {
         let mut parser = JavaScriptParser::new(false).unwrap();
@@ -884,6 +1073,27 @@ const config = {
         assert!(folds.iter().any(|f| f.fold_type == FoldType::ObjectLiteral));
     }
 
+    #[test]
+    fn test_tagged_template_fold() {
+        let mut parser = JavaScriptParser::new(false).unwrap();
+        let source = r#"
+const GET_USER = gql`
+  query GetUser($id: ID!) {
+    user(id: $id) {
+      name
+    }
+  }
+`;
+"#;
+        let config = default_config().with_preview_mode(PreviewMode::Names);
+        let folds = parser.parse(source, &config);
+        let fold = folds
+            .iter()
+            .find(|f| f.fold_type == FoldType::Literal)
+            .expect("template literal fold");
+        assert_eq!(fold.preview.as_deref(), Some("gql`query...` (7 lines)"));
+    }
+
     #[test]
     fn test_typescript_interface_fold() {
         let mut parser = JavaScriptParser::new(true).unwrap();
@@ -901,4 +1111,175 @@ interface User {
         // The object_type inside the interface should be captured
         assert!(folds.iter().any(|f| f.fold_type == FoldType::ClassBody) || folds.is_empty());
     }
+
+    #[test]
+    fn test_region_folds() {
+        let mut parser = JavaScriptParser::new(true).unwrap();
+        let source = r#"//#region Routes
+export function list() {
+  // #region validation
+  check();
+  // #endregion
+  return [];
+}
+//#endregion
+"#;
+        let folds = parser.parse(source, &default_config());
+
+        let regions: Vec<_> = folds.iter().filter(|f| f.fold_type == FoldType::Region).collect();
+        assert_eq!(regions.len(), 2);
+        assert_eq!(regions[0].preview.as_deref(), Some("Routes"));
+        assert_eq!((regions[0].start_line, regions[0].end_line), (1, 8));
+        assert_eq!(regions[1].preview.as_deref(), Some("validation"));
+        assert_eq!((regions[1].start_line, regions[1].end_line), (3, 5));
+        assert_eq!(regions[1].depth, 2);
+    }
+
+    #[test]
+    fn test_jsx_element_folds() {
+        let source = r#"export function App({ items }) {
+  return (
+    <Layout title="Shop">
+      <ul className="items">
+        {items.map((item) => (
+          <li key={item.id}>
+            <Item.Card item={item} />
+          </li>
+        ))}
+      </ul>
+      <Footer />
+    </Layout>
+  );
+}
+"#;
+        let config = default_config().with_min_fold_lines(3);
+        let jsx = |is_typescript: bool| -> Vec<(usize, usize, usize, String)> {
+            let mut parser = JavaScriptParser::new(is_typescript).unwrap();
+            parser
+                .parse(source, &config)
+                .into_iter()
+                .filter(|f| f.fold_type == FoldType::JsxElement)
+                .map(|f| (f.start_line, f.end_line, f.depth, f.preview.unwrap()))
+                .collect()
+        };
+
+        // `<li>` spans exactly 3 lines; the self-closing `<Item.Card />` and `<Footer />` never fold
+        let expected = vec![
+            (3, 12, 1, "<Layout …>".to_string()),
+            (4, 10, 2, "<ul …>".to_string()),
+            (6, 8, 3, "<li …>".to_string()),
+        ];
+        assert_eq!(jsx(false), expected);
+        assert_eq!(jsx(true), expected);
+
+        let mut parser = JavaScriptParser::new(false).unwrap();
+        let folds = parser.parse(source, &config.clone().with_min_fold_lines(4));
+        assert_eq!(folds.iter().filter(|f| f.fold_type == FoldType::JsxElement).count(), 2);
+
+        let mut filter = crate::models::FoldFilter::all();
+        filter.fold_jsx = false;
+        let folds = parser.parse(source, &config.with_fold_filter(filter));
+        assert!(folds.iter().all(|f| f.fold_type != FoldType::JsxElement));
+    }
+
+    #[test]
+    fn test_min_doc_lines() {
+        let mut parser = JavaScriptParser::new(false).unwrap();
+        let source = r#"/**
+ * Say hello.
+ */
+function greet(name) {
+    return "Hello, " + name;
+}
+"#;
+        let config = ScanConfig::default()
+            .with_fold_filter(crate::models::FoldFilter::all())
+            .with_min_fold_lines(6);
+
+        let folds = parser.parse(source, &config.clone().with_min_doc_lines(3));
+        let docs: Vec<_> = folds
+            .iter()
+            .filter(|f| f.fold_type == FoldType::DocComment)
+            .collect();
+        assert_eq!(docs.len(), 1);
+        assert_eq!(docs[0].line_count, 3);
+        assert!(!folds.iter().any(|f| f.fold_type == FoldType::Block));
+
+        let folds = parser.parse(source, &config.with_min_doc_lines(4));
+        assert!(!folds.iter().any(|f| f.fold_type == FoldType::DocComment));
+    }
+
+    #[test]
+    fn test_closing_context() {
+        let mut parser = JavaScriptParser::new(false).unwrap();
+        let source = r#"
+const settings = {
+    host: "localhost",
+    port: 8080,
+    debug: true,
+    retries: 3,
+    timeout: 30,
+    verbose: false,
+    ...defaults,
+    log() {},
+};
+"#;
+        let config = default_config().with_preview_mode(PreviewMode::Names);
+
+        let folds = parser.parse(source, &config);
+        let fold = folds
+            .iter()
+            .find(|f| f.fold_type == FoldType::ObjectLiteral)
+            .expect("object fold");
+        assert_eq!(fold.preview.as_deref(), Some("{ host, port, debug, retries, +4 more }"));
+
+        let folds = parser.parse(source, &config.with_closing_context(true));
+        let fold = folds
+            .iter()
+            .find(|f| f.fold_type == FoldType::ObjectLiteral)
+            .expect("object fold");
+        // Six pairs and a method; the spread is listed but not counted
+        assert_eq!(
+            fold.preview.as_deref(),
+            Some("{ host, port, debug, retries, +4 more } (7 props)")
+        );
+    }
+
+    #[test]
+    fn test_merge_adjacent_comments() {
+        let mut parser = JavaScriptParser::new(false).unwrap();
+        let source = r#"/*
+ * First block
+ */
+
+/*
+ * Second block
+ */
+function greet(name) {
+    return "Hello, " + name;
+}
+"#;
+        let config = default_config();
+
+        let folds = parser.parse(source, &config);
+        assert_eq!(folds.iter().filter(|f| f.fold_type == FoldType::Comment).count(), 2);
+
+        let config = config
+            .with_merge_adjacent(std::collections::HashSet::from([FoldType::Comment]), 1);
+        let folds = parser.parse(source, &config);
+        let comments: Vec<_> = folds
+            .iter()
+            .filter(|f| f.fold_type == FoldType::Comment)
+            .collect();
+        assert_eq!(comments.len(), 1);
+        assert_eq!(comments[0].start_line, 1);
+        assert_eq!(comments[0].end_line, 7);
+        assert_eq!(comments[0].line_count, 7);
+        assert_eq!(
+            comments[0].preview.as_deref(),
+            Some("/*...*/ (3 lines) | /*...*/ (3 lines)")
+        );
+        // Other fold types are untouched
+        assert!(folds.iter().any(|f| f.fold_type == FoldType::Block));
+    }
 }
@@ -0,0 +1,221 @@
+use crate::config::ScanConfig;
+use crate::models::{FoldRegion, Language};
+
+use super::{create_parser, FoldParser};
+use std::collections::BTreeMap;
+
+/// Folds the code inside Markdown fenced code blocks.
+///
+/// Prose is ignored. Each ```` ```python ```` / ```` ```js ```` block is parsed
+/// with the parser for its language and the resulting folds are shifted to
+/// document positions and tagged with the block's language and first line.
+pub struct MarkdownParser;
+
+/// A fenced code block with a recognized language
+struct FencedBlock {
+    language: Language,
+    /// Line of the first content line (1-indexed)
+    start_line: usize,
+    /// Byte offset of the first content line
+    start_byte: usize,
+    /// Byte offset just past the last content line
+    end_byte: usize,
+}
+
+impl MarkdownParser {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Locate fenced code blocks whose info string names a supported language
+    fn fenced_blocks(source: &str) -> Vec<FencedBlock> {
+        let mut blocks = Vec::new();
+        // (fence char, fence length, language, content start line, content start byte)
+        let mut open: Option<(char, usize, Option<Language>, usize, usize)> = None;
+        let mut offset = 0;
+
+        for (idx, line) in source.split_inclusive('\n').enumerate() {
+            let line_start = offset;
+            offset += line.len();
+
+            let trimmed = line.trim_start_matches(' ');
+            if line.len() - trimmed.len() > 3 {
+                continue;
+            }
+            let trimmed = trimmed.trim_end();
+            let Some(fence_char) = trimmed.chars().next().filter(|c| *c == '`' || *c == '~') else {
+                continue;
+            };
+            let fence_len = trimmed.chars().take_while(|c| *c == fence_char).count();
+            if fence_len < 3 {
+                continue;
+            }
+
+            match open {
+                Some((open_char, open_len, ref language, start_line, start_byte)) => {
+                    // A closing fence repeats the opening character with nothing after it
+                    if fence_char == open_char && fence_len >= open_len && trimmed.len() == fence_len {
+                        if let Some(language) = language.clone() {
+                            blocks.push(FencedBlock {
+                                language,
+                                start_line,
+                                start_byte,
+                                end_byte: line_start,
+                            });
+                        }
+                        open = None;
+                    }
+                }
+                None => {
+                    let info = trimmed[fence_len..].trim();
+                    let language = info
+                        .split_whitespace()
+                        .next()
+                        .and_then(Language::from_fence_info);
+                    open = Some((fence_char, fence_len, language, idx + 2, offset));
+                }
+            }
+        }
+
+        // An unclosed fence runs to the end of the document
+        if let Some((_, _, Some(language), start_line, start_byte)) = open {
+            blocks.push(FencedBlock {
+                language,
+                start_line,
+                start_byte,
+                end_byte: source.len(),
+            });
+        }
+
+        blocks
+    }
+}
+
+impl Default for MarkdownParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Move a fold parsed from a code block to its position in the document
+fn offset_fold(fold: &mut FoldRegion, block: &FencedBlock) {
+    fold.start_byte += block.start_byte;
+    fold.end_byte += block.start_byte;
+    fold.start_line += block.start_line - 1;
+    fold.end_line += block.start_line - 1;
+    fold.embedded_language = Some(block.language.clone());
+    fold.block_start_line = Some(block.start_line);
+    for child in &mut fold.children {
+        offset_fold(child, block);
+    }
+}
+
+impl FoldParser for MarkdownParser {
+    fn parse(&mut self, source: &str, config: &ScanConfig) -> Vec<FoldRegion> {
+        let mut folds = Vec::new();
+
+        for block in Self::fenced_blocks(source) {
+            let Ok(mut parser) = create_parser(&block.language) else {
+                continue;
+            };
+            let code = &source[block.start_byte..block.end_byte];
+            let discovered = folds.len();
+            for mut fold in parser.parse(code, config) {
+                offset_fold(&mut fold, &block);
+                fold.discovery_index += discovered;
+                folds.push(fold);
+            }
+        }
+
+        folds
+    }
+
+    fn node_kinds(&mut self, source: &str) -> BTreeMap<String, usize> {
+        let mut counts: BTreeMap<String, usize> = BTreeMap::new();
+
+        for block in Self::fenced_blocks(source) {
+            let Ok(mut parser) = create_parser(&block.language) else {
+                continue;
+            };
+            for (kind, count) in parser.node_kinds(&source[block.start_byte..block.end_byte]) {
+                *counts.entry(kind).or_insert(0) += count;
+            }
+        }
+
+        counts
+    }
+
+    fn language(&self) -> Language {
+        Language::Markdown
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::FoldType;
+
+    fn default_config() -> ScanConfig {
+        ScanConfig::default()
+            .with_min_fold_lines(2)
+            .with_fold_filter(crate::models::FoldFilter::all())
+    }
+
+    #[test]
+    fn test_fenced_code_blocks() {
+        let mut parser = MarkdownParser::new();
+        let source = r#"# Usage
+
+Some prose that should be ignored.
+
+```python
+def greet(name):
+    message = "Hello, " + name
+    return message
+```
+
+And a JavaScript example:
+
+```js
+function add(a, b) {
+    const sum = a + b;
+    return sum;
+}
+```
+
+```text
+not code
+```
+"#;
+
+        let folds = parser.parse(source, &default_config());
+
+        let python: Vec<_> = folds
+            .iter()
+            .filter(|f| f.embedded_language == Some(Language::Python))
+            .collect();
+        let javascript: Vec<_> = folds
+            .iter()
+            .filter(|f| f.embedded_language == Some(Language::JavaScript))
+            .collect();
+        assert_eq!(folds.len(), python.len() + javascript.len());
+
+        let py_block = python
+            .iter()
+            .find(|f| f.fold_type == FoldType::Block)
+            .expect("python function body fold");
+        assert_eq!(py_block.block_start_line, Some(6));
+        assert_eq!(py_block.start_line, 7);
+        assert_eq!(py_block.end_line, 8);
+        assert!(source[py_block.start_byte..py_block.end_byte].contains("return message"));
+
+        let js_block = javascript
+            .iter()
+            .find(|f| f.fold_type == FoldType::Block)
+            .expect("javascript function body fold");
+        assert_eq!(js_block.block_start_line, Some(14));
+        assert_eq!(js_block.start_line, 14);
+        assert_eq!(js_block.end_line, 17);
+        assert!(source[js_block.start_byte..js_block.end_byte].contains("return sum"));
+    }
+}
@@ -1,11 +1,20 @@
+mod csharp;
 mod javascript;
+mod markdown;
 mod python;
+mod rust;
+mod scala;
 
+pub use csharp::CSharpParser;
 pub use javascript::JavaScriptParser;
+pub use markdown::MarkdownParser;
 pub use python::PythonParser;
+pub use rust::RustParser;
+pub use scala::ScalaParser;
 
 use crate::config::ScanConfig;
-use crate::models::{FoldRegion, Language};
+use crate::models::{FoldRegion, FoldType, Language, PreviewMode, Suppression, SuppressionKind};
+use std::collections::BTreeMap;
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -16,6 +25,8 @@ pub enum ParserError {
     ParseError(String),
     #[error("Unsupported language: {0:?}")]
     UnsupportedLanguage(Language),
+    #[error("Invalid query: {0}")]
+    QueryError(String),
 }
 
 /// Trait for language-specific fold parsers
@@ -23,6 +34,14 @@ pub trait FoldParser {
     /// Parse source code and extract foldable regions
     fn parse(&mut self, source: &str, config: &ScanConfig) -> Vec<FoldRegion>;
 
+    /// Count every tree-sitter node kind in the parsed source
+    fn node_kinds(&mut self, source: &str) -> BTreeMap<String, usize>;
+
+    /// Type-checker suppression comments in the source (none for languages without them)
+    fn suppressions(&mut self, _source: &str) -> Vec<Suppression> {
+        Vec::new()
+    }
+
     /// Get the language this parser handles
     fn language(&self) -> Language;
 }
@@ -33,5 +52,330 @@ pub fn create_parser(language: &Language) -> Result<Box<dyn FoldParser>, ParserE
         Language::Python => Ok(Box::new(PythonParser::new()?)),
         Language::JavaScript => Ok(Box::new(JavaScriptParser::new(false)?)),
         Language::TypeScript => Ok(Box::new(JavaScriptParser::new(true)?)),
+        Language::CSharp => Ok(Box::new(CSharpParser::new()?)),
+        Language::Rust => Ok(Box::new(RustParser::new()?)),
+        Language::Scala => Ok(Box::new(ScalaParser::new()?)),
+        Language::Markdown => Ok(Box::new(MarkdownParser::new())),
+    }
+}
+
+/// Tree-sitter grammar used for a language (None for languages parsed without tree-sitter)
+pub fn tree_sitter_language(language: &Language) -> Option<tree_sitter::Language> {
+    match language {
+        Language::Python => Some(tree_sitter_python::LANGUAGE.into()),
+        Language::JavaScript => Some(tree_sitter_javascript::LANGUAGE.into()),
+        Language::TypeScript => Some(tree_sitter_typescript::LANGUAGE_TSX.into()),
+        Language::CSharp => Some(tree_sitter_c_sharp::LANGUAGE.into()),
+        Language::Rust => Some(tree_sitter_rust::LANGUAGE.into()),
+        Language::Scala => Some(tree_sitter_scala::LANGUAGE.into()),
+        Language::Markdown => None,
+    }
+}
+
+/// Number folds in the order the parser found them, before they are sorted
+///
+/// `FoldOrder::Discovery` restores this order once the scan is done.
+pub(crate) fn record_discovery_order(folds: &mut [FoldRegion]) {
+    for (index, fold) in folds.iter_mut().enumerate() {
+        fold.discovery_index = index;
+    }
+}
+
+/// Assign nesting depth to folds sorted by (start_byte, -end_byte)
+///
+/// A fold's depth is the number of other folds that fully contain it.
+pub(crate) fn assign_fold_depths(folds: &mut [FoldRegion]) {
+    // End offsets of the folds enclosing the current position
+    let mut open_ends: Vec<usize> = Vec::new();
+
+    for fold in folds.iter_mut() {
+        while let Some(&end) = open_ends.last() {
+            if end < fold.end_byte {
+                open_ends.pop();
+            } else {
+                break;
+            }
+        }
+        fold.depth = open_ends.len();
+        open_ends.push(fold.end_byte);
+    }
+}
+
+/// Set `has_blank_lines` on folds (and their children) from the file source
+///
+/// Blank lines are counted once up front, so each fold is a prefix-sum lookup.
+pub(crate) fn mark_blank_lines(folds: &mut [FoldRegion], source: &str) {
+    fn mark(folds: &mut [FoldRegion], blanks_before: &[usize]) {
+        let last = blanks_before.len() - 1;
+        for fold in folds {
+            let start = fold.start_line.saturating_sub(1).min(last);
+            let end = fold.end_line.min(last);
+            fold.has_blank_lines = blanks_before[end] > blanks_before[start];
+            mark(&mut fold.children, blanks_before);
+        }
+    }
+
+    // blanks_before[n] = number of blank lines among the first n lines
+    let mut blanks_before = vec![0];
+    for line in source.lines() {
+        let blanks = blanks_before[blanks_before.len() - 1];
+        blanks_before.push(blanks + usize::from(line.trim().is_empty()));
+    }
+    mark(folds, &blanks_before);
+}
+
+/// Count the named children of a node, ignoring comments
+pub(crate) fn count_direct_children(node: &tree_sitter::Node) -> usize {
+    let mut cursor = node.walk();
+    node.named_children(&mut cursor)
+        .filter(|child| child.kind() != "comment")
+        .count()
+}
+
+/// Append a direct child count to a preview, e.g. `{ a, b } (2 props)`
+pub(crate) fn append_child_count(preview: String, count: usize, noun: &str) -> String {
+    format!("{} ({} {})", preview, count, noun)
+}
+
+/// First and last non-blank lines of `text` joined by an ellipsis (`PreviewMode::Context`)
+///
+/// Text with a single non-blank line is returned trimmed, without an ellipsis.
+pub(crate) fn first_last_lines(text: &str) -> String {
+    let mut lines = text.lines().map(str::trim).filter(|line| !line.is_empty());
+    let first = lines.next().unwrap_or("");
+    match lines.next_back() {
+        Some(last) => format!("{} … {}", first, last),
+        None => first.to_string(),
+    }
+}
+
+/// Preview for a `ChainedCall` fold
+///
+/// With `PreviewMode::Names` the receiver and call names are shown as `a.b…d`
+/// (`segments` in source order); other modes show the call count.
+pub(crate) fn chain_preview(segments: &[String], depth: usize, mode: PreviewMode) -> String {
+    match (mode, segments) {
+        (PreviewMode::Names, [first, second, .., last]) => {
+            format!("{}.{}…{}", first, second, last)
+        }
+        (PreviewMode::Names, [_, ..]) => segments.join("."),
+        _ => format!("...chain ({} calls)", depth),
+    }
+}
+
+/// First line of a chain link's source, for `chain_preview`
+pub(crate) fn chain_segment(node: &tree_sitter::Node, source: &str) -> String {
+    source[node.byte_range()]
+        .lines()
+        .next()
+        .unwrap_or("")
+        .trim()
+        .to_string()
+}
+
+/// Merge adjacent sibling folds of the types in `config.merge_fold_types`
+///
+/// Expects folds sorted by position with depths assigned. Siblings separated by
+/// at most `config.merge_max_gap` lines become one region with a combined preview.
+pub(crate) fn merge_adjacent_folds(folds: Vec<FoldRegion>, config: &ScanConfig) -> Vec<FoldRegion> {
+    if config.merge_fold_types.is_empty() {
+        return folds;
     }
+
+    let mut merged: Vec<FoldRegion> = Vec::with_capacity(folds.len());
+    // Index into `merged` of the latest fold at each depth under the current parent
+    let mut last_at_depth: Vec<Option<usize>> = Vec::new();
+
+    for fold in folds {
+        let depth = fold.depth;
+        last_at_depth.truncate(depth + 1);
+        last_at_depth.resize(depth + 1, None);
+
+        if let Some(idx) = last_at_depth[depth] {
+            let prev = &mut merged[idx];
+            if prev.fold_type == fold.fold_type
+                && config.merge_fold_types.contains(&fold.fold_type)
+                && fold.start_line > prev.end_line
+                && fold.start_line - prev.end_line - 1 <= config.merge_max_gap
+            {
+                prev.end_byte = fold.end_byte;
+                prev.end_line = fold.end_line;
+                prev.end_column = fold.end_column;
+                prev.line_count = prev.end_line - prev.start_line + 1;
+                prev.preview = match (prev.preview.take(), fold.preview) {
+                    (Some(first), Some(second)) => Some(format!("{} | {}", first, second)),
+                    (first, second) => first.or(second),
+                };
+                prev.children.extend(fold.children);
+                continue;
+            }
+        }
+
+        last_at_depth[depth] = Some(merged.len());
+        merged.push(fold);
+    }
+
+    merged
+}
+
+/// Fold `# region Name` ... `# endregion` pragma comments (`//#region` in JS/TS)
+///
+/// Only nodes whose kind is in `comment_kinds` are considered. Each endregion
+/// closes the innermost open region; unmatched openers and closers are ignored.
+/// The region label becomes the preview.
+pub(crate) fn collect_region_folds(
+    tree: &tree_sitter::Tree,
+    source: &str,
+    comment_kinds: &[&str],
+) -> Vec<FoldRegion> {
+    let mut folds = Vec::new();
+    // Openers awaiting their endregion, innermost last
+    let mut open: Vec<(tree_sitter::Node, String)> = Vec::new();
+
+    for_each_comment(tree, comment_kinds, |node| {
+        match region_marker(&source[node.byte_range()]) {
+            Some(RegionMarker::Start(label)) => open.push((node, label)),
+            Some(RegionMarker::End) => {
+                if let Some((start, label)) = open.pop() {
+                    let mut fold = FoldRegion::new(
+                        FoldType::Region,
+                        start.start_byte(),
+                        node.end_byte(),
+                        start.start_position().row + 1,
+                        node.end_position().row + 1,
+                        start.start_position().column,
+                        node.end_position().column,
+                    );
+                    fold.preview = Some(if label.is_empty() {
+                        "region".to_string()
+                    } else {
+                        label
+                    });
+                    folds.push(fold);
+                }
+            }
+            None => {}
+        }
+    });
+
+    folds
+}
+
+/// Collect `# type: ignore` / `// @ts-ignore` / `// @ts-expect-error` comments
+///
+/// Only nodes whose kind is in `comment_kinds` are considered; each comment
+/// yields at most one suppression, reported at the comment's first line.
+pub(crate) fn collect_suppressions(
+    tree: &tree_sitter::Tree,
+    source: &str,
+    comment_kinds: &[&str],
+) -> Vec<Suppression> {
+    let mut suppressions = Vec::new();
+    for_each_comment(tree, comment_kinds, |node| {
+        if let Some(kind) = suppression_kind(&source[node.byte_range()]) {
+            suppressions.push(Suppression {
+                line: node.start_position().row + 1,
+                kind,
+            });
+        }
+    });
+    suppressions
+}
+
+/// Suppression contained in a single comment's text, if any
+fn suppression_kind(comment: &str) -> Option<SuppressionKind> {
+    if comment.starts_with('#') {
+        // mypy also honors `# noqa  # type: ignore[attr-defined]`
+        return comment
+            .split('#')
+            .filter_map(|part| part.trim_start().strip_prefix("type:"))
+            .any(|rest| rest.trim_start().starts_with("ignore"))
+            .then_some(SuppressionKind::TypeIgnore);
+    }
+
+    let body = comment
+        .strip_prefix("//")
+        .or_else(|| comment.strip_prefix("/*"))?
+        .trim_start_matches(['/', '*'])
+        .trim_start();
+    if body.starts_with("@ts-ignore") {
+        Some(SuppressionKind::TsIgnore)
+    } else if body.starts_with("@ts-expect-error") {
+        Some(SuppressionKind::TsExpectError)
+    } else {
+        None
+    }
+}
+
+/// Visit every node whose kind is in `comment_kinds`, in source order
+fn for_each_comment<'tree>(
+    tree: &'tree tree_sitter::Tree,
+    comment_kinds: &[&str],
+    mut visit: impl FnMut(tree_sitter::Node<'tree>),
+) {
+    for_each_node(tree, |node| {
+        if comment_kinds.contains(&node.kind()) {
+            visit(node);
+        }
+    });
+}
+
+/// Visit every node of a syntax tree in source (pre-)order
+fn for_each_node<'tree>(
+    tree: &'tree tree_sitter::Tree,
+    mut visit: impl FnMut(tree_sitter::Node<'tree>),
+) {
+    let mut cursor = tree.walk();
+
+    loop {
+        visit(cursor.node());
+
+        if cursor.goto_first_child() || cursor.goto_next_sibling() {
+            continue;
+        }
+        // Climb until a sibling is available or the root is reached
+        loop {
+            if !cursor.goto_parent() {
+                return;
+            }
+            if cursor.goto_next_sibling() {
+                break;
+            }
+        }
+    }
+}
+
+enum RegionMarker {
+    Start(String),
+    End,
+}
+
+/// Parse `# region Name`, `//#region Name`, `// #endregion` and the like
+fn region_marker(comment: &str) -> Option<RegionMarker> {
+    let text = comment.trim();
+    let text = text
+        .strip_prefix("//")
+        .or_else(|| text.strip_prefix('#'))?
+        .trim_start();
+    let text = text.strip_prefix('#').unwrap_or(text);
+
+    // The keyword must stand alone, so `# regional` is an ordinary comment
+    let keyword_ends = |rest: &str| rest.is_empty() || rest.starts_with(char::is_whitespace);
+    if let Some(rest) = text.strip_prefix("endregion") {
+        return keyword_ends(rest).then_some(RegionMarker::End);
+    }
+    let rest = text.strip_prefix("region")?;
+    let label = match rest.strip_prefix(':') {
+        Some(label) => label,
+        None if keyword_ends(rest) => rest,
+        None => return None,
+    };
+    Some(RegionMarker::Start(label.trim().to_string()))
+}
+
+/// Count occurrences of each node kind in a syntax tree
+pub(crate) fn count_node_kinds(tree: &tree_sitter::Tree) -> BTreeMap<String, usize> {
+    let mut counts = BTreeMap::new();
+    for_each_node(tree, |node| *counts.entry(node.kind().to_string()).or_insert(0) += 1);
+    counts
 }
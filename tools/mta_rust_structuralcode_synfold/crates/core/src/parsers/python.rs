@@ -1,8 +1,13 @@
 use crate::config::ScanConfig;
-use crate::models::{FoldRegion, FoldType, Language, PreviewMode};
+use crate::models::{FoldMode, FoldRegion, FoldType, Language, PreviewMode, Suppression};
 use tree_sitter::{Node, Parser};
 
-use super::{FoldParser, ParserError};
+use super::{
+    append_child_count, assign_fold_depths, count_direct_children, count_node_kinds,
+    chain_preview, chain_segment, collect_region_folds, collect_suppressions, first_last_lines,
+    mark_blank_lines, merge_adjacent_folds, record_discovery_order, FoldParser, ParserError,
+};
+use std::collections::BTreeMap;
 
 pub struct PythonParser {
     parser: Parser,
@@ -29,22 +34,36 @@ impl PythonParser {
         let root = tree.root_node();
 
         self.traverse_node(&root, source, &mut folds, config);
+        if config.fold_filter.fold_regions {
+            folds.extend(collect_region_folds(tree, source, &["comment"]));
+        }
+
+        record_discovery_order(&mut folds);
 
         // Sort by start position and filter by min_fold_lines
         folds.sort_by_key(|f| (f.start_byte, -(f.end_byte as i64)));
 
         // Apply min_fold_lines filter for block-type folds
-        folds
+        let min_fold_lines = config.min_fold_lines_for(&self.language());
+        let mut folds: Vec<FoldRegion> = folds
             .into_iter()
             .filter(|f| match f.fold_type {
-                FoldType::Block | FoldType::ClassBody => f.line_count >= config.min_fold_lines,
+                FoldType::Block | FoldType::ClassBody => f.line_count >= min_fold_lines,
                 FoldType::Import => f.line_count >= 2,
+                FoldType::Comment | FoldType::DocComment => {
+                    f.line_count >= config.min_doc_lines.unwrap_or(1)
+                }
                 FoldType::Literal | FoldType::ArrayLiteral | FoldType::ObjectLiteral => {
                     f.line_count >= 2
                 }
                 _ => true,
             })
-            .collect()
+            .collect();
+
+        assign_fold_depths(&mut folds);
+        let mut folds = merge_adjacent_folds(folds, config);
+        mark_blank_lines(&mut folds, source);
+        folds
     }
 
     fn traverse_node(
@@ -61,7 +80,12 @@ impl PythonParser {
             "function_definition" | "async_function_definition" => {
                 if config.fold_filter.fold_blocks {
                     if let Some(body) = node.child_by_field_name("body") {
-                        let fold = self.create_fold(&body, FoldType::Block, source);
+                        let fold = match config.fold_mode {
+                            FoldMode::Body => self.create_fold(&body, FoldType::Block, source),
+                            FoldMode::SignatureAndDoc => {
+                                self.create_body_fold_after_docstring(&body, source)
+                            }
+                        };
                         if let Some(mut f) = fold {
                             // Set preview based on mode
                             f.preview = Some(self.generate_function_preview(
@@ -76,13 +100,47 @@ impl PythonParser {
                 }
             }
 
+            // `match` bodies and each multi-line `case` clause (Python 3.10+)
+            "match_statement" | "case_clause" => {
+                if config.fold_filter.fold_blocks {
+                    let field = if kind == "match_statement" { "body" } else { "consequence" };
+                    if let Some(body) = node.child_by_field_name(field) {
+                        if body.end_position().row > body.start_position().row {
+                            let fold = if kind == "match_statement" {
+                                self.create_match_fold(node, &body)
+                            } else {
+                                self.create_fold(&body, FoldType::Block, source)
+                            };
+                            if let Some(mut f) = fold {
+                                f.preview = Some(self.clause_header(node, &body, source));
+                                folds.push(f);
+                            }
+                        }
+                    }
+                }
+            }
+
+            // `@property` getter followed by its setter/deleter
+            "decorated_definition" => {
+                if config.fold_filter.fold_properties {
+                    if let Some(f) = self.collect_property_group(node, source, config) {
+                        folds.push(f);
+                    }
+                }
+            }
+
             // Class definitions
             "class_definition" => {
                 if config.fold_filter.fold_classes {
                     if let Some(body) = node.child_by_field_name("body") {
                         let fold = self.create_fold(&body, FoldType::ClassBody, source);
                         if let Some(mut f) = fold {
-                            f.preview = Some(self.get_class_signature(node, source));
+                            let signature = self.get_class_signature(node, source);
+                            f.preview = Some(if config.closing_context {
+                                append_child_count(signature, count_direct_children(&body), "members")
+                            } else {
+                                signature
+                            });
                             folds.push(f);
                         }
                     }
@@ -205,6 +263,7 @@ impl PythonParser {
                                 source,
                                 f.line_count,
                                 config.preview_mode,
+                                config.closing_context,
                             ));
                             folds.push(f);
                         }
@@ -223,6 +282,7 @@ impl PythonParser {
                                 source,
                                 f.line_count,
                                 config.preview_mode,
+                                config.closing_context,
                             ));
                             folds.push(f);
                         }
@@ -233,7 +293,7 @@ impl PythonParser {
             // Chained method calls
             "call" => {
                 if config.fold_filter.fold_chains {
-                    if let Some(chain_fold) = self.detect_chain(node, source) {
+                    if let Some(chain_fold) = self.detect_chain(node, source, config) {
                         folds.push(chain_fold);
                     }
                 }
@@ -268,6 +328,55 @@ impl PythonParser {
         ))
     }
 
+    /// Block fold for a function body that leaves a leading docstring unfolded
+    ///
+    /// The fold runs from the first statement after the docstring to the end of
+    /// the body. Bodies without a docstring fold as usual; a body holding only a
+    /// docstring has nothing left to fold.
+    fn create_body_fold_after_docstring(&self, body: &Node, source: &str) -> Option<FoldRegion> {
+        let Some(docstring) = self.leading_docstring(body) else {
+            return self.create_fold(body, FoldType::Block, source);
+        };
+        let rest = docstring.next_named_sibling()?;
+
+        Some(FoldRegion::new(
+            FoldType::Block,
+            rest.start_byte(),
+            body.end_byte(),
+            rest.start_position().row + 1,
+            body.end_position().row + 1,
+            rest.start_position().column,
+            body.end_position().column,
+        ))
+    }
+
+    /// Block fold for a `match` body, starting on the `match` line after its colon
+    ///
+    /// The body holds only `case` clauses, so folding from the header keeps the
+    /// `match` line visible and hides every case, like a collapsed function body.
+    fn create_match_fold(&self, node: &Node, body: &Node) -> Option<FoldRegion> {
+        let mut cursor = node.walk();
+        let colon = node.children(&mut cursor).find(|c| c.kind() == ":")?;
+
+        Some(FoldRegion::new(
+            FoldType::Block,
+            colon.end_byte(),
+            body.end_byte(),
+            colon.end_position().row + 1,
+            body.end_position().row + 1,
+            colon.end_position().column,
+            body.end_position().column,
+        ))
+    }
+
+    /// The `expression_statement` holding a body's docstring, if its first statement is one
+    fn leading_docstring<'t>(&self, body: &Node<'t>) -> Option<Node<'t>> {
+        let first = body.named_child(0)?;
+        let is_docstring = first.kind() == "expression_statement"
+            && first.named_child(0).is_some_and(|c| c.kind() == "string");
+        is_docstring.then_some(first)
+    }
+
     fn get_node_text(&self, node: &Node, source: &str) -> String {
         source[node.byte_range()].to_string()
     }
@@ -302,6 +411,82 @@ impl PythonParser {
         }
     }
 
+    /// Header of a compound statement up to its body, e.g. `case Point(x=0, y=y)`
+    fn clause_header(&self, node: &Node, body: &Node, source: &str) -> String {
+        let header = &source[node.start_byte()..body.start_byte()];
+        let header = header.lines().next().unwrap_or("").trim();
+        header.strip_suffix(':').unwrap_or(header).trim_end().to_string()
+    }
+
+    /// Decorator expressions of a decorated definition, without the leading `@`
+    fn decorator_names<'a>(&self, node: &Node, source: &'a str) -> Vec<&'a str> {
+        let mut cursor = node.walk();
+        node.children(&mut cursor)
+            .filter(|c| c.kind() == "decorator")
+            .map(|c| source[c.byte_range()].trim_start_matches('@').trim())
+            .collect()
+    }
+
+    /// Name of the function wrapped by a decorated definition
+    fn decorated_function_name<'a>(&self, node: &Node, source: &'a str) -> Option<&'a str> {
+        node.child_by_field_name("definition")
+            .and_then(|d| d.child_by_field_name("name"))
+            .map(|n| &source[n.byte_range()])
+    }
+
+    /// Group an `@property` getter with the `@<name>.setter`/`@<name>.deleter`
+    /// definitions that immediately follow it into a single fold
+    fn collect_property_group(
+        &self,
+        start_node: &Node,
+        source: &str,
+        config: &ScanConfig,
+    ) -> Option<FoldRegion> {
+        if !self.decorator_names(start_node, source).contains(&"property") {
+            return None;
+        }
+        let name = self.decorated_function_name(start_node, source)?;
+        let accessors = [format!("{}.setter", name), format!("{}.deleter", name)];
+
+        let mut end_node = *start_node;
+        let mut next = start_node.next_sibling();
+        while let Some(ns) = next {
+            if ns.kind() == "comment" {
+                next = ns.next_sibling();
+                continue;
+            }
+            let is_accessor = ns.kind() == "decorated_definition"
+                && self
+                    .decorator_names(&ns, source)
+                    .iter()
+                    .any(|d| accessors.iter().any(|a| a == d));
+            if !is_accessor {
+                break;
+            }
+            end_node = ns;
+            next = ns.next_sibling();
+        }
+
+        if end_node == *start_node {
+            return None;
+        }
+
+        let mut fold = FoldRegion::new(
+            FoldType::Property,
+            start_node.start_byte(),
+            end_node.end_byte(),
+            start_node.start_position().row + 1,
+            end_node.end_position().row + 1,
+            start_node.start_position().column,
+            end_node.end_position().column,
+        );
+        fold.preview = Some(match config.preview_mode {
+            PreviewMode::Source => source[start_node.start_byte()..end_node.end_byte()].to_string(),
+            _ => format!("property {}", name),
+        });
+        Some(fold)
+    }
+
     fn collect_import_block(
         &self,
         start_node: &Node,
@@ -353,26 +538,40 @@ impl PythonParser {
         }
     }
 
-    fn detect_chain(&self, node: &Node, _source: &str) -> Option<FoldRegion> {
-        // Count depth of chained calls
+    fn detect_chain(&self, node: &Node, source: &str, config: &ScanConfig) -> Option<FoldRegion> {
+        // Count depth of chained calls, collecting link names from the outside in
         let mut depth = 0;
-        let mut current = node.clone();
+        let mut segments = Vec::new();
+        let mut current = *node;
 
         while current.kind() == "call" {
             depth += 1;
-            if let Some(func) = current.child_by_field_name("function") {
-                if func.kind() == "attribute" {
-                    if let Some(obj) = func.child_by_field_name("object") {
-                        current = obj;
-                        continue;
-                    }
+            let Some(func) = current.child_by_field_name("function") else {
+                break;
+            };
+            match (
+                func.kind(),
+                func.child_by_field_name("object"),
+                func.child_by_field_name("attribute"),
+            ) {
+                ("attribute", Some(object), Some(name)) => {
+                    segments.push(chain_segment(&name, source));
+                    current = object;
+                }
+                _ => {
+                    // Chain starts with a plain call: `foo().bar()`
+                    segments.push(chain_segment(&func, source));
+                    break;
                 }
             }
-            break;
+        }
+        if current.kind() != "call" {
+            segments.push(chain_segment(&current, source));
         }
 
-        // Only fold chains with 3+ calls that span multiple lines
-        if depth >= 3 && node.end_position().row > node.start_position().row {
+        // Only fold chains of `min_chain_depth`+ calls that span multiple lines
+        if depth >= config.min_chain_depth && node.end_position().row > node.start_position().row {
+            segments.reverse();
             let mut fold = FoldRegion::new(
                 FoldType::ChainedCall,
                 node.start_byte(),
@@ -382,7 +581,7 @@ impl PythonParser {
                 node.start_position().column,
                 node.end_position().column,
             );
-            fold.preview = Some(format!("...chain ({} calls)", depth));
+            fold.preview = Some(chain_preview(&segments, depth, config.preview_mode));
             Some(fold)
         } else {
             None
@@ -528,12 +727,17 @@ impl PythonParser {
         }
     }
 
-    /// Extract key names from a dictionary literal
-    fn extract_dict_keys(&self, node: &Node, source: &str) -> Vec<String> {
+    /// Extract key names from a dictionary literal, along with its direct child count
+    fn extract_dict_keys(&self, node: &Node, source: &str) -> (Vec<String>, usize) {
         let mut keys = Vec::new();
+        let mut child_count = 0;
 
         let mut cursor = node.walk();
         for child in node.children(&mut cursor) {
+            // A `**splat` adds an unknown number of keys, so it is not counted
+            if child.is_named() && !matches!(child.kind(), "comment" | "dictionary_splat") {
+                child_count += 1;
+            }
             if child.kind() == "pair" {
                 if let Some(key) = child.child_by_field_name("key") {
                     let key_text = self.get_node_text(&key, source);
@@ -549,7 +753,7 @@ impl PythonParser {
             }
         }
 
-        keys
+        (keys, child_count)
     }
 
     /// Generate preview based on mode
@@ -562,7 +766,7 @@ impl PythonParser {
     ) -> String {
         match mode {
             PreviewMode::Minimal => format!("{} imports", import_count),
-            PreviewMode::Names | PreviewMode::Flow => {
+            PreviewMode::Names | PreviewMode::Flow | PreviewMode::Context => {
                 let modules = self.collect_import_modules(start_node, source);
                 if modules.is_empty() {
                     format!("{} imports", import_count)
@@ -598,6 +802,10 @@ impl PythonParser {
                     format!("{} -> {}", signature, flow.join("/"))
                 }
             }
+            PreviewMode::Context => {
+                let body_text = self.get_node_text(body, source);
+                format!("{}: {}", signature, first_last_lines(&body_text))
+            }
             PreviewMode::Source => {
                 // Return full source of the function
                 self.get_node_text(node, source)
@@ -611,23 +819,26 @@ impl PythonParser {
         source: &str,
         line_count: usize,
         mode: PreviewMode,
+        closing_context: bool,
     ) -> String {
-        match mode {
-            PreviewMode::Minimal => format!("{{...}} ({} lines)", line_count),
-            PreviewMode::Names | PreviewMode::Flow => {
-                let keys = self.extract_dict_keys(node, source);
-                if keys.is_empty() {
-                    format!("{{...}} ({} lines)", line_count)
-                } else if keys.len() <= 5 {
-                    format!("{{ {} }}", keys.join(", "))
-                } else {
-                    format!("{{ {}, +{} more }}", keys[..4].join(", "), keys.len() - 4)
-                }
-            }
-            PreviewMode::Source => {
-                // Return full source of the dict/object
-                self.get_node_text(node, source)
-            }
+        if mode == PreviewMode::Source {
+            // Return full source of the dict/object
+            return self.get_node_text(node, source);
+        }
+
+        let (keys, child_count) = self.extract_dict_keys(node, source);
+        let preview = if mode == PreviewMode::Minimal || keys.is_empty() {
+            format!("{{...}} ({} lines)", line_count)
+        } else if keys.len() <= 5 {
+            format!("{{ {} }}", keys.join(", "))
+        } else {
+            format!("{{ {}, +{} more }}", keys[..4].join(", "), keys.len() - 4)
+        };
+
+        if closing_context {
+            append_child_count(preview, child_count, "props")
+        } else {
+            preview
         }
     }
 
@@ -642,6 +853,11 @@ impl PythonParser {
             PreviewMode::Minimal | PreviewMode::Names | PreviewMode::Flow => {
                 format!("\"...\" ({} lines)", line_count)
             }
+            PreviewMode::Context => {
+                // Opening and closing quote lines
+                let text = self.get_node_text(node, source);
+                format!("{} ({} lines)", first_last_lines(&text), line_count)
+            }
             PreviewMode::Source => {
                 self.get_node_text(node, source)
             }
@@ -659,6 +875,10 @@ impl PythonParser {
             PreviewMode::Minimal | PreviewMode::Names | PreviewMode::Flow => {
                 format!("\"\"\"...\"\"\" ({} lines)", line_count)
             }
+            PreviewMode::Context => {
+                let text = self.get_node_text(node, source);
+                format!("{} ({} lines)", first_last_lines(&text), line_count)
+            }
             PreviewMode::Source => {
                 self.get_node_text(node, source)
             }
@@ -671,10 +891,19 @@ impl PythonParser {
         source: &str,
         line_count: usize,
         mode: PreviewMode,
+        closing_context: bool,
     ) -> String {
         match mode {
-            PreviewMode::Minimal | PreviewMode::Names | PreviewMode::Flow => {
-                format!("[...] ({} lines)", line_count)
+            PreviewMode::Minimal
+            | PreviewMode::Names
+            | PreviewMode::Flow
+            | PreviewMode::Context => {
+                let preview = format!("[...] ({} lines)", line_count);
+                if closing_context {
+                    append_child_count(preview, count_direct_children(node), "items")
+                } else {
+                    preview
+                }
             }
             PreviewMode::Source => {
                 self.get_node_text(node, source)
@@ -713,6 +942,20 @@ impl FoldParser for PythonParser {
         }
     }
 
+    fn node_kinds(&mut self, source: &str) -> BTreeMap<String, usize> {
+        match self.parser.parse(source, None) {
+            Some(tree) => count_node_kinds(&tree),
+            None => BTreeMap::new(),
+        }
+    }
+
+    fn suppressions(&mut self, source: &str) -> Vec<Suppression> {
+        match self.parser.parse(source, None) {
+            Some(tree) => collect_suppressions(&tree, source, &["comment"]),
+            None => Vec::new(),
+        }
+    }
+
     fn language(&self) -> Language {
         Language::Python
     }
@@ -728,6 +971,18 @@ mod tests {
             .with_fold_filter(crate::models::FoldFilter::all())
     }
 
+    #[test]
+    fn test_node_kinds_histogram() {
+        let mut parser = PythonParser::new().unwrap();
+        let source = "import os\n\ndef a():\n    pass\n\nclass B:\n    def c(self):\n        pass\n";
+
+        let kinds = parser.node_kinds(source);
+        assert_eq!(kinds.get("function_definition"), Some(&2));
+        assert_eq!(kinds.get("class_definition"), Some(&1));
+        assert_eq!(kinds.get("import_statement"), Some(&1));
+        assert_eq!(kinds.get("module"), Some(&1));
+    }
+
     #[test]
     fn test_function_fold() {
         let mut parser = PythonParser::new().unwrap();
@@ -742,6 +997,58 @@ def hello():
         assert!(folds.iter().any(|f| f.fold_type == FoldType::Block));
     }
 
+    #[test]
+    fn test_signature_and_doc_fold_mode() {
+        let mut parser = PythonParser::new().unwrap();
+        let source = r#"
+def total(items):
+    """Sum every item.
+
+    Returns 0 for an empty list.
+    """
+    result = 0
+    for item in items:
+        result += item
+    return result
+"#;
+        let block_start = |config: &ScanConfig, parser: &mut PythonParser| {
+            let folds = parser.parse(source, config);
+            folds.iter().find(|f| f.fold_type == FoldType::Block).map(|f| f.start_line)
+        };
+
+        assert_eq!(block_start(&default_config(), &mut parser), Some(3));
+
+        let config = default_config().with_fold_mode(FoldMode::SignatureAndDoc);
+        assert_eq!(block_start(&config, &mut parser), Some(7));
+    }
+
+    #[test]
+    fn test_context_preview() {
+        let mut parser = PythonParser::new().unwrap();
+        let source = r#"
+def total(items):
+    result = 0
+    for item in items:
+        result += item
+    return result
+
+USAGE = """Usage:
+    total ITEMS...
+"""
+"#;
+        let config = default_config().with_preview_mode(PreviewMode::Context);
+        let folds = parser.parse(source, &config);
+
+        let block = folds.iter().find(|f| f.fold_type == FoldType::Block).unwrap();
+        assert_eq!(
+            block.preview.as_deref(),
+            Some("def total(items): result = 0 … return result")
+        );
+
+        let literal = folds.iter().find(|f| f.fold_type == FoldType::Literal).unwrap();
+        assert_eq!(literal.preview.as_deref(), Some("\"\"\"Usage: … \"\"\" (3 lines)"));
+    }
+
     #[test]
     fn test_class_fold() {
         let mut parser = PythonParser::new().unwrap();
@@ -797,4 +1104,218 @@ config = {
         let folds = parser.parse(source, &default_config());
         assert!(folds.iter().any(|f| f.fold_type == FoldType::ObjectLiteral));
     }
+
+    #[test]
+    fn test_fold_depth() {
+        let mut parser = PythonParser::new().unwrap();
+        let source = r#"
+class Outer:
+    def method(self):
+        def inner():
+            x = 1
+            y = 2
+            return x + y
+        return inner()
+"#;
+        let folds = parser.parse(source, &default_config());
+        let class_fold = folds.iter().find(|f| f.fold_type == FoldType::ClassBody).unwrap();
+        let blocks: Vec<&FoldRegion> = folds
+            .iter()
+            .filter(|f| f.fold_type == FoldType::Block)
+            .collect();
+
+        assert_eq!(blocks.len(), 2);
+        assert_eq!(class_fold.depth, 0);
+        assert_eq!(blocks[0].depth, 1);
+        assert_eq!(blocks[1].depth, 2);
+    }
+
+    #[test]
+    fn test_property_group_fold() {
+        let mut parser = PythonParser::new().unwrap();
+        let source = r#"
+class Account:
+    @property
+    def balance(self):
+        return self._balance
+
+    @balance.setter
+    def balance(self, value):
+        self._balance = value
+
+    def deposit(self, amount):
+        self._balance += amount
+"#;
+        let folds = parser.parse(source, &default_config());
+        let groups: Vec<&FoldRegion> = folds
+            .iter()
+            .filter(|f| f.preview.as_deref() == Some("property balance"))
+            .collect();
+
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[0].fold_type, FoldType::Property);
+        assert_eq!(groups[0].start_line, 3);
+        assert_eq!(groups[0].end_line, 9);
+
+        // `--fold-types property` alone still renders the group
+        let config = default_config()
+            .with_fold_filter(crate::models::FoldFilter::from_type_names("property"));
+        let folds = parser.parse(source, &config);
+        let applied = crate::engine::Renderer::new(config).applied_folds(source, &folds);
+        assert_eq!(applied.len(), 1);
+        assert_eq!(applied[0].fold_type, FoldType::Property);
+
+        let mut filter = crate::models::FoldFilter::all();
+        filter.fold_properties = false;
+        let config = default_config().with_fold_filter(filter);
+        let folds = parser.parse(source, &config);
+        assert!(!folds
+            .iter()
+            .any(|f| f.preview.as_deref() == Some("property balance")));
+    }
+
+    #[test]
+    fn test_region_folds() {
+        let mut parser = PythonParser::new().unwrap();
+        let source = r#"# region Setup
+import os
+
+# region: Helpers
+def helper():
+    return os.getcwd()
+# endregion
+
+# region Config
+# regional office notes
+CONFIG = {}
+# endregion
+"#;
+        let folds = parser.parse(source, &default_config());
+
+        let regions: Vec<_> = folds.iter().filter(|f| f.fold_type == FoldType::Region).collect();
+        assert_eq!(regions.len(), 2);
+        // The second endregion closes the innermost open region, leaving `Setup` unmatched
+        assert_eq!(regions[0].preview.as_deref(), Some("Helpers"));
+        assert_eq!((regions[0].start_line, regions[0].end_line), (4, 7));
+        assert_eq!(regions[1].preview.as_deref(), Some("Config"));
+        assert_eq!((regions[1].start_line, regions[1].end_line), (9, 12));
+
+        let mut filter = crate::models::FoldFilter::all();
+        filter.fold_regions = false;
+        let folds = parser.parse(source, &default_config().with_fold_filter(filter));
+        assert!(folds.iter().all(|f| f.fold_type != FoldType::Region));
+    }
+
+    #[test]
+    fn test_chain_names_preview() {
+        let mut parser = PythonParser::new().unwrap();
+        let source = r#"
+totals = (
+    df.dropna()
+    .groupby("key")
+    .sum()
+)
+"#;
+        let config = default_config().with_preview_mode(PreviewMode::Names);
+        let folds = parser.parse(source, &config);
+
+        let chains: Vec<_> = folds
+            .iter()
+            .filter(|f| f.fold_type == FoldType::ChainedCall)
+            .collect();
+        assert_eq!(chains.len(), 1);
+        assert_eq!(chains[0].preview.as_deref(), Some("df.dropna…sum"));
+    }
+
+    #[test]
+    fn test_min_doc_lines() {
+        let mut parser = PythonParser::new().unwrap();
+        let source = r#"def greet(name):
+    """Say hello.
+    Uses the given name.
+    """
+    return "Hello, " + name
+"#;
+        let config = ScanConfig::default()
+            .with_fold_filter(crate::models::FoldFilter::all())
+            .with_min_fold_lines(6);
+
+        let folds = parser.parse(source, &config.clone().with_min_doc_lines(3));
+        let docs: Vec<_> = folds
+            .iter()
+            .filter(|f| f.fold_type == FoldType::DocComment)
+            .collect();
+        assert_eq!(docs.len(), 1);
+        assert_eq!(docs[0].line_count, 3);
+        // The 4-line body is still below min_fold_lines
+        assert!(!folds.iter().any(|f| f.fold_type == FoldType::Block));
+
+        let folds = parser.parse(source, &config.with_min_doc_lines(4));
+        assert!(!folds.iter().any(|f| f.fold_type == FoldType::DocComment));
+    }
+
+    #[test]
+    fn test_match_case_folds() {
+        let mut parser = PythonParser::new().unwrap();
+        let source = r#"
+def handle(command):
+    match command:
+        case "start":
+            boot()
+            run()
+        case "stop":
+            halt()
+            flush()
+        case "pause":
+            wait()
+        case ["move", x, y]:
+            move_to(x, y)
+            redraw()
+        case _:
+            raise ValueError(command)
+"#;
+        let folds = parser.parse(source, &default_config());
+        let blocks: Vec<_> = folds.iter().filter(|f| f.fold_type == FoldType::Block).collect();
+
+        let body = blocks
+            .iter()
+            .find(|f| f.preview.as_deref() == Some("match command"))
+            .expect("match body fold");
+        // Starts on the `match` line, so the header stays visible when folded
+        assert_eq!((body.start_line, body.end_line), (3, 16));
+
+        // Multi-line cases fold on their own; the one-line cases do not
+        let cases: Vec<_> = blocks
+            .iter()
+            .filter_map(|f| f.preview.as_deref())
+            .filter(|p| p.starts_with("case "))
+            .collect();
+        assert_eq!(cases, vec!["case \"start\"", "case \"stop\"", "case [\"move\", x, y]"]);
+    }
+
+    #[test]
+    fn test_has_blank_lines() {
+        let mut parser = PythonParser::new().unwrap();
+        let source = r#"
+def spaced():
+    a = 1
+
+    return a
+
+def dense():
+    a = 1
+    b = 2
+    return a + b
+"#;
+        let folds = parser.parse(source, &default_config());
+        let block_at = |line: usize| {
+            folds
+                .iter()
+                .find(|f| f.fold_type == FoldType::Block && f.start_line == line)
+                .unwrap()
+        };
+
+        assert!(block_at(3).has_blank_lines);
+        assert!(!block_at(8).has_blank_lines);
+    }
 }
@@ -0,0 +1,730 @@
+use crate::config::ScanConfig;
+use crate::models::{FoldRegion, FoldType, Language, PreviewMode};
+use tree_sitter::{Node, Parser};
+
+use super::{
+    append_child_count, assign_fold_depths, count_direct_children, count_node_kinds,
+    chain_preview, chain_segment, mark_blank_lines, merge_adjacent_folds, record_discovery_order,
+    FoldParser, ParserError,
+};
+use std::collections::BTreeMap;
+
+pub struct RustParser {
+    parser: Parser,
+}
+
+impl RustParser {
+    pub fn new() -> Result<Self, ParserError> {
+        let mut parser = Parser::new();
+        parser
+            .set_language(&tree_sitter_rust::LANGUAGE.into())
+            .map_err(|e| ParserError::InitError(e.to_string()))?;
+
+        Ok(Self { parser })
+    }
+
+    /// Extract fold regions from the parse tree
+    fn extract_folds(
+        &self,
+        source: &str,
+        tree: &tree_sitter::Tree,
+        config: &ScanConfig,
+    ) -> Vec<FoldRegion> {
+        let mut folds = Vec::new();
+        let root = tree.root_node();
+
+        self.traverse_node(&root, source, &mut folds, config);
+
+        record_discovery_order(&mut folds);
+
+        // Sort by start position and filter by min_fold_lines
+        folds.sort_by_key(|f| (f.start_byte, -(f.end_byte as i64)));
+
+        // Apply min_fold_lines filter for block-type folds
+        let min_fold_lines = config.min_fold_lines_for(&self.language());
+        let mut folds: Vec<FoldRegion> = folds
+            .into_iter()
+            .filter(|f| match f.fold_type {
+                FoldType::Block | FoldType::ClassBody => f.line_count >= min_fold_lines,
+                FoldType::Import => f.line_count >= 2,
+                FoldType::Comment => f.line_count >= config.min_doc_lines.unwrap_or(1),
+                FoldType::DocComment => f.line_count >= config.min_doc_lines.unwrap_or(2),
+                _ => true,
+            })
+            .collect();
+
+        assign_fold_depths(&mut folds);
+        let mut folds = merge_adjacent_folds(folds, config);
+        mark_blank_lines(&mut folds, source);
+        folds
+    }
+
+    fn traverse_node(
+        &self,
+        node: &Node,
+        source: &str,
+        folds: &mut Vec<FoldRegion>,
+        config: &ScanConfig,
+    ) {
+        let kind = node.kind();
+
+        match kind {
+            // Functions and inline modules
+            "function_item" | "mod_item" => {
+                if config.fold_filter.fold_blocks {
+                    if let Some(body) = node.child_by_field_name("body") {
+                        if let Some(mut f) = self.create_fold(&body, FoldType::Block) {
+                            f.preview = Some(self.generate_function_preview(
+                                node,
+                                &body,
+                                source,
+                                config.preview_mode,
+                            ));
+                            folds.push(f);
+                        }
+                    }
+                }
+            }
+
+            // Type bodies: impl/trait blocks, struct fields and enum variants
+            "impl_item" | "trait_item" | "struct_item" | "enum_item" | "union_item" => {
+                if config.fold_filter.fold_classes {
+                    if let Some(body) = node.child_by_field_name("body") {
+                        if let Some(mut f) = self.create_fold(&body, FoldType::ClassBody) {
+                            let signature = self.get_signature(node, &body, source);
+                            f.preview = Some(match config.preview_mode {
+                                PreviewMode::Source => self.get_node_text(&body, source),
+                                _ if config.closing_context => append_child_count(
+                                    signature,
+                                    count_direct_children(&body),
+                                    "members",
+                                ),
+                                _ => signature,
+                            });
+                            folds.push(f);
+                        }
+                    }
+                }
+            }
+
+            // Use declarations (consecutive block)
+            "use_declaration" => {
+                if config.fold_filter.fold_imports && !self.follows_use(node) {
+                    if let Some(f) = self.collect_use_block(node, source, config) {
+                        folds.push(f);
+                    }
+                }
+            }
+
+            // Macro bodies: `vec![...]` folds as an array, `{ ... }`/`( ... )` as an object
+            "macro_invocation" => {
+                if let Some(body) = self.macro_body(node) {
+                    let is_array = self.get_node_text(&body, source).starts_with('[');
+                    let enabled = if is_array {
+                        config.fold_filter.fold_arrays
+                    } else {
+                        config.fold_filter.fold_objects
+                    };
+                    if enabled && body.end_position().row > body.start_position().row {
+                        let fold_type = if is_array {
+                            FoldType::ArrayLiteral
+                        } else {
+                            FoldType::ObjectLiteral
+                        };
+                        if let Some(mut f) = self.create_fold(&body, fold_type) {
+                            f.preview = Some(self.generate_macro_preview(
+                                node,
+                                &body,
+                                source,
+                                f.line_count,
+                                config.preview_mode,
+                            ));
+                            folds.push(f);
+                        }
+                    }
+                }
+            }
+
+            // Array expressions
+            "array_expression" => {
+                if config.fold_filter.fold_arrays
+                    && node.end_position().row > node.start_position().row
+                {
+                    if let Some(mut f) = self.create_fold(node, FoldType::ArrayLiteral) {
+                        let line_count = f.line_count;
+                        f.preview = Some(match config.preview_mode {
+                            PreviewMode::Source => self.get_node_text(node, source),
+                            _ => {
+                                let preview = format!("[...] ({} lines)", line_count);
+                                if config.closing_context {
+                                    append_child_count(preview, count_direct_children(node), "items")
+                                } else {
+                                    preview
+                                }
+                            }
+                        });
+                        folds.push(f);
+                    }
+                }
+            }
+
+            // Struct literals: `Config { name, port: 80, .. }`
+            "struct_expression" => {
+                if config.fold_filter.fold_objects {
+                    if let Some(body) = node.child_by_field_name("body") {
+                        if body.end_position().row > body.start_position().row {
+                            if let Some(mut f) = self.create_fold(&body, FoldType::ObjectLiteral) {
+                                f.preview = Some(self.generate_struct_preview(
+                                    node,
+                                    &body,
+                                    source,
+                                    config.preview_mode,
+                                    config.closing_context,
+                                ));
+                                folds.push(f);
+                            }
+                        }
+                    }
+                }
+            }
+
+            // Chained method calls: `.iter().map(..).filter(..)`
+            "call_expression" => {
+                if config.fold_filter.fold_chains && self.is_outermost_call(node) {
+                    if let Some(chain_fold) = self.detect_chain(node, source, config) {
+                        folds.push(chain_fold);
+                    }
+                }
+            }
+
+            // Parameter lists spanning several lines
+            "parameters" => {
+                if config.fold_filter.fold_arglists
+                    && node.end_position().row > node.start_position().row
+                {
+                    if let Some(f) = self.create_fold(node, FoldType::ArgList) {
+                        folds.push(f);
+                    }
+                }
+            }
+
+            // `///` and `//!` runs fold as doc comments
+            "line_comment" => {
+                if self.is_doc_comment(node, source)
+                    && config.fold_filter.fold_docs
+                    && !self.continues_doc_comment(node, source)
+                {
+                    if let Some(f) = self.collect_doc_comment(node, source, config) {
+                        folds.push(f);
+                    }
+                }
+            }
+
+            // Block comments fold individually
+            "block_comment" => {
+                if config.fold_filter.fold_comments
+                    && node.end_position().row > node.start_position().row
+                {
+                    if let Some(f) = self.create_fold(node, FoldType::Comment) {
+                        folds.push(f);
+                    }
+                }
+            }
+
+            _ => {}
+        }
+
+        // Recurse into children
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            self.traverse_node(&child, source, folds, config);
+        }
+    }
+
+    fn create_fold(&self, node: &Node, fold_type: FoldType) -> Option<FoldRegion> {
+        Some(FoldRegion::new(
+            fold_type,
+            node.start_byte(),
+            node.end_byte(),
+            node.start_position().row + 1,
+            node.end_position().row + 1,
+            node.start_position().column,
+            node.end_position().column,
+        ))
+    }
+
+    fn get_node_text(&self, node: &Node, source: &str) -> String {
+        source[node.byte_range()].to_string()
+    }
+
+    /// Item text from its start up to the body, collapsed to one line
+    fn get_signature(&self, node: &Node, body: &Node, source: &str) -> String {
+        source[node.start_byte()..body.start_byte()]
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// Whether the previous sibling (ignoring comments and attributes) is also a use declaration
+    fn follows_use(&self, node: &Node) -> bool {
+        let mut prev = node.prev_sibling();
+        while let Some(ps) = prev {
+            match ps.kind() {
+                "use_declaration" => return true,
+                "line_comment" | "block_comment" | "attribute_item" => prev = ps.prev_sibling(),
+                _ => return false,
+            }
+        }
+        false
+    }
+
+    fn collect_use_block(
+        &self,
+        start_node: &Node,
+        source: &str,
+        config: &ScanConfig,
+    ) -> Option<FoldRegion> {
+        let mut end_node = *start_node;
+        let mut paths = vec![self.use_target(start_node, source)];
+
+        // Walk forward to find consecutive use declarations
+        let mut next = start_node.next_sibling();
+        while let Some(ns) = next {
+            match ns.kind() {
+                "use_declaration" => {
+                    paths.push(self.use_target(&ns, source));
+                    end_node = ns;
+                    next = ns.next_sibling();
+                }
+                // Allow comments and `#[cfg(...)]` attributes between uses
+                "line_comment" | "block_comment" | "attribute_item" => next = ns.next_sibling(),
+                _ => break,
+            }
+        }
+
+        if paths.len() < 2 {
+            return None;
+        }
+
+        let mut fold = FoldRegion::new(
+            FoldType::Import,
+            start_node.start_byte(),
+            end_node.end_byte(),
+            start_node.start_position().row + 1,
+            end_node.end_position().row + 1,
+            start_node.start_position().column,
+            end_node.end_position().column,
+        );
+        fold.preview = Some(match config.preview_mode {
+            PreviewMode::Minimal => format!("{} uses", paths.len()),
+            PreviewMode::Names | PreviewMode::Flow | PreviewMode::Context => {
+                if paths.len() <= 5 {
+                    paths.join(", ")
+                } else {
+                    format!("{}, +{} more", paths[..4].join(", "), paths.len() - 4)
+                }
+            }
+            PreviewMode::Source => source[start_node.start_byte()..end_node.end_byte()].to_string(),
+        });
+        Some(fold)
+    }
+
+    /// Path named by a use declaration (`pub use std::io::{self, Read};` -> `std::io::{self, Read}`)
+    fn use_target(&self, node: &Node, source: &str) -> String {
+        match node.child_by_field_name("argument") {
+            Some(argument) => argument
+                .utf8_text(source.as_bytes())
+                .unwrap_or("")
+                .split_whitespace()
+                .collect::<Vec<_>>()
+                .join(" "),
+            None => self.get_node_text(node, source),
+        }
+    }
+
+    /// The delimited token tree of a macro invocation (`vec![...]`, `json!({ ... })`)
+    fn macro_body<'a>(&self, node: &Node<'a>) -> Option<Node<'a>> {
+        let mut cursor = node.walk();
+        let body = node
+            .named_children(&mut cursor)
+            .find(|child| child.kind() == "token_tree");
+        body
+    }
+
+    /// Whether this call is not itself the receiver of a further `.method()` call
+    fn is_outermost_call(&self, node: &Node) -> bool {
+        let mut parent = node.parent();
+        // `?` and `.await` sit between links of fallible/async chains
+        while let Some(p) = parent {
+            if matches!(p.kind(), "try_expression" | "await_expression") {
+                parent = p.parent();
+            } else {
+                break;
+            }
+        }
+        parent.is_none_or(|p| p.kind() != "field_expression")
+    }
+
+    fn detect_chain(&self, node: &Node, source: &str, config: &ScanConfig) -> Option<FoldRegion> {
+        // Count depth of chained calls, collecting link names from the outside in
+        let mut depth = 0;
+        let mut segments = Vec::new();
+        let mut current = *node;
+
+        while current.kind() == "call_expression" {
+            depth += 1;
+            let Some(func) = current.child_by_field_name("function") else {
+                break;
+            };
+            match (
+                func.kind(),
+                func.child_by_field_name("value"),
+                func.child_by_field_name("field"),
+            ) {
+                ("field_expression", Some(mut value), Some(name)) => {
+                    segments.push(chain_segment(&name, source));
+                    // Step through `?` and `.await` to the previous call
+                    while matches!(value.kind(), "try_expression" | "await_expression") {
+                        match value.named_child(0) {
+                            Some(inner) => value = inner,
+                            None => break,
+                        }
+                    }
+                    current = value;
+                }
+                _ => {
+                    // Chain starts with a plain call: `foo().bar()`
+                    segments.push(chain_segment(&func, source));
+                    break;
+                }
+            }
+        }
+        if current.kind() != "call_expression" {
+            segments.push(chain_segment(&current, source));
+        }
+
+        // Only fold chains of `min_chain_depth`+ calls that span multiple lines
+        if depth >= config.min_chain_depth && node.end_position().row > node.start_position().row {
+            segments.reverse();
+            let mut fold = FoldRegion::new(
+                FoldType::ChainedCall,
+                node.start_byte(),
+                node.end_byte(),
+                node.start_position().row + 1,
+                node.end_position().row + 1,
+                node.start_position().column,
+                node.end_position().column,
+            );
+            fold.preview = Some(chain_preview(&segments, depth, config.preview_mode));
+            Some(fold)
+        } else {
+            None
+        }
+    }
+
+    /// Outer (`//!`) and inner (`///`) doc comments; `////` is a plain comment
+    fn is_doc_comment(&self, node: &Node, source: &str) -> bool {
+        let text = self.get_node_text(node, source);
+        text.starts_with("//!") || (text.starts_with("///") && !text.starts_with("////"))
+    }
+
+    /// Whether this doc comment continues a doc comment on the previous line
+    fn continues_doc_comment(&self, node: &Node, source: &str) -> bool {
+        match node.prev_sibling() {
+            Some(ps) => {
+                ps.kind() == "line_comment"
+                    && ps.end_position().row + 1 >= node.start_position().row
+                    && self.is_doc_comment(&ps, source)
+            }
+            None => false,
+        }
+    }
+
+    fn collect_doc_comment(
+        &self,
+        start_node: &Node,
+        source: &str,
+        config: &ScanConfig,
+    ) -> Option<FoldRegion> {
+        let mut end_node = *start_node;
+        let mut next = start_node.next_sibling();
+        while let Some(ns) = next {
+            // Line comments include their trailing newline, so the next one
+            // starts on the row the previous one ends on
+            if ns.kind() == "line_comment"
+                && ns.start_position().row <= end_node.end_position().row + 1
+                && self.is_doc_comment(&ns, source)
+            {
+                end_node = ns;
+                next = ns.next_sibling();
+            } else {
+                break;
+            }
+        }
+
+        // Trim the trailing newline so the fold ends on the last comment line
+        let text = source[start_node.start_byte()..end_node.end_byte()].trim_end();
+        let end_byte = start_node.start_byte() + text.len();
+        let line_start = source[..end_byte].rfind('\n').map_or(0, |pos| pos + 1);
+
+        let mut fold = FoldRegion::new(
+            FoldType::DocComment,
+            start_node.start_byte(),
+            end_byte,
+            start_node.start_position().row + 1,
+            start_node.start_position().row + text.lines().count(),
+            start_node.start_position().column,
+            end_byte - line_start,
+        );
+
+        fold.preview = Some(match config.preview_mode {
+            PreviewMode::Source => text.to_string(),
+            _ => {
+                let marker = if text.starts_with("//!") { "//!" } else { "///" };
+                let first = text
+                    .lines()
+                    .map(|l| l.trim().trim_start_matches(marker).trim())
+                    .find(|l| !l.is_empty())
+                    .unwrap_or("");
+                format!("{} {}...", marker, first)
+            }
+        });
+        Some(fold)
+    }
+
+    /// Extract control flow keywords from a function body
+    fn extract_control_flow(&self, body: &Node) -> Vec<String> {
+        let mut flow = Vec::new();
+        self.collect_control_flow_recursive(body, &mut flow);
+
+        // Deduplicate while preserving order
+        let mut seen = std::collections::HashSet::new();
+        flow.retain(|item| seen.insert(item.clone()));
+
+        flow
+    }
+
+    fn collect_control_flow_recursive(&self, node: &Node, flow: &mut Vec<String>) {
+        match node.kind() {
+            "if_expression" => flow.push("if".to_string()),
+            "match_expression" => flow.push("match".to_string()),
+            "for_expression" => flow.push("for".to_string()),
+            "while_expression" => flow.push("while".to_string()),
+            "loop_expression" => flow.push("loop".to_string()),
+            "return_expression" => flow.push("return".to_string()),
+            "try_expression" => flow.push("?".to_string()),
+            "await_expression" => flow.push("await".to_string()),
+            _ => {}
+        }
+
+        // Recurse into children (but don't go into closures or nested items)
+        if !matches!(
+            node.kind(),
+            "function_item" | "closure_expression" | "impl_item" | "mod_item"
+        ) {
+            let mut cursor = node.walk();
+            for child in node.children(&mut cursor) {
+                self.collect_control_flow_recursive(&child, flow);
+            }
+        }
+    }
+
+    fn generate_function_preview(
+        &self,
+        node: &Node,
+        body: &Node,
+        source: &str,
+        mode: PreviewMode,
+    ) -> String {
+        let signature = self.get_signature(node, body, source);
+        match mode {
+            PreviewMode::Minimal | PreviewMode::Names => signature,
+            PreviewMode::Flow | PreviewMode::Context => {
+                let flow = self.extract_control_flow(body);
+                if flow.is_empty() {
+                    signature
+                } else {
+                    format!("{} -> {}", signature, flow.join("/"))
+                }
+            }
+            PreviewMode::Source => self.get_node_text(node, source),
+        }
+    }
+
+    fn generate_macro_preview(
+        &self,
+        node: &Node,
+        body: &Node,
+        source: &str,
+        line_count: usize,
+        mode: PreviewMode,
+    ) -> String {
+        match mode {
+            PreviewMode::Source => self.get_node_text(node, source),
+            _ => {
+                let name = self.get_signature(node, body, source);
+                let text = self.get_node_text(body, source);
+                let open = text.chars().next().unwrap_or('(');
+                let close = text.chars().last().unwrap_or(')');
+                format!("{}{}...{} ({} lines)", name, open, close, line_count)
+            }
+        }
+    }
+
+    fn generate_struct_preview(
+        &self,
+        node: &Node,
+        body: &Node,
+        source: &str,
+        mode: PreviewMode,
+        closing_context: bool,
+    ) -> String {
+        if mode == PreviewMode::Source {
+            return self.get_node_text(node, source);
+        }
+
+        let name = self.get_signature(node, body, source);
+        let mut fields = Vec::new();
+        let mut cursor = body.walk();
+        for child in body.named_children(&mut cursor) {
+            let field = match child.kind() {
+                "field_initializer" => child.child_by_field_name("field"),
+                "shorthand_field_initializer" => child.named_child(0),
+                _ => None,
+            };
+            if let Some(field) = field {
+                fields.push(self.get_node_text(&field, source));
+            }
+        }
+
+        let preview = if mode == PreviewMode::Minimal || fields.is_empty() {
+            format!("{} {{...}}", name)
+        } else if fields.len() <= 5 {
+            format!("{} {{ {} }}", name, fields.join(", "))
+        } else {
+            format!("{} {{ {}, +{} more }}", name, fields[..4].join(", "), fields.len() - 4)
+        };
+
+        if closing_context {
+            append_child_count(preview, count_direct_children(body), "props")
+        } else {
+            preview
+        }
+    }
+}
+
+impl FoldParser for RustParser {
+    fn parse(&mut self, source: &str, config: &ScanConfig) -> Vec<FoldRegion> {
+        match self.parser.parse(source, None) {
+            Some(tree) => self.extract_folds(source, &tree, config),
+            None => vec![],
+        }
+    }
+
+    fn node_kinds(&mut self, source: &str) -> BTreeMap<String, usize> {
+        match self.parser.parse(source, None) {
+            Some(tree) => count_node_kinds(&tree),
+            None => BTreeMap::new(),
+        }
+    }
+
+    fn language(&self) -> Language {
+        Language::Rust
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn default_config() -> ScanConfig {
+        ScanConfig::default()
+            .with_min_fold_lines(2)
+            .with_fold_filter(crate::models::FoldFilter::all())
+    }
+
+    #[test]
+    fn test_impl_with_functions() {
+        let mut parser = RustParser::new().unwrap();
+        let source = r#"
+pub struct Greeter {
+    name: String,
+    excited: bool,
+}
+
+impl Greeter {
+    pub fn hello(&self) -> String {
+        let greeting = format!("Hello, {}", self.name);
+        greeting
+    }
+
+    fn count(&self) -> usize {
+        let total = 0;
+        total
+    }
+}
+"#;
+        let folds = parser.parse(source, &default_config());
+
+        let class_folds: Vec<_> = folds.iter().filter(|f| f.fold_type == FoldType::ClassBody).collect();
+        assert_eq!(class_folds.len(), 2);
+        assert_eq!(class_folds[0].preview.as_deref(), Some("pub struct Greeter"));
+        assert_eq!(class_folds[1].preview.as_deref(), Some("impl Greeter"));
+
+        let fn_folds: Vec<_> = folds.iter().filter(|f| f.fold_type == FoldType::Block).collect();
+        assert_eq!(fn_folds.len(), 2);
+        assert!(fn_folds.iter().all(|f| f.depth == 1));
+        assert_eq!(fn_folds[0].preview.as_deref(), Some("pub fn hello(&self) -> String"));
+    }
+
+    #[test]
+    fn test_use_block_and_macro_folds() {
+        let mut parser = RustParser::new().unwrap();
+        let source = r#"use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+fn defaults() -> Vec<u32> {
+    vec![
+        1,
+        2,
+    ]
+}
+"#;
+        let config = default_config().with_preview_mode(PreviewMode::Names);
+        let folds = parser.parse(source, &config);
+
+        let imports: Vec<_> = folds.iter().filter(|f| f.fold_type == FoldType::Import).collect();
+        assert_eq!(imports.len(), 1);
+        assert_eq!(imports[0].start_line, 1);
+        assert_eq!(imports[0].end_line, 3);
+        assert_eq!(
+            imports[0].preview.as_deref(),
+            Some("std::collections::HashMap, std::fs, std::path::{Path, PathBuf}")
+        );
+
+        let arrays: Vec<_> = folds.iter().filter(|f| f.fold_type == FoldType::ArrayLiteral).collect();
+        assert_eq!(arrays.len(), 1);
+        assert_eq!(arrays[0].preview.as_deref(), Some("vec![...] (4 lines)"));
+    }
+
+    #[test]
+    fn test_iterator_chain() {
+        let mut parser = RustParser::new().unwrap();
+        let source = r#"
+fn evens(items: &[u32]) -> Vec<u32> {
+    items
+        .iter()
+        .map(|x| x * 2)
+        .filter(|x| x % 4 == 0)
+        .collect()
+}
+"#;
+        let folds = parser.parse(source, &default_config());
+
+        let chains: Vec<_> = folds.iter().filter(|f| f.fold_type == FoldType::ChainedCall).collect();
+        assert_eq!(chains.len(), 1);
+        assert_eq!(chains[0].preview.as_deref(), Some("...chain (4 calls)"));
+        assert_eq!(chains[0].start_line, 3);
+    }
+}
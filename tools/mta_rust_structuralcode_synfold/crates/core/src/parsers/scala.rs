@@ -0,0 +1,433 @@
+use crate::config::ScanConfig;
+use crate::models::{FoldRegion, FoldType, Language, PreviewMode};
+use tree_sitter::{Node, Parser};
+
+use super::{
+    append_child_count, assign_fold_depths, count_direct_children, count_node_kinds,
+    mark_blank_lines, merge_adjacent_folds, record_discovery_order, FoldParser, ParserError,
+};
+use std::collections::BTreeMap;
+
+pub struct ScalaParser {
+    parser: Parser,
+}
+
+impl ScalaParser {
+    pub fn new() -> Result<Self, ParserError> {
+        let mut parser = Parser::new();
+        parser
+            .set_language(&tree_sitter_scala::LANGUAGE.into())
+            .map_err(|e| ParserError::InitError(e.to_string()))?;
+
+        Ok(Self { parser })
+    }
+
+    /// Extract fold regions from the parse tree
+    fn extract_folds(
+        &self,
+        source: &str,
+        tree: &tree_sitter::Tree,
+        config: &ScanConfig,
+    ) -> Vec<FoldRegion> {
+        let mut folds = Vec::new();
+        let root = tree.root_node();
+
+        self.traverse_node(&root, source, &mut folds, config);
+
+        record_discovery_order(&mut folds);
+
+        // Sort by start position and filter by min_fold_lines
+        folds.sort_by_key(|f| (f.start_byte, -(f.end_byte as i64)));
+
+        // Apply min_fold_lines filter for block-type folds
+        let min_fold_lines = config.min_fold_lines_for(&self.language());
+        let mut folds: Vec<FoldRegion> = folds
+            .into_iter()
+            .filter(|f| match f.fold_type {
+                FoldType::Block | FoldType::ClassBody => f.line_count >= min_fold_lines,
+                FoldType::Import => f.line_count >= 2,
+                FoldType::Comment => f.line_count >= config.min_doc_lines.unwrap_or(1),
+                FoldType::DocComment => f.line_count >= config.min_doc_lines.unwrap_or(2),
+                _ => true,
+            })
+            .collect();
+
+        assign_fold_depths(&mut folds);
+        let mut folds = merge_adjacent_folds(folds, config);
+        mark_blank_lines(&mut folds, source);
+        folds
+    }
+
+    fn traverse_node(
+        &self,
+        node: &Node,
+        source: &str,
+        folds: &mut Vec<FoldRegion>,
+        config: &ScanConfig,
+    ) {
+        let kind = node.kind();
+
+        match kind {
+            // Method bodies (`def f(x: Int): Int = { ... }` or an indented Scala 3 block);
+            // abstract `function_declaration`s have no body, only their parameters fold
+            "function_definition" => {
+                if config.fold_filter.fold_blocks {
+                    if let Some(body) = node.child_by_field_name("body") {
+                        if let Some(mut f) = self.create_fold(&body, FoldType::Block) {
+                            f.preview = Some(self.generate_function_preview(
+                                node,
+                                &body,
+                                source,
+                                config.preview_mode,
+                            ));
+                            folds.push(f);
+                        }
+                    }
+                }
+            }
+
+            // Type bodies: classes, singleton objects and traits
+            "class_definition" | "object_definition" | "trait_definition" => {
+                if config.fold_filter.fold_classes {
+                    if let Some(body) = node.child_by_field_name("body") {
+                        if let Some(mut f) = self.create_fold(&body, FoldType::ClassBody) {
+                            let signature = self.get_signature(node, &body, source);
+                            f.preview = Some(match config.preview_mode {
+                                PreviewMode::Source => self.get_node_text(&body, source),
+                                _ if config.closing_context => append_child_count(
+                                    signature,
+                                    count_direct_children(&body),
+                                    "members",
+                                ),
+                                _ => signature,
+                            });
+                            folds.push(f);
+                        }
+                    }
+                }
+            }
+
+            // Import statements (consecutive block)
+            "import_declaration" => {
+                if config.fold_filter.fold_imports && !self.follows_import(node) {
+                    if let Some(f) = self.collect_import_block(node, source, config) {
+                        folds.push(f);
+                    }
+                }
+            }
+
+            // Parameter lists spanning several lines
+            "parameters" | "class_parameters" => {
+                if config.fold_filter.fold_arglists
+                    && node.end_position().row > node.start_position().row
+                {
+                    if let Some(f) = self.create_fold(node, FoldType::ArgList) {
+                        folds.push(f);
+                    }
+                }
+            }
+
+            // `/** ... */` folds as Scaladoc, other block comments as plain comments
+            "block_comment" => {
+                if node.end_position().row > node.start_position().row {
+                    let is_doc = self.get_node_text(node, source).starts_with("/**");
+                    let enabled = if is_doc {
+                        config.fold_filter.fold_docs
+                    } else {
+                        config.fold_filter.fold_comments
+                    };
+                    if enabled {
+                        let fold_type = if is_doc {
+                            FoldType::DocComment
+                        } else {
+                            FoldType::Comment
+                        };
+                        if let Some(mut f) = self.create_fold(node, fold_type) {
+                            if is_doc && config.preview_mode != PreviewMode::Source {
+                                f.preview = Some(self.generate_doc_preview(node, source));
+                            }
+                            folds.push(f);
+                        }
+                    }
+                }
+            }
+
+            _ => {}
+        }
+
+        // Recurse into children
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            self.traverse_node(&child, source, folds, config);
+        }
+    }
+
+    fn create_fold(&self, node: &Node, fold_type: FoldType) -> Option<FoldRegion> {
+        Some(FoldRegion::new(
+            fold_type,
+            node.start_byte(),
+            node.end_byte(),
+            node.start_position().row + 1,
+            node.end_position().row + 1,
+            node.start_position().column,
+            node.end_position().column,
+        ))
+    }
+
+    fn get_node_text(&self, node: &Node, source: &str) -> String {
+        source[node.byte_range()].to_string()
+    }
+
+    /// Definition text from its start up to the body, collapsed to one line
+    ///
+    /// Drops the `=` before a method body and the `:` opening a Scala 3 indented body.
+    fn get_signature(&self, node: &Node, body: &Node, source: &str) -> String {
+        source[node.start_byte()..body.start_byte()]
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+            .trim_end_matches(['=', ':'])
+            .trim_end()
+            .to_string()
+    }
+
+    /// Whether the previous sibling (ignoring comments) is also an import
+    fn follows_import(&self, node: &Node) -> bool {
+        let mut prev = node.prev_sibling();
+        while let Some(ps) = prev {
+            match ps.kind() {
+                "import_declaration" => return true,
+                "comment" | "block_comment" => prev = ps.prev_sibling(),
+                _ => return false,
+            }
+        }
+        false
+    }
+
+    fn collect_import_block(
+        &self,
+        start_node: &Node,
+        source: &str,
+        config: &ScanConfig,
+    ) -> Option<FoldRegion> {
+        let mut end_node = *start_node;
+        let mut paths = vec![self.import_target(start_node, source)];
+
+        // Walk forward to find consecutive imports
+        let mut next = start_node.next_sibling();
+        while let Some(ns) = next {
+            match ns.kind() {
+                "import_declaration" => {
+                    paths.push(self.import_target(&ns, source));
+                    end_node = ns;
+                    next = ns.next_sibling();
+                }
+                "comment" | "block_comment" => next = ns.next_sibling(),
+                _ => break,
+            }
+        }
+
+        if paths.len() < 2 {
+            return None;
+        }
+
+        let mut fold = FoldRegion::new(
+            FoldType::Import,
+            start_node.start_byte(),
+            end_node.end_byte(),
+            start_node.start_position().row + 1,
+            end_node.end_position().row + 1,
+            start_node.start_position().column,
+            end_node.end_position().column,
+        );
+        fold.preview = Some(match config.preview_mode {
+            PreviewMode::Minimal => format!("{} imports", paths.len()),
+            PreviewMode::Names | PreviewMode::Flow | PreviewMode::Context => {
+                if paths.len() <= 5 {
+                    paths.join(", ")
+                } else {
+                    format!("{}, +{} more", paths[..4].join(", "), paths.len() - 4)
+                }
+            }
+            PreviewMode::Source => source[start_node.start_byte()..end_node.end_byte()].to_string(),
+        });
+        Some(fold)
+    }
+
+    /// Path named by an import (`import scala.util.{Try, Success}` -> `scala.util.{Try, Success}`)
+    fn import_target(&self, node: &Node, source: &str) -> String {
+        let text = self.get_node_text(node, source);
+        text.trim_start_matches("import")
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// First line of Scaladoc text (`/** Greets the user. */` -> `/** Greets the user....`)
+    fn generate_doc_preview(&self, node: &Node, source: &str) -> String {
+        let text = self.get_node_text(node, source);
+        let first = text
+            .lines()
+            .map(|l| {
+                l.trim()
+                    .trim_start_matches("/**")
+                    .trim_end_matches("*/")
+                    .trim_start_matches('*')
+                    .trim()
+            })
+            .find(|l| !l.is_empty())
+            .unwrap_or("");
+        format!("/** {}...", first)
+    }
+
+    /// Extract control flow keywords from a method body
+    fn extract_control_flow(&self, body: &Node) -> Vec<String> {
+        let mut flow = Vec::new();
+        self.collect_control_flow_recursive(body, &mut flow);
+
+        // Deduplicate while preserving order
+        let mut seen = std::collections::HashSet::new();
+        flow.retain(|item| seen.insert(item.clone()));
+
+        flow
+    }
+
+    fn collect_control_flow_recursive(&self, node: &Node, flow: &mut Vec<String>) {
+        match node.kind() {
+            "if_expression" => flow.push("if".to_string()),
+            "match_expression" => flow.push("match".to_string()),
+            "for_expression" => flow.push("for".to_string()),
+            "while_expression" => flow.push("while".to_string()),
+            "try_expression" => flow.push("try".to_string()),
+            "return_expression" => flow.push("return".to_string()),
+            "throw_expression" => flow.push("throw".to_string()),
+            _ => {}
+        }
+
+        // Recurse into children (but don't go into lambdas or nested definitions)
+        if !matches!(
+            node.kind(),
+            "function_definition"
+                | "lambda_expression"
+                | "class_definition"
+                | "object_definition"
+                | "trait_definition"
+        ) {
+            let mut cursor = node.walk();
+            for child in node.children(&mut cursor) {
+                self.collect_control_flow_recursive(&child, flow);
+            }
+        }
+    }
+
+    fn generate_function_preview(
+        &self,
+        node: &Node,
+        body: &Node,
+        source: &str,
+        mode: PreviewMode,
+    ) -> String {
+        let signature = self.get_signature(node, body, source);
+        match mode {
+            PreviewMode::Minimal | PreviewMode::Names => signature,
+            PreviewMode::Flow | PreviewMode::Context => {
+                let flow = self.extract_control_flow(body);
+                if flow.is_empty() {
+                    signature
+                } else {
+                    format!("{} -> {}", signature, flow.join("/"))
+                }
+            }
+            PreviewMode::Source => self.get_node_text(node, source),
+        }
+    }
+}
+
+impl FoldParser for ScalaParser {
+    fn parse(&mut self, source: &str, config: &ScanConfig) -> Vec<FoldRegion> {
+        match self.parser.parse(source, None) {
+            Some(tree) => self.extract_folds(source, &tree, config),
+            None => vec![],
+        }
+    }
+
+    fn node_kinds(&mut self, source: &str) -> BTreeMap<String, usize> {
+        match self.parser.parse(source, None) {
+            Some(tree) => count_node_kinds(&tree),
+            None => BTreeMap::new(),
+        }
+    }
+
+    fn language(&self) -> Language {
+        Language::Scala
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn default_config() -> ScanConfig {
+        ScanConfig::default()
+            .with_min_fold_lines(2)
+            .with_fold_filter(crate::models::FoldFilter::all())
+    }
+
+    #[test]
+    fn test_object_with_methods() {
+        let mut parser = ScalaParser::new().unwrap();
+        let source = r#"
+object Greeter {
+  def hello(name: String): String = {
+    val greeting = s"Hello, $name"
+    greeting
+  }
+
+  def count(items: List[Int]): Int = {
+    val total = items.sum
+    total
+  }
+}
+"#;
+        let folds = parser.parse(source, &default_config());
+
+        let class_folds: Vec<_> = folds.iter().filter(|f| f.fold_type == FoldType::ClassBody).collect();
+        assert_eq!(class_folds.len(), 1);
+        assert_eq!(class_folds[0].preview.as_deref(), Some("object Greeter"));
+        assert_eq!(class_folds[0].start_line, 2);
+
+        let fn_folds: Vec<_> = folds.iter().filter(|f| f.fold_type == FoldType::Block).collect();
+        assert_eq!(fn_folds.len(), 2);
+        assert!(fn_folds.iter().all(|f| f.depth == 1));
+        assert_eq!(fn_folds[0].preview.as_deref(), Some("def hello(name: String): String"));
+        assert_eq!(fn_folds[1].start_line, 8);
+    }
+
+    #[test]
+    fn test_import_block() {
+        let mut parser = ScalaParser::new().unwrap();
+        let source = r#"import scala.collection.mutable
+import scala.util.{Try, Success}
+import java.time.Instant
+
+trait Clock {
+  def now(): Instant
+}
+"#;
+        let config = default_config().with_preview_mode(PreviewMode::Names);
+        let folds = parser.parse(source, &config);
+
+        let imports: Vec<_> = folds.iter().filter(|f| f.fold_type == FoldType::Import).collect();
+        assert_eq!(imports.len(), 1);
+        assert_eq!(imports[0].start_line, 1);
+        assert_eq!(imports[0].end_line, 3);
+        assert_eq!(
+            imports[0].preview.as_deref(),
+            Some("scala.collection.mutable, scala.util.{Try, Success}, java.time.Instant")
+        );
+
+        let traits: Vec<_> = folds.iter().filter(|f| f.fold_type == FoldType::ClassBody).collect();
+        assert_eq!(traits.len(), 1);
+        assert_eq!(traits[0].preview.as_deref(), Some("trait Clock"));
+    }
+}